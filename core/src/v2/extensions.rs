@@ -31,6 +31,15 @@ lazy_static! {
         prefer: false,
         builtin: true,
     });
+    /// Default coder for XML.
+    pub static ref XML_CODER: Arc<Coder> = Arc::new(Coder {
+        encoder_path: "serde_xml_rs::to_writer".into(),
+        decoder_path: "serde_xml_rs::from_reader".into(),
+        any_value: "serde_json::Value".into(),
+        error_path: "serde_xml_rs::Error".into(),
+        prefer: false,
+        builtin: true,
+    });
 }
 
 /// Wrapper for `mime::MediaRange` to support `BTree{Set, Map}`.
@@ -38,6 +47,18 @@ lazy_static! {
 pub struct MediaRange(pub mime::MediaRange);
 
 impl MediaRange {
+    /// Checks whether this is an XML media range (`*/xml` or `*/*+xml`).
+    pub fn is_xml(&self) -> bool {
+        let essence = self.0.as_ref().split(';').next().unwrap_or("").trim();
+        essence.ends_with("/xml") || essence.ends_with("+xml")
+    }
+
+    /// Checks whether this is the media range for server-sent events (`text/event-stream`).
+    pub fn is_event_stream(&self) -> bool {
+        let essence = self.0.as_ref().split(';').next().unwrap_or("").trim();
+        essence.eq_ignore_ascii_case("text/event-stream")
+    }
+
     /// Implementation from https://github.com/hyperium/mime/blob/65ea9c3d0cad4cb548b41124050c545120134035/src/range.rs#L155
     fn matches_params(&self, r: &Self) -> bool {
         for (name, value) in self.0.params() {
@@ -44,6 +44,7 @@ impl<S: Schema + Default> ResolvableApi<S> {
             paths: resolver.paths,
             base_path: self.base_path,
             host: self.host,
+            servers: self.servers,
             schemes: self.schemes,
             consumes: self.consumes,
             produces: self.produces,
@@ -1,7 +1,7 @@
 //! Models used by OpenAPI v2.
 
 pub use super::extensions::{
-    Coder, Coders, MediaRange, JSON_CODER, JSON_MIME, YAML_CODER, YAML_MIME,
+    Coder, Coders, MediaRange, JSON_CODER, JSON_MIME, XML_CODER, YAML_CODER, YAML_MIME,
 };
 
 use super::schema::Schema;
@@ -20,6 +20,10 @@ use std::fmt::{self, Display};
 use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
 
+/// Map used for paths in the spec, operations in a path and properties in a schema.
+/// This preserves the order in which the entries were specified.
+pub type SpecMap<K, V> = indexmap::IndexMap<K, V>;
+
 lazy_static! {
     /// Regex that can be used for fetching templated path parameters.
     static ref PATH_TEMPLATE_REGEX: Regex = Regex::new(r"\{(.*?)\}").expect("path template regex");
@@ -59,23 +63,43 @@ impl DataType {
     }
 }
 
-/// Supported data type formats.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq)]
-#[serde(rename_all = "lowercase")]
+/// Supported data type formats. Unknown formats are retained as `Other`
+/// (which is why this isn't `Copy`).
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(from = "String", into = "String")]
 pub enum DataTypeFormat {
     Int32,
     Int64,
     Float,
     Double,
+    Decimal,
     Byte,
     Binary,
     Date,
-    #[serde(rename = "date-time")]
     DateTime,
     Password,
     Uuid,
-    #[serde(other)]
-    Other,
+    Other(String),
+}
+
+impl DataTypeFormat {
+    /// Returns the name of this format in the spec.
+    pub fn as_str(&self) -> &str {
+        match self {
+            DataTypeFormat::Int32 => "int32",
+            DataTypeFormat::Int64 => "int64",
+            DataTypeFormat::Float => "float",
+            DataTypeFormat::Double => "double",
+            DataTypeFormat::Decimal => "decimal",
+            DataTypeFormat::Byte => "byte",
+            DataTypeFormat::Binary => "binary",
+            DataTypeFormat::Date => "date",
+            DataTypeFormat::DateTime => "date-time",
+            DataTypeFormat::Password => "password",
+            DataTypeFormat::Uuid => "uuid",
+            DataTypeFormat::Other(s) => s,
+        }
+    }
 }
 
 /// OpenAPI v2 spec which can be traversed and resolved for codegen.
@@ -92,11 +116,15 @@ pub struct Api<P, R, S> {
     pub swagger: Version,
     #[serde(default = "BTreeMap::new")]
     pub definitions: BTreeMap<String, S>,
-    pub paths: BTreeMap<String, PathItem<P, R>>,
+    pub paths: SpecMap<String, PathItem<P, R>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub host: Option<String>,
     #[serde(rename = "basePath", skip_serializing_if = "Option::is_none")]
     pub base_path: Option<String>,
+    /// Server URL templates (from OpenAPI v3). If specified, then the first
+    /// server is preferred over `host` and `basePath`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub servers: Vec<Server>,
     #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
     pub consumes: BTreeSet<MediaRange>,
     #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
@@ -209,6 +237,41 @@ pub struct Info {
     pub license: Option<License>,
 }
 
+/// Server object (from OpenAPI v3).
+///
+/// https://github.com/OAI/OpenAPI-Specification/blob/master/versions/3.0.2.md#serverObject
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Server {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub variables: BTreeMap<String, ServerVariable>,
+}
+
+/// Server variable object (from OpenAPI v3).
+///
+/// https://github.com/OAI/OpenAPI-Specification/blob/master/versions/3.0.2.md#serverVariableObject
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ServerVariable {
+    #[serde(default, rename = "enum", skip_serializing_if = "Vec::is_empty")]
+    pub enum_: Vec<String>,
+    pub default: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Server {
+    /// Returns the URL of this server with the default values substituted for variables.
+    pub fn default_url(&self) -> String {
+        self.variables
+            .iter()
+            .fold(self.url.clone(), |url, (name, var)| {
+                url.replace(&format!("{{{}}}", name), &var.default)
+            })
+    }
+}
+
 /// Contact object.
 ///
 /// https://github.com/OAI/OpenAPI-Specification/blob/master/versions/2.0.md#contactObject
@@ -279,6 +342,23 @@ pub struct ExternalDocs {
     pub url: String,
 }
 
+/// XML object for fine-tuning the XML representation of schemas.
+///
+/// https://github.com/OAI/OpenAPI-Specification/blob/master/versions/2.0.md#xml-object
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Xml {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attribute: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wrapped: Option<bool>,
+}
+
 /// Path item that can be traversed and resolved for codegen.
 pub type ResolvablePathItem<S> = PathItem<ResolvableParameter<S>, ResolvableResponse<S>>;
 
@@ -290,8 +370,8 @@ pub type DefaultPathItemRaw = PathItem<DefaultParameterRaw, DefaultResponseRaw>;
 /// https://github.com/OAI/OpenAPI-Specification/blob/master/versions/2.0.md#pathItemObject
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct PathItem<P, R> {
-    #[serde(flatten, default = "BTreeMap::default")]
-    pub methods: BTreeMap<HttpMethod, Operation<P, R>>,
+    #[serde(flatten, default = "SpecMap::default")]
+    pub methods: SpecMap<HttpMethod, Operation<P, R>>,
     #[serde(default = "Vec::default", skip_serializing_if = "Vec::is_empty")]
     pub parameters: Vec<Either<Reference, P>>,
 }
@@ -376,6 +456,12 @@ pub struct Parameter<S> {
     pub items: Option<Items>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub collection_format: Option<CollectionFormat>,
+    #[serde(rename = "x-delimiter", skip_serializing_if = "Option::is_none")]
+    pub delimiter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ParameterStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explode: Option<bool>,
     #[serde(default, skip_serializing_if = "is_false")]
     pub allow_empty_value: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -404,6 +490,8 @@ pub struct Parameter<S> {
     pub multiple_of: Option<f32>,
     #[serde(default, rename = "enum", skip_serializing_if = "Vec::is_empty")]
     pub enum_: Vec<serde_json::Value>,
+    #[serde(rename = "x-rust-type", skip_serializing_if = "Option::is_none")]
+    pub rust_type: Option<String>,
 }
 
 /// Items object.
@@ -420,6 +508,8 @@ pub struct Items {
     pub items: Option<Box<Items>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub collection_format: Option<CollectionFormat>,
+    #[serde(rename = "x-delimiter", skip_serializing_if = "Option::is_none")]
+    pub delimiter: Option<String>,
     #[serde(default, rename = "enum", skip_serializing_if = "Vec::is_empty")]
     pub enum_: Vec<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -444,6 +534,40 @@ pub struct Items {
     pub unique_items: Option<bool>,
     #[serde(rename = "multipleOf", skip_serializing_if = "Option::is_none")]
     pub multiple_of: Option<f32>,
+    #[serde(rename = "x-rust-type", skip_serializing_if = "Option::is_none")]
+    pub rust_type: Option<String>,
+}
+
+impl<S> Parameter<S> {
+    /// Returns the collection format for this parameter (if any). Custom delimiter
+    /// (`x-delimiter`) takes precedence over `collectionFormat`. If both are unspecified,
+    /// then this falls back to the format equivalent to the OpenAPI v3 `style` and
+    /// `explode` fields.
+    pub fn effective_collection_format(&self) -> Option<CollectionFormat> {
+        self.delimiter
+            .clone()
+            .map(CollectionFormat::Custom)
+            .or_else(|| self.collection_format.clone())
+            .or_else(|| {
+                self.style
+                    .map(|s| s.collection_format(self.explode))
+                    .or_else(|| {
+                        self.explode
+                            .map(|e| ParameterStyle::Form.collection_format(Some(e)))
+                    })
+            })
+    }
+}
+
+impl Items {
+    /// Returns the collection format for these items (if any). Custom delimiter
+    /// (`x-delimiter`) takes precedence over `collectionFormat`.
+    pub fn effective_collection_format(&self) -> Option<CollectionFormat> {
+        self.delimiter
+            .clone()
+            .map(CollectionFormat::Custom)
+            .or_else(|| self.collection_format.clone())
+    }
 }
 
 impl<S> Parameter<Resolvable<S>>
@@ -528,7 +652,7 @@ where
 }
 
 /// The location of the parameter.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Ord, PartialOrd)]
 #[serde(rename_all = "camelCase")]
 pub enum ParameterIn {
     Query,
@@ -539,7 +663,7 @@ pub enum ParameterIn {
 }
 
 /// Possible formats for array values in parameter.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
 #[serde(rename_all = "lowercase")]
 pub enum CollectionFormat {
     Csv,
@@ -547,6 +671,41 @@ pub enum CollectionFormat {
     Tsv,
     Pipes,
     Multi,
+    /// Custom delimiter (from the `x-delimiter` extension in parameters and items).
+    ///
+    /// **NOTE:** This is never (de)serialized as `collectionFormat`. Values containing
+    /// the delimiter aren't escaped.
+    #[serde(skip)]
+    Custom(String),
+}
+
+/// Serialization styles for (array) values in parameters (from OpenAPI v3).
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
+#[serde(rename_all = "camelCase")]
+pub enum ParameterStyle {
+    Form,
+    Simple,
+    SpaceDelimited,
+    PipeDelimited,
+}
+
+impl ParameterStyle {
+    /// Returns the collection format equivalent to this style. If `explode` is
+    /// unspecified, then it defaults to `true` for `form` and `false` for others.
+    ///
+    /// **NOTE:** Exploded arrays always result in multiple instances, because
+    /// delimiters don't apply when the values are exploded (except for `simple`
+    /// where it's always comma-separated).
+    pub fn collection_format(self, explode: Option<bool>) -> CollectionFormat {
+        let explode = explode.unwrap_or(self == ParameterStyle::Form);
+        match self {
+            ParameterStyle::Simple => CollectionFormat::Csv,
+            _ if explode => CollectionFormat::Multi,
+            ParameterStyle::Form => CollectionFormat::Csv,
+            ParameterStyle::SpaceDelimited => CollectionFormat::Ssv,
+            ParameterStyle::PipeDelimited => CollectionFormat::Pipes,
+        }
+    }
 }
 
 /// Operation that can be traversed and resolved for codegen.
@@ -565,6 +724,8 @@ pub struct Operation<P, R> {
     pub operation_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
     // *NOTE:* `consumes` and `produces` are optional, because
     // local media ranges can be used to override global media ranges
     // (including setting it to empty), so we cannot go for an empty set.
@@ -651,7 +812,7 @@ pub struct Response<S> {
 }
 
 /// The HTTP method used for an operation.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq, Hash, Ord, PartialOrd)]
 #[serde(rename_all = "lowercase")]
 pub enum HttpMethod {
     Get,
@@ -757,6 +918,55 @@ where
             Resolvable::Resolved { ref old, .. } => old.read().description().map(String::from),
         }
     }
+
+    /// Fetch the custom serde attributes for the field using this schema.
+    pub fn get_serde_attrs(&self) -> Vec<String> {
+        match *self {
+            Resolvable::Raw(ref s) => s.read().serde_attrs().to_vec(),
+            // Attributes belong to the field and not the referenced object.
+            Resolvable::Resolved { ref old, .. } => old.read().serde_attrs().to_vec(),
+        }
+    }
+
+    /// Fetch whether the field using this schema is deprecated.
+    pub fn get_deprecated(&self) -> bool {
+        match *self {
+            Resolvable::Raw(ref s) => s.read().is_deprecated(),
+            Resolvable::Resolved { ref old, .. } => old.read().is_deprecated(),
+        }
+    }
+
+    /// Fetch the external docs for the field using this schema.
+    pub fn get_external_docs(&self) -> Option<ExternalDocs> {
+        match *self {
+            Resolvable::Raw(ref s) => s.read().external_docs().cloned(),
+            Resolvable::Resolved { ref old, .. } => old.read().external_docs().cloned(),
+        }
+    }
+
+    /// Fetch the example value for the field using this schema.
+    pub fn get_example(&self) -> Option<serde_json::Value> {
+        match *self {
+            Resolvable::Raw(ref s) => s.read().example().cloned(),
+            Resolvable::Resolved { ref old, .. } => old.read().example().cloned(),
+        }
+    }
+
+    /// Fetch the XML hints for the field using this schema.
+    pub fn get_xml(&self) -> Option<Xml> {
+        match *self {
+            Resolvable::Raw(ref s) => s.read().xml().cloned(),
+            Resolvable::Resolved { ref old, .. } => old.read().xml().cloned(),
+        }
+    }
+
+    /// Fetch the `not` schema for the field using this schema.
+    pub fn get_not_schema(&self) -> Option<serde_json::Value> {
+        match *self {
+            Resolvable::Raw(ref s) => s.read().not_schema().cloned(),
+            Resolvable::Resolved { ref old, .. } => old.read().not_schema().cloned(),
+        }
+    }
 }
 
 /* Common trait impls */
@@ -852,6 +1062,34 @@ impl Display for HttpMethod {
     }
 }
 
+impl From<String> for DataTypeFormat {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "int32" => DataTypeFormat::Int32,
+            "int64" => DataTypeFormat::Int64,
+            "float" => DataTypeFormat::Float,
+            "double" => DataTypeFormat::Double,
+            "decimal" => DataTypeFormat::Decimal,
+            "byte" => DataTypeFormat::Byte,
+            "binary" => DataTypeFormat::Binary,
+            "date" => DataTypeFormat::Date,
+            "date-time" => DataTypeFormat::DateTime,
+            "password" => DataTypeFormat::Password,
+            "uuid" => DataTypeFormat::Uuid,
+            _ => DataTypeFormat::Other(s),
+        }
+    }
+}
+
+impl From<DataTypeFormat> for String {
+    fn from(f: DataTypeFormat) -> Self {
+        match f {
+            DataTypeFormat::Other(s) => s,
+            f => f.as_str().into(),
+        }
+    }
+}
+
 impl Default for Version {
     fn default() -> Self {
         Version::V2
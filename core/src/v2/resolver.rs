@@ -1,7 +1,7 @@
 use super::{
     models::{
         Either, HttpMethod, Reference, Resolvable, ResolvableParameter, ResolvablePathItem,
-        ResolvableResponse,
+        ResolvableResponse, SpecMap,
     },
     Schema,
 };
@@ -19,7 +19,7 @@ const PARAM_REF_PREFIX: &str = "#/parameters/";
 const RESP_REF_PREFIX: &str = "#/responses/";
 
 type DefinitionsMap<S> = BTreeMap<String, Resolvable<S>>;
-type OperationsMap<S> = BTreeMap<String, ResolvablePathItem<S>>;
+type OperationsMap<S> = SpecMap<String, ResolvablePathItem<S>>;
 type ParametersMap<S> = BTreeMap<String, ResolvableParameter<S>>;
 type ResponsesMap<S> = BTreeMap<String, ResolvableResponse<S>>;
 
@@ -81,7 +81,7 @@ where
         // Resolve path operations first. We may encounter anonymous
         // definitions along the way, which we'll insert into `self.defs`
         // and we'll have to resolve them anyway.
-        let mut paths = mem::replace(&mut self.paths, BTreeMap::new());
+        let mut paths = mem::replace(&mut self.paths, SpecMap::new());
         paths.iter_mut().try_for_each(|(path, map)| {
             trace!("Checking path: {}", path);
 
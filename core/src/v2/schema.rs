@@ -1,7 +1,8 @@
 //! Traits used for code and spec generation.
 
 use super::models::{
-    DataType, DataTypeFormat, DefaultOperationRaw, DefaultSchemaRaw, Either, Resolvable,
+    DataType, DataTypeFormat, DefaultOperationRaw, DefaultSchemaRaw, Either, ExternalDocs,
+    Resolvable, SpecMap, Xml,
 };
 
 use std::collections::{BTreeMap, BTreeSet};
@@ -13,6 +14,9 @@ use std::collections::{BTreeMap, BTreeSet};
 /// **NOTE:** Don't implement this by yourself! Please use the `#[api_v2_schema]`
 /// proc macro attribute instead.
 pub trait Schema: Sized {
+    /// Title for this schema, if any (`title` field).
+    fn title(&self) -> Option<&str>;
+
     /// Description for this schema, if any (`description` field).
     fn description(&self) -> Option<&str>;
 
@@ -37,15 +41,43 @@ pub trait Schema: Sized {
     /// Mutable access to `additional_properties` field, if it's a map.
     fn additional_properties_mut(&mut self) -> Option<&mut Either<bool, Resolvable<Self>>>;
 
-    /// Map of names and schema for properties, if it's an object (`properties` field)
-    fn properties(&self) -> Option<&BTreeMap<String, Resolvable<Self>>>;
+    /// Map of names and schema for properties, if it's an object (`properties` field).
+    /// With `preserve-order` feature, properties are in the order in which they were specified.
+    fn properties(&self) -> Option<&SpecMap<String, Resolvable<Self>>>;
 
     /// Mutable access to `properties` field.
-    fn properties_mut(&mut self) -> Option<&mut BTreeMap<String, Resolvable<Self>>>;
+    fn properties_mut(&mut self) -> Option<&mut SpecMap<String, Resolvable<Self>>>;
 
     /// Returns the required properties (if any) for this object.
     fn required_properties(&self) -> Option<&BTreeSet<String>>;
 
+    /// Returns whether this schema can be explicitly set to `null` (`x-nullable` field).
+    fn is_nullable(&self) -> bool;
+
+    /// Returns whether this schema is only sent in requests and never
+    /// returned in responses (`writeOnly` field).
+    fn is_write_only(&self) -> bool;
+
+    /// Returns whether this schema is only returned in responses and
+    /// never sent in requests (`readOnly` field).
+    fn is_read_only(&self) -> bool;
+
+    /// Returns whether this schema is deprecated (`deprecated` field).
+    fn is_deprecated(&self) -> bool;
+
+    /// Additional external documentation for this schema (`externalDocs` field).
+    fn external_docs(&self) -> Option<&ExternalDocs>;
+
+    /// Example value for this schema (`example` field).
+    fn example(&self) -> Option<&serde_json::Value>;
+
+    /// XML representation hints for this schema (`xml` field).
+    fn xml(&self) -> Option<&Xml>;
+
+    /// Custom serde attributes for the field using this schema (`x-rust-serde` field).
+    /// This can either be a single attribute or a list of attributes.
+    fn serde_attrs(&self) -> &[String];
+
     /// Enum variants in this schema (if any). It's `serde_json::Value`
     /// because:
     ///
@@ -53,29 +85,26 @@ pub trait Schema: Sized {
     /// - `serde_json::Value` works for both JSON and YAML.
     fn enum_variants(&self) -> Option<&[serde_json::Value]>;
 
+    /// Descriptions for the enum variants (`x-enum-descriptions` field). These
+    /// are in the same order as the variants, and some of them may be missing.
+    fn enum_descriptions(&self) -> &[Option<String>];
+
+    /// Path of an existing Rust type to be used for this schema instead of
+    /// the generated type (`x-rust-type` field).
+    fn rust_type(&self) -> Option<&str>;
+
+    /// Returns whether this object is a mixin which is flattened into the
+    /// objects referencing it (`x-rust-flatten` field).
+    fn is_flattened(&self) -> bool;
+
+    /// Schema which the values shouldn't match (`not` field). This is kept
+    /// as a raw value, since only simple subschemas (`enum` and `const`)
+    /// are supported for validation.
+    fn not_schema(&self) -> Option<&serde_json::Value>;
+
     /// Returns whether this definition "is" or "has" `Any` type.
     fn contains_any(&self) -> bool {
-        if self.data_type().is_none() {
-            return true;
-        }
-
-        self.properties()
-            .map(|t| t.values().any(|s| s.read().contains_any()))
-            .unwrap_or(false)
-            || self
-                .items()
-                .map(|e| match e {
-                    Either::Left(s) => s.read().contains_any(),
-                    Either::Right(v) => v.iter().any(|s| s.read().contains_any()),
-                })
-                .unwrap_or(false)
-            || self
-                .additional_properties()
-                .map(|e| match e {
-                    Either::Left(extra_props_allowed) => *extra_props_allowed,
-                    Either::Right(s) => s.read().contains_any(),
-                })
-                .unwrap_or(false)
+        contains_any(self, &mut vec![])
     }
 
     /* MARK: Resolver-specific methods. */
@@ -102,6 +131,53 @@ pub trait Schema: Sized {
     fn set_name(&mut self, name: &str);
 }
 
+/// Checks whether the given definition "is" or "has" `Any` type. Named
+/// definitions are tracked, so that we don't recurse into cyclic ones forever.
+fn contains_any<S: Schema>(schema: &S, visiting: &mut Vec<String>) -> bool {
+    // Custom types are always known.
+    if schema.rust_type().is_some() {
+        return false;
+    }
+
+    if schema.data_type().is_none() {
+        return true;
+    }
+
+    let name = schema.name();
+    if let Some(n) = name {
+        if visiting.iter().any(|v| v == n) {
+            return false;
+        }
+
+        visiting.push(n.into());
+    }
+
+    let has_any = schema
+        .properties()
+        .map(|t| t.values().any(|s| contains_any(&*s.read(), visiting)))
+        .unwrap_or(false)
+        || schema
+            .items()
+            .map(|e| match e {
+                Either::Left(s) => contains_any(&*s.read(), visiting),
+                Either::Right(v) => v.iter().any(|s| contains_any(&*s.read(), visiting)),
+            })
+            .unwrap_or(false)
+        || schema
+            .additional_properties()
+            .map(|e| match e {
+                Either::Left(extra_props_allowed) => *extra_props_allowed,
+                Either::Right(s) => contains_any(&*s.read(), visiting),
+            })
+            .unwrap_or(false);
+
+    if name.is_some() {
+        visiting.pop();
+    }
+
+    has_any
+}
+
 /// Trait for returning OpenAPI data type and format for the implementor.
 pub trait TypedData {
     /// The OpenAPI type for this implementor.
@@ -178,6 +254,13 @@ impl_type_simple!(
 #[cfg(feature = "uid")]
 impl_type_simple!(uuid::Uuid, DataType::String, DataTypeFormat::Uuid);
 
+#[cfg(feature = "decimal")]
+impl_type_simple!(
+    rust_decimal::Decimal,
+    DataType::String,
+    DataTypeFormat::Decimal
+);
+
 /// Represents a OpenAPI v2 schema convertible. This is auto-implemented by
 /// [`api_v2_schema`](https://paperclip.waffles.space/paperclip_actix_macros/attr.api_v2_schema.html) macro.
 ///
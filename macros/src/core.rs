@@ -111,6 +111,11 @@ pub fn emit_v2_schema_struct(input: TokenStream) -> TokenStream {
                 self.cyclic
             }
 
+            #[inline]
+            fn title(&self) -> Option<&str> {
+                self.title.as_ref().map(String::as_str)
+            }
+
             #[inline]
             fn description(&self) -> Option<&str> {
                 self.description.as_ref().map(String::as_str)
@@ -157,7 +162,7 @@ pub fn emit_v2_schema_struct(input: TokenStream) -> TokenStream {
             }
 
             #[inline]
-            fn properties(&self) -> Option<&std::collections::BTreeMap<String, paperclip::v2::models::Resolvable<Self>>> {
+            fn properties(&self) -> Option<&paperclip::v2::models::SpecMap<String, paperclip::v2::models::Resolvable<Self>>> {
                 if self.properties.is_empty() {
                     None
                 } else {
@@ -166,7 +171,7 @@ pub fn emit_v2_schema_struct(input: TokenStream) -> TokenStream {
             }
 
             #[inline]
-            fn properties_mut(&mut self) -> Option<&mut std::collections::BTreeMap<String, paperclip::v2::models::Resolvable<Self>>> {
+            fn properties_mut(&mut self) -> Option<&mut paperclip::v2::models::SpecMap<String, paperclip::v2::models::Resolvable<Self>>> {
                 if self.properties.is_empty() {
                     None
                 } else {
@@ -183,6 +188,50 @@ pub fn emit_v2_schema_struct(input: TokenStream) -> TokenStream {
                 }
             }
 
+            #[inline]
+            fn is_nullable(&self) -> bool {
+                self.nullable.unwrap_or(false)
+            }
+
+            #[inline]
+            fn is_write_only(&self) -> bool {
+                self.write_only.unwrap_or(false)
+            }
+
+            #[inline]
+            fn is_read_only(&self) -> bool {
+                self.read_only.unwrap_or(false)
+            }
+
+            #[inline]
+            fn is_deprecated(&self) -> bool {
+                self.deprecated.unwrap_or(false)
+            }
+
+            #[inline]
+            fn external_docs(&self) -> Option<&paperclip::v2::models::ExternalDocs> {
+                self.external_docs.as_ref()
+            }
+
+            #[inline]
+            fn example(&self) -> Option<&serde_json::Value> {
+                self.example.as_ref()
+            }
+
+            #[inline]
+            fn xml(&self) -> Option<&paperclip::v2::models::Xml> {
+                self.xml.as_ref()
+            }
+
+            #[inline]
+            fn serde_attrs(&self) -> &[String] {
+                match self.serde_attrs.as_ref() {
+                    Some(paperclip::v2::models::Either::Left(a)) => std::slice::from_ref(a),
+                    Some(paperclip::v2::models::Either::Right(v)) => v,
+                    None => &[],
+                }
+            }
+
             #[inline]
             fn enum_variants(&self) -> Option<&[serde_json::Value]> {
                 if self.enum_.is_empty() {
@@ -191,6 +240,26 @@ pub fn emit_v2_schema_struct(input: TokenStream) -> TokenStream {
                     Some(&self.enum_)
                 }
             }
+
+            #[inline]
+            fn enum_descriptions(&self) -> &[Option<String>] {
+                &self.enum_descriptions
+            }
+
+            #[inline]
+            fn not_schema(&self) -> Option<&serde_json::Value> {
+                self.not.as_ref()
+            }
+
+            #[inline]
+            fn rust_type(&self) -> Option<&str> {
+                self.rust_type.as_ref().map(String::as_str)
+            }
+
+            #[inline]
+            fn is_flattened(&self) -> bool {
+                self.flatten.unwrap_or(false)
+            }
         }
     });
 
@@ -295,8 +364,8 @@ fn schema_fields(name: &Ident, is_ref: bool) -> proc_macro2::TokenStream {
     ));
 
     gen.extend(quote!(
-        #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
-        pub properties: std::collections::BTreeMap<String,
+        #[serde(default, skip_serializing_if = "paperclip::v2::models::SpecMap::is_empty")]
+        pub properties: paperclip::v2::models::SpecMap<String,
     ));
     add_self(&mut gen);
     gen.extend(quote!(>,));
@@ -315,6 +384,11 @@ fn schema_fields(name: &Ident, is_ref: bool) -> proc_macro2::TokenStream {
         pub enum_: Vec<serde_json::Value>,
     ));
 
+    gen.extend(quote!(
+        #[serde(default, rename = "x-enum-descriptions", skip_serializing_if = "Vec::is_empty")]
+        pub enum_descriptions: Vec<Option<String>>,
+    ));
+
     gen.extend(quote!(
         #[serde(rename = "additionalProperties", skip_serializing_if = "Option::is_none")]
         pub extra_props: Option<paperclip::v2::models::Either<bool,
@@ -327,6 +401,61 @@ fn schema_fields(name: &Ident, is_ref: bool) -> proc_macro2::TokenStream {
         pub required: std::collections::BTreeSet<String>,
     ));
 
+    gen.extend(quote!(
+        #[serde(rename = "x-nullable", skip_serializing_if = "Option::is_none")]
+        pub nullable: Option<bool>,
+    ));
+
+    gen.extend(quote!(
+        #[serde(rename = "writeOnly", skip_serializing_if = "Option::is_none")]
+        pub write_only: Option<bool>,
+    ));
+
+    gen.extend(quote!(
+        #[serde(rename = "readOnly", skip_serializing_if = "Option::is_none")]
+        pub read_only: Option<bool>,
+    ));
+
+    gen.extend(quote!(
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub deprecated: Option<bool>,
+    ));
+
+    gen.extend(quote!(
+        #[serde(rename = "externalDocs", skip_serializing_if = "Option::is_none")]
+        pub external_docs: Option<paperclip::v2::models::ExternalDocs>,
+    ));
+
+    gen.extend(quote!(
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub example: Option<serde_json::Value>,
+    ));
+
+    gen.extend(quote!(
+        #[serde(rename = "x-rust-serde", skip_serializing_if = "Option::is_none")]
+        pub serde_attrs: Option<paperclip::v2::models::Either<String, Vec<String>>>,
+    ));
+
+    gen.extend(quote!(
+        #[serde(rename = "x-rust-type", skip_serializing_if = "Option::is_none")]
+        pub rust_type: Option<String>,
+    ));
+
+    gen.extend(quote!(
+        #[serde(rename = "x-rust-flatten", skip_serializing_if = "Option::is_none")]
+        pub flatten: Option<bool>,
+    ));
+
+    gen.extend(quote!(
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub xml: Option<paperclip::v2::models::Xml>,
+    ));
+
+    gen.extend(quote!(
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub not: Option<serde_json::Value>,
+    ));
+
     if is_ref {
         gen.extend(quote!(
             #[serde(skip)]
@@ -9,7 +9,7 @@ use actix_web::dev::{HttpServiceFactory, MessageBody, ServiceRequest, ServiceRes
 use actix_web::{web::HttpResponse, Error};
 use futures::IntoFuture;
 use paperclip_core::v2::models::{
-    DefaultApiRaw, DefaultOperationRaw, DefaultPathItemRaw, DefaultSchemaRaw, HttpMethod,
+    DefaultApiRaw, DefaultOperationRaw, DefaultPathItemRaw, DefaultSchemaRaw, HttpMethod, SpecMap,
 };
 use parking_lot::RwLock;
 
@@ -58,7 +58,7 @@ pub trait Mountable {
     ///
     /// **NOTE:** Overriding implementations must ensure that the `PathItem`
     /// is normalized before updating the input map.
-    fn update_operations(&mut self, map: &mut BTreeMap<String, DefaultPathItemRaw>) {
+    fn update_operations(&mut self, map: &mut SpecMap<String, DefaultPathItemRaw>) {
         let op_map = map
             .entry(self.path().into())
             .or_insert_with(Default::default);
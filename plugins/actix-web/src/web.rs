@@ -15,7 +15,7 @@ use actix_web::guard::Guard;
 use actix_web::{http::Method, Error, FromRequest, Responder};
 use futures::future::IntoFuture;
 use paperclip_core::v2::models::{
-    DefaultOperationRaw, DefaultPathItemRaw, DefaultSchemaRaw, HttpMethod,
+    DefaultOperationRaw, DefaultPathItemRaw, DefaultSchemaRaw, HttpMethod, SpecMap,
 };
 use paperclip_core::v2::schema::Apiv2Operation;
 
@@ -278,7 +278,7 @@ pub fn resource(path: &str) -> Resource {
 /// Wrapper for [`actix_web::Scope`](https://docs.rs/actix-web/*/actix_web/struct.Scope.html)
 pub struct Scope<S = actix_web::Scope> {
     path: String,
-    path_map: BTreeMap<String, DefaultPathItemRaw>,
+    path_map: SpecMap<String, DefaultPathItemRaw>,
     definitions: BTreeMap<String, DefaultSchemaRaw>,
     inner: S,
 }
@@ -288,7 +288,7 @@ impl Scope {
     pub fn new(path: &str) -> Self {
         Scope {
             path: path.into(),
-            path_map: BTreeMap::new(),
+            path_map: SpecMap::new(),
             definitions: BTreeMap::new(),
             inner: actix_web::Scope::new(path),
         }
@@ -342,7 +342,7 @@ where
         F: FnOnce(&mut ServiceConfig<actix_web::Scope<T>>),
     {
         let mut cfg = ServiceConfig {
-            path_map: BTreeMap::new(),
+            path_map: SpecMap::new(),
             definitions: BTreeMap::new(),
             scope: Some(self.inner),
         };
@@ -463,7 +463,7 @@ where
         M: Mountable,
     {
         self.definitions.extend(factory.definitions().into_iter());
-        let mut path_map = BTreeMap::new();
+        let mut path_map = SpecMap::new();
         factory.update_operations(&mut path_map);
         for (path, map) in path_map {
             self.path_map.insert(self.path.clone() + &path, map);
@@ -484,8 +484,8 @@ impl<T> Mountable for Scope<T> {
         mem::replace(&mut self.definitions, BTreeMap::new())
     }
 
-    fn update_operations(&mut self, map: &mut BTreeMap<String, DefaultPathItemRaw>) {
-        *map = mem::replace(&mut self.path_map, BTreeMap::new());
+    fn update_operations(&mut self, map: &mut SpecMap<String, DefaultPathItemRaw>) {
+        *map = mem::replace(&mut self.path_map, SpecMap::new());
     }
 }
 
@@ -677,7 +677,7 @@ where
 
 /// Wrapper for [`actix_web::web::ServiceConfig`](https://docs.rs/actix-web/*/actix_web/web/struct.ServiceConfig.html).
 pub struct ServiceConfig<S = actix_web::Scope> {
-    path_map: BTreeMap<String, DefaultPathItemRaw>,
+    path_map: SpecMap<String, DefaultPathItemRaw>,
     definitions: BTreeMap<String, DefaultSchemaRaw>,
     scope: Option<S>,
 }
@@ -697,8 +697,8 @@ impl<T> Mountable for ServiceConfig<T> {
         mem::replace(&mut self.definitions, BTreeMap::new())
     }
 
-    fn update_operations(&mut self, map: &mut BTreeMap<String, DefaultPathItemRaw>) {
-        *map = mem::replace(&mut self.path_map, BTreeMap::new());
+    fn update_operations(&mut self, map: &mut SpecMap<String, DefaultPathItemRaw>) {
+        *map = mem::replace(&mut self.path_map, SpecMap::new());
     }
 }
 
@@ -61,6 +61,59 @@ struct Opt {
     /// Version (defaults to 0.1.0)
     #[structopt(long = "version")]
     pub version: Option<String>,
+    /// Keep the original names of object fields (when they're valid Rust identifiers).
+    #[structopt(long = "keep-field-names")]
+    keep_field_names: bool,
+    /// Additional traits to be derived for the generated objects (e.g., `--derive Eq`).
+    /// `Eq`, `Hash` and `Ord` are skipped for objects containing floats or `Any` types.
+    #[structopt(long = "derive")]
+    derives: Vec<String>,
+    /// Preserve the order of properties, paths and operations in the spec
+    /// (instead of sorting them) in the generated code.
+    #[structopt(long = "preserve-order")]
+    preserve_order: bool,
+    /// Fail (instead of warning) when function names cannot be derived for operations.
+    #[structopt(long = "strict")]
+    strict: bool,
+    /// Generate standalone structs for the query parameters of operations,
+    /// which can be passed to builders in one call.
+    #[structopt(long = "query-structs")]
+    query_structs: bool,
+    /// Generate a trait for each tag with methods for the operations having that tag
+    /// (implemented for all API clients). Untagged operations go to `DefaultApi` trait.
+    #[structopt(long = "tag-traits")]
+    tag_traits: bool,
+    /// Type substituted for `Any` when it's not specified explicitly (for example,
+    /// `serde_yaml::Value`). By default, it's the `Any` value type of the coder.
+    #[structopt(long = "any-value")]
+    any_value: Option<String>,
+    /// Generate `#[serde(transparent)]` newtypes for named definitions of simple
+    /// types (instead of using those types directly).
+    #[structopt(long = "newtypes")]
+    newtypes: bool,
+    /// Generate a `fixtures` module with `example` functions for objects,
+    /// which return the objects built from the (valid) examples in their schema.
+    #[structopt(long = "fixtures")]
+    fixtures: bool,
+    /// Map `date-time` and `date` formats of object fields to `time::OffsetDateTime`
+    /// and `time::Date` (instead of strings). The generated crate then depends on
+    /// `time` 0.3, which needs a recent stable Rust (at least 1.48).
+    #[structopt(long = "time-types")]
+    time_types: bool,
+    /// Prefix for the environment variables read by the generated `EnvClient::from_env`
+    /// (defaults to the spec title in upper snake case).
+    #[structopt(long = "env-prefix")]
+    env_prefix: Option<String>,
+    /// Generate a blocking client (in `client::blocking` module) along with the async one.
+    #[structopt(long = "blocking")]
+    blocking: bool,
+    /// Fail (instead of warning) when schemas or parameters have unknown formats.
+    #[structopt(long = "strict-formats")]
+    strict_formats: bool,
+    /// Custom formats which are mapped to their base types without any warnings
+    /// (e.g., `--known-format int-or-string`).
+    #[structopt(long = "known-format")]
+    known_formats: Vec<String>,
 }
 
 fn parse_args_and_run() -> Result<(), Error> {
@@ -71,6 +124,20 @@ fn parse_args_and_run() -> Result<(), Error> {
 
     let spec = opt.spec.resolve()?;
     let mut state = EmitterState::default();
+    state.keep_field_names = opt.keep_field_names;
+    state.derives = opt.derives;
+    state.preserve_order = opt.preserve_order;
+    state.strict = opt.strict;
+    state.query_structs = opt.query_structs;
+    state.tag_traits = opt.tag_traits;
+    state.any_value = opt.any_value;
+    state.newtypes = opt.newtypes;
+    state.fixtures = opt.fixtures;
+    state.time_types = opt.time_types;
+    state.env_prefix = opt.env_prefix;
+    state.blocking = opt.blocking;
+    state.strict_formats = opt.strict_formats;
+    state.known_formats = opt.known_formats;
 
     if let Some(o) = opt.output {
         fs::create_dir_all(&o)?;
@@ -20,6 +20,7 @@ fn main() {
         ("CLI_MAIN", "src/build/cli_main.hbs"),
         ("UTIL_MOD", "src/build/util_mod.hbs"),
         ("CLI_UTIL", "src/build/cli_util.hbs"),
+        ("OPERATIONS_MOD", "src/build/operations_mod.hbs"),
     ];
 
     let mut contents = String::from(
@@ -34,6 +34,12 @@ pub enum PaperClipError {
         _1, _0
     )]
     MissingParametersInPath(String, HashSet<String>),
+    /// Function names cannot be derived for some operations (in strict mode).
+    #[fail(display = "Cannot derive names for operation(s): {:?}", _0)]
+    UnreachableOperations(Vec<String>),
+    /// Unknown format for some schema or parameter (in strict mode).
+    #[fail(display = "Unknown format {:?} in {}", _0, _1)]
+    UnknownFormat(String, String),
     /// Invalid host for URL.
     #[fail(display = "Cannot parse host {:?}: {}", _0, _1)]
     InvalidHost(String, url::ParseError),
@@ -46,6 +52,12 @@ pub enum PaperClipError {
     /// The name for the given definition is missing or invalid.
     #[fail(display = "Invalid name for definition")]
     InvalidDefinitionName,
+    /// Two definitions have been mapped to the same path.
+    #[fail(
+        display = "Definitions {:?} and {:?} have been mapped to the same path: {:?}",
+        _0, _1, _2
+    )]
+    DefinitionPathCollision(String, String, PathBuf),
     /// A valid path cannot be obtained for the given definition.
     #[fail(display = "Invalid path for definition: {:?}", _0)]
     InvalidDefinitionPath(PathBuf),
@@ -1,20 +1,24 @@
-use super::object::{ApiObject, ObjectField, OpRequirement, Parameter, Response};
+use super::object::{
+    ApiObject, EnumKind, EnumVariant, ObjectField, OpRequirement, Parameter, Response,
+    StatusResponse,
+};
 use super::state::{ChildModule, EmitterState};
-use super::CrateMeta;
+use super::{CrateMeta, RUST_KEYWORDS};
 use crate::error::PaperClipError;
 use crate::v2::{
     im::ArcRwLock,
     models::{
         self, Coder, CollectionFormat, DataType, DataTypeFormat, Either, HttpMethod, Items,
         MediaRange, ParameterIn, Reference, Resolvable, ResolvableApi, ResolvableOperation,
-        ResolvableParameter, ResolvablePathItem, JSON_CODER, JSON_MIME, YAML_CODER, YAML_MIME,
+        ResolvableParameter, ResolvablePathItem, JSON_CODER, JSON_MIME, XML_CODER, YAML_CODER,
+        YAML_MIME,
     },
     Schema,
 };
 use failure::Error;
 use heck::{CamelCase, SnekCase};
 use itertools::Itertools;
-use std::collections::{BTreeSet, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fmt::Debug;
 use std::fs;
 use std::ops::Deref;
@@ -26,6 +30,12 @@ use url::Host;
 pub(super) const ANY_GENERIC_PARAMETER: &str = "Any";
 /// Identifier used for file types in schema. This will be replaced with `ResponseStream`.
 pub(super) const FILE_MARKER: &str = "--FILE--";
+/// Type used for `decimal` formats of strings (when the `decimal` feature is enabled).
+pub(super) const DECIMAL_TYPE: &str = "rust_decimal::Decimal";
+/// Type used for `date-time` formats (when `time` types are enabled in the state).
+pub(super) const OFFSET_DATE_TIME_TYPE: &str = "time::OffsetDateTime";
+/// Type used for `date` formats (when `time` types are enabled in the state).
+pub(super) const DATE_TYPE: &str = "time::Date";
 
 /// Some "thing" emitted by the emitter.
 #[derive(Debug)]
@@ -105,6 +115,7 @@ pub trait Emitter: Sized {
     fn state(&self) -> &EmitterState;
 
     /// Returns an iterator of path components for the given definition.
+    /// The components are relocated through `Emitter::remap_def`.
     ///
     /// **NOTE:** All components are [snake_cased](https://docs.rs/heck/*/heck/trait.SnekCase.html)
     /// (including the definition name).
@@ -113,28 +124,61 @@ pub trait Emitter: Sized {
         def: &'a Self::Definition,
     ) -> Result<Box<dyn Iterator<Item = String> + 'a>, Error> {
         let state = self.state();
-        def.name()
-            .map(|n| n.split(state.ns_sep).map(SnekCase::to_snek_case))
-            .ok_or_else(|| {
-                trace!("Missing name for definition: {:?}", def);
-                PaperClipError::InvalidDefinitionName.into()
-            })
-            .map(|i| Box::new(i) as Box<_>)
+        let name = def.name().ok_or_else(|| {
+            trace!("Missing name for definition: {:?}", def);
+            PaperClipError::InvalidDefinitionName
+        })?;
+
+        let components = name
+            .split(state.ns_sep)
+            .map(SnekCase::to_snek_case)
+            .collect();
+        let tags = state.def_tags.borrow();
+        let tags = tags.get(name).map(Vec::as_slice).unwrap_or_default();
+        let components = self.remap_def(def, components, tags);
+        if components.is_empty() {
+            return Err(PaperClipError::InvalidDefinitionName.into());
+        }
+
+        Ok(Box::new(components.into_iter().map(|c| c.to_snek_case())))
+    }
+
+    /// Relocates the given definition by returning its new path components
+    /// (modules followed by the type name). This gets the components derived
+    /// from the name of the definition and the tags of the operations using
+    /// this definition (in the order in which they're encountered in the spec).
+    ///
+    /// By default, definitions are not relocated. Definitions remapped to the
+    /// same path result in an error.
+    fn remap_def(
+        &self,
+        def: &Self::Definition,
+        components: Vec<String>,
+        tags: &[String],
+    ) -> Vec<String> {
+        let _ = (def, tags);
+        components
     }
 
     /// Returns the [CamelCase](https://docs.rs/heck/*/heck/trait.CamelCase.html)
-    /// name for the given definition.
+    /// name for the given definition. Its title is preferred (if it's a valid
+    /// identifier and if it doesn't collide with other types in its module).
     fn def_name(&self, def: &Self::Definition) -> Result<String, Error> {
-        Ok(self
-            .def_ns_name(def)?
+        let components = self.def_ns_name(def)?.collect::<Vec<_>>();
+        let derived = components
             .last()
             .map(|s| s.to_camel_case())
-            .expect("last item always exists for split?"))
+            .expect("last item always exists for split?");
+        let owner = format!("#/definitions/{}", def.name().unwrap_or_default());
+        Ok(self
+            .state()
+            .type_name(&components.join("::"), def.title(), derived, &owner))
     }
 
     /// Returns the [CamelCase](https://docs.rs/heck/*/heck/trait.CamelCase.html)
     /// name for some definition based on its parent names. This is called whenever
     /// a definition doesn't have a name (i.e., through `$ref`) and we have to generate it.
+    /// As with named definitions, the title is preferred (if it's usable).
     fn def_anon_name(&self, def: &Self::Definition, parents: &[&str]) -> Option<String> {
         let mut name = String::new();
         parents.iter().for_each(|s| {
@@ -146,7 +190,13 @@ pub trait Emitter: Sized {
             trace!("Unable to get name for anonymous schema: {:?}", def);
             None
         } else {
-            Some(name.to_camel_case())
+            // Anonymous objects are in the module of their first parent.
+            Some(self.state().type_name(
+                &parents[0].to_snek_case(),
+                def.title(),
+                name.to_camel_case(),
+                &parents.join("/"),
+            ))
         }
     }
 
@@ -236,7 +286,26 @@ pub trait Emitter: Sized {
             coders.insert(YAML_MIME.clone(), YAML_CODER.clone());
         }
 
+        // Add XML coder for the XML media ranges used in the spec (if any).
+        let op_ranges = api
+            .paths
+            .values()
+            .flat_map(|p| p.methods.values())
+            .flat_map(|op| op.consumes.iter().chain(op.produces.iter()))
+            .flat_map(|r| r.iter());
+        for range in api
+            .consumes
+            .iter()
+            .chain(api.produces.iter())
+            .chain(op_ranges)
+        {
+            if range.is_xml() && coders.matching_coder(range).is_none() {
+                coders.insert(range.clone(), XML_CODER.clone());
+            }
+        }
+
         state.set_media_info(api.spec_format, &coders);
+        *state.spec_info.borrow_mut() = api.info.clone();
 
         // Set host and base path.
         if let Some(h) = api.host.as_ref() {
@@ -260,15 +329,89 @@ pub trait Emitter: Sized {
             state.base_url.borrow_mut().set_path(p);
         }
 
+        *state.security_schemes.borrow_mut() = api.security_definitions.clone();
+        *state.security.borrow_mut() = api.security.clone();
+
+        // Relative server URLs are resolved against the host.
+        let origin = state.base_url.borrow().origin().ascii_serialization();
+        *state.servers.borrow_mut() = api
+            .servers
+            .iter()
+            .cloned()
+            .map(|mut s| {
+                if s.url.starts_with('/') {
+                    s.url.insert_str(0, &origin);
+                }
+
+                s
+            })
+            .collect();
+
+        // Servers (if any) are preferred over host and base path.
+        if let Some(server) = state.servers.borrow().first() {
+            let url = server.default_url();
+            let u = state
+                .base_url
+                .borrow()
+                .join(&url)
+                .map_err(|e| PaperClipError::InvalidBasePathURL(url, e))?;
+            *state.base_url.borrow_mut() = u;
+        }
+
+        // Collect the tags of operations using each definition (for remapping).
+        for (_, map) in spec_order(&api.paths, state.preserve_order) {
+            for (_, op) in spec_order(&map.methods, state.preserve_order) {
+                if op.tags.is_empty() {
+                    continue;
+                }
+
+                let body_schemas = map
+                    .parameters
+                    .iter()
+                    .chain(op.parameters.iter())
+                    .filter_map(|p| p.read().schema.as_ref().map(|s| (**s).clone()));
+                let mut def_tags = state.def_tags.borrow_mut();
+                for schema in
+                    body_schemas.chain(RequirementCollector::<Self>::get_2xx_response_schema(op))
+                {
+                    let schema = schema.read();
+                    let name = match schema.name() {
+                        Some(n) => n,
+                        None => continue,
+                    };
+
+                    let tags = def_tags.entry(name.into()).or_default();
+                    for tag in &op.tags {
+                        if !tags.contains(tag) {
+                            tags.push(tag.clone());
+                        }
+                    }
+                }
+            }
+        }
+
         let gen = CodegenEmitter(self);
+        let mut def_paths = HashMap::new();
         // Generate file contents by accumulating definitions.
         for (name, schema) in &api.definitions {
             debug!("Creating definition {}", name);
             let schema = schema.read();
+            // Aliases (resolved to other definitions) share the same name and path.
+            let def_name = schema.name().unwrap_or(name).to_owned();
+            let path = self.def_mod_path(&schema)?;
+            match def_paths.insert(path.clone(), def_name.clone()) {
+                Some(other) if other != def_name => {
+                    return Err(
+                        PaperClipError::DefinitionPathCollision(other, def_name, path).into(),
+                    );
+                }
+                _ => (),
+            }
+
             gen.generate_from_definition(&schema)?;
         }
 
-        for (path, map) in &api.paths {
+        for (path, map) in spec_order(&api.paths, state.preserve_order) {
             RequirementCollector {
                 path,
                 emitter: self,
@@ -297,8 +440,36 @@ pub trait Emitter: Sized {
         def: &Self::Definition,
         ctx: DefinitionContext<'a>,
     ) -> Result<EmittedUnit, Error> {
+        // Existing Rust types are never defined by us.
+        if let Some(ty) = def.rust_type() {
+            trace!("Matches custom type: {}", ty);
+            if ctx.define {
+                return Ok(EmittedUnit::None);
+            }
+
+            return Ok(EmittedUnit::Known(ty.to_owned()));
+        }
+
+        self.state().check_format(def.format(), || {
+            if ctx.parents.is_empty() {
+                format!("{:?}", def.name().unwrap_or_default())
+            } else {
+                format!("{:?}", ctx.parents.join("/"))
+            }
+        })?;
+
+        if let Some((kind, variants)) = enum_variants(def) {
+            trace!("Matches {:?} enum", kind);
+            return CodegenEmitter(self).emit_enum(def, ctx, kind, variants);
+        }
+
         if let Some(ty) = matching_unit_type(def.format(), def.data_type()) {
             trace!("Matches unit type: {}", ty);
+            // Named definitions (i.e., not properties) are wrapped if needed.
+            if self.state().newtypes && def.name().is_some() {
+                return CodegenEmitter(self).emit_newtype(def, ctx, ty);
+            }
+
             if ctx.define {
                 return Ok(EmittedUnit::None);
             }
@@ -346,12 +517,23 @@ where
     /// add them to `EmitterState`.
     fn generate_from_definition(&self, def: &E::Definition) -> Result<(), Error> {
         // Generate the object.
-        let objects = match self.build_def(def, DefinitionContext::default().define(true))? {
+        let mut objects = match self.build_def(def, DefinitionContext::default().define(true))? {
             EmittedUnit::Objects(o) => o,
             // We don't care about type aliases because we resolve them anyway.
             _ => return Ok(()),
         };
 
+        // Examples are used for fixtures only if they can be decoded into the object.
+        if let (true, Some(example)) = (self.state().fixtures, def.example()) {
+            match check_example(def, example, "") {
+                Ok(()) => objects[0].example = Some(example.clone()),
+                Err(ptr) => warn!(
+                    "Skipping example for {:?}, which doesn't match the schema (at {:?}).",
+                    objects[0].name, ptr
+                ),
+            }
+        }
+
         self.add_objects_to_path(objects, self.def_mod_path(def)?)
     }
 
@@ -460,44 +642,92 @@ where
         }
 
         if !ctx.define {
-            // Use absolute paths to save some pain.
-            let mut ty_path = String::from(self.state().mod_prefix.trim_matches(':'));
-
-            // If this is an anonymous object, then address it directly.
-            if def.name().is_none() {
-                let objects = match self.build_def(def, ctx.clone().define(true))? {
-                    EmittedUnit::Objects(o) => o,
-                    _ => unreachable!(),
-                };
+            return self.emit_type_path(def, ctx);
+        }
 
-                // If the object has an anonymous name, then it would definitely
-                // be in its own module, which is identified by the initial parent name.
-                if let Some(name) = self.def_anon_name(def, &ctx.parents) {
-                    ty_path.push_str("::");
-                    let parent = ctx.parents.get(0).expect("expected first parent name");
-                    ty_path.push_str(&parent.to_snek_case());
-                    ty_path.push_str("::");
-                    ty_path.push_str(&name);
-                    return Ok(EmittedUnit::KnownButAnonymous(ty_path, objects));
-                }
-            }
+        self.emit_struct(def, ctx)
+    }
 
-            let mut iter = self.def_ns_name(def)?.peekable();
-            while let Some(mut c) = iter.next() {
-                ty_path.push_str("::");
-                if iter.peek().is_none() {
-                    ty_path.push_str(&c);
-                    ty_path.push_str("::");
-                    c = c.to_camel_case();
-                }
+    /// Assumes that the given definition has enum variants (of a supported kind)
+    /// and returns the corresponding Rust enum.
+    fn emit_enum<'c>(
+        &self,
+        def: &E::Definition,
+        ctx: DefinitionContext<'c>,
+        kind: EnumKind,
+        variants: Vec<EnumVariant>,
+    ) -> Result<EmittedUnit, Error> {
+        if !ctx.define {
+            return self.emit_type_path(def, ctx);
+        }
+
+        let name = self.def_name(def).or_else(|e| {
+            // anonymous enum
+            self.def_anon_name(def, &ctx.parents).ok_or_else(|| e)
+        })?;
+        let mut obj = ApiObject::with_name(&name);
+        obj.description = def.description().map(String::from);
+        obj.enum_kind = Some(kind);
+        obj.variants = variants;
+        Ok(EmittedUnit::Objects(vec![obj]))
+    }
+
+    /// Helper for `build_def` - This returns the (transparent) newtype wrapping
+    /// the given type for some named definition.
+    fn emit_newtype(
+        &self,
+        def: &E::Definition,
+        ctx: DefinitionContext<'_>,
+        ty: &str,
+    ) -> Result<EmittedUnit, Error> {
+        if !ctx.define {
+            return self.emit_type_path(def, ctx);
+        }
+
+        let mut obj = ApiObject::with_name(self.def_name(def)?);
+        obj.description = def.description().map(String::from);
+        obj.newtype = Some(ty.into());
+        Ok(EmittedUnit::Objects(vec![obj]))
+    }
+
+    /// Returns the (absolute) path to the Rust type for the given definition,
+    /// defining the objects for anonymous definitions along the way.
+    fn emit_type_path(
+        &self,
+        def: &E::Definition,
+        ctx: DefinitionContext<'_>,
+    ) -> Result<EmittedUnit, Error> {
+        // Use absolute paths to save some pain.
+        let mut ty_path = String::from(self.state().mod_prefix.trim_matches(':'));
+
+        // If this is an anonymous object, then address it directly.
+        if def.name().is_none() {
+            let objects = match self.build_def(def, ctx.clone().define(true))? {
+                EmittedUnit::Objects(o) => o,
+                _ => unreachable!(),
+            };
 
-                ty_path.push_str(&c);
+            // If the object has an anonymous name, then it would definitely
+            // be in its own module, which is identified by the initial parent name.
+            if let Some(name) = self.def_anon_name(def, &ctx.parents) {
+                ty_path.push_str("::");
+                let parent = ctx.parents.get(0).expect("expected first parent name");
+                ty_path.push_str(&parent.to_snek_case());
+                ty_path.push_str("::");
+                ty_path.push_str(&name);
+                return Ok(EmittedUnit::KnownButAnonymous(ty_path, objects));
             }
+        }
 
-            return Ok(EmittedUnit::Known(ty_path));
+        for c in self.def_ns_name(def)? {
+            ty_path.push_str("::");
+            ty_path.push_str(&c);
         }
 
-        self.emit_struct(def, ctx)
+        ty_path.push_str("::");
+        ty_path.push_str(&self.def_name(def)?);
+
+        Ok(EmittedUnit::Known(ty_path))
     }
 
     /// Checks if the given definition is a simple map and returns the corresponding `BTreeMap`.
@@ -536,37 +766,95 @@ where
         })?;
         let mut obj = ApiObject::with_name(&name);
         obj.description = def.description().map(String::from);
+        obj.xml_name = def
+            .xml()
+            .and_then(|x| x.name.clone())
+            .filter(|n| n != &name);
 
         // If we don't have any parents and there's a name for this object,
         // then it's the root object - add the name to parents before checking
-        // its properties.
+        // its properties. This is the derived name (instead of the title), since
+        // anonymous objects are placed in the module identified by the first parent.
+        let parent;
         let mut ctx = ctx.clone();
         if ctx.parents.is_empty() && def.name().is_some() {
-            ctx = ctx.add_parent(&name);
+            parent = self.def_ns_name(def)?.last().unwrap_or_default();
+            ctx = ctx.add_parent(&parent);
         }
 
         // Anonymous objects that we've collected along the way.
         let mut objects = vec![];
 
+        // Keys of the mixins flattened into this object (if any).
+        let mut mixin_keys = vec![];
         if let Some(props) = def.properties() {
-            props
-                .iter()
+            spec_order(props, self.state().preserve_order)
+                .into_iter()
                 .try_for_each(|(name, prop)| -> Result<(), Error> {
                     let schema = prop.read();
                     let ctx = ctx.clone().define(false).add_parent(name);
                     let ty = self.build_def(&schema, ctx)?;
-                    let ty_path = ty.known_type();
+                    let mut ty_path = ty.known_type();
+                    let is_required = def
+                        .required_properties()
+                        .map(|s| s.contains(name))
+                        .unwrap_or(false);
+
+                    // Serde adapters for `time` types don't work with `Patch` values
+                    // (or defaults for required fields), so those stay as strings.
+                    let adaptable = if is_required {
+                        !schema.is_write_only()
+                    } else {
+                        !schema.is_nullable()
+                    };
+                    if ty_path == "String" && adaptable {
+                        if let Some(t) = self.time_type(&schema) {
+                            ty_path = t.into();
+                        }
+                    }
+
+                    // Mixins are flattened into this object (so that their
+                    // properties are decoded from the keys of this object).
+                    let flatten = schema.is_flattened();
+                    if flatten {
+                        let keys = schema
+                            .properties()
+                            .map(|p| p.keys().cloned().collect::<Vec<_>>())
+                            .unwrap_or_default();
+                        mixin_keys.push((name.clone(), keys));
+                    }
+
+                    let forbidden = match prop.get_not_schema() {
+                        Some(not) => forbidden_values(&not).unwrap_or_else(|| {
+                            warn!(
+                                "Unsupported `not` schema for field {:?} in {:?}. Skipping validation.",
+                                name, obj.name
+                            );
+
+                            vec![]
+                        }),
+                        None => vec![],
+                    };
 
                     obj.fields.push(ObjectField {
                         name: name.clone(),
                         description: prop.get_description(),
+                        deprecated: prop.get_deprecated(),
+                        external_docs: prop.get_external_docs(),
+                        example: prop.get_example(),
                         ty_path,
-                        is_required: def
-                            .required_properties()
-                            .map(|s| s.contains(name))
-                            .unwrap_or(false),
+                        is_required: is_required && !flatten,
                         needs_any: schema.contains_any(),
                         boxed: schema.is_cyclic(),
+                        custom_type: self.has_custom_type(&schema),
+                        nullable: schema.is_nullable() && !flatten,
+                        write_only: schema.is_write_only(),
+                        read_only: schema.is_read_only(),
+                        keep_name: self.state().keep_field_names,
+                        flatten,
+                        custom_attrs: prop.get_serde_attrs(),
+                        xml: prop.get_xml(),
+                        forbidden,
                         child_req_fields: self.children_requirements(&schema),
                     });
 
@@ -578,6 +866,101 @@ where
                 })?
         }
 
+        // Explicit properties are preferred over the ones from mixins.
+        for (mixin, keys) in mixin_keys {
+            let explicit = obj
+                .fields
+                .iter()
+                .find(|f| !f.flatten && keys.contains(&f.name))
+                .map(|f| f.name.clone());
+            if let Some(key) = explicit {
+                warn!(
+                    "Property {:?} in {:?} collides with flattened mixin {:?}. Skipping mixin.",
+                    key, name, mixin
+                );
+
+                obj.fields.retain(|f| f.name != mixin);
+            }
+        }
+
+        for field in obj.fields.iter().filter(|f| f.is_xml_attribute()) {
+            if obj
+                .fields
+                .iter()
+                .any(|f| !f.is_xml_attribute() && f.xml_name() == field.xml_name())
+            {
+                warn!(
+                    "XML attribute {:?} in {:?} collides with an element of the same name.",
+                    field.xml_name(),
+                    name
+                );
+            }
+        }
+
+        // If the object has additional properties along with its known
+        // properties, then we collect the unknown keys in a flattened map.
+        let extra_value = match def.additional_properties() {
+            _ if obj.fields.is_empty() => None,
+            Some(Either::Left(true)) => Some((ANY_GENERIC_PARAMETER.to_owned(), true, false)),
+            Some(Either::Right(s)) => {
+                let schema = s.read();
+                if schema.data_type().is_none() && schema.rust_type().is_none() {
+                    Some((ANY_GENERIC_PARAMETER.to_owned(), true, false))
+                } else {
+                    let ctx = ctx
+                        .clone()
+                        .define(false)
+                        .add_parent("additional_properties");
+                    let ty = self.build_def(&schema, ctx)?;
+                    let ty_path = ty.known_type();
+                    if let EmittedUnit::KnownButAnonymous(_, mut o) = ty {
+                        objects.append(&mut o);
+                    }
+
+                    Some((
+                        ty_path,
+                        schema.contains_any(),
+                        self.has_custom_type(&schema),
+                    ))
+                }
+            }
+            _ => None,
+        };
+
+        if let Some((value_ty, needs_any, custom_type)) = extra_value {
+            let mut field = ObjectField {
+                name: "additional_properties".into(),
+                description: None,
+                deprecated: false,
+                external_docs: None,
+                example: None,
+                ty_path: format!("std::collections::BTreeMap<String, {}>", value_ty),
+                is_required: false,
+                needs_any,
+                boxed: false,
+                custom_type,
+                nullable: false,
+                write_only: false,
+                read_only: false,
+                keep_name: true,
+                flatten: true,
+                custom_attrs: vec![],
+                xml: None,
+                forbidden: vec![],
+                child_req_fields: vec![],
+            };
+
+            while obj
+                .fields
+                .iter()
+                .any(|f| f.rust_name() == field.rust_name())
+            {
+                field.name.push('_');
+            }
+
+            obj.fields.push(field);
+        }
+
         objects.insert(0, obj);
         Ok(EmittedUnit::Objects(objects))
     }
@@ -588,10 +971,18 @@ where
     fn children_requirements(&self, schema: &E::Definition) -> Vec<String> {
         match schema.data_type() {
             Some(DataType::Object) => {
-                if let Some(Either::Right(s)) = schema.additional_properties() {
-                    return self.children_requirements(&s.read());
-                } else if let Some(s) = schema.required_properties() {
-                    return s.iter().cloned().collect();
+                // Objects with known properties are structs (even if they have
+                // additional properties), so only maps lead us to their values.
+                let is_map = schema.properties().map(|p| p.is_empty()).unwrap_or(true);
+                match schema.additional_properties() {
+                    Some(Either::Right(s)) if is_map => {
+                        return self.children_requirements(&s.read())
+                    }
+                    _ => {
+                        if let Some(s) = schema.required_properties() {
+                            return s.iter().cloned().collect();
+                        }
+                    }
                 }
             }
             Some(DataType::Array) => {
@@ -604,6 +995,50 @@ where
 
         vec![]
     }
+
+    /// Checks whether the "deepest" child type (see `children_requirements`)
+    /// in the given definition is an existing Rust type.
+    fn has_custom_type(&self, schema: &E::Definition) -> bool {
+        if schema.rust_type().is_some() {
+            return true;
+        }
+
+        match schema.data_type() {
+            Some(DataType::Object) => {
+                let is_map = schema.properties().map(|p| p.is_empty()).unwrap_or(true);
+                match schema.additional_properties() {
+                    Some(Either::Right(s)) if is_map => self.has_custom_type(&s.read()),
+                    _ => false,
+                }
+            }
+            Some(DataType::Array) => schema
+                .items()
+                .and_then(|e| e.left_or_one_in_right())
+                .map(|s| self.has_custom_type(&s.read()))
+                .unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    /// Returns the `time` type for the given definition if it's a `date-time`
+    /// or `date` string and if `time` types have been enabled in the state.
+    #[cfg(feature = "time-types")]
+    fn time_type(&self, schema: &E::Definition) -> Option<&'static str> {
+        if !self.state().time_types || schema.rust_type().is_some() {
+            return None;
+        }
+
+        match schema.format() {
+            Some(DataTypeFormat::DateTime) => Some(OFFSET_DATE_TIME_TYPE),
+            Some(DataTypeFormat::Date) => Some(DATE_TYPE),
+            _ => None,
+        }
+    }
+
+    #[cfg(not(feature = "time-types"))]
+    fn time_type(&self, _schema: &E::Definition) -> Option<&'static str> {
+        None
+    }
 }
 
 /// Abstraction which takes care of adding requirements for operations.
@@ -627,12 +1062,12 @@ where
         debug!("Collecting builder requirement for {:?}", self.path);
 
         // Collect all the parameters local to some API call.
-        let (unused_params, _) = self.collect_parameters(&self.map.parameters)?;
+        let (unused_params, _) = self.collect_parameters(&self.map.parameters, None)?;
         // FIXME: What if a body is "required" globally (for all operations)?
         // This means, operations can override the body with some other schema
         // and we may need to map it to the appropriate builders.
 
-        for (&meth, op) in &self.map.methods {
+        for (&meth, op) in spec_order(&self.map.methods, self.emitter.state().preserve_order) {
             self.collect_from_operation(meth, op, &unused_params)?;
         }
 
@@ -690,19 +1125,34 @@ where
         op: &ResolvableOperation<E::Definition>,
         unused_params: &[Parameter],
     ) -> Result<(), Error> {
-        let (mut params, schema_path) = self.collect_parameters(&op.parameters)?;
+        let (mut params, schema_path) =
+            self.collect_parameters(&op.parameters, op.consumes.as_ref())?;
         // If we have unused params which don't exist in the method-specific
         // params (which take higher precedence), then we can copy those inside.
         for global_param in unused_params {
             if params
                 .iter()
-                .find(|p| p.name == global_param.name)
+                .find(|p| p.name == global_param.name && p.presence == global_param.presence)
                 .is_none()
             {
                 params.push(global_param.clone());
             }
         }
 
+        // Parameters in different locations can share the same name, in which
+        // case, those not in path are suffixed with their location in Rust.
+        for i in 0..params.len() {
+            let (name, presence) = (&params[i].name, params[i].presence);
+            if presence != ParameterIn::Path
+                && params
+                    .iter()
+                    .any(|p| &p.name == name && p.presence != presence)
+            {
+                let alias = format!("{}_{:?}", name, presence).to_snek_case();
+                params[i].alias = Some(alias);
+            }
+        }
+
         params = params
             .into_iter()
             .filter(|p| {
@@ -741,7 +1191,11 @@ where
         }
 
         let default_fmt = CollectionFormat::default();
-        it_fmts.insert(0, p.collection_format.unwrap_or(default_fmt));
+        it_fmts.insert(
+            0,
+            p.effective_collection_format()
+                .unwrap_or_else(|| default_fmt.clone()),
+        );
         it_fmts.pop(); // pop the final format, as it's unnecessary.
         let is_url_encoded = p.in_ == ParameterIn::Query || p.in_ == ParameterIn::FormData;
         if it_fmts.contains(&CollectionFormat::Multi) {
@@ -749,7 +1203,7 @@ where
                 let mut fmt_idx_iter = it_fmts
                     .iter()
                     .enumerate()
-                    .filter(|&(_, &fmt)| fmt == CollectionFormat::Multi);
+                    .filter(|&(_, fmt)| *fmt == CollectionFormat::Multi);
                 fmt_idx_iter.next().expect("expected collection format?");
                 // We support URL encoding multiple values only when it's specified in root.
                 fmt_idx_iter.next().is_some()
@@ -775,7 +1229,7 @@ where
                             continue;
                         }
 
-                        *f = default_fmt;
+                        *f = default_fmt.clone();
                     }
                 }
             }
@@ -783,9 +1237,14 @@ where
     }
 
     /// Given a bunch of resolved parameters, validate and collect a simplified version of them.
+    ///
+    /// Array bodies are collected as (required) parameters, since there's no object for
+    /// them. `Any` in those is replaced with the type used by the coder for the given
+    /// media ranges (or the ones in API), since they're not generic.
     fn collect_parameters(
         &mut self,
         obj_params: &[Either<Reference, ResolvableParameter<E::Definition>>],
+        consumes: Option<&BTreeSet<MediaRange>>,
     ) -> Result<(Vec<Parameter>, Option<PathBuf>), Error> {
         let def_mods = self.emitter.state().def_mods.borrow();
         let mut schema_path = None;
@@ -802,6 +1261,43 @@ where
                     continue;
                 }
 
+                let schema = def.read();
+                if schema.data_type() == Some(DataType::Array) {
+                    let ty = self
+                        .emitter
+                        .build_def(&schema, DefinitionContext::default())?
+                        .known_type();
+                    let ty_path = if schema.contains_any() {
+                        let state = self.emitter.state();
+                        let coder = self.get_coder(consumes, &self.api.consumes);
+                        let any_value = match (&state.any_value, &coder) {
+                            (Some(v), _) => v,
+                            (None, Some((_, c))) => &c.any_value,
+                            (None, None) => &JSON_CODER.any_value,
+                        };
+
+                        let mut ty_path = String::new();
+                        ApiObject::write_field_with_any(&ty, any_value, &mut ty_path)?;
+                        ty_path
+                    } else {
+                        ty
+                    };
+
+                    params.push(Parameter {
+                        name: p.name.clone(),
+                        description: p.description.clone(),
+                        ty_path,
+                        presence: p.in_,
+                        required: true,
+                        delimiting: vec![],
+                        alias: None,
+                        custom_type: CodegenEmitter(self.emitter).has_custom_type(&schema),
+                        variants: None,
+                        send_default: false,
+                    });
+                    continue;
+                }
+
                 warn!(
                     "Unregistered schema for parameter {:?} in path {:?}: {:?}",
                     p.name, self.path, def
@@ -815,20 +1311,57 @@ where
                 self.template_params.remove(&p.name);
             }
 
+            let state = self.emitter.state();
+            let mut items = p.items.as_ref();
+            state.check_format(p.format.as_ref(), || {
+                format!("parameter {:?} in path {:?}", p.name, self.path)
+            })?;
+            while let Some(i) = items {
+                state.check_format(i.format.as_ref(), || {
+                    format!("items of parameter {:?} in path {:?}", p.name, self.path)
+                })?;
+                items = i.items.as_deref();
+            }
+
             // Enforce that the parameter is an allowed type and collect it.
-            let (ty, mut it_fmts) =
-                match resolve_parameter_type(p.data_type, p.format.as_ref(), p.items.as_ref()) {
-                    Some(t) => t,
-                    None => {
-                        warn!(
-                            "Skipping parameter {:?} with unknown type {:?} in path {:?}",
-                            p.name, p.data_type, self.path
-                        );
-                        continue;
-                    }
-                };
+            let (ty, mut it_fmts) = match resolve_parameter_type(
+                p.data_type,
+                p.format.as_ref(),
+                p.rust_type.as_ref(),
+                p.items.as_ref(),
+            ) {
+                Some(t) => t,
+                None => {
+                    warn!(
+                        "Skipping parameter {:?} with unknown type {:?} in path {:?}",
+                        p.name, p.data_type, self.path
+                    );
+                    continue;
+                }
+            };
 
             self.validate_collection_format(&p, &mut it_fmts);
+            self.emitter.state().add_delimiters(&it_fmts);
+
+            // Enums are generated for query parameters with constrained values. Those
+            // with defaults are optional (since the server uses the default anyway).
+            let variants =
+                if p.in_ == ParameterIn::Query && p.rust_type.is_none() && !p.enum_.is_empty() {
+                    enum_kind(p.data_type, p.format.as_ref()).and_then(|kind| {
+                        enum_variants_from_values(
+                            Some(&p.name),
+                            &kind,
+                            &p.enum_,
+                            &[],
+                            p.default.as_ref(),
+                        )
+                        .map(|v| (kind, v))
+                    })
+                } else {
+                    None
+                };
+
+            let has_default = variants.iter().flat_map(|(_, v)| v).any(|v| v.is_default);
 
             params.push(Parameter {
                 name: p.name.clone(),
@@ -836,8 +1369,12 @@ where
                 ty_path: ty,
                 presence: p.in_,
                 // NOTE: parameter is required if it's in path
-                required: p.required || p.in_ == ParameterIn::Path,
+                required: (p.required && !has_default) || p.in_ == ParameterIn::Path,
                 delimiting: it_fmts,
+                alias: None,
+                custom_type: has_custom_type(p.rust_type.as_ref(), p.items.as_ref()),
+                variants,
+                send_default: has_default && self.emitter.state().send_defaults,
             });
         }
 
@@ -859,9 +1396,13 @@ where
             schema_path
         );
 
+        let statuses = self.get_2xx_responses(op)?;
         let state = self.emitter.state();
         let mut def_mods = state.def_mods.borrow_mut();
         let obj = def_mods.get_mut(schema_path).expect("bleh?");
+        let mut params = params;
+        self.add_param_enums(obj, meth, &mut params);
+        let enum_name = Self::response_enum_name(obj, meth, &statuses);
         let ops = obj[0] // first object is always the globally defined object.
             .paths
             .entry(self.path.into())
@@ -886,14 +1427,21 @@ where
                 listable: false,
                 id: op.operation_id.clone(),
                 description: op.description.clone(),
+                tags: op.tags.clone(),
                 deprecated: op.deprecated,
                 params,
                 response: Response {
                     contains_any: response_contains_any,
+                    empty: response_ty_path.is_none()
+                        && op.responses.keys().any(|c| c.starts_with('2')),
                     ty_path: response_ty_path,
+                    event_stream: self.produces_event_stream(op),
+                    statuses,
+                    enum_name,
                 },
                 body_required: true,
                 encoding: self.get_coder(op.consumes.as_ref(), &self.api.consumes),
+                encodings: self.get_coders(op.consumes.as_ref(), &self.api.consumes),
                 decoding: self.get_coder(op.produces.as_ref(), &self.api.produces),
             },
         );
@@ -921,6 +1469,7 @@ where
             }
         };
 
+        let statuses = self.get_2xx_responses(op)?;
         let schema = &*s.read();
         let state = self.emitter.state();
         let listable = schema
@@ -989,6 +1538,9 @@ where
             },
         };
 
+        let mut params = params;
+        self.add_param_enums(obj, meth, &mut params);
+        let enum_name = Self::response_enum_name(obj, meth, &statuses);
         let ops = obj[0] // first object is always the globally defined object.
             .paths
             .entry(self.path.into())
@@ -999,6 +1551,7 @@ where
             OpRequirement {
                 id: op.operation_id.clone(),
                 description: op.description.clone(),
+                tags: op.tags.clone(),
                 deprecated: op.deprecated,
                 params,
                 body_required: false,
@@ -1006,8 +1559,13 @@ where
                 response: Response {
                     ty_path: response_ty_path,
                     contains_any: schema.contains_any(),
+                    event_stream: self.produces_event_stream(op),
+                    empty: false,
+                    statuses,
+                    enum_name,
                 },
                 encoding: self.get_coder(op.consumes.as_ref(), &self.api.consumes),
+                encodings: self.get_coders(op.consumes.as_ref(), &self.api.consumes),
                 decoding: self.get_coder(op.produces.as_ref(), &self.api.produces),
             },
         );
@@ -1015,6 +1573,101 @@ where
         Ok(())
     }
 
+    /// Adds the enums for the given (enum) parameters of an operation to the objects
+    /// in the module to which the operation is bound, and updates the types of those
+    /// parameters. Enums are named after the object, method and parameter (and suffixed
+    /// with a number if that name has already been taken by another enum).
+    fn add_param_enums(
+        &self,
+        objects: &mut Vec<ApiObject>,
+        meth: HttpMethod,
+        params: &mut [Parameter],
+    ) {
+        let mod_prefix = self.emitter.state().mod_prefix.trim_matches(':');
+        for param in params.iter_mut() {
+            let (kind, variants) = match param.variants.take() {
+                Some(v) => v,
+                None => continue,
+            };
+
+            let name = Self::unique_name(
+                objects,
+                format!(
+                    "{}{}{}",
+                    objects[0].name,
+                    meth,
+                    param.alias.as_ref().unwrap_or(&param.name).to_camel_case()
+                ),
+            );
+
+            let path = objects[0].path.clone();
+            param.ty_path = format!("{}::{}::{}", mod_prefix, path, name);
+            let mut obj = ApiObject::with_name(name);
+            obj.description = Some(format!(
+                "Values for `{}` parameter of `{}` operation in `{}`.",
+                param.name,
+                meth.to_string().to_uppercase(),
+                self.path
+            ));
+            obj.path = path;
+            obj.enum_kind = Some(kind);
+            obj.variants = variants;
+            obj.is_param = true;
+            objects.push(obj);
+        }
+    }
+
+    /// Returns the name of the enum for the given successful responses of an operation
+    /// (if we need one) in the module to which the operation is bound. The enum is named
+    /// after the object and method (and suffixed with a number if that name has already
+    /// been taken).
+    fn response_enum_name(
+        objects: &[ApiObject],
+        meth: HttpMethod,
+        statuses: &[StatusResponse<String>],
+    ) -> Option<String> {
+        if statuses.is_empty() {
+            return None;
+        }
+
+        Some(Self::unique_name(
+            objects,
+            format!("{}{}Response", objects[0].name, meth),
+        ))
+    }
+
+    /// Returns the given name suffixed with the least number required for making it
+    /// unique among the given objects (and the enums for the responses of their operations).
+    fn unique_name(objects: &[ApiObject], base_name: String) -> String {
+        let is_taken = |name: &str| {
+            objects.iter().any(|o| {
+                o.name == name
+                    || o.paths
+                        .values()
+                        .flat_map(|ops| ops.req.values())
+                        .any(|r| r.response.enum_name.as_deref() == Some(name))
+            })
+        };
+
+        let mut name = base_name.clone();
+        let mut idx = 1;
+        while is_taken(&name) {
+            name = format!("{}{}", base_name, idx);
+            idx += 1;
+        }
+
+        name
+    }
+
+    /// Returns whether the given operation produces server-sent events.
+    fn produces_event_stream(&self, op: &ResolvableOperation<E::Definition>) -> bool {
+        op.produces
+            .as_ref()
+            .unwrap_or(&self.api.produces)
+            .iter()
+            .any(MediaRange::is_event_stream)
+    }
+
     /// Returns the first 2xx response schema in this operation.
     ///
     /// **NOTE:** This assumes that 2xx response schemas are the same for an operation.
@@ -1031,6 +1684,69 @@ where
             .next()
     }
 
+    /// Returns the successful responses of this operation based on their status codes,
+    /// if they have different schemas (or if they should be distinguished regardless).
+    /// Otherwise, this is empty.
+    fn get_2xx_responses(
+        &self,
+        op: &ResolvableOperation<E::Definition>,
+    ) -> Result<Vec<StatusResponse<String>>, Error> {
+        let state = self.emitter.state();
+        let coder = self.get_coder(op.produces.as_ref(), &self.api.produces);
+        let any_value = match (&state.any_value, &coder) {
+            (Some(v), _) => v,
+            (None, Some((_, c))) => &c.any_value,
+            (None, None) => &JSON_CODER.any_value,
+        };
+
+        let mut responses: Vec<StatusResponse<String>> = vec![];
+        for (code, resp) in op.responses.iter() {
+            // Non-numeric (or non-2xx) codes are ignored.
+            let code = match code.parse::<u16>() {
+                Ok(c) if (200..300).contains(&c) => c,
+                _ => continue,
+            };
+
+            let ty_path = match resp.read().schema.as_ref() {
+                Some(s) => {
+                    let schema = s.read();
+                    let ty = self
+                        .emitter
+                        .build_def(&schema, DefinitionContext::default())?
+                        .known_type();
+                    if schema.contains_any() {
+                        let mut ty_path = String::new();
+                        ApiObject::write_field_with_any(&ty, any_value, &mut ty_path)?;
+                        Some(ty_path)
+                    } else {
+                        Some(ty)
+                    }
+                }
+                None => None,
+            };
+
+            if !state.split_statuses {
+                if let Some(r) = responses.iter_mut().find(|r| r.ty_path == ty_path) {
+                    r.codes.push(code);
+                    continue;
+                }
+            }
+
+            responses.push(StatusResponse {
+                codes: vec![code],
+                ty_path,
+            });
+        }
+
+        // We need an enum only if there are multiple variants with bodies. Otherwise,
+        // responses without schema are rejected (as empty) or not decoded at all.
+        if responses.iter().filter(|r| r.ty_path.is_some()).count() < 2 {
+            responses.clear();
+        }
+
+        Ok(responses)
+    }
+
     /// Returns the coder based on the given local and global media range, and `None`
     /// if it's JSON (as we already support it).
     fn get_coder(
@@ -1047,7 +1763,7 @@ where
 
         let mut coders = ranges
             .iter()
-            .filter_map(|r| self.api.coders.matching_coder(r).map(|c| (r, c)))
+            .filter_map(|r| self.matching_coder(r).map(|c| (r, c)))
             .sorted_by(|(_, a), (_, b)| b.prefer.cmp(&a.prefer)); // sort based on preference.
 
         let (range, coder) = coders
@@ -1059,15 +1775,52 @@ where
 
         Some((range.0.as_ref().into(), coder))
     }
+
+    /// Returns all the concrete media ranges (i.e., without wildcards) which
+    /// have coders (including the built-in ones), sorted based on preference.
+    fn get_coders(
+        &self,
+        local_ref: Option<&BTreeSet<MediaRange>>,
+        global_ref: &BTreeSet<MediaRange>,
+    ) -> Vec<(String, Arc<Coder>)> {
+        local_ref
+            .unwrap_or(global_ref)
+            .iter()
+            .filter(|r| !r.0.as_ref().contains('*'))
+            .filter_map(|r| self.matching_coder(r).map(|c| (r.0.as_ref().into(), c)))
+            .sorted_by(|(_, a), (_, b)| b.prefer.cmp(&a.prefer))
+            .collect()
+    }
+
+    /// Returns the coder for the given media range, falling back to the
+    /// built-in coders for JSON, YAML and XML.
+    fn matching_coder(&self, range: &MediaRange) -> Option<Arc<Coder>> {
+        self.api.coders.matching_coder(range).or_else(|| {
+            if range == &*JSON_MIME {
+                Some(JSON_CODER.clone())
+            } else if range == &*YAML_MIME {
+                Some(YAML_CODER.clone())
+            } else if range.is_xml() {
+                Some(XML_CODER.clone())
+            } else {
+                None
+            }
+        })
+    }
 }
 
-/// Ensures that a parameter type is either a simple type or an array
-/// and returns the resolved Rust type.
+/// Ensures that a parameter type is either a simple type (or an existing
+/// Rust type) or an array and returns the resolved Rust type.
 fn resolve_parameter_type(
     dt: Option<DataType>,
     dt_fmt: Option<&DataTypeFormat>,
+    rust_type: Option<&String>,
     items: Option<&Items>,
 ) -> Option<(String, Vec<CollectionFormat>)> {
+    if let Some(t) = rust_type {
+        return Some((t.clone(), vec![]));
+    }
+
     match matching_unit_type(dt_fmt, dt) {
         Some(t) => return Some((t.into(), vec![])),
         None if dt == Some(DataType::File) => return Some((FILE_MARKER.into(), vec![])),
@@ -1076,9 +1829,10 @@ fn resolve_parameter_type(
                 if let Some((ty, mut fmts)) = resolve_parameter_type(
                     i.data_type,
                     i.format.as_ref(),
+                    i.rust_type.as_ref(),
                     i.items.as_ref().map(Deref::deref),
                 ) {
-                    fmts.insert(0, i.collection_format.unwrap_or_default());
+                    fmts.insert(0, i.effective_collection_format().unwrap_or_default());
                     // We collect it as `Vec` for now - we'll replace it with our
                     // `Delimited` wrapper when we actually write the code.
                     return Some((String::from("Vec<") + ty.as_str() + ">", fmts));
@@ -1091,6 +1845,249 @@ fn resolve_parameter_type(
     None
 }
 
+/// Checks whether the (innermost) type of a parameter with the given
+/// `x-rust-type` and items is an existing Rust type.
+fn has_custom_type(rust_type: Option<&String>, items: Option<&Items>) -> bool {
+    if rust_type.is_some() {
+        return true;
+    }
+
+    match items {
+        Some(Items {
+            rust_type, items, ..
+        }) => has_custom_type(rust_type.as_ref(), items.as_ref().map(|i| &**i)),
+        None => false,
+    }
+}
+
+/// Checks if the given definition is a string or integer enum and returns
+/// the kind of its values along with the variants.
+fn enum_variants<S>(def: &S) -> Option<(EnumKind, Vec<EnumVariant>)>
+where
+    S: Schema + Debug,
+{
+    let values = def.enum_variants()?;
+    let kind = enum_kind(def.data_type(), def.format())?;
+    let variants =
+        enum_variants_from_values(def.name(), &kind, values, def.enum_descriptions(), None)?;
+    Some((kind, variants))
+}
+
+/// Returns the kind of enum values for the given data type (if it's supported).
+fn enum_kind(dt: Option<DataType>, dt_fmt: Option<&DataTypeFormat>) -> Option<EnumKind> {
+    match dt {
+        Some(DataType::String) => Some(EnumKind::String),
+        Some(DataType::Integer) => Some(EnumKind::Integer(
+            matching_unit_type(dt_fmt, dt)
+                .expect("expected integer type")
+                .into(),
+        )),
+        _ => None,
+    }
+}
+
+/// Returns the variants for the given enum values (along with their descriptions)
+/// if all of them are supported. The variant matching the given default value
+/// (if any) is marked as the default.
+///
+/// Duplicate values are skipped, and variant names colliding with those of
+/// the previous values (say, `foo-bar` and `foo_bar`) are suffixed with numbers.
+fn enum_variants_from_values(
+    name: Option<&str>,
+    kind: &EnumKind,
+    values: &[serde_json::Value],
+    descriptions: &[Option<String>],
+    default: Option<&serde_json::Value>,
+) -> Option<Vec<EnumVariant>> {
+    let mut variants = vec![];
+    for (i, value) in values.iter().enumerate() {
+        if variants.iter().any(|v: &EnumVariant| v.value == *value) {
+            continue;
+        }
+
+        let mut var_name = match (kind, value) {
+            // Other characters (like '.') can't be in identifiers, so they're treated
+            // as word separators.
+            (EnumKind::String, serde_json::Value::String(s)) => s
+                .replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+                .to_camel_case(),
+            (EnumKind::Integer(ref repr), serde_json::Value::Number(n))
+                if n.as_i64()
+                    .map_or(false, |n| repr != "i32" || n as i32 as i64 == n) =>
+            {
+                // We can't have '-' in identifiers.
+                let n = n.as_i64().expect("expected integer");
+                if n < 0 {
+                    format!("VMinus{}", -(n as i128))
+                } else {
+                    format!("V{}", n)
+                }
+            }
+            _ => {
+                warn!(
+                    "Skipping enum generation for {:?} because of unsupported value {:?}",
+                    name, value
+                );
+                return None;
+            }
+        };
+
+        // Identifiers can't be empty, begin with digits or be keywords (`Self`
+        // can't be a raw identifier either).
+        if !var_name.starts_with(char::is_alphabetic)
+            || RUST_KEYWORDS.iter().any(|&k| k == var_name)
+        {
+            var_name.insert(0, 'V');
+        }
+
+        let base_name = var_name.clone();
+        let mut idx = 1;
+        while variants.iter().any(|v| v.name == var_name) {
+            var_name = format!("{}{}", base_name, idx);
+            idx += 1;
+        }
+
+        variants.push(EnumVariant {
+            name: var_name,
+            value: value.clone(),
+            description: descriptions
+                .get(i)
+                .and_then(Clone::clone)
+                .filter(|d| !d.trim().is_empty()),
+            is_default: default == Some(value),
+        });
+    }
+
+    Some(variants)
+}
+
+/// Returns the entries of the given map in the order they were specified in the
+/// spec (if it should be preserved), or sorted by their keys otherwise.
+fn spec_order<'a, K: Ord + 'a, V: 'a>(
+    map: impl IntoIterator<Item = (&'a K, &'a V)>,
+    preserve: bool,
+) -> Vec<(&'a K, &'a V)> {
+    let mut entries = map.into_iter().collect::<Vec<_>>();
+    if !preserve {
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+    }
+
+    entries
+}
+
+/// Returns the values forbidden by the given `not` schema. Only `enum` and
+/// `const` subschemas are supported. If both are specified, then a value
+/// matches the subschema only if it satisfies both of them.
+fn forbidden_values(not: &serde_json::Value) -> Option<Vec<serde_json::Value>> {
+    let map = not.as_object()?;
+    if map.is_empty() || map.keys().any(|k| k != "enum" && k != "const") {
+        return None;
+    }
+
+    let mut values = match map.get("enum") {
+        Some(v) => v.as_array()?.clone(),
+        None => vec![],
+    };
+
+    if let Some(c) = map.get("const") {
+        if map.contains_key("enum") {
+            values.retain(|v| v == c);
+        } else {
+            values.push(c.clone());
+        }
+    }
+
+    Some(values)
+}
+
+/// Checks whether the given example can be decoded into the type generated for
+/// the given definition. Returns the (JSON pointer) path to the mismatching value
+/// otherwise. Custom types and `Any` are assumed to accept any value.
+fn check_example<S>(def: &S, value: &serde_json::Value, ptr: &str) -> Result<(), String>
+where
+    S: Schema + Debug,
+{
+    use serde_json::Value;
+
+    if def.rust_type().is_some() || def.data_type().is_none() {
+        return Ok(());
+    }
+
+    if value.is_null() {
+        return if def.is_nullable() {
+            Ok(())
+        } else {
+            Err(ptr.into())
+        };
+    }
+
+    if let (Some(variants), Some(_)) = (def.enum_variants(), enum_variants(def)) {
+        return if variants.contains(value) {
+            Ok(())
+        } else {
+            Err(ptr.into())
+        };
+    }
+
+    let is_valid = match (def.data_type(), value) {
+        (Some(DataType::Boolean), Value::Bool(_)) | (Some(DataType::String), Value::String(_)) => {
+            true
+        }
+        (Some(DataType::Integer), Value::Number(n)) => n.is_i64() || n.is_u64(),
+        (Some(DataType::Number), Value::Number(_)) => true,
+        (Some(DataType::Array), Value::Array(values)) => {
+            if let Some(Either::Left(s)) = def.items() {
+                let schema = s.read();
+                for (i, v) in values.iter().enumerate() {
+                    check_example(&*schema, v, &format!("{}/{}", ptr, i))?;
+                }
+            }
+
+            true
+        }
+        (Some(DataType::Object), Value::Object(map)) => {
+            let props = def.properties();
+            for (name, s) in props.into_iter().flat_map(|p| p.iter()) {
+                let schema = s.read();
+                let is_required = def
+                    .required_properties()
+                    .map(|s| s.contains(name))
+                    .unwrap_or(false);
+                match map.get(name) {
+                    // Optional fields can be `null` (or missing) and required
+                    // write-only fields can be missing.
+                    Some(Value::Null) if !is_required => (),
+                    Some(v) => check_example(&*schema, v, &format!("{}/{}", ptr, name))?,
+                    None if is_required && !schema.is_write_only() => {
+                        return Err(format!("{}/{}", ptr, name))
+                    }
+                    None => (),
+                }
+            }
+
+            // Unknown keys go into the map (if any), and they're ignored otherwise.
+            if let Some(Either::Right(s)) = def.additional_properties() {
+                let schema = s.read();
+                for (name, v) in map
+                    .iter()
+                    .filter(|(k, _)| props.map(|p| !p.contains_key(*k)).unwrap_or(true))
+                {
+                    check_example(&*schema, v, &format!("{}/{}", ptr, name))?;
+                }
+            }
+
+            true
+        }
+        _ => false,
+    };
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(ptr.into())
+    }
+}
+
 /// Checks if the given type/format matches a known Rust type and returns it.
 fn matching_unit_type(
     format: Option<&DataTypeFormat>,
@@ -1101,6 +2098,8 @@ fn matching_unit_type(
         Some(DataTypeFormat::Int64) => Some("i64"),
         Some(DataTypeFormat::Float) => Some("f32"),
         Some(DataTypeFormat::Double) => Some("f64"),
+        #[cfg(feature = "decimal")]
+        Some(DataTypeFormat::Decimal) if type_ == Some(DataType::String) => Some(DECIMAL_TYPE),
         _ => match type_ {
             Some(DataType::Integer) => Some("i64"),
             Some(DataType::Number) => Some("f64"),
@@ -1,9 +1,13 @@
 use super::emitter::ANY_GENERIC_PARAMETER;
-use super::object::{ApiObject, ApiObjectBuilder, Response, StructField, TypeParameters};
+use super::object::{
+    ApiObject, ApiObjectBuilder, ObjectField, Property, Response, StatusResponse, StructField,
+    TypeParameters,
+};
 use super::RUST_KEYWORDS;
-use crate::v2::models::{CollectionFormat, ParameterIn, JSON_CODER, JSON_MIME};
+use crate::v2::models::{CollectionFormat, HttpMethod, ParameterIn, JSON_CODER, JSON_MIME};
 use heck::{CamelCase, KebabCase, SnekCase};
 
+use std::collections::HashSet;
 use std::fmt::{self, Display, Write};
 use std::iter;
 use std::rc::Rc;
@@ -24,11 +28,19 @@ impl ApiObject {
     /// for building the actual object.
     // FIXME: Make operations generic across builders. This will reduce the
     // number of structs generated.
-    pub fn impl_repr<'a>(&'a self, helper_module_prefix: &'a str) -> ApiObjectImpl<'a> {
+    ///
+    /// `Any` types (which aren't specified explicitly) are substituted with
+    /// the given type (if any) instead of the `Any` value type of the coders.
+    pub fn impl_repr<'a>(
+        &'a self,
+        helper_module_prefix: &'a str,
+        any_value: Option<&'a str>,
+    ) -> ApiObjectImpl<'a> {
         let needs_any = self.fields.iter().any(|f| f.needs_any);
         // Always emit a builder for API objects (regardless of operations).
         let main_builder = ApiObjectBuilder {
             helper_module_prefix,
+            any_value,
             object: &self.name,
             body_required: true,
             fields: &self.fields,
@@ -48,20 +60,24 @@ impl ApiObject {
                     .map(move |(&method, req)| ApiObjectBuilder {
                         idx,
                         is_list_op: req.listable,
+                        fallback_taken: false,
                         multiple_builders_exist: {
                             let mut iter =
                                 self.paths.values().flat_map(|path_ops| path_ops.req.iter());
                             iter.next().is_some() && iter.next().is_some()
                         },
                         helper_module_prefix,
+                        any_value,
                         rel_path: Some(path),
                         description: req.description.as_ref().map(String::as_str),
                         object: &self.name,
                         op_id: req.id.as_ref().map(String::as_str),
                         deprecated: req.deprecated,
+                        tags: &req.tags,
                         method: Some(method),
                         body_required: req.body_required,
                         encoding: req.encoding.as_ref(),
+                        encodings: &req.encodings,
                         decoding: req.decoding.as_ref(),
                         fields: &self.fields,
                         global_params: &path_ops.params,
@@ -70,21 +86,47 @@ impl ApiObject {
                         response: Response {
                             ty_path: req.response.ty_path.as_ref().map(String::as_str),
                             contains_any: req.response.contains_any,
+                            event_stream: req.response.event_stream,
+                            empty: req.response.empty,
+                            statuses: req
+                                .response
+                                .statuses
+                                .iter()
+                                .map(|s| StatusResponse {
+                                    codes: s.codes.clone(),
+                                    ty_path: s.ty_path.as_ref().map(AsRef::as_ref),
+                                })
+                                .collect(),
+                            enum_name: req.response.enum_name.as_deref(),
                         },
                     })
             });
 
+        let mut builders = iter::once(if main_builder.fields.is_empty() {
+            None
+        } else {
+            Some(main_builder)
+        })
+        .filter_map(|b| b)
+        .chain(path_iter)
+        .collect::<Vec<_>>();
+
+        // Operations without a usable ID fallback to method-based names,
+        // which could've already been claimed by other operation IDs.
+        let claimed = builders
+            .iter()
+            .filter_map(ApiObjectBuilder::op_id_fn_name)
+            .collect::<HashSet<_>>();
+        for builder in builders.iter_mut().filter(|b| b.op_id_fn_name().is_none()) {
+            builder.fallback_taken = builder
+                .constructor_fn_name()
+                .map(|n| claimed.contains(&n))
+                .unwrap_or(false);
+        }
+
         ApiObjectImpl {
             inner: self,
-            builders: iter::once(if main_builder.fields.is_empty() {
-                None
-            } else {
-                Some(main_builder)
-            })
-            .filter_map(|b| b)
-            .chain(path_iter)
-            .collect::<Vec<_>>()
-            .into(),
+            builders: builders.into(),
         }
     }
 }
@@ -101,24 +143,34 @@ impl<'a> ApiObjectImpl<'a> {
                 write!(f, "\n      about: {:?}", desc)?;
             }
 
+            // Array bodies are read from payload (like objects).
+            let array_body = builder
+                .struct_fields_iter()
+                .find(|f| f.param_loc == Some(ParameterIn::Body));
             let mut iter = builder
                 .struct_fields_iter()
-                .filter(|f| f.prop.is_parameter())
+                .filter(|f| f.prop.is_parameter() && f.param_loc != Some(ParameterIn::Body))
                 .peekable();
 
             // Has at least one argument or body.
-            if iter.peek().is_some() || builder.body_required {
+            if iter.peek().is_some() || builder.body_required || array_body.is_some() {
                 f.write_str("\n      args:")?;
-                if builder.body_required {
+                let schema = match array_body.as_ref() {
+                    Some(field) => Some(field.ty),
+                    None if builder.body_required => Some(self.inner.name.as_str()),
+                    None => None,
+                };
+
+                if let Some(s) = schema {
                     write!(
                         f,
                         "
         - payload:
             long: payload
-            help: \"Path to payload (schema: {obj}) or pass '-' for stdin\"
+            help: \"Path to payload (schema: {schema}) or pass '-' for stdin\"
             takes_value: true
             required: true",
-                        obj = self.inner.name
+                        schema = s
                     )?;
                 }
             }
@@ -176,6 +228,11 @@ impl<'a> ApiObjectImpl<'a> {
         }
 
         for builder in &self.builders[1..] {
+            // Unreachable operations have already been reported by the emitter.
+            if builder.constructor_fn_name().is_none() {
+                continue;
+            }
+
             let name = match builder.op_id {
                 Some(n) => n.to_kebab_case(),
                 None => {
@@ -201,6 +258,12 @@ impl<'a> ApiObjectImpl<'a> {
         F: Write,
     {
         for builder in &*self.builders {
+            // Unreachable operations have already been reported by the emitter.
+            let name = match builder.constructor_fn_name() {
+                Some(n) => n,
+                None => continue,
+            };
+
             let mut temp = String::new();
             let has_fields = builder.has_atleast_one_field();
             if builder.description.is_none() {
@@ -213,14 +276,12 @@ impl<'a> ApiObjectImpl<'a> {
 
             // All builder constructor functions are inlined.
             temp.write_str("    #[inline]\n    pub fn ")?;
-            if let Some(name) = builder.constructor_fn_name() {
-                temp.write_str(&name)?;
+            temp.write_str(&name)?;
+
+            if builder.method.is_some() {
                 ApiObject::write_docs(builder.description.as_ref(), f, 1)?;
             } else {
-                // If we can't generate a name of a builder, then we go for a
-                // simple object builder.
                 f.write_str("\n    /// Create a builder for this object.")?;
-                temp.write_str("builder")?;
             }
 
             // Now that we've written the docs, we can write the actual method signature.
@@ -274,6 +335,126 @@ impl<'a> ApiObjectImpl<'a> {
         Ok(())
     }
 
+    /// Writes the `new` function for objects with required fields, which takes
+    /// the fields required for constructing the object (i.e., not read-only).
+    fn write_new_method<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let builder = match self.builders.iter().find(|b| b.method.is_none()) {
+            Some(b) => b,
+            None => return Ok(()),
+        };
+
+        // Objects without required fields can be defaulted, and operations
+        // could have already claimed the name.
+        if !self.inner.fields.iter().any(|f| f.is_required)
+            || self
+                .builders
+                .iter()
+                .any(|b| b.constructor_fn_name() == Some("new".into()))
+        {
+            return Ok(());
+        }
+
+        let builder_impl = builder.impl_repr();
+        let fields = builder
+            .struct_fields_iter()
+            .filter(|f| f.prop.is_required())
+            .map(|f| {
+                let mut name = f.name.to_snek_case();
+                if RUST_KEYWORDS.iter().any(|&k| k == name) {
+                    name.insert_str(0, "r#");
+                }
+
+                (name, f)
+            })
+            .collect::<Vec<_>>();
+
+        f.write_str("\n    /// Create this object with the fields required for constructing it.")?;
+        f.write_str("\n    #[inline]\n    pub fn new(")?;
+        fields
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, (name, field))| {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+
+                f.write_str(name)?;
+                f.write_str(": ")?;
+                builder_impl.write_builder_ty(
+                    field.ty,
+                    field.strict_child_fields,
+                    field.needs_any,
+                    field.custom_type,
+                    f,
+                )
+            })?;
+
+        f.write_str(") -> Self {\n        Self::builder()")?;
+        fields
+            .iter()
+            .try_for_each(|(name, _)| write!(f, ".{}({})", name, name))?;
+        f.write_str(".build()\n    }\n")
+    }
+
+    /// Writes the `validate` method for objects with fields which have
+    /// forbidden values (from `not` schemas).
+    fn write_validate_method<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let fields = self
+            .inner
+            .fields
+            .iter()
+            .filter(|f| !f.forbidden.is_empty() && !f.needs_any)
+            .collect::<Vec<_>>();
+        if fields.is_empty() {
+            return Ok(());
+        }
+
+        let prefix = self.builders[0].helper_module_prefix;
+        f.write_str("\nimpl")?;
+        let needs_any = self.inner.fields.iter().any(|f| f.needs_any);
+        if needs_any {
+            ApiObject::write_any_generic(f)?;
+        }
+
+        f.write_str(" ")?;
+        f.write_str(&self.inner.name)?;
+        if needs_any {
+            ApiObject::write_any_generic(f)?;
+        }
+
+        write!(
+            f,
+            " {{
+    /// Checks the values of this object against the `not` constraints in its schema.
+    pub fn validate(&self) -> Result<(), {}util::ValidationError> {{",
+            prefix
+        )?;
+
+        for field in fields {
+            let forbidden = field
+                .forbidden
+                .iter()
+                .map(|v| format!("{:?}", v.to_string()))
+                .collect::<Vec<_>>();
+            write!(
+                f,
+                "\n        {}util::check_not({:?}, &self.{}, &[{}])?;",
+                prefix,
+                field.name,
+                field.rust_name(),
+                forbidden.join(", ")
+            )?;
+        }
+
+        f.write_str("\n        Ok(())\n    }\n}\n")
+    }
+
     /// Writes the `Into` impl for fulfilled builders (if they have a body).
     fn write_into_impl<F>(&self, builder: &ApiObjectBuilder<'_>, f: &mut F) -> fmt::Result
     where
@@ -317,17 +498,113 @@ impl<'a> ApiObjectImpl<'a> {
 /// Represents the API object builder impl.
 pub struct ApiObjectBuilderImpl<'a, 'b>(&'a ApiObjectBuilder<'b>);
 
+/// Represents the standalone struct for the query parameters of an
+/// operation (along with the builder method which accepts it).
+pub struct ApiObjectBuilderQuery<'a, 'b>(&'a ApiObjectBuilder<'b>);
+
 impl<'a> ApiObjectBuilder<'a> {
     /// Returns a struct representing the impl for this builder.
     pub fn impl_repr(&self) -> ApiObjectBuilderImpl<'_, '_> {
         ApiObjectBuilderImpl(self)
     }
+
+    /// Returns a struct representing the query struct for this builder
+    /// (if the operation has query parameters).
+    pub fn query_repr(&self) -> Option<ApiObjectBuilderQuery<'_, '_>> {
+        if self
+            .struct_fields_iter()
+            .any(|f| f.param_loc == Some(ParameterIn::Query))
+        {
+            Some(ApiObjectBuilderQuery(self))
+        } else {
+            None
+        }
+    }
+
+    /// Writes the (unsafe) transmute of this builder into the given type. This relies
+    /// on the builder being `repr(C)` (see `ApiObjectBuilder::has_markers`). The layouts
+    /// of both types are checked in debug builds before transmuting.
+    ///
+    /// The compiler can't check the sizes of builders generic over `Any`, so those
+    /// are copied bitwise (and the original is forgotten) instead.
+    fn write_transmute<F>(&self, ty: &str, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        write!(
+            f,
+            "{}util::debug_assert_same_layout::<Self, {}>();\n        ",
+            self.helper_module_prefix, ty
+        )?;
+
+        if self.needs_any {
+            f.write_str("let this = std::mem::ManuallyDrop::new(self);\n        unsafe { std::mem::transmute_copy(&*this) }")
+        } else {
+            f.write_str("unsafe { std::mem::transmute(self) }")
+        }
+    }
 }
 
 impl<'a, 'b> ApiObjectBuilderImpl<'a, 'b>
 where
     'b: 'a,
 {
+    /// Writes the blocking `Sendable` impl for this builder, which forwards to
+    /// the async impl. Streaming (file) responses are wrapped in blocking readers.
+    pub(super) fn write_blocking_sendable<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let method = match (self.0.rel_path, self.0.method) {
+            (Some(_), Some(m)) => m,
+            _ => return Ok(()),
+        };
+
+        let is_file = method != HttpMethod::Head && self.0.response.is_file() && !self.0.is_list_op;
+        let prefix = self.0.helper_module_prefix;
+        write!(
+            f,
+            "\nimpl<Client: {}client::ApiClient + Sync + 'static",
+            prefix
+        )?;
+        if self.0.needs_any {
+            f.write_str(", Any: serde::Serialize + Sync")?;
+        }
+
+        write!(f, "> {}client::blocking::Sendable<Client> for ", prefix)?;
+        self.0.write_name(f)?;
+        self.0
+            .write_generics_if_necessary(f, None, TypeParameters::ChangeAll)?;
+        if is_file {
+            write!(
+                f,
+                " {{
+    type Output = {prefix}client::blocking::ResponseReader<<<Client as {prefix}client::ApiClient>::Response as {prefix}client::Response>::Stream>;
+
+    fn send(&self, client: &{prefix}client::blocking::Client<Client>) -> Result<Self::Output, {prefix}client::ApiError<Client::Response>> {{
+        let stream = client.block_on({prefix}client::Sendable::send(self, client.inner()))?;
+        Ok(client.reader(stream))
+    }}
+}}
+",
+                prefix = prefix
+            )
+        } else {
+            write!(
+                f,
+                " {{
+    type Output = <Self as {prefix}client::Sendable<Client>>::Output;
+
+    fn send(&self, client: &{prefix}client::blocking::Client<Client>) -> Result<Self::Output, {prefix}client::ApiError<Client::Response>> {{
+        client.block_on({prefix}client::Sendable::send(self, client.inner()))
+    }}
+}}
+",
+                prefix = prefix
+            )
+        }
+    }
+
     /// Writes impl for getting args from `clap::ArgMatches`
     pub(super) fn write_arg_parsing<F>(&self, f: &mut F) -> fmt::Result
     where
@@ -342,12 +619,7 @@ where
         self.0.write_name(f)?;
         self.0.write_generics_if_necessary(
             f,
-            Some(
-                self.0
-                    .encoding
-                    .map(|(_, c)| c.any_value.as_str())
-                    .unwrap_or_else(|| JSON_CODER.any_value.as_str()),
-            ),
+            Some(self.0.default_any_value(self.0.encoding)),
             TypeParameters::ChangeAll,
         )?;
 
@@ -392,6 +664,10 @@ where
 
             f.write_str("\n            param_")?;
             f.write_str(&sk)?;
+            if field.param_loc == Some(ParameterIn::Body) {
+                return f.write_str(": Some(crate::cli::read_from_input(matches)?),");
+            }
+
             let mut ty = String::new();
             ApiObjectBuilder::write_wrapped_ty(
                 self.0.helper_module_prefix,
@@ -433,18 +709,146 @@ where
         )
     }
 
+    /// Writes the `build` method for an object builder (i.e., one that's
+    /// not bound to any operation).
+    fn write_build_method<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        if self.0.method.is_some() || !self.0.body_required {
+            return Ok(());
+        }
+
+        f.write_str("\n    /// Returns the object built using this builder.")?;
+        f.write_str("\n    #[inline]\n    pub fn build(self) -> ")?;
+        f.write_str(self.0.object)?;
+        if self.0.needs_any {
+            ApiObject::write_any_generic(f)?;
+        }
+
+        f.write_str(" {\n        self.body\n    }\n")
+    }
+
+    /// Writes the `build` method in a separate impl for the fulfilled
+    /// object builder (i.e., once all the required fields have been set).
+    fn write_build_method_impl<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        if self.0.method.is_some() || !self.0.body_required {
+            return Ok(());
+        }
+
+        f.write_str("\nimpl")?;
+        if self.0.needs_any {
+            ApiObject::write_any_generic(f)?;
+        }
+
+        f.write_str(" ")?;
+        self.0.write_name(f)?;
+        self.0
+            .write_generics_if_necessary(f, None, TypeParameters::ChangeAll)?;
+        f.write_str(" {")?;
+        self.write_build_method(f)?;
+        f.write_str("}\n")
+    }
+
+    /// Writes the `reset` method for builders with required fields or parameters,
+    /// which resets their type parameters to `Missing*` (so that a configured
+    /// builder can be cloned and reused for other objects or API calls).
+    fn write_reset_method<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        // The method is renamed if it collides with a field or parameter.
+        let method = if self
+            .0
+            .struct_fields_iter()
+            .any(|f| f.name.to_snek_case() == "reset")
+        {
+            "reset_builder"
+        } else {
+            "reset"
+        };
+
+        let mut ret_ty = String::new();
+        self.0.write_name(&mut ret_ty)?;
+        self.0
+            .write_generics_if_necessary(&mut ret_ty, None, TypeParameters::ReplaceAll)?;
+
+        f.write_str("\n    /// Resets this builder, so that the required fields and parameters")?;
+        f.write_str("\n    /// should be set again. Other values are retained.")?;
+        write!(
+            f,
+            "\n    #[inline]\n    pub fn {}(self) -> {} {{\n        ",
+            method, ret_ty
+        )?;
+        self.0.write_transmute(&ret_ty, f)?;
+        f.write_str("\n    }\n")
+    }
+
+    /// Writes the method for wrapping this builder with a borrowed body. The
+    /// builder is marked as having its (required) body fields set, since the
+    /// body is sent instead.
+    fn write_with_body_method<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let body_fields = self
+            .0
+            .struct_fields_iter()
+            .filter(|f| f.prop == Property::RequiredField)
+            .map(|f| f.name)
+            .collect::<Vec<_>>();
+
+        let mut builder_ty = String::new();
+        self.0.write_name(&mut builder_ty)?;
+        self.0.write_generics_if_necessary(
+            &mut builder_ty,
+            None,
+            TypeParameters::ChangeMany(&body_fields),
+        )?;
+
+        let mut body_ty = format!("self::{}", self.0.object);
+        if self.0.needs_any {
+            ApiObject::write_any_generic(&mut body_ty)?;
+        }
+
+        let mut transmute = String::new();
+        self.0.write_transmute(&builder_ty, &mut transmute)?;
+        write!(
+            f,
+            "
+    /// Sends the given body for this API call (instead of the one in this builder),
+    /// without moving or cloning it. The body fields needn't be set in that case.
+    #[inline]
+    pub fn with_body(self, body: &{body}) -> {prefix}client::BorrowedBody<'_, {builder}, {body}> {{
+        let builder = {{
+            {transmute}
+        }};
+        {prefix}client::BorrowedBody::new(builder, body)
+    }}
+",
+            body = body_ty,
+            builder = builder_ty,
+            transmute = transmute.replace('\n', "\n    "),
+            prefix = self.0.helper_module_prefix
+        )
+    }
+
     /// Builds the method parameter type using the actual field type.
     ///
     /// For example, if a field is `Vec<T>`, then we replace it (in builder method)
-    /// with `impl Iterator<Item=Into<T>>`, and if we had `BTreeMap<String, T>`,
-    /// then we replace it with `impl Iterator<Item = (String, T)>` and
-    /// we do this... recursively.
+    /// with `impl IntoIterator<Item=Into<T>>`, and if we had `BTreeMap<String, T>`,
+    /// then we replace it with `impl IntoIterator<Item = (String, T)>` and
+    /// we do this... recursively. Custom types (`custom`) are treated like simple types.
     // FIXME: Investigate if there's a better way.
     fn write_builder_ty<F>(
         &self,
         ty: &str,
         req: &[String],
         needs_any: bool,
+        custom: bool,
         f: &mut F,
     ) -> fmt::Result
     where
@@ -452,15 +856,18 @@ where
     {
         if let Some(i) = ty.find('<') {
             if ty[..i].ends_with("Vec") {
-                f.write_str("impl Iterator<Item = ")?;
-                self.write_builder_ty(&ty[i + 1..ty.len() - 1], req, needs_any, f)?;
+                f.write_str("impl IntoIterator<Item = ")?;
+                self.write_builder_ty(&ty[i + 1..ty.len() - 1], req, needs_any, custom, f)?;
                 f.write_str(">")?;
             } else if ty[..i].ends_with("std::collections::BTreeMap") {
-                f.write_str("impl Iterator<Item = (String, ")?;
-                self.write_builder_ty(&ty[i + 9..ty.len() - 1], req, needs_any, f)?;
+                f.write_str("impl IntoIterator<Item = (String, ")?;
+                self.write_builder_ty(&ty[i + 9..ty.len() - 1], req, needs_any, custom, f)?;
                 f.write_str(")>")?;
+            } else {
+                // Other generic types (i.e., objects with `Any` already replaced).
+                f.write_str(ty)?;
             }
-        } else if ApiObject::is_simple_type(ty) {
+        } else if custom || ApiObject::is_simple_type(ty) {
             write!(f, "impl Into<{}", ty)?;
             if needs_any && ty != ANY_GENERIC_PARAMETER {
                 ApiObject::write_any_generic(f)?;
@@ -509,13 +916,15 @@ where
     {
         if let Some(i) = ty.find('<') {
             if ty[..i].ends_with("Vec") {
-                f.write_str("value.map(|value| ")?;
+                f.write_str("value.into_iter().map(|value| ")?;
                 Self::write_value_map(&ty[i + 1..ty.len() - 1], f)?;
                 f.write_str(").collect::<Vec<_>>()")?;
             } else if ty[..i].ends_with("std::collections::BTreeMap") {
-                f.write_str("value.map(|(key, value)| (key, ")?;
+                f.write_str("value.into_iter().map(|(key, value)| (key, ")?;
                 Self::write_value_map(&ty[i + 9..ty.len() - 1], f)?;
                 f.write_str(")).collect::<std::collections::BTreeMap<_, _>>()")?;
+            } else {
+                f.write_str("value")?;
             }
         } else {
             f.write_str("value")?;
@@ -536,8 +945,6 @@ where
             field.prop.is_required(),
             self.0.needs_container(),
         );
-        let needs_trailing_dash =
-            field.prop.is_field() && RUST_KEYWORDS.iter().any(|&k| k == field_name);
 
         ApiObject::write_docs(field.desc, f, 1)?;
         if field.desc.is_none() {
@@ -555,18 +962,30 @@ where
         if field.needs_file {
             f.write_str("impl AsRef<std::path::Path>")?;
         } else {
-            self.write_builder_ty(&field.ty, &field.strict_child_fields, field.needs_any, f)?;
+            self.write_builder_ty(
+                field.ty,
+                field.strict_child_fields,
+                field.needs_any,
+                field.custom_type,
+                f,
+            )?;
         }
 
         f.write_str(") -> ")?;
+        let mut ret_ty = String::new();
         if prop_is_required {
-            self.0.write_name(f)?;
-            self.0
-                .write_generics_if_necessary(f, None, TypeParameters::ChangeOne(field.name))?;
+            self.0.write_name(&mut ret_ty)?;
+            self.0.write_generics_if_necessary(
+                &mut ret_ty,
+                None,
+                TypeParameters::ChangeOne(field.name),
+            )?;
         } else {
-            f.write_str("Self")?;
+            ret_ty.push_str("Self");
         }
 
+        f.write_str(&ret_ty)?;
+
         f.write_str(" {\n        self.")?;
         if needs_container {
             f.write_str("inner.")?;
@@ -574,21 +993,29 @@ where
 
         if prop_is_parameter {
             f.write_str("param_")?;
+            f.write_str(&field_name)?;
         // If it's not a parameter, then it's definitely a body field.
-        } else if self.0.body_required {
-            f.write_str("body.")?;
-        }
+        } else {
+            if self.0.body_required {
+                f.write_str("body.")?;
+            }
 
-        f.write_str(&field_name)?;
-        if needs_trailing_dash {
-            f.write_str("_")?;
+            f.write_str(field.field_name.as_ref().unwrap_or(&field_name))?;
         }
 
         f.write_str(" = ")?;
-        if prop_is_parameter || !prop_is_required {
+        if field.is_patch {
+            f.write_str(self.0.helper_module_prefix)?;
+            f.write_str("util::Patch::Value(")?;
+        } else if field.is_option {
             f.write_str("Some(")?;
         }
 
+        // Builders of (boxed) children can't be converted into boxes directly.
+        if field.boxed {
+            f.write_str("Box::new(")?;
+        }
+
         if field.needs_file {
             f.write_str("value.as_ref().into()")?;
         } else if field.overridden && self.0.body_required {
@@ -602,43 +1029,126 @@ where
             }
 
             f.write_str("body.")?;
-            f.write_str(&field_name)?;
-            if needs_trailing_dash {
-                f.write_str("_")?;
-            }
-
+            f.write_str(field.field_name.as_ref().unwrap_or(&field_name))?;
             f.write_str(" = val.clone().into();")?;
             f.write_str("\n            val\n        }")?;
         } else {
             Self::write_value_map(field.ty, f)?;
         }
 
-        if prop_is_parameter || !prop_is_required {
+        if field.boxed {
+            f.write_str(")")?;
+        }
+
+        if field.is_option {
             f.write_str(")")?;
         }
 
         f.write_str(";\n        ")?;
         // We need to transmute only if there's a required field/parameter.
         if prop_is_required {
-            f.write_str("unsafe { std::mem::transmute(self) }")?;
+            self.0.write_transmute(&ret_ty, f)?;
         } else {
             f.write_str("self")?;
         }
 
-        f.write_str("\n    }\n")
+        f.write_str("\n    }\n")?;
+
+        // Optional parameters can be cleared. Required parameters can't be,
+        // because that'd break the guarantees of the builder.
+        if prop_is_parameter && !prop_is_required && !field.overridden {
+            write!(
+                f,
+                "
+    /// Clears the value set for `{name}` (if any).
+    #[inline]
+    pub fn clear_{name}(mut self) -> Self {{
+        self.{container}param_{name} = None;
+        self
+    }}
+",
+                name = field_name,
+                container = if needs_container { "inner." } else { "" },
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the method for inserting entries into the flattened map
+    /// of additional properties.
+    fn write_extra_method<F>(&self, field: &ObjectField, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        // We don't want to collide with the setters of other properties.
+        let mut method = String::from("extra");
+        while self
+            .0
+            .struct_fields_iter()
+            .any(|f| f.name.to_snek_case() == method)
+        {
+            method.push('_');
+        }
+
+        let map_ty = field.ty_path.as_str();
+        let value_ty = &map_ty[map_ty.find(',').expect("expected map") + 2..map_ty.len() - 1];
+        write!(
+            f,
+            "
+    /// Inserts an additional property into this object (replacing the previous value for that key, if any).
+    #[inline]
+    pub fn {}(mut self, key: impl Into<String>, value: ",
+            method
+        )?;
+        self.write_builder_ty(
+            value_ty,
+            &field.child_req_fields,
+            field.needs_any,
+            field.custom_type,
+            f,
+        )?;
+        f.write_str(") -> Self {\n        self.")?;
+        if self.0.needs_container() {
+            f.write_str("inner.")?;
+        }
+
+        f.write_str("body.")?;
+        f.write_str(&field.rust_name())?;
+        f.write_str(".insert(key.into(), ")?;
+        Self::write_value_map(value_ty, f)?;
+        f.write_str(");\n        self\n    }\n")
     }
 }
 
+/// Methods (in `Sendable` impls) for modifying the request.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ModifyMethod {
+    /// `modify`, which encodes the body in the preferred content type.
+    Default,
+    /// `modify_with_content_type`, which encodes the body in the given content type.
+    ContentType,
+    /// `modify_with_body`, which encodes the given body (instead of the builder's).
+    Body,
+}
+
 /// Codegen for `Sendable` trait for operation builders.
 struct SendableCodegen<'a, 'b> {
     builder: &'a ApiObjectBuilder<'b>,
     needs_container: bool,
     is_multipart: bool,
     path_items: String,
+    /// Checks for empty values of path parameters.
+    path_checks: String,
+    /// Delimited query parameters, which are encoded into the relative path
+    /// (so that their delimiters aren't encoded).
+    path_query: String,
     headers: String,
     form: String,
     query: String,
     multi_value_query: Vec<String>,
+    /// Reference to the body parameter (if it's an array body).
+    body_param: Option<String>,
 }
 
 impl<'a, 'b> From<&'a ApiObjectBuilder<'b>> for SendableCodegen<'a, 'b> {
@@ -647,11 +1157,14 @@ impl<'a, 'b> From<&'a ApiObjectBuilder<'b>> for SendableCodegen<'a, 'b> {
             builder,
             needs_container: builder.needs_container(),
             path_items: String::new(),
+            path_checks: String::new(),
+            path_query: String::new(),
             headers: String::new(),
             is_multipart: builder.struct_fields_iter().any(|f| f.needs_file),
             form: String::new(),
             query: String::new(),
             multi_value_query: vec![],
+            body_param: None,
         }
     }
 }
@@ -667,8 +1180,18 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
             _ => return Ok(()),
         };
 
+        // `HEAD` responses don't have a body, so we shouldn't attempt to
+        // decode them (even if the spec specifies a schema).
+        let has_body = method != HttpMethod::Head;
+        let is_file = has_body && self.builder.response.is_file();
+        let is_event_stream = has_body && !is_file && self.builder.response.event_stream;
+        // Successful responses without schema (e.g., `204 No Content`) aren't decoded either.
+        let is_empty = has_body && !is_event_stream && self.builder.response.empty;
+        // Successful responses with different schemas are decoded into an enum.
+        let has_statuses =
+            has_body && !is_file && !is_event_stream && !self.builder.response.statuses.is_empty();
         f.write_str("\n")?;
-        if self.builder.response.is_file() {
+        if !has_body || is_empty || is_file || is_event_stream {
             f.write_str("#[async_trait::async_trait]\n")?;
         }
 
@@ -676,8 +1199,10 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
         f.write_str(self.builder.helper_module_prefix)?;
         f.write_str("client::ApiClient + Sync + 'static")?;
 
+        // Bodies are encoded by reference (see `Sendable::modify_with_body`),
+        // so the ones generic over `Any` should be `Sync`.
         if self.builder.needs_any {
-            f.write_str(", Any: serde::Serialize")?;
+            f.write_str(", Any: serde::Serialize + Sync")?;
         }
 
         f.write_str("> ")?;
@@ -687,40 +1212,63 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
         self.builder
             .write_generics_if_necessary(f, None, TypeParameters::ChangeAll)?;
         f.write_str(" {\n    type Output = ")?;
-        if self.builder.is_list_op {
-            f.write_str("Vec<")?;
-        }
+        let mut accepted_range = None;
+        if !has_body || is_empty {
+            f.write_str("()")?;
+        } else if has_statuses {
+            f.write_str("self::")?;
+            f.write_str(self.builder.response.enum_name.unwrap_or_default())?;
+            if self.builder.response.contains_any {
+                accepted_range = Some(match self.builder.decoding {
+                    Some((r, _)) => r.as_str(),
+                    None => JSON_MIME.0.as_ref(),
+                });
+            }
+        } else {
+            if is_event_stream {
+                write!(f, "{prefix}util::EventStream<<<Client as {prefix}client::ApiClient>::Response as {prefix}client::Response>::Stream, ",
+                       prefix=self.builder.helper_module_prefix)?;
+            }
 
-        if self.builder.response.is_file() {
-            write!(f, "{prefix}util::ResponseStream<<<Client as {prefix}client::ApiClient>::Response as {prefix}client::Response>::Stream>",
-                   prefix=self.builder.helper_module_prefix)?;
-        } else if let Some(resp) = self.builder.response.ty_path.as_ref() {
-            // If we've acquired a response type, then write that.
-            f.write_str(resp)?;
-        }
+            if self.builder.is_list_op {
+                f.write_str("Vec<")?;
+            }
 
-        // If the type has `Any` or if we don't know what we're going to get, then
-        // assume we have to write `Any` type.
-        let mut accepted_range = None;
-        if self.builder.needs_any
-            || self.builder.response.ty_path.is_none()
-            || self.builder.response.contains_any
-        {
-            let (range, coder) = match self.builder.decoding {
-                Some(&(ref r, ref c)) => (r.as_str(), c),
-                None => ((*JSON_MIME).0.as_ref(), &*JSON_CODER),
-            };
+            if is_file {
+                write!(f, "{prefix}util::ResponseStream<<<Client as {prefix}client::ApiClient>::Response as {prefix}client::Response>::Stream>",
+                       prefix=self.builder.helper_module_prefix)?;
+            } else if let Some(resp) = self.builder.response.ty_path.as_ref() {
+                // If we've acquired a response type, then write that.
+                f.write_str(resp)?;
+            }
 
-            accepted_range = Some(range);
-            if self.builder.response.ty_path.is_some() {
-                write!(f, "<{}>", coder.any_value)?;
-            } else {
-                f.write_str(&coder.any_value)?;
+            // If the type has `Any` or if we don't know what we're going to get, then
+            // assume we have to write `Any` type.
+            if self.builder.needs_any
+                || self.builder.response.ty_path.is_none()
+                || self.builder.response.contains_any
+            {
+                let range = match self.builder.decoding {
+                    Some((r, _)) => r.as_str(),
+                    None => JSON_MIME.0.as_ref(),
+                };
+
+                accepted_range = Some(range);
+                let any_value = self.builder.default_any_value(self.builder.decoding);
+                if self.builder.response.ty_path.is_some() {
+                    write!(f, "<{}>", any_value)?;
+                } else {
+                    f.write_str(any_value)?;
+                }
             }
-        }
 
-        if self.builder.is_list_op {
-            f.write_str(">")?;
+            if self.builder.is_list_op {
+                f.write_str(">")?;
+            }
+
+            if is_event_stream {
+                f.write_str(">")?;
+            }
         }
 
         f.write_str(";\n\n    const METHOD: http::Method = http::Method::")?;
@@ -734,47 +1282,345 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
                 Some(ParameterIn::Header) => self.handle_header_param(field),
                 Some(ParameterIn::FormData) => self.handle_form_param(field),
                 Some(ParameterIn::Query) => self.handle_query_param(field),
+                Some(ParameterIn::Body) => self.handle_body_param(field),
                 _ => (),
             });
 
         // Determine if we need a `&'static str` or `String`
-        if self.path_items.is_empty() {
-            write!(f, "\"{}\".into()", path)?;
-        } else {
-            write!(f, "format!(\"{}\"{}).into()", path, self.path_items)?;
-        }
-
-        f.write_str("\n    }")?;
-
+        if !self.path_query.is_empty() {
+            if self.path_items.is_empty() {
+                write!(f, "let mut path = String::from(\"{}\");", path)?;
+            } else {
+                write!(
+                    f,
+                    "let mut path = format!(\"{}\"{});",
+                    path, self.path_items
+                )?;
+            }
+
+            write!(
+                f,
+                "\n        {}util::append_query(&mut path, &[{}\n        ]);\n        path.into()",
+                self.builder.helper_module_prefix, self.path_query
+            )?;
+        } else if self.path_items.is_empty() {
+            write!(f, "\"{}\".into()", path)?;
+        } else {
+            write!(f, "format!(\"{}\"{}).into()", path, self.path_items)?;
+        }
+
+        f.write_str("\n    }")?;
+
+        if !self.path_checks.is_empty() {
+            f.write_str("\n\n    fn empty_path_param(&self) -> Option<&'static str> {")?;
+            f.write_str(&self.path_checks)?;
+            f.write_str("\n        None\n    }")?;
+        }
+
+        // Event streams are requested explicitly (each event is decoded separately).
+        let fallback_range = accepted_range;
+        if is_event_stream {
+            accepted_range = Some("text/event-stream");
+        }
+
         // Check whether `modify` method needs to be overridden (i.e. body and other params).
-        if self.builder.body_required
+        if is_event_stream
+            || self.builder.body_required
+            || self.body_param.is_some()
             || !self.form.is_empty()
             || !self.query.is_empty()
             || !self.multi_value_query.is_empty()
             || !self.headers.is_empty()
         {
-            self.write_modify_method(f, accepted_range)?;
+            let method = if self.builder.body_required {
+                ModifyMethod::Body
+            } else {
+                ModifyMethod::Default
+            };
+
+            self.write_modify_method(f, accepted_range, method)?;
+        }
+
+        // Operations accepting multiple content types can have their body
+        // encoded in any of those types.
+        if self.builder.body_required && self.builder.encodings.len() > 1 {
+            self.write_modify_method(f, accepted_range, ModifyMethod::ContentType)?;
+        }
+
+        // Responses with `Any` can be decoded with the preferred decoder
+        // (if we can't find a decoder for their actual media type).
+        if let Some(r) = fallback_range.filter(|_| !is_event_stream) {
+            write!(
+                f,
+                "\n\n    fn fallback_media_range(&self) -> Option<&'static str> {{\n        Some({:?})\n    }}",
+                r
+            )?;
         }
 
-        if self.builder.response.is_file() {
+        if !has_body {
+            self.write_empty_acceptor(f)?;
+        } else if is_empty {
+            self.write_unit_acceptor(f)?;
+        } else if is_file {
             self.write_file_acceptor(f)?;
+        } else if is_event_stream {
+            self.write_event_stream_acceptor(f)?;
+        }
+
+        f.write_str("\n}\n")?;
+        self.write_wrapper_methods(method, f)?;
+        if has_statuses {
+            self.write_response_enum(path, method, f)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the enum for successful responses of this operation along with its
+    /// `FromResponse` impl, which decodes the responses based on their status codes.
+    fn write_response_enum<F>(&self, path: &str, method: HttpMethod, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let prefix = self.builder.helper_module_prefix;
+        let name = self.builder.response.enum_name.unwrap_or_default();
+        write!(
+            f,
+            "
+/// Successful responses of the `{method} {path}` operation (based on their status codes).
+/// Other successful responses are decoded as the first variant.
+#[derive(Debug, Clone)]
+pub enum {name} {{",
+            method = method.to_string().to_uppercase(),
+            path = path,
+            name = name
+        )?;
+
+        let statuses = &self.builder.response.statuses;
+        for resp in statuses {
+            let codes = resp
+                .codes
+                .iter()
+                .map(|&c| match StatusResponse::<&str>::reason_phrase(c) {
+                    Some(r) => format!("`{} {}`", c, r),
+                    None => format!("`{}`", c),
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, "\n    /// {}\n    {}", codes, resp.variant_name())?;
+            if let Some(ty) = resp.ty_path {
+                write!(f, "({})", ty)?;
+            }
+
+            f.write_str(",")?;
+        }
+
+        write!(
+            f,
+            "
+}}
+
+#[async_trait::async_trait]
+impl {prefix}client::FromResponse for {name} {{
+    async fn from_response<R: {prefix}client::Response + 'static>(resp: R, fallback_range: Option<&'static str>) -> Result<(Self, {prefix}client::RawResponse<R>), {prefix}client::ApiError<R>> {{
+        let status = resp.status();
+        match status.as_u16() {{",
+            prefix = prefix,
+            name = name
+        )?;
+
+        // The first response is the fallback for unknown status codes.
+        for (i, resp) in statuses
+            .iter()
+            .enumerate()
+            .skip(1)
+            .chain(statuses.iter().enumerate().take(1))
+        {
+            f.write_str("\n            ")?;
+            if i == 0 {
+                f.write_str("_")?;
+            } else {
+                let codes = resp.codes.iter().map(u16::to_string).collect::<Vec<_>>();
+                f.write_str(&codes.join(" | "))?;
+            }
+
+            if resp.ty_path.is_some() {
+                write!(
+                    f,
+                    " => {prefix}client::decode_response(resp, fallback_range).await
+                .map(|(v, raw)| ({name}::{variant}(v), raw)),",
+                    prefix = prefix,
+                    name = name,
+                    variant = resp.variant_name()
+                )?;
+            } else {
+                write!(
+                    f,
+                    " => {{
+                let (_, bytes) = resp.body_bytes().await?;
+                Ok(({name}::{variant}, {prefix}client::RawResponse {{ status, body: {prefix}client::RawBody::Bytes(bytes) }}))
+            }},",
+                    prefix = prefix,
+                    name = name,
+                    variant = resp.variant_name()
+                )?;
+            }
+        }
+
+        f.write_str("\n        }\n    }\n}\n")
+    }
+
+    /// Writes the methods for wrapping the (complete) builder with a timeout and
+    /// an `ETag` (for `GET` and `HEAD` operations), unless they conflict with
+    /// property methods.
+    fn write_wrapper_methods<F>(&self, method: HttpMethod, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let has_field = |name: &str| {
+            self.builder
+                .struct_fields_iter()
+                .any(|field| field.name.to_snek_case() == name)
+        };
+
+        let mut methods = String::new();
+        if !has_field("timeout") {
+            write!(
+                methods,
+                "
+    /// Overrides the client's timeout for this API call.
+    #[inline]
+    pub fn timeout(self, timeout: std::time::Duration) -> {prefix}client::Timeout<Self> {{
+        {prefix}client::Timeout::new(self, timeout)
+    }}
+",
+                prefix = self.builder.helper_module_prefix
+            )?;
+        }
+
+        let is_conditional = method == HttpMethod::Get || method == HttpMethod::Head;
+        if is_conditional && !has_field("if_none_match") {
+            write!(
+                methods,
+                "
+    /// Makes this a conditional API call using the `ETag` from an earlier response.
+    #[inline]
+    pub fn if_none_match(self, etag: impl Into<String>) -> {prefix}client::IfNoneMatch<Self> {{
+        {prefix}client::IfNoneMatch::new(self, etag)
+    }}
+",
+                prefix = self.builder.helper_module_prefix
+            )?;
+        }
+
+        if !has_field("header") {
+            write!(
+                methods,
+                "
+    /// Adds a header (which isn't declared in the spec) to this API call.
+    #[inline]
+    pub fn header(self, name: impl AsRef<str>, value: impl AsRef<str>) -> {prefix}client::ExtraHeaders<Self> {{
+        {prefix}client::ExtraHeaders::new(self).header(name, value)
+    }}
+",
+                prefix = self.builder.helper_module_prefix
+            )?;
+        }
+
+        if !has_field("dry_run") {
+            write!(
+                methods,
+                "
+    /// Returns the request for this API call without sending it (i.e., without any network I/O).
+    #[inline]
+    pub fn dry_run(&self) -> Result<{prefix}client::DryRunRequest, {prefix}client::ApiError<reqwest::r#async::Response>>
+    where
+        Self: {prefix}client::Sendable<{prefix}client::DryRunClient>,
+    {{
+        {prefix}client::dry_run(self)
+    }}
+",
+                prefix = self.builder.helper_module_prefix
+            )?;
+        }
+
+        if self.builder.body_required
+            && self.builder.encodings.len() > 1
+            && !has_field("content_type")
+        {
+            write!(
+                methods,
+                "
+    /// Encodes the body in the given content type (one of {ranges}) for this API call.
+    #[inline]
+    pub fn content_type(self, content_type: impl Into<String>) -> {prefix}client::ContentType<Self> {{
+        {prefix}client::ContentType::new(self, content_type)
+    }}
+",
+                ranges = self
+                    .builder
+                    .encodings
+                    .iter()
+                    .map(|(r, _)| format!("`{}`", r))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                prefix = self.builder.helper_module_prefix
+            )?;
+        }
+
+        if methods.is_empty() {
+            return Ok(());
+        }
+
+        f.write_str("\nimpl")?;
+        if self.builder.needs_any {
+            ApiObject::write_any_generic(f)?;
         }
 
-        f.write_str("\n}\n")
+        f.write_str(" ")?;
+        self.builder.write_name(f)?;
+        self.builder
+            .write_generics_if_necessary(f, None, TypeParameters::ChangeAll)?;
+        f.write_str(" {")?;
+        f.write_str(&methods)?;
+        f.write_str("}\n")
     }
 
     /// Handle field for a path parameter.
     fn handle_path_param(&mut self, field: StructField) {
-        let _ = write!(self.path_items, ", {}=self.", &field.name);
         let name = field.name.to_snek_case();
-        if self.needs_container {
-            self.path_items.push_str("inner.");
-        }
+        let container = if self.needs_container { "inner." } else { "" };
+        // Values are percent-encoded, so that they always fill a single path segment
+        // (delimited values are encoded separately, so that delimiters are left as is).
+        let value = format!(
+            "self.{}param_{}.as_ref().expect(\"missing parameter {}?\")",
+            container, name, name
+        );
+        let prefix = self.builder.helper_module_prefix;
+        let _ = if field.delimiting.is_empty() {
+            write!(
+                self.path_items,
+                ", {}={}util::encode_path_param(&{}.to_string())",
+                field.param_name, prefix, value
+            )
+        } else {
+            write!(
+                self.path_items,
+                ", {}={}.encode_with({}util::encode_path_param)",
+                field.param_name, value, prefix
+            )
+        };
 
         let _ = write!(
-            self.path_items,
-            "param_{name}.as_ref().expect(\"missing parameter {name}?\")",
-            name = name
+            self.path_checks,
+            "
+        if self.{container}param_{name}.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {{
+            return Some({param:?});
+        }}
+",
+            container = container,
+            name = name,
+            param = field.param_name,
         );
     }
 
@@ -806,7 +1652,7 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
         let _ = write!(
             self.headers,
             "req = req.header({:?}, {});",
-            &field.name,
+            &field.param_name,
             if is_required { &param_ref } else { "&v" }
         );
 
@@ -815,6 +1661,16 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
         }
     }
 
+    /// Handle field for an (array) body parameter.
+    fn handle_body_param(&mut self, field: StructField) {
+        let name = field.name.to_snek_case();
+        self.body_param = Some(format!(
+            "self.{}param_{name}.as_ref().expect(\"missing parameter {name}?\")",
+            if self.needs_container { "inner." } else { "" },
+            name = name
+        ));
+    }
+
     /// Handle field for a form data parameter.
     fn handle_form_param(&mut self, field: StructField) {
         let name = field.name.to_snek_case();
@@ -834,7 +1690,7 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
                 } else {
                     "ser.append_pair"
                 },
-                &field.name,
+                &field.param_name,
                 if self.is_multipart { "" } else { "&" },
             );
 
@@ -850,7 +1706,7 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
             }}",
                 if self.needs_container { "inner." } else { "" },
                 name,
-                &field.name,
+                &field.param_name,
             );
 
             return;
@@ -872,17 +1728,13 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
             } else {
                 "ser.append_pair"
             },
-            &field.name,
+            &field.param_name,
             if self.is_multipart { "" } else { "&" },
         );
     }
 
     /// Handle field for an URL query parameter.
     fn handle_query_param(&mut self, field: StructField) {
-        if !self.query.is_empty() {
-            self.query.push_str(",");
-        }
-
         let name = field.name.to_snek_case();
         if let Some(CollectionFormat::Multi) = field.delimiting.get(0) {
             self.multi_value_query.push(format!(
@@ -892,35 +1744,129 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
             }}).unwrap_or_default()",
                 if self.needs_container { "inner." } else { "" },
                 name,
-                &field.name,
+                &field.param_name,
             ));
 
             return;
         }
 
-        let _ = write!(self.query, "\n            ({:?}, self.", &field.name);
-        if self.needs_container {
-            self.query.push_str("inner.");
+        let container = if self.needs_container { "inner." } else { "" };
+        // Values of delimited parameters are encoded separately (leaving the delimiters
+        // as they are), so they're added to the relative path.
+        if !field.delimiting.is_empty() {
+            let _ = write!(
+                self.path_query,
+                "\n            ({:?}, self.{}param_{}.as_ref().map(|v| v.encode_with({}util::encode_query_value))),",
+                field.param_name, container, name, self.builder.helper_module_prefix
+            );
+
+            return;
         }
 
-        let _ = write!(
-            self.query,
-            "param_{name}.as_ref().map(std::string::ToString::to_string))",
-            name = name
-        );
+        if !self.query.is_empty() {
+            self.query.push_str(",");
+        }
+
+        // Unset parameters are omitted, unless their defaults should be sent.
+        if field.send_default {
+            let _ = write!(
+                self.query,
+                "\n            ({:?}, Some(self.{}param_{}.unwrap_or_default().to_string()))",
+                field.param_name, container, name
+            );
+        } else {
+            let _ = write!(
+                self.query,
+                "\n            ({:?}, self.{}param_{}.as_ref().map(std::string::ToString::to_string))",
+                field.param_name, container, name
+            );
+        }
     }
 
     /// We have determined that we have to override the default `modify` method.
-    fn write_modify_method<F>(&mut self, f: &mut F, accepted_range: Option<&str>) -> fmt::Result
+    ///
+    /// For `ModifyMethod::Body`, this also writes `modify` (which forwards the
+    /// builder's body), so that borrowed bodies are encoded in the same way.
+    fn write_modify_method<F>(
+        &mut self,
+        f: &mut F,
+        accepted_range: Option<&str>,
+        method: ModifyMethod,
+    ) -> fmt::Result
     where
         F: Write,
     {
-        f.write_str("\n\n    fn modify(&self, req: Client::Request) -> Result<Client::Request, ")?;
+        // Encoded bodies are set through the client (for compression, if needed).
+        let encoding = if self.builder.body_required || self.body_param.is_some() {
+            self.builder.encoding
+        } else {
+            None
+        };
+
+        // Builder's own body is checked against its `not` constraints (if any).
+        let inner = if self.needs_container { "inner." } else { "" };
+        let validation = if self.builder.body_required
+            && self.body_param.is_none()
+            && self
+                .builder
+                .fields
+                .iter()
+                .any(|f| !f.forbidden.is_empty() && !f.needs_any)
+        {
+            format!("\n        self.{}body.validate()?;", inner)
+        } else {
+            String::new()
+        };
+
+        let content_type_override = method == ModifyMethod::ContentType;
+        if method == ModifyMethod::Body {
+            write!(
+                f,
+                "
+
+    fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, {prefix}client::ApiError<Client::Response>> {{{validation}
+        self.modify_with_body(client, req, &self.{inner}body)
+    }}",
+                prefix = self.builder.helper_module_prefix,
+                validation = validation,
+                inner = inner
+            )?;
+        }
+
+        match method {
+            ModifyMethod::ContentType => {
+                f.write_str("\n\n    fn modify_with_content_type(&self, ")?
+            }
+            ModifyMethod::Body => {
+                f.write_str("\n\n    fn modify_with_body<B: serde::Serialize + Sync>(&self, ")?;
+                if encoding.is_none() {
+                    f.write_str("_")?;
+                }
+            }
+            ModifyMethod::Default => {
+                f.write_str("\n\n    fn modify(&self, ")?;
+                if encoding.is_none() {
+                    f.write_str("_")?;
+                }
+            }
+        }
+
+        f.write_str("client: &Client, req: Client::Request")?;
+        match method {
+            ModifyMethod::ContentType => f.write_str(", content_type: &str")?,
+            ModifyMethod::Body => f.write_str(", body: &B")?,
+            ModifyMethod::Default => (),
+        }
+
+        f.write_str(") -> Result<Client::Request, ")?;
         f.write_str(&self.builder.helper_module_prefix)?;
         f.write_str("client::ApiError<Client::Response>> {")?;
         f.write_str("\n        use ")?;
         f.write_str(&self.builder.helper_module_prefix)?;
         f.write_str("client::Request;")?;
+        if method != ModifyMethod::Body {
+            f.write_str(&validation)?;
+        }
 
         if !self.headers.is_empty() {
             f.write_str("\n        let mut req = req;")?;
@@ -928,37 +1874,61 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
             f.write_str("\n")?;
         }
 
-        f.write_str("\n        Ok(req")?;
-        if self.builder.body_required {
-            f.write_str("\n        ")?;
-            if let Some((range, coder)) = self.builder.encoding {
+        let body = match self.body_param.as_ref() {
+            _ if method == ModifyMethod::Body => "body",
+            Some(b) => b.as_str(),
+            None if self.needs_container => "&self.inner.body",
+            None => "&self.body",
+        };
+
+        if content_type_override {
+            f.write_str("\n        let req = match content_type {")?;
+            for (range, coder) in self.builder.encodings {
                 write!(
                     f,
-                    ".header(http::header::CONTENT_TYPE.as_str(), {:?})",
-                    range
+                    "
+            {:?} => client.set_body(req, {{
+                let mut vec = vec![];
+                {}(&mut vec, {})?;
+                vec
+            }})?,",
+                    range, coder.encoder_path, body
                 )?;
+            }
 
-                f.write_str(
-                    "\n        .body_bytes({
+            write!(
+                f,
+                "
+            _ => return Err({}client::ApiError::UnsupportedContentType(content_type.into())),
+        }};",
+                self.builder.helper_module_prefix
+            )?;
+        } else if let Some((_, coder)) = encoding {
+            f.write_str(
+                "
+        let req = client.set_body(req, {
             let mut vec = vec![];
             ",
-                )?;
-                f.write_str(&coder.encoder_path)?;
-                f.write_str("(&mut vec, ")?;
-            } else {
-                f.write_str(".json(")?;
-            }
-
-            f.write_str("&self.")?;
-            if self.needs_container {
-                f.write_str("inner.")?;
-            }
-
-            f.write_str("body)")?;
+            )?;
+            f.write_str(&coder.encoder_path)?;
+            f.write_str("(&mut vec, ")?;
+            f.write_str(body)?;
+            f.write_str(")?;\n            vec\n        })?;")?;
+        }
 
-            if self.builder.encoding.is_some() {
-                f.write_str("?;\n            vec\n        })")?;
-            }
+        f.write_str("\n        Ok(req")?;
+        if content_type_override {
+            f.write_str("\n        .header(http::header::CONTENT_TYPE.as_str(), content_type)")?;
+        } else if let Some((range, _)) = encoding {
+            write!(
+                f,
+                "\n        .header(http::header::CONTENT_TYPE.as_str(), {:?})",
+                range
+            )?;
+        } else if self.builder.body_required || self.body_param.is_some() {
+            f.write_str("\n        .json(")?;
+            f.write_str(body)?;
+            f.write_str(")")?;
         }
 
         if let Some(r) = accepted_range {
@@ -1005,30 +1975,100 @@ impl<'a, 'b> SendableCodegen<'a, 'b> {
             f.write_str("\n        ])")?;
         }
 
-        for q in self.multi_value_query.drain(..) {
+        for q in &self.multi_value_query {
             f.write_str("\n        .query({")?;
-            f.write_str(&q)?;
+            f.write_str(q)?;
             f.write_str("\n        })")?;
         }
 
         f.write_str(")\n    }")
     }
 
-    /// Writes async `send` method for this operation assuming that the response is a file.
+    /// Writes async `accept_response` method for this operation assuming that the response
+    /// doesn't have a body (i.e., we don't decode anything).
+    fn write_empty_acceptor<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        write!(
+            f,
+            "
+
+    async fn accept_response(&self, resp: Client::Response) -> Result<(Self::Output, {prefix}client::RawResponse<Client::Response>), {prefix}client::ApiError<Client::Response>> {{
+        use {prefix}client::Response;
+
+        let status = resp.status();
+        Ok(((), {prefix}client::RawResponse {{ status, body: {prefix}client::RawBody::Empty }}))
+    }}",
+            prefix = self.builder.helper_module_prefix
+        )
+    }
+
+    /// Writes async `accept_response` method for this operation assuming that the response
+    /// doesn't have a schema (i.e., we don't decode anything, but the body is still buffered).
+    fn write_unit_acceptor<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        write!(
+            f,
+            "
+
+    async fn accept_response(&self, resp: Client::Response) -> Result<(Self::Output, {prefix}client::RawResponse<Client::Response>), {prefix}client::ApiError<Client::Response>> {{
+        use {prefix}client::Response;
+
+        let status = resp.status();
+        let (_, bytes) = resp.body_bytes().await?;
+        Ok(((), {prefix}client::RawResponse {{ status, body: {prefix}client::RawBody::Bytes(bytes) }}))
+    }}",
+            prefix = self.builder.helper_module_prefix
+        )
+    }
+
+    /// Writes async `accept_response` method for this operation assuming that the response
+    /// is a file (the response is streamed, so the raw body isn't buffered).
     fn write_file_acceptor<F>(&self, f: &mut F) -> fmt::Result
     where
         F: Write,
     {
-        f.write_str("\n\n    async fn send(&self, client: &Client) -> Result<Self::Output, ")?;
-        f.write_str(&self.builder.helper_module_prefix)?;
-        f.write_str("client::ApiError<Client::Response>> {\n        use ")?;
-        f.write_str(&self.builder.helper_module_prefix)?;
-        f.write_str(
-            "client::Response;
+        write!(
+            f,
+            "
+
+    async fn accept_response(&self, mut resp: Client::Response) -> Result<(Self::Output, {prefix}client::RawResponse<Client::Response>), {prefix}client::ApiError<Client::Response>> {{
+        use {prefix}client::Response;
+
+        let status = resp.status();
+        Ok((resp.stream(), {prefix}client::RawResponse {{ status, body: {prefix}client::RawBody::Streamed }}))
+    }}",
+            prefix = self.builder.helper_module_prefix
+        )
+    }
+
+    /// Writes async `accept_response` method for this operation assuming that the response
+    /// is a stream of server-sent events (the payload of each event is decoded as it arrives).
+    fn write_event_stream_acceptor<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let decoder = match self.builder.decoding {
+            Some((_, c)) => &c.decoder_path,
+            None => &JSON_CODER.decoder_path,
+        };
 
-        let mut resp = self.send_raw(client).await?;
-        Ok(resp.stream())
-    }",
+        write!(
+            f,
+            "
+
+    async fn accept_response(&self, mut resp: Client::Response) -> Result<(Self::Output, {prefix}client::RawResponse<Client::Response>), {prefix}client::ApiError<Client::Response>> {{
+        use {prefix}client::Response;
+
+        let status = resp.status();
+        let stream = {prefix}util::EventStream::new(resp.stream(), |data: &[u8]| {decoder}(data).map_err(Into::into));
+        Ok((stream, {prefix}client::RawResponse {{ status, body: {prefix}client::RawBody::Streamed }}))
+    }}",
+            prefix = self.builder.helper_module_prefix,
+            decoder = decoder,
         )
     }
 }
@@ -1056,13 +2096,207 @@ impl<'a> Display for ApiObjectImpl<'a> {
 
         f.write_str(" {")?;
         self.write_builder_methods(f)?;
+        self.write_new_method(f)?;
         f.write_str("}\n")?;
 
         for builder in &*self.builders {
             self.write_into_impl(builder, f)?;
         }
 
-        Ok(())
+        self.write_validate_method(f)
+    }
+}
+
+impl<'a, 'b> Display for ApiObjectBuilderQuery<'a, 'b> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let builder = self.0;
+        let fields = builder
+            .struct_fields_iter()
+            .filter(|f| f.param_loc == Some(ParameterIn::Query))
+            .collect::<Vec<_>>();
+        let required = fields
+            .iter()
+            .filter(|f| f.prop.is_required())
+            .map(|f| f.name)
+            .collect::<Vec<_>>();
+        let idents = fields
+            .iter()
+            .map(|field| {
+                let mut ident = field.name.to_snek_case();
+                if RUST_KEYWORDS.iter().any(|&k| k == ident) {
+                    ident.push('_');
+                }
+
+                ident
+            })
+            .collect::<Vec<_>>();
+
+        let (mut name, mut builder_name) = (String::new(), String::new());
+        builder.write_query_name(&mut name)?;
+        builder.write_name(&mut builder_name)?;
+
+        write!(
+            f,
+            "
+/// Query parameters for [`{builder}`](./struct.{builder}.html).
+#[derive(Debug, Clone, {default}Serialize)]
+pub struct {name} {{",
+            builder = builder_name,
+            name = name,
+            // Required parameters shouldn't be skipped by defaulting.
+            default = if required.is_empty() { "Default, " } else { "" },
+        )?;
+
+        for (field, ident) in fields.iter().zip(&idents) {
+            ApiObject::write_docs(field.desc, f, 1)?;
+            if field.desc.is_none() {
+                f.write_str("\n")?;
+            }
+
+            let mut serde_attrs = vec![];
+            if ident != field.param_name {
+                serde_attrs.push(format!("rename = {:?}", field.param_name));
+            }
+
+            if !field.prop.is_required() {
+                serde_attrs.push("skip_serializing_if = \"Option::is_none\"".into());
+            }
+
+            if !serde_attrs.is_empty() {
+                writeln!(f, "    #[serde({})]", serde_attrs.join(", "))?;
+            }
+
+            write!(f, "    pub {}: ", ident)?;
+            if !field.prop.is_required() {
+                f.write_str("Option<")?;
+            }
+
+            ApiObjectBuilder::write_wrapped_ty(
+                builder.helper_module_prefix,
+                field.ty,
+                field.delimiting,
+                f,
+            )?;
+            if !field.prop.is_required() {
+                f.write_str(">")?;
+            }
+
+            f.write_str(",")?;
+        }
+
+        f.write_str("\n}\n")?;
+
+        // Required parameters are enforced by the constructor.
+        if !required.is_empty() {
+            write!(
+                f,
+                "
+impl {name} {{
+    /// Creates the query parameters with the given values for required
+    /// parameters (optional parameters are unset).
+    #[inline]
+    pub fn new(",
+                name = name
+            )?;
+
+            let (mut values, mut num_args) = (vec![], 0);
+            for (field, ident) in fields.iter().zip(&idents) {
+                if !field.prop.is_required() {
+                    values.push(format!("{}: None", ident));
+                    continue;
+                }
+
+                if num_args > 0 {
+                    f.write_str(", ")?;
+                }
+
+                num_args += 1;
+                values.push(ident.clone());
+                write!(f, "{}: ", ident)?;
+                ApiObjectBuilder::write_wrapped_ty(
+                    builder.helper_module_prefix,
+                    field.ty,
+                    field.delimiting,
+                    f,
+                )?;
+            }
+
+            write!(
+                f,
+                ") -> Self {{\n        {name} {{ {values} }}\n    }}\n}}\n",
+                name = name,
+                values = values.join(", ")
+            )?;
+        }
+
+        // The method is renamed if it collides with a parameter.
+        let method = if builder
+            .struct_fields_iter()
+            .any(|f| f.name.to_snek_case() == "query")
+        {
+            "query_params"
+        } else {
+            "query"
+        };
+
+        let mut generics = String::new();
+        builder.write_generics_if_necessary(&mut generics, None, TypeParameters::Generic)?;
+        write!(
+            f,
+            "
+impl{generics} {builder}{generics} {{
+    /// Sets all query parameters of this operation at once (overriding the ones set before).
+    #[inline]
+    pub fn {method}(mut self, query: {name}) -> ",
+            generics = generics,
+            builder = builder_name,
+            method = method,
+            name = name,
+        )?;
+
+        let mut ret_ty = String::new();
+        if required.is_empty() {
+            ret_ty.push_str("Self");
+        } else {
+            builder.write_name(&mut ret_ty)?;
+            builder.write_generics_if_necessary(
+                &mut ret_ty,
+                None,
+                TypeParameters::ChangeMany(&required),
+            )?;
+        }
+
+        f.write_str(&ret_ty)?;
+        f.write_str(" {")?;
+        let container = if builder.needs_container() {
+            "inner."
+        } else {
+            ""
+        };
+
+        for (field, ident) in fields.iter().zip(&idents) {
+            write!(
+                f,
+                "\n        self.{}param_{} = ",
+                container,
+                field.name.to_snek_case()
+            )?;
+            if field.prop.is_required() {
+                write!(f, "Some(query.{});", ident)?;
+            } else {
+                write!(f, "query.{};", ident)?;
+            }
+        }
+
+        // We need to transmute only if there's a required parameter.
+        if required.is_empty() {
+            f.write_str("\n        self")?;
+        } else {
+            f.write_str("\n        ")?;
+            builder.write_transmute(&ret_ty, f)?;
+        }
+
+        f.write_str("\n    }\n}\n")
     }
 }
 
@@ -1073,28 +2307,67 @@ impl<'a, 'b> Display for ApiObjectBuilderImpl<'a, 'b> {
             .write_generics_if_necessary(&mut generics, None, TypeParameters::Generic)?;
 
         let mut has_fields = false;
+        let mut write_impl_header = |f: &mut fmt::Formatter| {
+            if has_fields {
+                return Ok(());
+            }
+
+            has_fields = true;
+            f.write_str("impl")?;
+            f.write_str(&generics)?;
+            f.write_str(" ")?;
+            self.0.write_name(f)?;
+            f.write_str(&generics)?;
+            f.write_str(" {")
+        };
+
         self.0
             .struct_fields_iter()
             .filter(|f| (self.0.body_required && f.prop.is_field()) || f.prop.is_parameter())
-            .enumerate()
-            .try_for_each(|(i, field)| {
-                if i == 0 {
-                    has_fields = true;
-                    f.write_str("impl")?;
-                    f.write_str(&generics)?;
-                    f.write_str(" ")?;
-                    self.0.write_name(f)?;
-                    f.write_str(&generics)?;
-                    f.write_str(" {")?;
-                }
-
+            .try_for_each(|field| {
+                write_impl_header(f)?;
                 self.write_property_method(field, f)
             })?;
 
+        if self.0.body_required {
+            self.0
+                .fields
+                .iter()
+                .filter(|f| f.is_extra_map())
+                .try_for_each(|field| {
+                    write_impl_header(f)?;
+                    self.write_extra_method(field, f)
+                })?;
+        }
+
+        if self.0.struct_fields_iter().any(|f| f.prop.is_required()) {
+            write_impl_header(f)?;
+            self.write_reset_method(f)?;
+        }
+
+        let has_with_body = self
+            .0
+            .struct_fields_iter()
+            .any(|f| f.name.to_snek_case() == "with_body");
+        if self.0.body_required && self.0.method.is_some() && !has_with_body {
+            write_impl_header(f)?;
+            self.write_with_body_method(f)?;
+        }
+
+        // If the object builder doesn't have any type parameters, then
+        // `build` goes along with the property methods.
+        if has_fields && generics.is_empty() {
+            self.write_build_method(f)?;
+        }
+
         if has_fields {
             f.write_str("}\n")?;
         }
 
+        if !has_fields || !generics.is_empty() {
+            self.write_build_method_impl(f)?;
+        }
+
         SendableCodegen::from(self.0).write_impl_if_needed(f)
     }
 }
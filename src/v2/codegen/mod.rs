@@ -25,6 +25,23 @@ const RUST_KEYWORDS: &[&str] = &[
     "use", "where", "while",
 ];
 
+/// Checks whether the given name can be used as-is for a Rust identifier.
+fn is_valid_ident(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map(|c| c.is_ascii_alphabetic() || (c == '_' && name.len() > 1))
+        .unwrap_or(false)
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Adds '_' suffix to the given name if it matches a Rust keyword.
+fn escape_keyword(name: &mut String) {
+    if RUST_KEYWORDS.iter().any(|&k| k == name) {
+        name.push('_');
+    }
+}
+
 /// Default emitter for anything that implements `Schema` trait.
 ///
 /// This doesn't do anything special, as `Emitter` trait methods take
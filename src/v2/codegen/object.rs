@@ -3,16 +3,20 @@
 //! This contains the necessary objects for generating actual
 //! API objects, their builders, impls, etc.
 
-pub use super::impls::{ApiObjectBuilderImpl, ApiObjectImpl};
-
-use super::emitter::{ANY_GENERIC_PARAMETER, FILE_MARKER};
-use super::RUST_KEYWORDS;
-use crate::v2::models::{Coder, CollectionFormat, HttpMethod, ParameterIn};
+pub use super::impls::{ApiObjectBuilderImpl, ApiObjectBuilderQuery, ApiObjectImpl};
+
+use super::emitter::{
+    ANY_GENERIC_PARAMETER, DATE_TYPE, DECIMAL_TYPE, FILE_MARKER, OFFSET_DATE_TIME_TYPE,
+};
+use super::{escape_keyword, is_valid_ident};
+use crate::v2::models::{
+    Coder, CollectionFormat, ExternalDocs, HttpMethod, ParameterIn, SpecMap, Xml, JSON_CODER,
+};
 use heck::{CamelCase, SnekCase};
 use lazy_static::lazy_static;
 use regex::{Captures, Regex};
 
-use std::collections::{BTreeMap, HashSet};
+use std::collections::HashSet;
 use std::fmt::{self, Display, Write};
 use std::iter;
 use std::sync::Arc;
@@ -22,7 +26,7 @@ lazy_static! {
     static ref DOC_REGEX: Regex = Regex::new(r"\[|\]").expect("invalid doc regex?");
 }
 
-/// Represents a (simplified) Rust struct or enum.
+/// Represents a (simplified) Rust struct, newtype or enum.
 #[derive(Default, Debug, Clone)]
 pub struct ApiObject {
     /// Name of the struct (camel-cased).
@@ -34,14 +38,54 @@ pub struct ApiObject {
     /// List of fields.
     pub fields: Vec<ObjectField>,
     /// Paths with operations which address this object.
-    pub paths: BTreeMap<String, PathOps>,
+    pub paths: SpecMap<String, PathOps>,
+    /// If this object is an enum, then the kind of values held by its variants.
+    pub enum_kind: Option<EnumKind>,
+    /// Variants of this object (if it's an enum).
+    pub variants: Vec<EnumVariant>,
+    /// Whether this enum has been generated for (enum) parameters, in which case
+    /// it implements `Display` and `FromStr` (based on the values of its variants).
+    pub is_param: bool,
+    /// If this object is a (transparent) newtype, then the type of the wrapped value.
+    pub newtype: Option<String>,
+    /// Example for this object (if it's valid for the schema), used for fixtures.
+    pub example: Option<serde_json::Value>,
+    /// Additional traits to be derived for this object.
+    pub derives: Vec<String>,
+    /// Name of the root element when this object is represented in XML
+    /// (if it's different from the object name).
+    pub xml_name: Option<String>,
+}
+
+/// Kind of values held by the variants of an enum.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnumKind {
+    /// Variants are strings.
+    String,
+    /// Variants are integers. This holds the Rust type used
+    /// for the enum's representation (`#[repr(...)]`).
+    Integer(String),
+}
+
+/// Represents an enum variant.
+#[derive(Debug, Clone)]
+pub struct EnumVariant {
+    /// Name of the variant (camel-cased).
+    pub name: String,
+    /// Value of this variant in the schema.
+    pub value: serde_json::Value,
+    /// Description for this variant (if any), to be used for docs.
+    pub description: Option<String>,
+    /// Whether this is the default value (of a parameter), which is used for the
+    /// enum's `Default` impl. If none of the variants are, then the first one is.
+    pub is_default: bool,
 }
 
 /// Operations in a path.
 #[derive(Default, Debug, Clone)]
 pub struct PathOps {
     /// Operations for this object and their associated requirements.
-    pub req: BTreeMap<HttpMethod, OpRequirement>,
+    pub req: SpecMap<HttpMethod, OpRequirement>,
     /// Parameters required for all operations in this path.
     pub params: Vec<Parameter>,
 }
@@ -56,6 +100,8 @@ pub struct OpRequirement {
     pub id: Option<String>,
     /// Description of this operation (if any), to be used for docs.
     pub description: Option<String>,
+    /// Tags of this operation (if any), for grouping operations in traits.
+    pub tags: Vec<String>,
     /// Whether the operation is deprecated or not.
     pub deprecated: bool,
     /// Parameters required for this operation.
@@ -70,6 +116,10 @@ pub struct OpRequirement {
     /// methods that don't accept a body. If there's no coder, then JSON
     /// encoding is assumed.
     pub encoding: Option<(String, Arc<Coder>)>,
+    /// All media ranges (and their encoders) acceptable for the request body,
+    /// sorted based on preference. This is used for overriding the preferred
+    /// encoding in operations which accept multiple content types.
+    pub encodings: Vec<(String, Arc<Coder>)>,
     /// Preferred media range and decoder for the client. This is used only
     /// when objects make use of `Any` type. If there's no coder, then JSON
     /// encoding is assumed.
@@ -78,12 +128,65 @@ pub struct OpRequirement {
 
 #[derive(Default, Debug, Clone)]
 pub struct Response<S> {
-    /// Type path for this operation's response (if any). If this is empty,
-    /// then we go for `Any`.
+    /// Type path for this operation's response (if any). If this is empty
+    /// (and the response isn't known to be empty), then we go for `Any`.
     pub ty_path: Option<S>,
     /// Whether the response contains an `Any`. This is useful when operations
     /// get bound to some other object.
     pub contains_any: bool,
+    /// Whether the response is a stream of server-sent events (`text/event-stream`),
+    /// in which case, the type path refers to the payload of each event.
+    pub event_stream: bool,
+    /// Whether the successful response is known to be empty (i.e., there's
+    /// some 2xx response, but none of them have a schema).
+    pub empty: bool,
+    /// Successful responses of this operation based on their status codes. This
+    /// is set only when those responses have different schemas (or when they're
+    /// distinguished regardless), in which case, an enum is generated for them.
+    pub statuses: Vec<StatusResponse<S>>,
+    /// Name of the enum generated for the above responses (if any). This is
+    /// unique among the objects in the module of the operation.
+    pub enum_name: Option<S>,
+}
+
+/// Successful response for some status code(s). This is a variant in the
+/// enum generated for the responses of an operation.
+#[derive(Default, Debug, Clone)]
+pub struct StatusResponse<S> {
+    /// Status codes (2xx) sharing this response.
+    pub codes: Vec<u16>,
+    /// Type path of the response body (if any). `Any` (if any) has
+    /// already been substituted in this path.
+    pub ty_path: Option<S>,
+}
+
+impl<S> StatusResponse<S> {
+    /// Returns the name of the enum variant for this response, which is
+    /// based on the reason phrase of its (first) status code.
+    pub fn variant_name(&self) -> String {
+        let code = self.codes[0];
+        match Self::reason_phrase(code) {
+            Some(r) => r.to_camel_case(),
+            None => format!("Status{}", code),
+        }
+    }
+
+    /// Returns the (canonical) reason phrase for the given 2xx status code.
+    pub fn reason_phrase(code: u16) -> Option<&'static str> {
+        Some(match code {
+            200 => "OK",
+            201 => "Created",
+            202 => "Accepted",
+            203 => "Non-Authoritative Information",
+            204 => "No Content",
+            205 => "Reset Content",
+            206 => "Partial Content",
+            207 => "Multi-Status",
+            208 => "Already Reported",
+            226 => "IM Used",
+            _ => return None,
+        })
+    }
 }
 
 impl<S> Response<S>
@@ -114,6 +217,19 @@ pub struct Parameter {
     pub presence: ParameterIn,
     /// If the parameter is an array of values, then the format for collecting them.
     pub delimiting: Vec<CollectionFormat>,
+    /// Name used for the builder field and method (instead of `name`) when
+    /// parameters in different locations share the same name.
+    pub alias: Option<String>,
+    /// Whether the (innermost) type of this parameter is an existing Rust
+    /// type (from `x-rust-type` extension).
+    pub custom_type: bool,
+    /// Variants of this parameter (if its values are constrained). The enum for
+    /// these is added to the module of the object to which the parameter's
+    /// operation is bound (and `ty_path` is updated then).
+    pub variants: Option<(EnumKind, Vec<EnumVariant>)>,
+    /// Whether the default value should be sent when this parameter is unset.
+    /// This applies to enum parameters with defaults (when `EmitterState::send_defaults` is set).
+    pub send_default: bool,
 }
 
 /// Represents a struct field.
@@ -125,12 +241,46 @@ pub struct ObjectField {
     pub ty_path: String,
     /// Description of this operation (if any), to be used for docs.
     pub description: Option<String>,
+    /// Whether this field is deprecated (noted in docs).
+    pub deprecated: bool,
+    /// External documentation for this field (if any), linked in docs.
+    pub external_docs: Option<ExternalDocs>,
+    /// Example value for this field (if any), shown in docs.
+    pub example: Option<serde_json::Value>,
     /// Whether this field is required (i.e., not optional).
     pub is_required: bool,
     /// Whether this field's type "is" or "has" an `Any` type.
     pub needs_any: bool,
     /// Whether this field should be boxed.
     pub boxed: bool,
+    /// Whether the "deepest" child type of this field is an existing Rust
+    /// type (from `x-rust-type` extension), which is treated like a simple type.
+    pub custom_type: bool,
+    /// Whether this field can be explicitly set to `null`. If it's not
+    /// required, then it's represented as a tri-state `Patch` value.
+    pub nullable: bool,
+    /// Whether this field is only sent in requests. Such fields are missing
+    /// in responses, so they're never required for deserializing.
+    pub write_only: bool,
+    /// Whether this field is only returned in responses. Such fields are
+    /// never required for constructing the object (but they're still
+    /// required for deserializing).
+    pub read_only: bool,
+    /// Whether to keep the original name of this field in the Rust struct
+    /// (as long as it's a valid identifier) instead of snake-casing it.
+    pub keep_name: bool,
+    /// Whether this field is flattened by serde - either a map which collects the
+    /// unknown keys of the object or a mixin object (from `x-rust-flatten` extension).
+    /// Such fields are always present (not optional).
+    pub flatten: bool,
+    /// Custom serde attributes for this field (from `x-rust-serde` extension).
+    /// These override the generated attributes with the same key.
+    pub custom_attrs: Vec<String>,
+    /// XML representation hints for this field (if any).
+    pub xml: Option<Xml>,
+    /// Values which this field shouldn't have (from a `not` schema). These are
+    /// checked by the object's `validate` method before sending requests.
+    pub forbidden: Vec<serde_json::Value>,
     /// Required fields of the "deepest" child type in the given definition.
     ///
     /// Now, what do I mean by "deepest"? For example, if we had `Vec<Vec<Vec<T>>>`
@@ -144,7 +294,21 @@ pub struct ObjectField {
     pub child_req_fields: Vec<String>,
 }
 
+/// Represents the Rust struct definition for an API object.
+pub struct ApiObjectStruct<'a> {
+    inner: &'a ApiObject,
+    helper_module_prefix: &'a str,
+}
+
 impl ApiObject {
+    /// Returns a struct representing the Rust struct definition for this object.
+    pub fn struct_repr<'a>(&'a self, helper_module_prefix: &'a str) -> ApiObjectStruct<'a> {
+        ApiObjectStruct {
+            inner: self,
+            helper_module_prefix,
+        }
+    }
+
     /// Create an object with the given name.
     pub fn with_name<S>(name: S) -> Self
     where
@@ -204,22 +368,26 @@ impl ApiObject {
     /// Returns whether this type is simple (i.e., not an object defined by us).
     #[inline]
     pub(super) fn is_simple_type(ty: &str) -> bool {
-        !ty.contains("::") || ty.ends_with("Delimited")
+        !ty.contains("::")
+            || ty.ends_with("Delimited")
+            || ty == DECIMAL_TYPE
+            || ty == OFFSET_DATE_TIME_TYPE
+            || ty == DATE_TYPE
     }
 
-    /// Assuming that the given type "is" or "has" `Any`, this adds
-    /// the appropriate generic parameter.
-    fn write_field_with_any<F>(ty: &str, f: &mut F) -> fmt::Result
+    /// Assuming that the given type "is" or "has" `Any`, this adds the
+    /// appropriate generic parameter (or replaces `Any` with the given type).
+    pub(super) fn write_field_with_any<F>(ty: &str, any: &str, f: &mut F) -> fmt::Result
     where
         F: Write,
     {
         if let Some(i) = ty.find('<') {
             if ty[..i].ends_with("Vec") {
                 f.write_str(&ty[..=i])?;
-                Self::write_field_with_any(&ty[i + 1..ty.len() - 1], f)?;
+                Self::write_field_with_any(&ty[i + 1..ty.len() - 1], any, f)?;
             } else if ty[..i].ends_with("std::collections::BTreeMap") {
                 f.write_str(&ty[..i + 9])?;
-                Self::write_field_with_any(&ty[i + 9..ty.len() - 1], f)?;
+                Self::write_field_with_any(&ty[i + 9..ty.len() - 1], any, f)?;
             } else {
                 unreachable!("no other generics expected.");
             }
@@ -228,9 +396,13 @@ impl ApiObject {
             return Ok(());
         }
 
+        if ty == ANY_GENERIC_PARAMETER {
+            return f.write_str(any);
+        }
+
         f.write_str(ty)?;
         if !Self::is_simple_type(ty) {
-            Self::write_any_generic(f)?;
+            write!(f, "<{}>", any)?;
         }
 
         Ok(())
@@ -252,6 +424,8 @@ pub(super) struct ApiObjectBuilder<'a> {
     pub op_id: Option<&'a str>,
     /// Whether the operation is deprecated or not.
     pub deprecated: bool,
+    /// Tags of the operation (if any).
+    pub tags: &'a [String],
     /// HTTP method for the operation - all builders (other than object builders)
     /// have this.
     pub method: Option<HttpMethod>,
@@ -265,6 +439,8 @@ pub(super) struct ApiObjectBuilder<'a> {
     pub object: &'a str,
     /// Encoding for the operation, if it's not JSON.
     pub encoding: Option<&'a (String, Arc<Coder>)>,
+    /// Media ranges (and their encoders) acceptable for the body.
+    pub encodings: &'a [(String, Arc<Coder>)],
     /// Decoding for the operation, if it's not JSON.
     ///
     /// **NOTE:** We use this to set the `Accept` header for operations
@@ -272,6 +448,9 @@ pub(super) struct ApiObjectBuilder<'a> {
     pub decoding: Option<&'a (String, Arc<Coder>)>,
     /// Whether there are multiple builders for this object.
     pub multiple_builders_exist: bool,
+    /// Whether the fallback (method-based) name for this builder has already
+    /// been claimed by another operation's ID.
+    pub fallback_taken: bool,
     /// Fields in this builder.
     pub fields: &'a [ObjectField],
     /// Parameters global to this URL path.
@@ -280,6 +459,9 @@ pub(super) struct ApiObjectBuilder<'a> {
     pub local_params: &'a [Parameter],
     /// Whether this builder is generic over `Any` type.
     pub needs_any: bool,
+    /// Type substituted for `Any` when it's not specified explicitly (if it's
+    /// been overridden). By default, it's the `Any` value type of the coder.
+    pub any_value: Option<&'a str>,
 }
 
 /// The property we're dealing with.
@@ -321,6 +503,7 @@ impl Property {
 pub(super) enum TypeParameters<'a> {
     Generic,
     ChangeOne(&'a str),
+    ChangeMany(&'a [&'a str]),
     ReplaceAll,
     ChangeAll,
 }
@@ -330,6 +513,9 @@ pub(super) enum TypeParameters<'a> {
 pub(super) struct StructField<'a> {
     /// Name of this field (case unspecified).
     pub name: &'a str,
+    /// Name of the parameter in API calls (if it is a parameter). This is
+    /// different from `name` only when the parameter has an alias.
+    pub param_name: &'a str,
     /// Type of this field.
     pub ty: &'a str,
     /// What this field represents.
@@ -350,22 +536,121 @@ pub(super) struct StructField<'a> {
     pub needs_any: bool,
     /// Whether this field indicates a file upload.
     pub needs_file: bool,
+    /// Whether this field is a tri-state `Patch` value. This is only
+    /// applicable for object fields.
+    pub is_patch: bool,
+    /// Whether the value is wrapped in an `Option` (in the Rust struct for
+    /// object fields, or in the builder for parameters).
+    pub is_option: bool,
+    /// Whether the value is boxed in the Rust struct. This is only
+    /// applicable for object fields.
+    pub boxed: bool,
+    /// Whether the (innermost) type is an existing Rust type (from `x-rust-type`
+    /// extension), which is treated like a simple type.
+    pub custom_type: bool,
+    /// Name of the associated field in the object's Rust struct (if this is
+    /// an object field or a parameter overriding one).
+    pub field_name: Option<String>,
+    /// Whether the default value should be sent when this parameter is unset.
+    pub send_default: bool,
+}
+
+impl ObjectField {
+    /// Returns the name of this field in the Rust struct.
+    pub fn rust_name(&self) -> String {
+        let mut name = if self.keep_name && is_valid_ident(&self.name) {
+            self.name.clone()
+        } else {
+            self.name.to_snek_case()
+        };
+
+        escape_keyword(&mut name);
+        name
+    }
+
+    /// Returns the docs for this field (if any). Deprecation, external docs
+    /// and example value (if any) are noted below the description.
+    pub fn docs(&self) -> Option<String> {
+        let mut sections = vec![];
+        if let Some(desc) = self.description.as_ref() {
+            sections.push(desc.clone());
+        }
+
+        if self.deprecated {
+            sections.push("**Deprecated**".into());
+        }
+
+        if let Some(docs) = self.external_docs.as_ref() {
+            sections.push(match docs.description.as_ref() {
+                Some(desc) => format!("External docs: {} (<{}>)", desc, docs.url),
+                None => format!("External docs: <{}>", docs.url),
+            });
+        }
+
+        if let Some(value) = self.example.as_ref() {
+            sections.push(format!("Example: `{}`", value));
+        }
+
+        if sections.is_empty() {
+            None
+        } else {
+            Some(sections.join("\n\n"))
+        }
+    }
+
+    /// Returns whether this field is represented as a tri-state `Patch` value.
+    #[inline]
+    pub fn is_patch(&self) -> bool {
+        self.nullable && !self.is_required
+    }
+
+    /// Returns the name of this field in XML.
+    pub fn xml_name(&self) -> &str {
+        self.xml
+            .as_ref()
+            .and_then(|x| x.name.as_ref())
+            .unwrap_or(&self.name)
+    }
+
+    /// Returns whether this field is represented as an XML attribute.
+    pub fn is_xml_attribute(&self) -> bool {
+        self.xml.as_ref().and_then(|x| x.attribute).unwrap_or(false)
+    }
+
+    /// Returns whether this field is the flattened map of additional properties.
+    pub fn is_extra_map(&self) -> bool {
+        self.flatten && self.ty_path.starts_with("std::collections::BTreeMap<")
+    }
 }
 
 impl<'a> ApiObjectBuilder<'a> {
-    /// Name of the constructor function which creates this builder.
+    /// Returns the type substituted for `Any` when it's not specified explicitly,
+    /// given the coder used for some media range (JSON, if there's none).
+    pub(super) fn default_any_value(&self, coder: Option<&'a (String, Arc<Coder>)>) -> &'a str {
+        match (self.any_value, coder) {
+            (Some(v), _) => v,
+            (None, Some((_, c))) => &c.any_value,
+            (None, None) => &JSON_CODER.any_value,
+        }
+    }
+
+    /// Name of the constructor function which creates this builder (if
+    /// one can be derived).
     pub fn constructor_fn_name(&self) -> Option<String> {
-        match (self.op_id, self.method) {
-            // If there's an operation ID, then we go for that ...
-            (Some(id), _) => Some(id.to_snek_case()),
+        match (self.op_id_fn_name(), self.method) {
+            // If there's a usable operation ID, then we go for that ...
+            (Some(name), _) => Some(name),
+            // If we have to fallback to the method, but some other operation
+            // has already claimed that name, then there's nothing we can do.
+            (None, Some(_)) if self.fallback_taken => None,
             // If there's a method and we *don't* have any collisions
             // (i.e., two or more paths for same object), then we default
             // to using the method ...
-            (_, Some(meth)) if !self.multiple_builders_exist => {
+            (None, Some(meth)) if !self.multiple_builders_exist => {
                 Some(meth.to_string().to_snek_case())
             }
             // If there's a method, then we go for numbered functions ...
-            (_, Some(meth)) => {
+            (None, Some(meth)) => {
                 let mut name = meth.to_string().to_snek_case();
                 if self.idx > 0 {
                     name.push('_');
@@ -374,10 +659,23 @@ impl<'a> ApiObjectBuilder<'a> {
 
                 Some(name)
             }
-            // We don't know what to do ...
-            // FIXME: Use route and method to generate a name.
-            _ => None,
+            // If there's no method, then it's the builder for the object itself ...
+            (None, None) if self.rel_path.is_none() => Some("builder".into()),
+            // ... unless this is an operation, in which case it's unreachable.
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the function name derived from the operation ID, if it
+    /// yields a valid Rust identifier.
+    pub(super) fn op_id_fn_name(&self) -> Option<String> {
+        let mut name = self.op_id?.to_snek_case();
+        if !is_valid_ident(&name) {
+            return None;
         }
+
+        escape_keyword(&mut name);
+        Some(name)
     }
 
     /// Returns an iterator of all fields and parameters required for the Rust builder struct.
@@ -388,38 +686,53 @@ impl<'a> ApiObjectBuilder<'a> {
     /// and a parameter, then the latter overrides the former.
     pub(super) fn struct_fields_iter(&self) -> impl Iterator<Item = StructField<'a>> + 'a {
         let body_required = self.body_required;
-        let field_iter = self.fields.iter().map(move |field| StructField {
-            name: field.name.as_str(),
-            ty: field.ty_path.as_str(),
-            // We "require" the object fields only if the object itself is required.
-            prop: if body_required && field.is_required {
-                Property::RequiredField
-            } else {
-                Property::OptionalField
-            },
-            desc: field.description.as_ref().map(String::as_str),
-            strict_child_fields: &*field.child_req_fields,
-            param_loc: None,
-            overridden: false,
-            needs_any: field.needs_any,
-            needs_file: field.ty_path == FILE_MARKER,
-            delimiting: &[],
-        });
+        // Flattened maps (and mixins) are not set through builders.
+        let field_iter = self
+            .fields
+            .iter()
+            .filter(|f| !f.flatten)
+            .map(move |field| StructField {
+                name: field.name.as_str(),
+                param_name: field.name.as_str(),
+                ty: field.ty_path.as_str(),
+                // We "require" the object fields only if the object itself is required
+                // (and read-only fields are never required for constructing objects).
+                prop: if body_required && field.is_required && !field.read_only {
+                    Property::RequiredField
+                } else {
+                    Property::OptionalField
+                },
+                desc: field.description.as_ref().map(String::as_str),
+                strict_child_fields: &*field.child_req_fields,
+                param_loc: None,
+                overridden: false,
+                needs_any: field.needs_any,
+                needs_file: field.ty_path == FILE_MARKER,
+                delimiting: &[],
+                is_patch: field.is_patch(),
+                is_option: !field.is_required,
+                boxed: field.boxed,
+                custom_type: field.custom_type,
+                field_name: Some(field.rust_name()),
+                send_default: false,
+            });
 
         let param_iter = self
             .global_params
             .iter()
             .chain(self.local_params.iter())
             .scan(HashSet::new(), |set, param| {
-                // Local parameters override global parameters.
-                if set.contains(&param.name) {
+                // Local parameters override global parameters (parameters
+                // are unique by their name and location).
+                if set.contains(&(&param.name, param.presence)) {
                     // Workaround because `scan` stops when it encounters
                     // `None`, but we want filtering.
                     Some(None)
                 } else {
-                    set.insert(&param.name);
+                    set.insert((&param.name, param.presence));
                     Some(Some(StructField {
-                        name: param.name.as_str(),
+                        name: param.alias.as_ref().unwrap_or(&param.name).as_str(),
+                        param_name: param.name.as_str(),
                         ty: param.ty_path.as_str(),
                         prop: if param.required {
                             Property::RequiredParam
@@ -433,6 +746,12 @@ impl<'a> ApiObjectBuilder<'a> {
                         needs_any: false,
                         needs_file: param.ty_path == FILE_MARKER,
                         delimiting: &param.delimiting,
+                        is_patch: false,
+                        is_option: true,
+                        boxed: false,
+                        custom_type: param.custom_type,
+                        field_name: None,
+                        send_default: param.send_default,
                     }))
                 }
             })
@@ -447,6 +766,7 @@ impl<'a> ApiObjectBuilder<'a> {
             {
                 if v.ty == field.ty {
                     v.overridden = true;
+                    v.field_name = field.field_name;
                 }
 
                 // We don't know what we should do when we encounter
@@ -512,6 +832,13 @@ impl<'a> ApiObjectBuilder<'a> {
                         f.write_str(&field.name.to_camel_case())?;
                         return f.write_str("Exists");
                     }
+                    // Change the given names to `{Name}Exists`
+                    TypeParameters::ChangeMany(names) if names.contains(&field.name) => {
+                        f.write_str(self.helper_module_prefix)?;
+                        f.write_str("generics::")?;
+                        f.write_str(&field.name.to_camel_case())?;
+                        return f.write_str("Exists");
+                    }
                     // All names should be changed to `{Name}Exists`
                     TypeParameters::ChangeAll => {
                         f.write_str(self.helper_module_prefix)?;
@@ -555,12 +882,19 @@ impl<'a> ApiObjectBuilder<'a> {
             .any(|f| f.prop.is_parameter() || f.prop.is_required())
     }
 
+    /// Returns whether the builder struct has phantom fields for marking the
+    /// required fields/parameters. Such builders are `repr(C)`, because they're
+    /// transmuted into one another (differing only in the marker types) and the
+    /// layout of `repr(Rust)` structs isn't guaranteed to be the same for those.
+    pub(super) fn has_markers(&self) -> bool {
+        self.struct_fields_iter().any(|f| f.prop.is_required())
+    }
+
     /// Returns whether a separate container is needed for the builder struct.
     pub(super) fn needs_container(&self) -> bool {
-        // This is perhaps one of those important blocks, because this
-        // decides whether to mark builder structs as `repr(transparent)`
-        // (for unsafely transmuting). It's UB to transmute `repr(Rust)`
-        // structs, so we put stuff into a container and transmute
+        // This decides whether the builder's fields are put into a separate
+        // container (for unsafely transmuting). Both the builder and the
+        // container are `repr(C)` (see `has_markers`), and we use the container
         // whenever a builder:
         //
         // - Has at least one operation parameter that's required (or)
@@ -581,6 +915,24 @@ impl<'a> ApiObjectBuilder<'a> {
                 && self.local_params.len() + self.global_params.len() > 0)
     }
 
+    /// Write the name of the query struct for this builder into the given formatter.
+    pub(super) fn write_query_name<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        f.write_str(self.object)?;
+        if let Some(method) = self.method {
+            write!(f, "{}", method)?;
+        }
+
+        f.write_str("Query")?;
+        if self.idx > 0 {
+            f.write_str(&self.idx.to_string())?;
+        }
+
+        Ok(())
+    }
+
     /// Write this builder's container name into the given formatter.
     pub(super) fn write_container_name<F>(&self, f: &mut F) -> fmt::Result
     where
@@ -601,15 +953,16 @@ impl<'a> ApiObjectBuilder<'a> {
     where
         F: fmt::Write,
     {
-        if !ty.contains("Vec") {
+        // Array bodies aren't delimited.
+        if !ty.starts_with("Vec<") || delims.is_empty() {
             return f.write_str(ty);
         }
 
-        // In parameters, we're limited to basic types and arrays,
+        // In parameters, we're limited to basic (or custom) types and arrays,
         // so we can assume that whatever `<>` we encounter, they're
         // all for `Vec`.
-        let delim_ty = String::from(module_prefix) + "util::Delimited";
-        let mut ty = ty.replace("Vec", &delim_ty);
+        let delim_ty = String::from(module_prefix) + "util::Delimited<";
+        let mut ty = ty.replace("Vec<", &delim_ty);
         let mut new_ty = String::new();
         // From the reverse, because we replace from inside out.
         let mut delim_idx = delims.len();
@@ -617,11 +970,9 @@ impl<'a> ApiObjectBuilder<'a> {
             delim_idx -= 1;
             new_ty.push_str(&ty[..idx]);
             new_ty.push_str(", ");
-            write!(
-                &mut new_ty,
-                "{}util::{:?}",
-                module_prefix, delims[delim_idx]
-            )?;
+            new_ty.push_str(module_prefix);
+            new_ty.push_str("util::");
+            new_ty.push_str(&delimiter_marker(&delims[delim_idx]));
             new_ty.push('>');
             if idx == ty.len() - 1 {
                 break;
@@ -682,6 +1033,19 @@ impl<'a> ApiObjectBuilder<'a> {
     }
 }
 
+/// Returns the name of the marker type (in the generated `util` module) for the
+/// given collection format. Markers for custom delimiters are named after the
+/// (hex-encoded) bytes of those delimiters, so that they're valid identifiers.
+pub(super) fn delimiter_marker(fmt: &CollectionFormat) -> String {
+    match fmt {
+        CollectionFormat::Custom(d) => d.bytes().fold(String::from("Custom"), |mut s, b| {
+            let _ = write!(s, "{:02X}", b);
+            s
+        }),
+        f => format!("{:?}", f),
+    }
+}
+
 impl<'a> Display for ApiObjectBuilder<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str("/// Builder ")?;
@@ -708,12 +1072,13 @@ impl<'a> Display for ApiObjectBuilder<'a> {
         }
 
         // If the builder "needs" parameters/fields, then we go for a separate
-        // container which holds both the body (if any) and the parameters,
-        // so that we can make the actual builder `#[repr(transparent)]`
-        // for safe transmuting.
+        // container which holds both the body (if any) and the parameters.
         let needs_container = self.needs_container();
-        if needs_container {
-            f.write_str("#[repr(transparent)]\n")?;
+        // Builders with markers are transmuted into one another (when setting
+        // the required fields/parameters), so their layouts should only depend
+        // on the declared order of fields (and not on the marker types).
+        if self.has_markers() {
+            f.write_str("#[repr(C)]\n")?;
         }
 
         f.write_str("#[derive(Debug, Clone)]\npub struct ")?;
@@ -729,7 +1094,7 @@ impl<'a> Display for ApiObjectBuilder<'a> {
 
         let mut container = String::new();
         if needs_container {
-            container.push_str("#[derive(Debug, Default, Clone)]\nstruct ");
+            container.push_str("#[repr(C)]\n#[derive(Debug, Default, Clone)]\nstruct ");
             self.write_container_name(&mut container)?;
             if self.needs_any {
                 ApiObject::write_any_generic(&mut container)?;
@@ -797,42 +1162,431 @@ impl<'a> Display for ApiObjectBuilder<'a> {
     }
 }
 
-impl Display for ApiObject {
+impl<'a> ApiObjectStruct<'a> {
+    /// Writes the `#[derive(...)]` attribute with the given traits along
+    /// with the additional derives for this object.
+    fn write_derives<F>(&'a self, mut derives: Vec<&'a str>, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        for d in &self.inner.derives {
+            if !derives.contains(&d.as_str()) {
+                derives.push(d);
+            }
+        }
+
+        write!(f, "#[derive({})]", derives.join(", "))
+    }
+
+    /// Writes the `Default` impl for this struct, where required `time` values
+    /// default to the Unix epoch.
+    fn write_default_impl<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let obj = self.inner;
+        let generics = if obj.fields.iter().any(|f| f.needs_any) {
+            (
+                format!("<{}: Default>", ANY_GENERIC_PARAMETER),
+                format!("<{}>", ANY_GENERIC_PARAMETER),
+            )
+        } else {
+            Default::default()
+        };
+
+        write!(
+            f,
+            "
+impl{impl_generics} Default for {name}{ty_generics} {{
+    fn default() -> Self {{
+        {name} {{",
+            impl_generics = generics.0,
+            name = obj.name,
+            ty_generics = generics.1
+        )?;
+
+        for field in &obj.fields {
+            let value = match field.ty_path.as_str() {
+                OFFSET_DATE_TIME_TYPE if field.is_required => "time::OffsetDateTime::UNIX_EPOCH",
+                DATE_TYPE if field.is_required => "time::OffsetDateTime::UNIX_EPOCH.date()",
+                _ => "Default::default()",
+            };
+
+            write!(f, "\n            {}: {},", field.rust_name(), value)?;
+        }
+
+        f.write_str(
+            "
+        }
+    }
+}
+",
+        )
+    }
+
+    /// Writes the Rust newtype definition (along with its impls) for this object,
+    /// which (de)serializes as the wrapped value.
+    fn write_newtype<F>(&self, ty: &str, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        self.write_derives(
+            vec!["Debug", "Default", "Clone", "Deserialize", "Serialize"],
+            f,
+        )?;
+        write!(
+            f,
+            "
+#[serde(transparent)]
+pub struct {name}(pub {ty});
+
+impl From<{ty}> for {name} {{
+    fn from(value: {ty}) -> Self {{
+        {name}(value)
+    }}
+}}
+",
+            name = self.inner.name,
+            ty = ty
+        )
+    }
+
+    /// Writes the Rust enum definition (along with its impls) for this object.
+    fn write_enum<F>(&self, kind: &EnumKind, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let obj = self.inner;
+        let mut derives = vec!["Debug", "Clone", "Copy", "PartialEq", "Eq", "Hash"];
+        if *kind == EnumKind::String {
+            derives.extend(&["Deserialize", "Serialize"]);
+        }
+
+        self.write_derives(derives, f)?;
+        if let EnumKind::Integer(repr) = kind {
+            write!(f, "\n#[repr({})]", repr)?;
+        }
+
+        f.write_str("\npub enum ")?;
+        f.write_str(&obj.name)?;
+        f.write_str(" {")?;
+
+        obj.variants.iter().try_for_each(|var| {
+            match var.description.as_ref() {
+                Some(desc) => {
+                    ApiObject::write_docs(Some(desc), f, 1)?;
+                    f.write_str("    ")?;
+                }
+                None => f.write_str("\n    ")?,
+            }
+
+            match var.value {
+                serde_json::Value::String(ref s) if *s != var.name => {
+                    write!(f, "#[serde(rename = {:?})]\n    ", s)?;
+                }
+                _ => (),
+            }
+
+            f.write_str(&var.name)?;
+            if let EnumKind::Integer(_) = kind {
+                write!(f, " = {}", var.value)?;
+            }
+
+            f.write_str(",")
+        })?;
+
+        if !obj.variants.is_empty() {
+            f.write_str("\n")?;
+        }
+
+        f.write_str("}\n")?;
+
+        // First variant is the default (so that objects can still derive `Default`),
+        // unless a parameter specifies the default value.
+        if let Some(var) = obj
+            .variants
+            .iter()
+            .find(|v| v.is_default)
+            .or_else(|| obj.variants.first())
+        {
+            write!(
+                f,
+                "
+impl Default for {name} {{
+    fn default() -> Self {{
+        {name}::{var}
+    }}
+}}
+",
+                name = obj.name,
+                var = var.name
+            )?;
+        }
+
+        if obj.is_param {
+            self.write_enum_param_impls(f)?;
+        }
+
+        let repr = match kind {
+            EnumKind::Integer(repr) => repr,
+            EnumKind::String => return Ok(()),
+        };
+
+        write!(
+            f,
+            "
+impl serde::Serialize for {name} {{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+        serializer.serialize_{repr}(*self as {repr})
+    }}
+}}
+
+impl<'de> serde::Deserialize<'de> for {name} {{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+        match <{repr} as serde::Deserialize>::deserialize(deserializer)? {{",
+            name = obj.name,
+            repr = repr
+        )?;
+
+        obj.variants.iter().try_for_each(|var| {
+            write!(
+                f,
+                "\n            {} => Ok({}::{}),",
+                var.value, obj.name, var.name
+            )
+        })?;
+
+        write!(
+            f,
+            "
+            v => Err(serde::de::Error::custom(format!(\"unknown variant `{{}}` for {name}\", v))),
+        }}
+    }}
+}}
+",
+            name = obj.name
+        )
+    }
+
+    /// Writes the `Display` and `FromStr` impls for an enum used by parameters,
+    /// so that its values can be encoded in (and parsed from) URLs.
+    fn write_enum_param_impls<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write,
+    {
+        let obj = self.inner;
+        write!(
+            f,
+            "
+impl std::fmt::Display for {name} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        f.write_str(match self {{",
+            name = obj.name
+        )?;
+
+        obj.variants.iter().try_for_each(|var| {
+            write!(
+                f,
+                "\n            {}::{} => {:?},",
+                obj.name,
+                var.name,
+                value_str(&var.value)
+            )
+        })?;
+
+        write!(
+            f,
+            "
+        }})
+    }}
+}}
+
+impl std::str::FromStr for {name} {{
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {{
+        match s {{",
+            name = obj.name
+        )?;
+
+        obj.variants.iter().try_for_each(|var| {
+            write!(
+                f,
+                "\n            {:?} => Ok({}::{}),",
+                value_str(&var.value),
+                obj.name,
+                var.name
+            )
+        })?;
+
+        write!(
+            f,
+            "
+            _ => Err(format!(\"unknown variant `{{}}` for {name}\", s)),
+        }}
+    }}
+}}
+",
+            name = obj.name
+        )
+    }
+}
+
+/// Returns the given (enum) value as it appears in URLs.
+fn value_str(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        v => v.to_string(),
+    }
+}
+
+impl<'a> Display for ApiObjectStruct<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        ApiObject::write_docs(self.description.as_ref(), f, 0)?;
+        let obj = self.inner;
+        ApiObject::write_docs(obj.description.as_ref(), f, 0)?;
+        if let Some(kind) = obj.enum_kind.as_ref() {
+            return self.write_enum(kind, f);
+        }
+
+        if let Some(ty) = obj.newtype.as_ref() {
+            return self.write_newtype(ty, f);
+        }
+
+        // `time` types don't implement `Default`, so required fields of those
+        // types need a manual impl.
+        let needs_default_impl = obj.fields.iter().any(|f| {
+            f.is_required && (f.ty_path == OFFSET_DATE_TIME_TYPE || f.ty_path == DATE_TYPE)
+        });
+        let mut derives = vec!["Debug", "Default", "Clone", "Deserialize", "Serialize"];
+        if needs_default_impl {
+            derives.retain(|&d| d != "Default");
+        }
+
+        self.write_derives(derives, f)?;
+        let mut container_attrs = vec![];
+        // Root element name in XML.
+        if let Some(name) = obj.xml_name.as_ref() {
+            container_attrs.push(format!("rename = \"{}\"", name));
+        }
+
+        // Objects with only optional fields (and flattened maps) can be decoded from
+        // partial (or empty) objects, so they default to the derived `Default`. This
+        // is skipped for objects generic over `Any`, because the derived `Default`
+        // (and hence, `Deserialize`) would then require `Any: Default`.
+        let all_optional =
+            !obj.fields.is_empty() && obj.fields.iter().all(|f| f.flatten || !f.is_required);
+        if all_optional && !obj.fields.iter().any(|f| f.needs_any) {
+            container_attrs.push("default".into());
+        }
+
+        if !container_attrs.is_empty() {
+            write!(f, "\n#[serde({})]", container_attrs.join(", "))?;
+        }
 
-        f.write_str("#[derive(Debug, Default, Clone, Deserialize, Serialize)]")?;
         f.write_str("\npub struct ")?;
-        f.write_str(&self.name)?;
-        if self.fields.iter().any(|f| f.needs_any) {
+        f.write_str(&obj.name)?;
+        if obj.fields.iter().any(|f| f.needs_any) {
             ApiObject::write_any_generic(f)?;
         }
 
         f.write_str(" {")?;
 
-        self.fields.iter().try_for_each(|field| {
-            let mut new_name = field.name.to_snek_case();
-            // Check if the field matches a Rust keyword and add '_' suffix.
-            if RUST_KEYWORDS.iter().any(|&k| k == new_name) {
-                new_name.push('_');
-            }
+        // XML names of fields represented as elements.
+        let xml_elements = obj
+            .fields
+            .iter()
+            .filter(|f| !f.is_xml_attribute())
+            .map(ObjectField::xml_name)
+            .collect::<HashSet<_>>();
 
-            ApiObject::write_docs(field.description.as_ref(), f, 1)?;
-            if field.description.is_none() {
+        obj.fields.iter().try_for_each(|field| {
+            let new_name = field.rust_name();
+
+            let docs = field.docs();
+            ApiObject::write_docs(docs.as_ref(), f, 1)?;
+            if docs.is_none() {
                 f.write_str("\n")?;
             }
 
+            let mut serde_attrs = vec![];
+            if field.flatten {
+                serde_attrs.push("flatten".into());
+            } else if new_name != field.name.as_str() {
+                serde_attrs.push(format!("rename = \"{}\"", field.name));
+            }
+
+            // We can't rename fields only for XML, so we add an alias for decoding.
+            // Attributes and elements look the same to serde, so if they collide,
+            // then the element gets the name.
+            let xml_name = field.xml_name();
+            if xml_name != field.name
+                && !(field.is_xml_attribute() && xml_elements.contains(xml_name))
+            {
+                serde_attrs.push(format!("alias = \"{}\"", xml_name));
+            }
+
+            // Date-times are (de)serialized as RFC 3339 strings.
+            if field.ty_path == OFFSET_DATE_TIME_TYPE {
+                if field.is_required {
+                    serde_attrs.push("with = \"time::serde::rfc3339\"".into());
+                } else {
+                    // Adapters don't default to `None` for missing values.
+                    if !all_optional {
+                        serde_attrs.push("default".into());
+                    }
+
+                    serde_attrs.push("with = \"time::serde::rfc3339::option\"".into());
+                }
+            }
+
+            // Missing values are skipped, whereas `null` is serialized.
+            if field.is_patch() {
+                if !all_optional {
+                    serde_attrs.push("default".into());
+                }
+
+                serde_attrs.push(format!(
+                    "skip_serializing_if = \"{}util::Patch::is_missing\"",
+                    self.helper_module_prefix
+                ));
+            } else if field.write_only && field.is_required {
+                // Required write-only fields are missing in responses.
+                serde_attrs.push("default".into());
+            }
+
+            // Custom attributes win over the generated ones.
+            serde_attrs.retain(|a: &String| {
+                !field
+                    .custom_attrs
+                    .iter()
+                    .any(|c| serde_attr_key(c) == serde_attr_key(a))
+            });
+
             f.write_str("    ")?;
-            if new_name != field.name.as_str() {
-                f.write_str("#[serde(rename = \"")?;
-                f.write_str(&field.name)?;
-                f.write_str("\")]\n    ")?;
+            if !serde_attrs.is_empty() {
+                f.write_str("#[serde(")?;
+                f.write_str(&serde_attrs.join(", "))?;
+                f.write_str(")]\n    ")?;
+            }
+
+            if !field.custom_attrs.is_empty() {
+                f.write_str("#[serde(")?;
+                f.write_str(&field.custom_attrs.join(", "))?;
+                f.write_str(")]\n    ")?;
+            }
+
+            // Original names may not be snake-cased.
+            if new_name.trim_end_matches('_') != field.name.to_snek_case() {
+                f.write_str("#[allow(non_snake_case)]\n    ")?;
             }
 
             f.write_str("pub ")?;
             f.write_str(&new_name)?;
             f.write_str(": ")?;
-            if !field.is_required {
+            if field.is_patch() {
+                f.write_str(self.helper_module_prefix)?;
+                f.write_str("util::Patch<")?;
+            } else if !field.is_required && !field.flatten {
                 f.write_str("Option<")?;
             }
 
@@ -841,7 +1595,7 @@ impl Display for ApiObject {
             }
 
             if field.needs_any {
-                Self::write_field_with_any(&field.ty_path, f)?;
+                ApiObject::write_field_with_any(&field.ty_path, ANY_GENERIC_PARAMETER, f)?;
             } else {
                 f.write_str(&field.ty_path)?;
             }
@@ -850,7 +1604,7 @@ impl Display for ApiObject {
                 f.write_str(">")?;
             }
 
-            if !field.is_required {
+            if !field.is_required && !field.flatten {
                 f.write_str(">")?;
             }
 
@@ -858,10 +1612,23 @@ impl Display for ApiObject {
             Ok(())
         })?;
 
-        if !self.fields.is_empty() {
+        if !obj.fields.is_empty() {
             f.write_str("\n")?;
         }
 
-        f.write_str("}\n")
+        f.write_str("}\n")?;
+        if needs_default_impl {
+            self.write_default_impl(f)?;
+        }
+
+        Ok(())
     }
 }
+
+/// Returns the key of the given serde attribute (i.e., `with` in `with = "foo"`).
+fn serde_attr_key(attr: &str) -> &str {
+    attr.split(|c| c == '=' || c == '(')
+        .next()
+        .unwrap_or("")
+        .trim()
+}
@@ -1,16 +1,18 @@
+use super::emitter::{ANY_GENERIC_PARAMETER, DATE_TYPE, DECIMAL_TYPE, OFFSET_DATE_TIME_TYPE};
+use super::object::{self, ApiObject, TypeParameters};
 use super::template::{self, TEMPLATE};
-use super::{object::ApiObject, CrateMeta, EmitMode};
+use super::{CrateMeta, EmitMode, RUST_KEYWORDS};
 use crate::error::PaperClipError;
-use crate::v2::models::{Coders, SpecFormat};
+use crate::v2::models::{
+    Coders, CollectionFormat, DataTypeFormat, Info, SecurityScheme, Server, SpecFormat,
+};
 use failure::Error;
-use heck::CamelCase;
-#[cfg(feature = "cli")]
-use heck::SnekCase;
+use heck::{CamelCase, ShoutySnakeCase, SnekCase};
 use itertools::Itertools;
 use url::Url;
 
 use std::cell::RefCell;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt::Write as _;
 #[cfg(feature = "cli")]
 use std::fs;
@@ -20,6 +22,9 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
+/// Items of the client module which are re-exported in the prelude.
+const PRELUDE_CLIENT_ITEMS: &[&str] = &["ApiClient", "ApiError", "EnvClient", "Sendable"];
+
 /// Holds the state for your schema emitter.
 #[derive(Debug)]
 pub struct EmitterState {
@@ -29,10 +34,81 @@ pub struct EmitterState {
     pub ns_sep: &'static str,
     /// Module prefix for using in generated code.
     pub mod_prefix: &'static str,
+    /// Whether to keep the original names of object fields (when they're
+    /// valid Rust identifiers) instead of converting them to snake case.
+    pub keep_field_names: bool,
+    /// Additional traits to be derived for the generated objects (say, `PartialEq`,
+    /// `Eq`, `Hash`, etc.). `Eq`, `Hash` and `Ord` are skipped for objects containing
+    /// floats or `Any` (and `PartialOrd` for objects containing `Any`).
+    pub derives: Vec<String>,
+    /// Whether to preserve the order in which properties, paths and operations
+    /// are specified in the spec. By default, they're sorted by name (and methods).
+    pub preserve_order: bool,
+    /// Whether to fail (instead of warning) when function names cannot be
+    /// derived for some operations.
+    pub strict: bool,
+    /// Whether to generate standalone structs for the query parameters of
+    /// operations (which can be passed to the builders in one call).
+    pub query_structs: bool,
+    /// Whether to generate a trait for each tag (with methods for the operations
+    /// having that tag), which is implemented for all API clients. Untagged
+    /// operations go to `DefaultApi` trait.
+    pub tag_traits: bool,
+    /// Type substituted for `Any` when it's not specified explicitly (for example,
+    /// `serde_yaml::Value` in responses). By default, it's the `Any` value type
+    /// of the coder used for the associated media range.
+    pub any_value: Option<String>,
+    /// Whether to generate `#[serde(transparent)]` newtypes for named definitions
+    /// of simple types (strings, numbers and booleans). By default, the simple
+    /// types are used in place of such definitions.
+    pub newtypes: bool,
+    /// Whether to generate a `fixtures` module with `example` functions for
+    /// objects, which return the objects built from the examples in their
+    /// schema (if they're valid).
+    pub fixtures: bool,
+    /// Whether successful responses sharing the same schema (for different
+    /// status codes) should have separate variants in the response enums of
+    /// operations. By default, they're collapsed into a single variant.
+    pub split_statuses: bool,
+    /// Whether enum query parameters with default values should always be sent
+    /// (with the default value when they're unset). By default, unset parameters
+    /// are omitted (and explicitly set values are always sent).
+    pub send_defaults: bool,
+    /// Whether to map `date-time` and `date` formats of object fields to
+    /// `time::OffsetDateTime` and `time::Date` (instead of strings). Date-times
+    /// are (de)serialized as RFC 3339 strings. This is gated behind `"time-types"` feature.
+    ///
+    /// **NOTE:** The generated crate then depends on `time` 0.3, which needs a much
+    /// newer compiler than the nightly used for this repository (at least Rust 1.48,
+    /// and later releases of `time` need newer stable releases).
+    #[cfg(feature = "time-types")]
+    pub time_types: bool,
+    /// Prefix for the environment variables read by the generated
+    /// `EnvClient::from_env` (for example, `PETSTORE` for `PETSTORE_BASE_URL`).
+    /// By default, this is the spec title in upper snake case.
+    pub env_prefix: Option<String>,
+    /// Whether to generate a `blocking` client (in the `client` module), which
+    /// drives the API calls on its own runtime, along with the blocking
+    /// `Sendable` impls for operation builders.
+    pub blocking: bool,
+    /// Whether to fail (instead of warning) when some schema or parameter has
+    /// a format which isn't known (the type falls back to its base type).
+    pub strict_formats: bool,
+    /// Custom formats which are known to be informational - these are mapped
+    /// to their base types without any warnings (or errors in strict mode).
+    pub known_formats: Vec<String>,
 
     /* MARK: Private fields. */
     /// Base URL for the API.
     pub(super) base_url: RefCell<Url>,
+    /// Information about the API (obtained from `Api.info`).
+    pub(super) spec_info: RefCell<Info>,
+    /// Server URL templates (obtained from `Api.servers`).
+    pub(super) servers: RefCell<Vec<Server>>,
+    /// Security schemes (obtained from `Api.securityDefinitions`).
+    pub(super) security_schemes: RefCell<BTreeMap<String, SecurityScheme>>,
+    /// Global security requirements (obtained from `Api.security`).
+    pub(super) security: RefCell<Vec<BTreeMap<String, Vec<String>>>>,
     /// Fallback encoding when we don't have a choice (obtained from `Api.spec_format`).
     default_encoding: RefCell<SpecFormat>,
     /// If crate metadata is specified, then `lib.rs` and `Cargo.toml` are generated
@@ -51,10 +127,18 @@ pub struct EmitterState {
     media_coders: RefCell<Vec<MediaCoder>>,
     /// Unit types used by builders.
     unit_types: RefCell<HashSet<String>>,
+    /// Custom delimiters (`x-delimiter`) used by parameters.
+    delimiters: RefCell<BTreeSet<String>>,
     /// Generated CLI YAML for clap.
     cli_yaml: RefCell<String>,
     /// Generated match arms for clap subcommands and matches.
     cli_match_arms: RefCell<String>,
+    /// Operations (method and path) for which function names couldn't be derived.
+    unreachable_ops: RefCell<Vec<String>>,
+    /// Tags of operations using each definition (mapped by definition name).
+    pub(super) def_tags: RefCell<HashMap<String, Vec<String>>>,
+    /// Type names (mapped by their module and name) claimed by definitions.
+    type_names: RefCell<HashMap<(String, String), String>>,
 }
 
 /// Indicates a child module in codegen working directory.
@@ -70,7 +154,7 @@ impl EmitterState {
     /// Sets the base URL for this session.
     ///
     /// **NOTE:** Once `Emitter::generate` is called, this gets overridden
-    /// by `host` and `basePath` fields in spec (if they exist).
+    /// by `host` and `basePath` fields (or the first server) in spec (if they exist).
     pub fn set_url(&self, url: &str) -> Result<(), Error> {
         let u = Url::parse(url).map_err(|e| PaperClipError::InvalidBasePathURL(url.into(), e))?;
         *self.base_url.borrow_mut() = u;
@@ -80,26 +164,74 @@ impl EmitterState {
     /// Base URL for this API.
     ///
     /// **NOTE:** Once `Emitter::generate` is called, this gets overridden
-    /// by `host` and `basePath` fields in spec (if they exist).
+    /// by `host` and `basePath` fields (or the first server) in spec (if they exist).
     pub fn base_url(&self) -> String {
         self.base_url.borrow().to_string()
     }
 
+    /// Operations (as `METHOD /path`) which were skipped by the emitter in its
+    /// last run, because function names couldn't be derived for them.
+    pub fn unreachable_operations(&self) -> Vec<String> {
+        self.unreachable_ops.borrow().clone()
+    }
+
     /// Resets internal state-related information used by the emitter.
     pub(crate) fn reset_internal_fields(&self) {
         *self.mod_children.borrow_mut() = Default::default();
         *self.def_mods.borrow_mut() = Default::default();
         *self.rel_paths.borrow_mut() = Default::default();
         *self.unit_types.borrow_mut() = Default::default();
+        *self.delimiters.borrow_mut() = Default::default();
         *self.cli_yaml.borrow_mut() = Default::default();
         *self.cli_match_arms.borrow_mut() = Default::default();
+        *self.unreachable_ops.borrow_mut() = Default::default();
+        *self.def_tags.borrow_mut() = Default::default();
         *self.media_coders.borrow_mut() = Default::default();
     }
 
+    /// Checks that the given format (if any) is known. Unknown formats fall back
+    /// to the base type with a warning (or fail in strict mode).
+    pub(super) fn check_format<F>(
+        &self,
+        format: Option<&DataTypeFormat>,
+        location: F,
+    ) -> Result<(), PaperClipError>
+    where
+        F: FnOnce() -> String,
+    {
+        let format = match format {
+            Some(DataTypeFormat::Other(f)) if !self.known_formats.contains(f) => f,
+            _ => return Ok(()),
+        };
+
+        let location = location();
+        if self.strict_formats {
+            return Err(PaperClipError::UnknownFormat(format.clone(), location));
+        }
+
+        warn!(
+            "Unknown format {:?} in {}. Falling back to the base type.",
+            format, location
+        );
+        Ok(())
+    }
+
+    /// Registers the custom delimiters (if any) in the given collection formats,
+    /// so that their marker types are added to the `util` module.
+    pub(super) fn add_delimiters(&self, fmts: &[CollectionFormat]) {
+        let mut delimiters = self.delimiters.borrow_mut();
+        for fmt in fmts {
+            if let CollectionFormat::Custom(d) = fmt {
+                delimiters.insert(d.clone());
+            }
+        }
+    }
+
     /// Sets the media type information for encoder/decoders.
     pub(crate) fn set_media_info(&self, spec_format: SpecFormat, coders: &Coders) {
         *self.default_encoding.borrow_mut() = spec_format;
 
+        let mut error_types = HashSet::new();
         *self.media_coders.borrow_mut() = coders
             .iter()
             .map(|(r, c)| (r.0.as_ref(), c))
@@ -108,10 +240,54 @@ impl EmitterState {
                 error_variant: r.replace('*', "wildcard").to_camel_case(),
                 error_ty_path: c.error_path.clone(),
                 decoder: c.decoder_path.clone(),
+                // Multiple ranges can share coders, but we can only have one
+                // conversion for an error type.
+                impl_from: error_types.insert(c.error_path.clone()),
             })
             .collect();
     }
 
+    /// Returns the type name for some definition (identified by the given owner)
+    /// in the given module. The (camel-cased) title is preferred over the derived
+    /// name, unless it's not a valid identifier or if it's already been claimed by
+    /// some other definition in the same module.
+    pub(super) fn type_name(
+        &self,
+        module: &str,
+        title: Option<&str>,
+        derived: String,
+        owner: &str,
+    ) -> String {
+        let mut names = self.type_names.borrow_mut();
+        let title = title.map(CamelCase::to_camel_case).filter(|t| {
+            t.chars()
+                .next()
+                .map(|c| c.is_ascii_alphabetic())
+                .unwrap_or(false)
+                && t.chars().all(|c| c.is_ascii_alphanumeric())
+                && !RUST_KEYWORDS.contains(&t.as_str())
+        });
+
+        if let Some(t) = title {
+            let claimant = names
+                .entry((module.into(), t.clone()))
+                .or_insert_with(|| owner.into());
+            if claimant == owner {
+                return t;
+            }
+
+            warn!(
+                "Title {:?} (for {:?}) collides with another type in {:?} module. Using {:?} instead.",
+                t, owner, module, derived
+            );
+        }
+
+        names
+            .entry((module.into(), derived.clone()))
+            .or_insert_with(|| owner.into());
+        derived
+    }
+
     /// Once the emitter has generated the struct definitions,
     /// we can call this method to generate the module declarations
     /// from root.
@@ -175,13 +351,15 @@ pub mod {name} {{
     /// Once the emitter has generated the struct definitions,
     /// we can call this method to write the definitions to leaf modules.
     pub(crate) fn write_definitions(&self) -> Result<(), Error> {
+        self.set_derives();
+        let module_prefix = self.normalized_mod_prefix();
         let def_mods = self.def_mods.borrow();
         info!("Writing definitions.");
         for (i, (mod_path, object)) in def_mods
             .iter()
             .flat_map(move |(p, l)| l.iter().map(move |o| (p, o)).enumerate())
         {
-            let contents = object.to_string();
+            let contents = object.struct_repr(&module_prefix).to_string();
             if i == 0 {
                 self.write_contents(&contents, mod_path)?;
             } else {
@@ -192,6 +370,77 @@ pub mod {name} {{
         Ok(())
     }
 
+    /// Sets the additional derives for all objects, skipping those which can't
+    /// be derived for some objects because of their (transitive) fields.
+    fn set_derives(&self) {
+        if self.derives.is_empty() {
+            return;
+        }
+
+        let prefix = self.normalized_mod_prefix();
+        let mut def_mods = self.def_mods.borrow_mut();
+        let ty_path = |obj: &ApiObject| format!("{}{}::{}", prefix, obj.path, obj.name);
+
+        // Whether objects (transitively) contain floats or `Any`. We begin by assuming
+        // that they don't, and keep marking them until nothing changes. Since values
+        // only change from `false` to `true`, this terminates for cyclic objects.
+        let mut caps = def_mods
+            .values()
+            .flatten()
+            .map(|obj| (ty_path(obj), (false, false)))
+            .collect::<HashMap<_, _>>();
+
+        loop {
+            let mut changed = false;
+            for obj in def_mods.values().flatten() {
+                let cap = obj.fields.iter().fold((false, false), |cap, field| {
+                    let (has_float, has_any) = if field.needs_any {
+                        (false, true)
+                    } else {
+                        field_capability(&field.ty_path, &caps)
+                    };
+
+                    (cap.0 || has_float, cap.1 || has_any)
+                });
+
+                let path = ty_path(obj);
+                if caps[&path] != cap {
+                    caps.insert(path, cap);
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        for obj in def_mods.values_mut().flatten() {
+            let (has_float, has_any) = caps[&ty_path(obj)];
+            obj.derives = self
+                .derives
+                .iter()
+                .filter(|&d| {
+                    let supported = match d.as_str() {
+                        "Eq" | "Hash" | "Ord" => !has_float && !has_any,
+                        "PartialOrd" => !has_any,
+                        _ => true,
+                    };
+
+                    if !supported {
+                        info!(
+                            "Skipping {} derive for {} because it has floats or `Any` types.",
+                            d, obj.name
+                        );
+                    }
+
+                    supported
+                })
+                .cloned()
+                .collect();
+        }
+    }
+
     /// Once the emitter has collected requirements for paths,
     /// we can use this method to add builder structs and their impls.
     pub(crate) fn add_builders(&self) -> Result<(), Error> {
@@ -208,8 +457,25 @@ pub mod {name} {{
             .flat_map(move |(p, l)| l.iter().map(move |o| (p, o)))
         {
             let mut builder_content = String::new();
-            let repr = object.impl_repr(&module_prefix);
+            let repr = object.impl_repr(&module_prefix, self.any_value.as_ref().map(AsRef::as_ref));
             for builder in &*repr.builders {
+                if builder.constructor_fn_name().is_none() {
+                    let op = format!(
+                        "{} {}",
+                        builder
+                            .method
+                            .map(|m| m.to_string().to_uppercase())
+                            .unwrap_or_else(|| "<unknown>".into()),
+                        builder.rel_path.unwrap_or_default()
+                    );
+                    warn!(
+                        "Unable to derive function name for operation ({}) in {:?}. Skipping.",
+                        op, object.name
+                    );
+                    self.unreachable_ops.borrow_mut().push(op);
+                    continue;
+                }
+
                 builder
                     .struct_fields_iter()
                     .filter(|f| f.prop.is_required())
@@ -222,6 +488,16 @@ pub mod {name} {{
                 builder_content.push('\n');
                 let inner_repr = builder.impl_repr();
                 let _ = write!(builder_content, "{}", inner_repr);
+                if self.query_structs {
+                    if let Some(query) = builder.query_repr() {
+                        let _ = write!(builder_content, "{}", query);
+                    }
+                }
+
+                if self.blocking {
+                    inner_repr.write_blocking_sendable(&mut builder_content)?;
+                }
+
                 if is_cli {
                     inner_repr.write_arg_parsing(&mut builder_content)?;
                 }
@@ -239,6 +515,11 @@ pub mod {name} {{
             self.append_contents(&builder_content, mod_path)?;
         }
 
+        let unreachable = self.unreachable_ops.borrow();
+        if self.strict && !unreachable.is_empty() {
+            return Err(PaperClipError::UnreachableOperations(unreachable.clone()).into());
+        }
+
         Ok(())
     }
 
@@ -254,11 +535,35 @@ pub mod generics {
     include!(\"./generics.rs\");
 }
 
+pub mod operations {
+    include!(\"./operations.rs\");
+}
+
 pub mod util {
     include!(\"./util.rs\");
 }
 ",
         );
+        if self.tag_traits {
+            content.push_str(
+                "
+pub mod tags {
+    include!(\"./tags.rs\");
+}
+",
+            );
+        }
+
+        if self.fixtures {
+            content.push_str(
+                "
+pub mod fixtures {
+    include!(\"./fixtures.rs\");
+}
+",
+            );
+        }
+
         self.append_contents(&content, &module)?;
 
         content.clear();
@@ -276,10 +581,42 @@ pub mod util {
         content.push_str("\n");
         self.write_contents(&content, &module)?;
 
+        module.set_file_name("operations.rs");
+        let contents = template::render(
+            TEMPLATE::OPERATIONS_MOD,
+            &OperationsModContext {
+                operations: &self.operation_contexts(),
+            },
+        )?;
+        self.write_contents(&contents, &module)?;
+
         module.set_file_name("util.rs");
-        let contents = template::render(TEMPLATE::UTIL_MOD, &EmptyContext {})?;
+        let contents = template::render(
+            TEMPLATE::UTIL_MOD,
+            &UtilModContext {
+                delimiters: self
+                    .delimiters
+                    .borrow()
+                    .iter()
+                    .map(|d| DelimiterContext {
+                        name: object::delimiter_marker(&CollectionFormat::Custom(d.clone())),
+                        value: format!("{:?}", d),
+                    })
+                    .collect(),
+            },
+        )?;
         self.write_contents(&contents, &module)?;
 
+        if self.tag_traits {
+            module.set_file_name("tags.rs");
+            self.write_contents(&self.tag_traits_contents()?, &module)?;
+        }
+
+        if self.fixtures {
+            module.set_file_name("fixtures.rs");
+            self.write_contents(&self.fixtures_contents()?, &module)?;
+        }
+
         self.add_cli_deps_if_needed()?;
         self.create_manifest()
     }
@@ -287,16 +624,506 @@ pub mod util {
     /// Once the builders have been added, we can add API client dependencies.
     pub(crate) fn add_client_deps(&self) -> Result<(), Error> {
         let module = self.root_module_path();
+        let info = self.spec_info.borrow();
+        let operation_count = self
+            .def_mods
+            .borrow()
+            .values()
+            .flat_map(|objects| objects.iter())
+            .flat_map(|obj| obj.paths.values())
+            .map(|ops| ops.req.len())
+            .sum();
+        let env_prefix = self
+            .env_prefix
+            .clone()
+            .unwrap_or_else(|| info.title.to_shouty_snake_case())
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+            .collect::<String>();
+
         let contents = template::render(
             TEMPLATE::CLIENT_MOD,
             &ClientModContext {
                 mod_prefix: &self.normalized_mod_prefix(),
                 media_coders: &*self.media_coders.borrow(),
                 base_url: self.base_url.borrow().as_str(),
+                spec_title: &format!("{:?}", info.title),
+                spec_version: &format!("{:?}", info.version),
+                operation_count,
+                servers: &self.server_contexts(),
+                env_prefix: &env_prefix,
+                env_credentials: &self.env_credential_contexts(&env_prefix),
+                blocking: self.blocking,
+                operation_paths: &self.operation_path_contexts(),
             },
         )?;
 
-        self.append_contents(&contents, &module)
+        self.append_contents(&contents, &module)?;
+        self.append_contents(&self.prelude_contents(), &module)
+    }
+
+    /// Returns the methods and path templates of the operations in the
+    /// registry (sorted by path and method).
+    fn operation_path_contexts(&self) -> Vec<OperationPathContext> {
+        let def_mods = self.def_mods.borrow();
+        let mut ops = def_mods
+            .values()
+            .flat_map(|objects| objects.iter())
+            .flat_map(|obj| obj.paths.iter())
+            .flat_map(|(path, ops)| ops.req.keys().map(move |&m| (path, m)))
+            .collect::<Vec<_>>();
+        ops.sort();
+
+        ops.into_iter()
+            .map(|(path, method)| OperationPathContext {
+                method: method.to_string().to_uppercase(),
+                path: format!("{:?}", path),
+            })
+            .collect()
+    }
+
+    /// Returns the templating contexts for the operations in the registry
+    /// (sorted by path and method).
+    fn operation_contexts(&self) -> Vec<OperationContext> {
+        let module_prefix = self.normalized_mod_prefix();
+        let def_mods = self.def_mods.borrow();
+        let mut ops = def_mods
+            .values()
+            .flat_map(|objects| objects.iter())
+            .flat_map(|obj| {
+                obj.paths
+                    .iter()
+                    .flat_map(move |(path, ops)| ops.req.iter().map(move |r| (obj, path, ops, r)))
+            })
+            .collect::<Vec<_>>();
+        ops.sort_by(|a, b| (a.1, a.3 .0).cmp(&(b.1, b.3 .0)));
+
+        ops.into_iter()
+            .map(|(obj, path, path_ops, (method, req))| {
+                let obj_path = format!("{}{}::{}", module_prefix, obj.path, obj.name);
+                // Operation-specific parameters override the ones in the path.
+                let params = path_ops
+                    .params
+                    .iter()
+                    .filter(|p| {
+                        !req.params
+                            .iter()
+                            .any(|q| q.name == p.name && q.presence == p.presence)
+                    })
+                    .chain(req.params.iter())
+                    .map(|p| ParameterContext {
+                        name: format!("{:?}", p.name),
+                        location: format!("{:?}", p.presence),
+                        required: p.required,
+                    })
+                    .collect();
+
+                let response = req.response.ty_path.as_ref().map(|ty| {
+                    // Operations returning this object refer to it by name.
+                    let ty = if req.response.is_file() {
+                        "file"
+                    } else if ty == &obj.name {
+                        &obj_path
+                    } else {
+                        ty.as_str()
+                    };
+
+                    if req.listable {
+                        format!("Vec<{}>", ty)
+                    } else {
+                        ty.into()
+                    }
+                });
+
+                OperationContext {
+                    method: format!("{:?}", method.to_string().to_uppercase()),
+                    path: format!("{:?}", path),
+                    id: format!("{:?}", req.id),
+                    params,
+                    body: if req.body_required {
+                        format!("Some({:?})", obj_path)
+                    } else {
+                        "None".into()
+                    },
+                    response: format!("{:?}", response),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the `example` functions for objects in the registry, which
+    /// decode the (valid) examples from their schema.
+    fn fixtures_contents(&self) -> Result<String, Error> {
+        let module_prefix = self.normalized_mod_prefix();
+        let def_mods = self.def_mods.borrow();
+        let mut fixtures = BTreeMap::new();
+        for obj in def_mods.values().flat_map(|objects| objects.iter()) {
+            let example = match obj.example.as_ref() {
+                Some(e) => e,
+                None => continue,
+            };
+
+            let ty = format!("{}{}::{}", module_prefix, obj.path, obj.name);
+            let mut content = String::new();
+            if obj.fields.iter().any(|f| f.needs_any) {
+                write!(
+                    content,
+                    "\nimpl<{any}: serde::de::DeserializeOwned> {ty}<{any}> {{",
+                    any = ANY_GENERIC_PARAMETER,
+                    ty = ty
+                )?;
+            } else {
+                write!(content, "\nimpl {} {{", ty)?;
+            }
+
+            write!(
+                content,
+                "
+    /// Returns the example for this object (from the spec).
+    pub fn example() -> Self {{
+        serde_json::from_str({:?}).expect(\"invalid example?\")
+    }}
+}}
+",
+                serde_json::to_string(example)?
+            )?;
+            fixtures.insert(ty, content);
+        }
+
+        Ok(fixtures.values().map(String::as_str).collect())
+    }
+
+    /// Returns the `prelude` module, which re-exports all objects along with the
+    /// client traits. Objects sharing the same name (with other objects or the
+    /// client items) are aliased using the fewest parent modules which make their
+    /// names unique. Objects which still collide are skipped (with a warning).
+    fn prelude_contents(&self) -> String {
+        let module_prefix = self.normalized_mod_prefix();
+        let def_mods = self.def_mods.borrow();
+        if def_mods
+            .values()
+            .flatten()
+            .any(|obj| obj.path.split("::").next() == Some("prelude"))
+        {
+            warn!("Definitions already have a `prelude` module. Skipping prelude.");
+            return String::new();
+        }
+
+        let mut objects = def_mods
+            .values()
+            .flatten()
+            .map(|obj| (obj.path.as_str(), obj.name.as_str()))
+            .collect::<Vec<_>>();
+        objects.sort();
+
+        let mut groups = BTreeMap::new();
+        for &(path, name) in &objects {
+            groups.entry(name).or_insert_with(Vec::new).push(path);
+        }
+
+        // Names are claimed by the client items and the objects which don't collide.
+        let mut claimed = PRELUDE_CLIENT_ITEMS
+            .iter()
+            .map(|&n| String::from(n))
+            .collect::<HashSet<_>>();
+        claimed.extend(
+            groups
+                .iter()
+                .filter(|(n, paths)| paths.len() == 1 && !PRELUDE_CLIENT_ITEMS.contains(n))
+                .map(|(&n, _)| String::from(n)),
+        );
+
+        let mut aliases = HashMap::new();
+        for (&name, paths) in &groups {
+            if paths.len() == 1 && !PRELUDE_CLIENT_ITEMS.contains(&name) {
+                continue;
+            }
+
+            // Objects usually have their own modules, which don't add anything to aliases.
+            let own_module = name.to_snek_case();
+            let segments = paths
+                .iter()
+                .map(|p| {
+                    let mut s = p.split("::").collect::<Vec<_>>();
+                    if s.len() > 1 && s.last() == Some(&own_module.as_str()) {
+                        s.pop();
+                    }
+
+                    s
+                })
+                .collect::<Vec<_>>();
+            let max_depth = segments.iter().map(Vec::len).max().unwrap_or(0);
+            let found = (1..=max_depth).find_map(|depth| {
+                let names = segments
+                    .iter()
+                    .map(|s| {
+                        let mut alias = s[s.len().saturating_sub(depth)..]
+                            .iter()
+                            .map(|m| m.to_camel_case())
+                            .collect::<String>();
+                        alias.push_str(name);
+                        alias
+                    })
+                    .collect::<Vec<_>>();
+                let unique = names.iter().collect::<HashSet<_>>().len() == names.len();
+                if unique && names.iter().all(|n| !claimed.contains(n.as_str())) {
+                    Some(names)
+                } else {
+                    None
+                }
+            });
+
+            match found {
+                Some(names) => {
+                    for (path, alias) in paths.iter().zip(names) {
+                        claimed.insert(alias.clone());
+                        aliases.insert((*path, name), alias);
+                    }
+                }
+                None => warn!(
+                    "Unable to alias {:?} objects in {:?} for prelude. Skipping.",
+                    name, paths
+                ),
+            }
+        }
+
+        let mut content = format!(
+            "
+/// Re-exports of all objects and the API client (for glob imports).
+pub mod prelude {{
+    pub use {}client::{{{}}};",
+            module_prefix,
+            PRELUDE_CLIENT_ITEMS.join(", ")
+        );
+
+        for (path, name) in objects {
+            let alias = aliases.get(&(path, name));
+            if alias.is_none() && (groups[name].len() > 1 || PRELUDE_CLIENT_ITEMS.contains(&name)) {
+                continue;
+            }
+
+            let _ = write!(content, "\n    pub use {}{}::{}", module_prefix, path, name);
+            if let Some(a) = alias {
+                content.push_str(" as ");
+                content.push_str(a);
+            }
+
+            content.push(';');
+        }
+
+        content.push_str("\n}\n");
+        content
+    }
+
+    /// Returns the traits for the tags of operations in the registry, where
+    /// each method forwards to the builder of the corresponding operation.
+    fn tag_traits_contents(&self) -> Result<String, Error> {
+        let module_prefix = self.normalized_mod_prefix();
+        let def_mods = self.def_mods.borrow();
+        let mut traits = BTreeMap::new();
+        for obj in def_mods.values().flat_map(|objects| objects.iter()) {
+            let repr = obj.impl_repr(&module_prefix, self.any_value.as_ref().map(AsRef::as_ref));
+            for builder in &*repr.builders {
+                let (name, method, path) = match (
+                    builder.constructor_fn_name(),
+                    builder.method,
+                    builder.rel_path,
+                ) {
+                    (Some(n), Some(m), Some(p)) => (n, m, p),
+                    _ => continue,
+                };
+
+                let mut ret_ty = format!("{}{}::", module_prefix, obj.path);
+                builder.write_name(&mut ret_ty)?;
+                builder.write_generics_if_necessary(
+                    &mut ret_ty,
+                    Some(builder.default_any_value(builder.encoding)),
+                    TypeParameters::ReplaceAll,
+                )?;
+
+                let mut docs = String::new();
+                ApiObject::write_docs(builder.description, &mut docs, 1)?;
+                let op = TagTraitMethod {
+                    sort_key: (path.to_owned(), method.to_string()),
+                    name: name.clone(),
+                    alt_name: format!("{}_{}", obj.name.to_snek_case(), name),
+                    ret_ty,
+                    call: format!("{}{}::{}::{}()", module_prefix, obj.path, obj.name, name),
+                    docs,
+                    deprecated: builder.deprecated,
+                };
+
+                if builder.tags.is_empty() {
+                    traits
+                        .entry(String::from("DefaultApi"))
+                        .or_insert_with(|| (None, vec![]))
+                        .1
+                        .push(op);
+                    continue;
+                }
+
+                for tag in builder.tags {
+                    let mut trait_name = tag.to_camel_case();
+                    if !trait_name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                        trait_name.insert_str(0, "Tag");
+                    }
+
+                    trait_name.push_str("Api");
+                    traits
+                        .entry(trait_name)
+                        .or_insert_with(|| (Some(tag.as_str()), vec![]))
+                        .1
+                        .push(op.clone());
+                }
+            }
+        }
+
+        let mut content = String::new();
+        for (trait_name, (tag, mut methods)) in traits {
+            methods.sort_by(|a, b| a.sort_key.cmp(&b.sort_key));
+            // Operations of different objects could share the same
+            // (method-based) name, so we qualify those with the object.
+            let mut seen = HashSet::new();
+            let shared = methods
+                .iter()
+                .filter(|m| !seen.insert(&m.name))
+                .map(|m| m.name.clone())
+                .collect::<HashSet<_>>();
+            let mut names = HashSet::new();
+            for m in &mut methods {
+                if shared.contains(&m.name) {
+                    m.name = m.alt_name.clone();
+                }
+
+                while !names.insert(m.name.clone()) {
+                    m.name.push('_');
+                }
+            }
+
+            match tag {
+                Some(t) => write!(content, "\n/// Operations tagged with `{}`.", t)?,
+                None => content.push_str("\n/// Operations without any tags."),
+            }
+
+            write!(content, "\npub trait {} {{", trait_name)?;
+            for (i, m) in methods.iter().enumerate() {
+                if i > 0 {
+                    content.push('\n');
+                }
+
+                if m.docs.is_empty() {
+                    content.push('\n');
+                } else {
+                    content.push_str(&m.docs);
+                }
+
+                if m.deprecated {
+                    content.push_str("    #[deprecated]\n");
+                }
+
+                write!(content, "    fn {}(&self) -> {};", m.name, m.ret_ty)?;
+            }
+
+            write!(
+                content,
+                "\n}}\n\nimpl<Client: {}client::ApiClient> {} for Client {{",
+                module_prefix, trait_name
+            )?;
+            for (i, m) in methods.iter().enumerate() {
+                if i > 0 {
+                    content.push('\n');
+                }
+
+                content.push_str("\n    #[inline]");
+                if m.deprecated {
+                    content.push_str("\n    #[allow(deprecated)]");
+                }
+
+                write!(
+                    content,
+                    "\n    fn {}(&self) -> {} {{\n        {}\n    }}",
+                    m.name, m.ret_ty, m.call
+                )?;
+            }
+
+            content.push_str("\n}\n");
+        }
+
+        Ok(content)
+    }
+
+    /// Returns the templating contexts for server URL templates.
+    fn server_contexts(&self) -> Vec<ServerContext> {
+        self.servers
+            .borrow()
+            .iter()
+            .map(|server| ServerContext {
+                url: format!("{:?}", server.url),
+                description: format!("{:?}", server.description),
+                variables: server
+                    .variables
+                    .iter()
+                    .map(|(name, var)| ServerVariableContext {
+                        name: format!("{:?}", name),
+                        default: format!("{:?}", var.default),
+                        allowed: var.enum_.iter().map(|v| format!("{:?}", v)).join(", "),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Returns the templating contexts for the credentials (of security schemes)
+    /// read from environment variables with the given prefix. Schemes present in
+    /// all global security requirements are required.
+    fn env_credential_contexts(&self, prefix: &str) -> Vec<EnvCredentialContext> {
+        let schemes = self.security_schemes.borrow();
+        let security = self.security.borrow();
+        let count = |ty: &str| schemes.values().filter(|s| s.type_ == ty).count();
+        let (api_keys, tokens) = (count("apiKey"), count("oauth2"));
+
+        schemes
+            .iter()
+            .filter_map(|(name, scheme)| {
+                // Variables are qualified with scheme names only when there are multiple
+                // schemes of the same kind.
+                let (suffix, multiple, location) = match (&*scheme.type_, &*scheme.in_) {
+                    ("apiKey", "header") => (
+                        "API_KEY",
+                        api_keys > 1,
+                        format!("Header({:?})", scheme.name),
+                    ),
+                    ("apiKey", "query") => (
+                        "API_KEY",
+                        api_keys > 1,
+                        format!("Query({:?})", scheme.name),
+                    ),
+                    ("oauth2", _) => ("ACCESS_TOKEN", tokens > 1, "Bearer".into()),
+                    _ => {
+                        warn!(
+                            "Skipping unsupported security scheme {:?} for reading credentials from environment.",
+                            name
+                        );
+                        return None;
+                    }
+                };
+
+                let var = if multiple {
+                    format!("{}_{}_{}", prefix, name.to_shouty_snake_case(), suffix)
+                } else {
+                    format!("{}_{}", prefix, suffix)
+                };
+
+                Some(EnvCredentialContext {
+                    scheme: format!("{:?}", name),
+                    scheme_name: name.clone(),
+                    var: format!("{:?}", var),
+                    var_name: var,
+                    location,
+                    required: !security.is_empty() && security.iter().all(|r| r.contains_key(name)),
+                })
+            })
+            .collect()
     }
 
     /// Writes the given contents to a file at the given path (truncating the file if it exists).
@@ -411,6 +1238,28 @@ impl EmitterState {
         self.working_dir.join("mod.rs")
     }
 
+    /// Checks whether decimals are used by any of the emitted objects or their operations.
+    fn uses_decimal(&self) -> bool {
+        self.def_mods.borrow().values().flatten().any(|obj| {
+            let mut params = obj.paths.values().flat_map(|ops| {
+                ops.params
+                    .iter()
+                    .chain(ops.req.values().flat_map(|r| &r.params))
+            });
+            obj.fields.iter().any(|f| f.ty_path.contains(DECIMAL_TYPE))
+                || params.any(|p| p.ty_path.contains(DECIMAL_TYPE))
+        })
+    }
+
+    /// Checks whether `time` types are used by any of the emitted objects.
+    fn uses_time(&self) -> bool {
+        self.def_mods.borrow().values().flatten().any(|obj| {
+            obj.fields
+                .iter()
+                .any(|f| f.ty_path == OFFSET_DATE_TIME_TYPE || f.ty_path == DATE_TYPE)
+        })
+    }
+
     /// Creates a Cargo.toml manifest in the working directory (if it's a crate).
     fn create_manifest(&self) -> Result<(), Error> {
         let mut man_path = self.root_module_path();
@@ -432,6 +1281,14 @@ impl EmitterState {
                     version: &format!("{:?}", meta.version.as_ref().unwrap()),
                     authors: &format!("{:?}", meta.authors.as_ref().unwrap()),
                     is_cli,
+                    needs_xml: self
+                        .media_coders
+                        .borrow()
+                        .iter()
+                        .any(|c| c.error_ty_path == crate::v2::models::XML_CODER.error_path),
+                    needs_decimal: self.uses_decimal(),
+                    needs_time: self.uses_time(),
+                    blocking: self.blocking,
                 },
             )?;
 
@@ -515,9 +1372,30 @@ impl Clone for EmitterState {
             working_dir: self.working_dir.clone(),
             mod_prefix: self.mod_prefix,
             ns_sep: self.ns_sep,
+            keep_field_names: self.keep_field_names,
+            derives: self.derives.clone(),
+            preserve_order: self.preserve_order,
+            strict: self.strict,
+            query_structs: self.query_structs,
+            tag_traits: self.tag_traits,
+            any_value: self.any_value.clone(),
+            newtypes: self.newtypes,
+            fixtures: self.fixtures,
+            split_statuses: self.split_statuses,
+            send_defaults: self.send_defaults,
+            #[cfg(feature = "time-types")]
+            time_types: self.time_types,
+            env_prefix: self.env_prefix.clone(),
+            blocking: self.blocking,
+            strict_formats: self.strict_formats,
+            known_formats: self.known_formats.clone(),
             #[cfg(feature = "cli")]
             crate_meta: self.crate_meta.clone(),
             base_url: self.base_url.clone(),
+            spec_info: self.spec_info.clone(),
+            servers: self.servers.clone(),
+            security_schemes: self.security_schemes.clone(),
+            security: self.security.clone(),
             default_encoding: self.default_encoding.clone(),
             // Get rid of all state-specific fields.
             ..Default::default()
@@ -531,15 +1409,40 @@ impl Default for EmitterState {
             working_dir: PathBuf::from("."),
             mod_prefix: "crate::",
             ns_sep: ".",
+            keep_field_names: false,
+            derives: vec![],
+            preserve_order: false,
+            strict: false,
+            query_structs: false,
+            tag_traits: false,
+            any_value: None,
+            newtypes: false,
+            fixtures: false,
+            split_statuses: false,
+            send_defaults: false,
+            #[cfg(feature = "time-types")]
+            time_types: false,
+            env_prefix: None,
+            blocking: false,
+            strict_formats: false,
+            known_formats: vec![],
             #[cfg(feature = "cli")]
             crate_meta: Rc::new(RefCell::new(None)),
             base_url: RefCell::new("https://example.com".parse().expect("invalid URL?")),
+            spec_info: RefCell::new(Info::default()),
+            servers: RefCell::new(vec![]),
+            security_schemes: RefCell::new(BTreeMap::new()),
+            security: RefCell::new(vec![]),
             def_mods: RefCell::new(HashMap::new()),
             rel_paths: RefCell::new(HashSet::new()),
             mod_children: RefCell::new(HashMap::new()),
             unit_types: RefCell::new(HashSet::new()),
+            delimiters: RefCell::new(BTreeSet::new()),
             cli_yaml: RefCell::new(String::new()),
             cli_match_arms: RefCell::new(String::new()),
+            unreachable_ops: RefCell::new(vec![]),
+            def_tags: RefCell::new(HashMap::new()),
+            type_names: RefCell::new(HashMap::new()),
             media_coders: RefCell::new(vec![]),
             default_encoding: RefCell::new(SpecFormat::Json),
         }
@@ -558,6 +1461,24 @@ impl PartialEq for ChildModule {
     }
 }
 
+/// Returns whether the given (field) type contains floats or `Any` using
+/// the known capabilities of objects.
+fn field_capability(ty: &str, caps: &HashMap<String, (bool, bool)>) -> (bool, bool) {
+    if let Some(i) = ty.find('<') {
+        if ty[..i].ends_with("Vec") {
+            return field_capability(&ty[i + 1..ty.len() - 1], caps);
+        } else if ty[..i].ends_with("std::collections::BTreeMap") {
+            return field_capability(&ty[i + 9..ty.len() - 1], caps);
+        }
+    }
+
+    match ty {
+        "f32" | "f64" => (true, false),
+        ANY_GENERIC_PARAMETER => (false, true),
+        _ => caps.get(ty).cloned().unwrap_or_default(),
+    }
+}
+
 /* Templating contexts */
 
 #[cfg(feature = "cli")]
@@ -567,6 +1488,10 @@ struct ManifestContext<'a> {
     version: &'a str,
     authors: &'a str,
     is_cli: bool,
+    needs_xml: bool,
+    needs_decimal: bool,
+    needs_time: bool,
+    blocking: bool,
 }
 
 #[derive(serde::Serialize)]
@@ -580,6 +1505,78 @@ struct ClientModContext<'a> {
     base_url: &'a str,
     mod_prefix: &'a str,
     media_coders: &'a [MediaCoder],
+    spec_title: &'a str,
+    spec_version: &'a str,
+    operation_count: usize,
+    servers: &'a [ServerContext],
+    env_prefix: &'a str,
+    env_credentials: &'a [EnvCredentialContext],
+    blocking: bool,
+    operation_paths: &'a [OperationPathContext],
+}
+
+#[derive(serde::Serialize)]
+struct OperationPathContext {
+    method: String,
+    path: String,
+}
+
+#[derive(serde::Serialize)]
+struct OperationsModContext<'a> {
+    operations: &'a [OperationContext],
+}
+
+#[derive(Debug, serde::Serialize)]
+struct OperationContext {
+    method: String,
+    path: String,
+    id: String,
+    params: Vec<ParameterContext>,
+    body: String,
+    response: String,
+}
+
+/// Method (for some operation) in the trait generated for a tag.
+#[derive(Debug, Clone)]
+struct TagTraitMethod {
+    sort_key: (String, String),
+    name: String,
+    alt_name: String,
+    ret_ty: String,
+    call: String,
+    docs: String,
+    deprecated: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ParameterContext {
+    name: String,
+    location: String,
+    required: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ServerContext {
+    url: String,
+    description: String,
+    variables: Vec<ServerVariableContext>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct EnvCredentialContext {
+    scheme: String,
+    scheme_name: String,
+    var: String,
+    var_name: String,
+    location: String,
+    required: bool,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ServerVariableContext {
+    name: String,
+    default: String,
+    allowed: String,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -588,6 +1585,7 @@ struct MediaCoder {
     decoder: String,
     error_variant: String,
     error_ty_path: String,
+    impl_from: bool,
 }
 
 #[derive(serde::Serialize)]
@@ -596,5 +1594,16 @@ struct ClapYamlContext<'a> {
     version: &'a str,
 }
 
+#[derive(serde::Serialize)]
+struct UtilModContext {
+    delimiters: Vec<DelimiterContext>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct DelimiterContext {
+    name: String,
+    value: String,
+}
+
 #[derive(serde::Serialize)]
 struct EmptyContext {}
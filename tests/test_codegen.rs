@@ -7,8 +7,11 @@ use paperclip::v2::{
     models::{DefaultSchema, ResolvableApi},
 };
 
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::Read;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 lazy_static! {
     static ref ROOT: String = String::from(env!("CARGO_MANIFEST_DIR"));
@@ -62,6 +65,81 @@ fn assert_file_contains_content_at(path: &str, matching_content: &str, index: Op
     }
 }
 
+/// Code generated for a spec fixture in its own temporary directory
+/// (which is removed when this is dropped).
+struct Generated<E = DefaultEmitter<DefaultSchema>> {
+    emitter: E,
+    dir: PathBuf,
+}
+
+impl<E> Generated<E> {
+    /// Returns the contents of the given generated file.
+    fn read(&self, name: &str) -> String {
+        let mut contents = String::new();
+        let mut fd = File::open(self.dir.join(name)).expect("missing file");
+        fd.read_to_string(&mut contents).expect("reading file");
+        contents
+    }
+}
+
+impl<E> Drop for Generated<E> {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// Resolves the spec fixture with the given name (`tests/{name}-v2.yaml`).
+fn resolve_fixture(name: &str) -> ResolvableApi<DefaultSchema> {
+    let fd = File::open(format!("{}/tests/{}-v2.yaml", *ROOT, name)).expect("file?");
+    let raw: ResolvableApi<DefaultSchema> = v2::from_reader(fd).expect("deserializing spec");
+    raw.resolve().expect("resolution")
+}
+
+/// Generates code for the given spec fixture using the emitter created from the
+/// given state. The code is written to a unique temporary directory (i.e., the
+/// working directory in the state is ignored), so that tests don't clobber each other.
+fn try_generate_fixture_with<E, F>(
+    name: &str,
+    mut state: EmitterState,
+    emitter: F,
+) -> Result<Generated<E>, failure::Error>
+where
+    E: Emitter<Definition = DefaultSchema>,
+    F: FnOnce(EmitterState) -> E,
+{
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    let id = COUNT.fetch_add(1, Ordering::SeqCst);
+    let dir = std::env::temp_dir().join(format!("paperclip_{}_{}_{}", name, process::id(), id));
+    state.working_dir = dir.clone();
+    let generated = Generated {
+        emitter: emitter(state),
+        dir,
+    };
+
+    generated.emitter.generate(&resolve_fixture(name))?;
+    Ok(generated)
+}
+
+/// Same as `try_generate_fixture_with`, but for the default emitter.
+fn try_generate_fixture(name: &str, state: EmitterState) -> Result<Generated, failure::Error> {
+    try_generate_fixture_with(name, state, DefaultEmitter::from)
+}
+
+/// Same as `try_generate_fixture`, but panics if codegen fails.
+fn generate_fixture(name: &str, state: EmitterState) -> Generated {
+    try_generate_fixture(name, state).expect("codegen")
+}
+
+/// Generates code for the given spec fixture into the given directory under `tests`.
+/// Unlike `generate_fixture`, the code is kept around, so that the generated crate
+/// (along with the tests checked into that directory) can be built and run later.
+fn generate_fixture_in(name: &str, dir: &str, mut state: EmitterState) {
+    state.working_dir = PathBuf::from(&*ROOT).join("tests").join(dir);
+    let emitter = DefaultEmitter::from(state);
+    emitter.generate(&resolve_fixture(name)).expect("codegen");
+}
+
 #[test]
 fn test_lib_creation() {
     assert_file_contains_content_at(
@@ -96,6 +174,10 @@ pub mod post_shipments_body {
     include!(\"./post_shipments_body.rs\");
 }
 
+pub mod priority {
+    include!(\"./priority.rs\");
+}
+
 pub mod status {
     include!(\"./status.rs\");
 }
@@ -130,8 +212,10 @@ path = \"lib.rs\"
 [dependencies]
 async-trait = \"0.1\"
 failure = \"0.1\"
+flate2 = { version = \"1.0\", optional = true }
 futures = \"0.1\"
 futures-preview = { version = \"0.3.0-alpha.19\", features = [\"compat\"], package = \"futures-preview\" }
+futures-timer = \"1.0\"
 http = \"0.1\"
 lazy_static = \"1.4\"
 log = \"0.4\"
@@ -146,6 +230,11 @@ tokio-io-old = { version = \"0.1\", package = \"tokio-io\" }
 tokio-fs-old = { version = \"0.1\", package = \"tokio-fs\" }
 url = \"2.1\"
 
+[features]
+gzip = [\"flate2\"]
+interop-http = []
+interop-reqwest = []
+
 [workspace]
 ",
         Some(0),
@@ -164,11 +253,16 @@ fn test_overridden_path() {
         type Response = reqwest::r#async::Response;
 
         fn request_builder(&self, method: http::Method, rel_path: &str) -> Self::Request {
-            let mut u = String::from(\"https://pets.com:8888/api\");
+            let mut u = String::from(BASE_URL.trim_end_matches('/'));
+            u.push('/');
             u.push_str(rel_path.trim_start_matches('/'));
             self.request(method, &u)
         }
 
+        fn request_builder_for_url(&self, method: http::Method, url: &str) -> Option<Self::Request> {
+            Some(self.request(method, url))
+        }
+
         async fn make_request(&self, req: Self::Request) -> Result<Self::Response, ApiError<Self::Response>> {
             let req = req.build().map_err(ApiError::Reqwest)?;
             let resp = self.execute(req).map_err(ApiError::Reqwest).compat().await?;
@@ -176,7 +270,127 @@ fn test_overridden_path() {
         }
     }
 ",
-        Some(7206),
+        Some(25030),
+    );
+}
+
+#[test]
+fn test_redirect_following() {
+    // 307/308 redirects re-send the headers and body (through `modify`) to the same origin,
+    // whereas others (including 303) switch to `GET`.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+        /// Convenience method for returning a raw response after sending a request.
+        ///
+        /// Failed requests are retried based on the client's [`RetryPolicy`](struct.RetryPolicy.html)
+        /// and redirects are followed based on the client's [`RedirectPolicy`](struct.RedirectPolicy.html).
+        ///
+        /// The timeout (if any) applies to each request (including retries and redirects)
+        /// and covers connecting to the server until the response headers are received.
+        /// Reading the body isn't covered, so that streamed responses (i.e., file downloads)
+        /// aren't cut off by the timeout. Use the timeout of the underlying HTTP client
+        /// for limiting the total transfer time.
+        async fn send_raw(&self, client: &Client) -> Result<Client::Response, ApiError<Client::Response>> {
+            if let Some(name) = self.empty_path_param() {
+                return Err(ApiError::EmptyPathParameter(name))
+            }
+
+            let rel_path = self.rel_path();
+            let timeout = self.request_timeout().or_else(|| client.timeout());
+            let retry_policy = client.retry_policy();
+            let mut retries = 0;
+            let mut resp = loop {
+                let req = self.modify(client, client.request_builder(Self::METHOD, &rel_path))?;
+                let result = make_request_within(client, req, timeout).await;
+                match retry_policy.retry_delay(&Self::METHOD, retries, &result) {
+                    Some(delay) => client.sleep(delay).await,
+                    None => break result?,
+                }
+
+                retries += 1;
+            };
+
+            let policy = client.redirect_policy();
+            let (mut method, mut resend) = (Self::METHOD, true);
+            for _ in 0..policy.max_redirects {
+                let (url, same_origin) = match policy.redirect_url(&resp) {
+                    Some(u) => u,
+                    None => break,
+                };
+
+                let preserve = resp.status() != http::StatusCode::SEE_OTHER
+                    && policy.preserve_method.contains(&resp.status());
+                // Headers and body are only re-sent to the same origin.
+                if resend && preserve && !same_origin {
+                    break
+                }
+
+                resend &= preserve;
+                if !resend && method != http::Method::HEAD {
+                    method = http::Method::GET;
+                }
+
+                let mut req = match client.request_builder_for_url(method.clone(), &url) {
+                    Some(req) => req,
+                    None => break,
+                };
+
+                if resend {
+                    req = self.modify(client, req)?;
+                }
+
+                resp = make_request_within(client, req, timeout).await?;
+            }
+",
+        None,
+    );
+
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+    impl Default for RedirectPolicy {
+        fn default() -> Self {
+            RedirectPolicy {
+                max_redirects: 10,
+                preserve_method: vec![
+                    http::StatusCode::TEMPORARY_REDIRECT,
+                    http::StatusCode::PERMANENT_REDIRECT,
+                ],
+            }
+        }
+    }
+",
+        None,
+    );
+
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+            let base = resp.url().and_then(|u| url::Url::parse(u).ok());
+            let url = match base.as_ref() {
+                Some(base) => base.join(location).ok()?,
+                None => url::Url::parse(location).ok()?,
+            };
+
+            let same_origin = base.map(|b| b.origin() == url.origin()).unwrap_or(false);
+            Some((url.as_str().to_owned(), same_origin))
+",
+        None,
+    );
+
+    // Existing clients don't need to support absolute URLs (in which case,
+    // redirects aren't followed).
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+        /// By default, absolute URLs are not supported (i.e., this returns `None`),
+        /// in which case redirects are not followed.
+        fn request_builder_for_url(&self, _method: http::Method, _url: &str) -> Option<Self::Request> {
+            None
+        }
+",
+        None,
     );
 }
 
@@ -189,6 +403,7 @@ impl<XAuth, Id, Name> PetPostBuilder<XAuth, Id, Name> {
     #[inline]
     pub fn x_auth(mut self, value: impl Into<String>) -> PetPostBuilder<crate::generics::XAuthExists, Id, Name> {
         self.inner.param_x_auth = Some(value.into());
+        crate::util::debug_assert_same_layout::<Self, PetPostBuilder<crate::generics::XAuthExists, Id, Name>>();
         unsafe { std::mem::transmute(self) }
     }
 
@@ -198,7 +413,7 @@ impl<XAuth, Id, Name> PetPostBuilder<XAuth, Id, Name> {
         self
     }
 ",
-        Some(4005),
+        Some(6211),
     );
 
     assert_file_contains_content_at(
@@ -213,7 +428,11 @@ impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<
         \"/pets\".into()
     }
 
-    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+    fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        self.modify_with_body(client, req, &self.inner.body)
+    }
+
+    fn modify_with_body<B: serde::Serialize + Sync>(&self, client: &Client, req: Client::Request, body: &B) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
         use crate::client::Request;
         let mut req = req;
         req = req.header(\"X-Auth\", &self.inner.param_x_auth.as_ref().map(std::string::ToString::to_string).expect(\"missing parameter x_auth?\"));
@@ -221,17 +440,17 @@ impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<
             req = req.header(\"X-Pet-ID\", &v);
         }
 
-        Ok(req
-        .header(http::header::CONTENT_TYPE.as_str(), \"application/yaml\")
-        .body_bytes({
+        let req = client.set_body(req, {
             let mut vec = vec![];
-            serde_yaml::to_writer(&mut vec, &self.inner.body)?;
+            serde_yaml::to_writer(&mut vec, body)?;
             vec
-        }))
+        })?;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), \"application/yaml\"))
     }
 }
 ",
-        Some(5492),
+        Some(9374),
     );
 }
 
@@ -257,7 +476,7 @@ impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<
     }
 }
 ",
-        Some(2992),
+        Some(4071),
     );
 }
 
@@ -289,12 +508,16 @@ fn test_anonymous_object_definition_in_schema() {
     assert_file_contains_content_at(
         &(ROOT.clone() + "/tests/test_pet/order.rs"),
         "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
 pub struct Order {
     pub address: Option<crate::order::OrderAddress>,
     pub id: Option<i64>,
     pub list: Option<Vec<crate::order::OrderListItem>>,
+    pub priority: Option<crate::priority::Priority>,
+    pub status: Option<crate::order::OrderStatus>,
 }
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
 pub struct OrderAddress {
     pub code: Option<String>,
     pub line1: Option<String>,
@@ -302,11 +525,27 @@ pub struct OrderAddress {
     pub name: Option<String>,
 }
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
 pub struct OrderListItem {
     #[serde(rename = \"petId\")]
     pub pet_id: Option<i64>,
     pub quantity: Option<i64>,
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum OrderStatus {
+    #[serde(rename = \"placed\")]
+    Placed,
+    #[serde(rename = \"approved\")]
+    Approved,
+    #[serde(rename = \"delivered\")]
+    Delivered,
+}
+
+impl Default for OrderStatus {
+    fn default() -> Self {
+        OrderStatus::Placed
+    }
+}
 
 impl Order {
     /// Create a builder for this object.
@@ -344,10 +583,28 @@ impl OrderBuilder {
     }
 
     #[inline]
-    pub fn list(mut self, value: impl Iterator<Item = crate::order::OrderListItem>) -> Self {
-        self.body.list = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+    pub fn list(mut self, value: impl IntoIterator<Item = crate::order::OrderListItem>) -> Self {
+        self.body.list = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    #[inline]
+    pub fn priority(mut self, value: crate::priority::Priority) -> Self {
+        self.body.priority = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn status(mut self, value: crate::order::OrderStatus) -> Self {
+        self.body.status = Some(value.into());
         self
     }
+
+    /// Returns the object built using this builder.
+    #[inline]
+    pub fn build(self) -> Order {
+        self.body
+    }
 }
 
 impl OrderAddress {
@@ -396,6 +653,12 @@ impl OrderAddressBuilder {
         self.body.name = Some(value.into());
         self
     }
+
+    /// Returns the object built using this builder.
+    #[inline]
+    pub fn build(self) -> OrderAddress {
+        self.body
+    }
 }
 
 impl OrderListItem {
@@ -432,6 +695,12 @@ impl OrderListItemBuilder {
         self.body.quantity = Some(value.into());
         self
     }
+
+    /// Returns the object built using this builder.
+    #[inline]
+    pub fn build(self) -> OrderListItem {
+        self.body
+    }
 }
 ",
         Some(0),
@@ -439,681 +708,4610 @@ impl OrderListItemBuilder {
 }
 
 #[test]
-fn test_anonymous_object_definition_in_body() {
-    let _ = &*CLI_CODEGEN;
+fn test_object_builder_without_operations() {
+    // `Category` isn't bound to any operation, but it still gets a builder.
     assert_file_contains_content_at(
-        &(ROOT.clone() + "/tests/test_pet/post_shipments_body.rs"),
-        "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
-pub struct PostShipmentsBody {
-    pub address: Option<crate::post_shipments_body::PostShipmentsBodyAddress>,
-    #[serde(rename = \"orderId\")]
-    pub order_id: Option<String>,
-}
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
-pub struct PostShipmentsBodyAddress {
-    pub code: Option<String>,
-    pub line1: Option<String>,
-    pub line2: Option<String>,
-    pub name: Option<String>,
-}
-
-impl PostShipmentsBody {
+        &(ROOT.clone() + "/tests/test_pet/category.rs"),
+        "
+impl Category {
     /// Create a builder for this object.
     #[inline]
-    pub fn builder() -> PostShipmentsBodyBuilder {
-        PostShipmentsBodyBuilder {
-            body: Default::default(),
-        }
-    }
-
-    /// Create shipment for order
-    #[deprecated]
-    #[inline]
-    pub fn post() -> PostShipmentsBodyPostBuilder {
-        PostShipmentsBodyPostBuilder {
+    pub fn builder() -> CategoryBuilder {
+        CategoryBuilder {
             body: Default::default(),
         }
     }
 }
+",
+        Some(288),
+    );
 
-impl Into<PostShipmentsBody> for PostShipmentsBodyBuilder {
-    fn into(self) -> PostShipmentsBody {
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/category.rs"),
+        "
+    /// Returns the object built using this builder.
+    #[inline]
+    pub fn build(self) -> Category {
         self.body
     }
 }
+",
+        None,
+    );
 
-impl Into<PostShipmentsBody> for PostShipmentsBodyPostBuilder {
-    fn into(self) -> PostShipmentsBody {
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/pet.rs"),
+        "
+impl PetBuilder<crate::generics::IdExists, crate::generics::NameExists> {
+    /// Returns the object built using this builder.
+    #[inline]
+    pub fn build(self) -> Pet {
         self.body
     }
 }
+",
+        None,
+    );
+}
 
-/// Builder for [`PostShipmentsBody`](./struct.PostShipmentsBody.html) object.
-#[derive(Debug, Clone)]
-pub struct PostShipmentsBodyBuilder {
-    body: self::PostShipmentsBody,
+#[test]
+fn test_nullable_field_as_patch() {
+    // Optional `x-nullable` fields are skipped when missing and serialized as `null` when null.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/category.rs"),
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Category {
+    pub id: Option<i64>,
+    pub name: Option<String>,
+    #[serde(rename = \"parentId\", skip_serializing_if = \"crate::util::Patch::is_missing\")]
+    pub parent_id: crate::util::Patch<i64>,
 }
+",
+        Some(0),
+    );
 
-impl PostShipmentsBodyBuilder {
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/category.rs"),
+        "
     #[inline]
-    pub fn address(mut self, value: crate::post_shipments_body::PostShipmentsBodyAddress) -> Self {
-        self.body.address = Some(value.into());
+    pub fn parent_id(mut self, value: impl Into<i64>) -> Self {
+        self.body.parent_id = crate::util::Patch::Value(value.into());
         self
     }
+",
+        None,
+    );
 
-    #[inline]
-    pub fn order_id(mut self, value: impl Into<String>) -> Self {
-        self.body.order_id = Some(value.into());
-        self
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/util.rs"),
+        "
+impl<T: Serialize> Serialize for Patch<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        match self {
+            Patch::Value(v) => v.serialize(serializer),
+            _ => serializer.serialize_none(),
+        }
     }
 }
-
-/// Builder created by [`PostShipmentsBody::post`](./struct.PostShipmentsBody.html#method.post) method for a `POST` operation associated with `PostShipmentsBody`.
-#[derive(Debug, Clone)]
-pub struct PostShipmentsBodyPostBuilder {
-    body: self::PostShipmentsBody,
+",
+        None,
+    );
 }
 
-impl PostShipmentsBodyPostBuilder {
-    #[inline]
-    pub fn address(mut self, value: crate::post_shipments_body::PostShipmentsBodyAddress) -> Self {
-        self.body.address = Some(value.into());
-        self
-    }
-
-    #[inline]
-    pub fn order_id(mut self, value: impl Into<String>) -> Self {
-        self.body.order_id = Some(value.into());
-        self
-    }
+#[test]
+fn test_integer_enum() {
+    // Integer enums are (de)serialized as their values.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/priority.rs"),
+        "
+/// Priority of an order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum Priority {
+    V1 = 1,
+    V5 = 5,
+    V9 = 9,
 }
 
-impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for PostShipmentsBodyPostBuilder {
-    type Output = serde_yaml::Value;
-
-    const METHOD: http::Method = http::Method::POST;
-
-    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
-        \"/shipments\".into()
+impl Default for Priority {
+    fn default() -> Self {
+        Priority::V1
     }
+}
 
-    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
-        use crate::client::Request;
-        Ok(req
-        .header(http::header::CONTENT_TYPE.as_str(), \"application/yaml\")
-        .body_bytes({
-            let mut vec = vec![];
-            serde_yaml::to_writer(&mut vec, &self.body)?;
-            vec
-        })
-        .header(http::header::ACCEPT.as_str(), \"application/yaml\"))
+impl serde::Serialize for Priority {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(*self as i32)
     }
 }
 
-impl PostShipmentsBodyAddress {
-    /// Create a builder for this object.
-    #[inline]
-    pub fn builder() -> PostShipmentsBodyAddressBuilder {
-        PostShipmentsBodyAddressBuilder {
-            body: Default::default(),
+impl<'de> serde::Deserialize<'de> for Priority {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match <i32 as serde::Deserialize>::deserialize(deserializer)? {
+            1 => Ok(Priority::V1),
+            5 => Ok(Priority::V5),
+            9 => Ok(Priority::V9),
+            v => Err(serde::de::Error::custom(format!(\"unknown variant `{}` for Priority\", v))),
         }
     }
 }
+",
+        Some(0),
+    );
 
-impl Into<PostShipmentsBodyAddress> for PostShipmentsBodyAddressBuilder {
-    fn into(self) -> PostShipmentsBodyAddress {
-        self.body
-    }
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/order.rs"),
+        "    pub priority: Option<crate::priority::Priority>,
+    pub status: Option<crate::order::OrderStatus>,
 }
+",
+        None,
+    );
 
-/// Builder for [`PostShipmentsBodyAddress`](./struct.PostShipmentsBodyAddress.html) object.
-#[derive(Debug, Clone)]
-pub struct PostShipmentsBodyAddressBuilder {
-    body: self::PostShipmentsBodyAddress,
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/order.rs"),
+        "
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum OrderStatus {
+    #[serde(rename = \"placed\")]
+    Placed,
+    #[serde(rename = \"approved\")]
+    Approved,
+    #[serde(rename = \"delivered\")]
+    Delivered,
 }
 
-impl PostShipmentsBodyAddressBuilder {
-    #[inline]
-    pub fn code(mut self, value: impl Into<String>) -> Self {
-        self.body.code = Some(value.into());
-        self
+impl Default for OrderStatus {
+    fn default() -> Self {
+        OrderStatus::Placed
     }
+}
+",
+        None,
+    );
+}
 
-    #[inline]
-    pub fn line1(mut self, value: impl Into<String>) -> Self {
-        self.body.line1 = Some(value.into());
+#[test]
+fn test_decoder_selection_by_content_type() {
+    // Decoder is chosen based on the response's actual media type.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+        match decoder {
+            Some(0) => {
+                let (resp, bytes) = resp.body_bytes().await?;
+                let value = serde_json::from_reader(decompress(&resp, bytes.as_ref())?.as_ref()).map_err(ApiError::from)?;
+                Ok((value, RawResponse { status, body: RawBody::Bytes(bytes) }))
+            },
+            Some(1) => {
+                let (resp, bytes) = resp.body_bytes().await?;
+                let value = serde_yaml::from_reader(decompress(&resp, bytes.as_ref())?.as_ref()).map_err(ApiError::from)?;
+                Ok((value, RawResponse { status, body: RawBody::Bytes(bytes) }))
+            },
+",
+        None,
+    );
+
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+        pub fn decoder_index(ty: &mime::MediaType) -> Option<usize> {
+            if M_0.matches(ty) {
+                return Some(0)
+            }
+            if M_1.matches(ty) {
+                return Some(1)
+            }
+
+            None
+        }
+",
+        None,
+    );
+
+    // Operations with `Any` in response fall back to their preferred decoder.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/post_shipments_body.rs"),
+        "
+    fn fallback_media_range(&self) -> Option<&'static str> {
+        Some(\"application/yaml\")
+    }
+}
+",
+        None,
+    );
+}
+
+#[test]
+fn test_send_with_raw() {
+    // Raw response (status and undecoded bytes) is returned along with the decoded value.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+        async fn send(&self, client: &Client) -> Result<Self::Output, ApiError<Client::Response>> {
+            let (value, _) = self.send_with_raw(client).await?;
+            Ok(value)
+        }
+
+        /// Same as [`send`](#method.send), but this also returns the raw response
+        /// (i.e., status code and undecoded body) along with the response object.
+        /// This is useful for debugging (and for operations with incomplete schema).
+        async fn send_with_raw(&self, client: &Client) -> Result<(Self::Output, RawResponse<Client::Response>), ApiError<Client::Response>> {
+            let resp = self.send_raw(client).await?;
+            self.accept_response(resp).await
+        }
+",
+        None,
+    );
+
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+    impl<R: Response> RawResponse<R> {
+        /// Returns the undecoded bytes of the response body (if it's been buffered).
+        pub fn bytes(&self) -> Option<&[u8]> {
+            match &self.body {
+                RawBody::Bytes(b) => Some(b.as_ref()),
+                RawBody::Streamed | RawBody::Empty => None,
+            }
+        }
+    }
+",
+        None,
+    );
+}
+
+#[test]
+fn test_send_error() {
+    // Errors are classified based on where the call has failed.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+    pub enum SendError<R: Debug + Send + 'static> {
+        /// The request couldn't be sent or the response couldn't be received.
+        Transport(ApiError<R>),
+        /// The server has responded with an unsuccessful status code.
+        Api {
+            status: http::status::StatusCode,
+            body: Vec<u8>,
+        },
+        /// The successful response couldn't be decoded.
+        Decode(ApiError<R>),
+        /// The request couldn't be built from the given values (i.e., encoding failed).
+        Validation(ApiError<R>),
+    }
+
+    impl<R: Response + 'static> SendError<R> {
+        /// Classifies the error obtained while sending a request. Unsuccessful
+        /// responses are buffered, so that their bodies can be inspected.
+        async fn from_send(err: ApiError<R>) -> Self {
+            match err {
+                ApiError::Failure(_, status, resp) => match resp.into_inner().body_bytes().await {
+                    Ok((_, body)) => SendError::Api { status, body: body.as_ref().to_vec() },
+                    Err(e) => SendError::Transport(e),
+                },
+                ApiError::Reqwest(_) | ApiError::Io(_) | ApiError::Timeout(_) => SendError::Transport(err),
+                // Coders are only used for encoding the request body at this point.
+                _ => SendError::Validation(err),
+            }
+        }
+
+        /// Classifies the error obtained while accepting a successful response.
+        fn from_accept(err: ApiError<R>) -> Self {
+            match err {
+                ApiError::Reqwest(_) | ApiError::Timeout(_) => SendError::Transport(err),
+                _ => SendError::Decode(err),
+            }
+        }
+    }
+",
+        None,
+    );
+
+    // `SendError` is a standard error (so that it composes with `?` in other error
+    // types), which chains to the underlying transport/decoding errors.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+    impl<R: Debug + Send + 'static> std::fmt::Display for SendError<R> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            match self {
+                SendError::Transport(e) => write!(f, \"Error sending request: {}\", e),
+                SendError::Api { status, body } => {
+                    write!(f, \"API request failed (code: {})\", status)?;
+                    // Only the beginning of the body is shown, since it could be large.
+                    let body = String::from_utf8_lossy(body);
+                    let body = body.trim();
+                    if !body.is_empty() {
+                        let summary = body.chars().take(100).collect::<String>();
+                        write!(f, \": {}\", summary)?;
+                        if summary.len() < body.len() {
+                            f.write_str(\"...\")?;
+                        }
+                    }
+
+                    Ok(())
+                },
+                SendError::Decode(e) => write!(f, \"Error decoding response: {}\", e),
+                SendError::Validation(e) => write!(f, \"Invalid request: {}\", e),
+            }
+        }
+    }
+
+    impl<R: Debug + Send + 'static> std::error::Error for SendError<R> {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                SendError::Transport(e) | SendError::Decode(e) | SendError::Validation(e) => e.source_error(),
+                SendError::Api { .. } => None,
+            }
+        }
+    }
+",
+        None,
+    );
+
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+    impl<R: Debug + Send + 'static> ApiError<R> {
+        /// Returns the underlying error (if any) for chaining through `std::error::Error::source`.
+        fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                ApiError::Reqwest(e) => Some(e),
+                ApiError::Io(e) => Some(e),
+                ApiError::Validation(e) => Some(e),
+                ApiError::ApplicationJson(e) => Some(e),
+                ApiError::ApplicationYaml(e) => Some(e),
+                _ => None,
+            }
+        }
+    }
+",
+        None,
+    );
+
+    // Successful responses which can't be decoded are `Decode` errors.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+        async fn try_send(&self, client: &Client) -> Result<Self::Output, SendError<Client::Response>> {
+            let resp = match self.send_raw(client).await {
+                Ok(r) => r,
+                Err(e) => return Err(SendError::from_send(e).await),
+            };
+
+            let (value, _) = self.accept_response(resp).await.map_err(SendError::from_accept)?;
+            Ok(value)
+        }
+",
+        None,
+    );
+}
+
+#[test]
+fn test_retry_policy() {
+    // Retries are disabled by default, and the delay is obtained from the
+    // `Retry-After` header or the exponential backoff.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+        /// Policy for retrying failed API calls. By default, requests are not retried.
+        fn retry_policy(&self) -> RetryPolicy {
+            RetryPolicy::none()
+        }
+
+        /// Default timeout for API calls (if any). This can be overridden
+        /// for individual API calls using [`Timeout`](struct.Timeout.html).
+        ///
+        /// See [`Sendable::send_raw`](trait.Sendable.html#method.send_raw) for
+        /// what's covered by the timeout.
+        fn timeout(&self) -> Option<Duration> {
+            None
+        }
+
+        /// Waits for the given duration (for backing off before retrying requests
+        /// and for timing out requests).
+        async fn sleep(&self, duration: Duration) {
+            let _ = futures_timer::Delay::new(duration).await;
+        }
+",
+        None,
+    );
+
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+            let retry_after = match result {
+                Ok(resp) if self.statuses.contains(&resp.status()) => resp
+                    .header(http::header::RETRY_AFTER.as_str())
+                    .and_then(|v| v.trim().parse().ok())
+                    .map(Duration::from_secs),
+                Err(ApiError::Reqwest(_))
+                | Err(ApiError::Io(_))
+                | Err(ApiError::Timeout(_)) if self.retry_errors => None,
+                _ => return None,
+            };
+",
+        None,
+    );
+    // Server-provided delays (say, `Retry-After: 3600`) are also capped at `max_backoff`.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+            let delay = retry_after.unwrap_or_else(|| {
+                let factor = 1u32.checked_shl(retries as u32).unwrap_or(u32::max_value());
+                self.initial_backoff.checked_mul(factor).unwrap_or(self.max_backoff)
+            });
+
+            Some(delay.min(self.max_backoff))
+        }
+",
+        None,
+    );
+
+    // Only idempotent methods are retried by default.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+                methods: vec![
+                    http::Method::GET,
+                    http::Method::HEAD,
+                    http::Method::PUT,
+                    http::Method::DELETE,
+                    http::Method::OPTIONS,
+                ],
+",
+        None,
+    );
+}
+
+#[test]
+fn test_timeouts() {
+    // Client-level timeout can be overridden by wrapping complete builders.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/pet.rs"),
+        "
+impl PetGetBuilder {
+    /// Overrides the client's timeout for this API call.
+    #[inline]
+    pub fn timeout(self, timeout: std::time::Duration) -> crate::client::Timeout<Self> {
+        crate::client::Timeout::new(self, timeout)
+    }
+
+    /// Makes this a conditional API call using the `ETag` from an earlier response.
+    #[inline]
+    pub fn if_none_match(self, etag: impl Into<String>) -> crate::client::IfNoneMatch<Self> {
+        crate::client::IfNoneMatch::new(self, etag)
+    }
+
+    /// Adds a header (which isn't declared in the spec) to this API call.
+    #[inline]
+    pub fn header(self, name: impl AsRef<str>, value: impl AsRef<str>) -> crate::client::ExtraHeaders<Self> {
+        crate::client::ExtraHeaders::new(self).header(name, value)
+    }
+
+    /// Returns the request for this API call without sending it (i.e., without any network I/O).
+    #[inline]
+    pub fn dry_run(&self) -> Result<crate::client::DryRunRequest, crate::client::ApiError<reqwest::r#async::Response>>
+    where
+        Self: crate::client::Sendable<crate::client::DryRunClient>,
+    {
+        crate::client::dry_run(self)
+    }
+}
+",
+        None,
+    );
+
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+        let timeout = match timeout {
+            Some(t) => t,
+            None => return client.make_request(req).await,
+        };
+
+        match futures_preview::future::select(client.make_request(req), client.sleep(timeout)).await {
+            futures_preview::future::Either::Left((result, _)) => result,
+            futures_preview::future::Either::Right(_) => Err(ApiError::Timeout(timeout)),
+        }
+",
+        None,
+    );
+
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+        fn request_timeout(&self) -> Option<Duration> {
+            None
+        }
+
+        /// Sends the request and returns a future for the response object.
+        ///
+        /// The decoder is chosen based on the response's `Content-Type`.
+        async fn send(&self, client: &Client) -> Result<Self::Output, ApiError<Client::Response>> {
+            let (value, _) = self.send_with_raw(client).await?;
+            Ok(value)
+        }
+
+        /// Same as [`send`](#method.send), but this also returns the raw response
+        /// (i.e., status code and undecoded body) along with the response object.
+        /// This is useful for debugging (and for operations with incomplete schema).
+        async fn send_with_raw(&self, client: &Client) -> Result<(Self::Output, RawResponse<Client::Response>), ApiError<Client::Response>> {
+            let resp = self.send_raw(client).await?;
+            self.accept_response(resp).await
+        }
+
+        /// Same as [`send`](#method.send), but the errors are classified (as [`SendError`](enum.SendError.html))
+        /// based on where the call has failed, so that they can be matched upon.
+        ///
+        /// **NOTE:** Successful responses which couldn't be decoded are `Decode` errors
+        /// and not `Api` errors.
+        async fn try_send(&self, client: &Client) -> Result<Self::Output, SendError<Client::Response>> {
+            let resp = match self.send_raw(client).await {
+                Ok(r) => r,
+                Err(e) => return Err(SendError::from_send(e).await),
+            };
+
+            let (value, _) = self.accept_response(resp).await.map_err(SendError::from_accept)?;
+            Ok(value)
+        }
+
+        /// Obtains the response object from a successful response. By default, this
+        /// uses the [`FromResponse`](trait.FromResponse.html) impl of the output object,
+        /// but builders override this method for operations whose responses shouldn't
+        /// be decoded (i.e., file downloads, `HEAD` requests and responses without schema).
+        async fn accept_response(&self, resp: Client::Response) -> Result<(Self::Output, RawResponse<Client::Response>), ApiError<Client::Response>> {
+            <Self::Output as FromResponse>::from_response(resp, self.fallback_media_range()).await
+        }
+
+        /// Convenience method for returning a raw response after sending a request.
+        ///
+        /// Failed requests are retried based on the client's [`RetryPolicy`](struct.RetryPolicy.html)
+        /// and redirects are followed based on the client's [`RedirectPolicy`](struct.RedirectPolicy.html).
+        ///
+        /// The timeout (if any) applies to each request (including retries and redirects)
+        /// and covers connecting to the server until the response headers are received.
+        /// Reading the body isn't covered, so that streamed responses (i.e., file downloads)
+        /// aren't cut off by the timeout. Use the timeout of the underlying HTTP client
+        /// for limiting the total transfer time.
+        async fn send_raw(&self, client: &Client) -> Result<Client::Response, ApiError<Client::Response>> {
+            if let Some(name) = self.empty_path_param() {
+                return Err(ApiError::EmptyPathParameter(name))
+            }
+
+            let rel_path = self.rel_path();
+            let timeout = self.request_timeout().or_else(|| client.timeout());
+            let retry_policy = client.retry_policy();
+            let mut retries = 0;
+            let mut resp = loop {
+                let req = self.modify(client, client.request_builder(Self::METHOD, &rel_path))?;
+                let result = make_request_within(client, req, timeout).await;
+                match retry_policy.retry_delay(&Self::METHOD, retries, &result) {
+                    Some(delay) => client.sleep(delay).await,
+                    None => break result?,
+                }
+
+                retries += 1;
+            };
+
+            let policy = client.redirect_policy();
+            let (mut method, mut resend) = (Self::METHOD, true);
+            for _ in 0..policy.max_redirects {
+                let (url, same_origin) = match policy.redirect_url(&resp) {
+                    Some(u) => u,
+                    None => break,
+                };
+
+                let preserve = resp.status() != http::StatusCode::SEE_OTHER
+                    && policy.preserve_method.contains(&resp.status());
+                // Headers and body are only re-sent to the same origin.
+                if resend && preserve && !same_origin {
+                    break
+                }
+
+                resend &= preserve;
+                if !resend && method != http::Method::HEAD {
+                    method = http::Method::GET;
+                }
+
+                let mut req = match client.request_builder_for_url(method.clone(), &url) {
+                    Some(req) => req,
+                    None => break,
+                };
+
+                if resend {
+                    req = self.modify(client, req)?;
+                }
+
+                resp = make_request_within(client, req, timeout).await?;
+            }
+
+            if resp.status().is_success() {
+                Ok(resp)
+            } else {
+                Err(ApiError::Failure(rel_path.into_owned(), resp.status(), Mutex::new(resp)))
+            }
+        }
+    }
+
+    /// Decompresses the response body based on its `Content-Encoding` (if the
+    /// `gzip` feature is enabled). Bodies with other (or missing) encodings are
+    /// returned as they are, since servers may choose not to compress responses.
+    ///
+    /// **NOTE:** HTTP clients (like `reqwest`) may decompress the body by themselves,
+    /// in which case, `Content-Encoding` is usually removed from the response.
+    #[cfg(feature = \"gzip\")]
+    fn decompress<'a, R: Response>(resp: &R, bytes: &'a [u8]) -> std::io::Result<Cow<'a, [u8]>> {
+        use std::io::Read;
+
+        let encoding = resp.header(http::header::CONTENT_ENCODING.as_str())
+            .map(|e| e.trim().to_ascii_lowercase());
+        let mut buf = vec![];
+        match encoding.as_ref().map(String::as_str) {
+            Some(\"gzip\") | Some(\"x-gzip\") => flate2::read::GzDecoder::new(bytes).read_to_end(&mut buf)?,
+            Some(\"deflate\") => flate2::read::ZlibDecoder::new(bytes).read_to_end(&mut buf)?,
+            _ => return Ok(Cow::Borrowed(bytes)),
+        };
+
+        Ok(Cow::Owned(buf))
+    }
+
+    #[cfg(not(feature = \"gzip\"))]
+    fn decompress<'a, R: Response>(_resp: &R, bytes: &'a [u8]) -> std::io::Result<Cow<'a, [u8]>> {
+        Ok(Cow::Borrowed(bytes))
+    }
+
+    /// Objects obtained from successful responses of API calls.
+    #[async_trait::async_trait]
+    pub trait FromResponse: Sized {
+        /// Obtains this object from the given response. The decoder for the given fallback
+        /// media range (if any) is used when the response's media type doesn't match any
+        /// of the known decoders (or when it's missing).
+        async fn from_response<R: Response + 'static>(resp: R, fallback_range: Option<&'static str>) -> Result<(Self, RawResponse<R>), ApiError<R>>;
+    }
+
+    /// Deserializable objects are decoded from the response body (see [`decode_response`](fn.decode_response.html)).
+    #[async_trait::async_trait]
+    impl<T: serde::de::DeserializeOwned> FromResponse for T {
+        async fn from_response<R: Response + 'static>(resp: R, fallback_range: Option<&'static str>) -> Result<(Self, RawResponse<R>), ApiError<R>> {
+            decode_response(resp, fallback_range).await
+        }
+    }
+
+    /// Decodes the body of the given response based on its `Content-Type`. The decoder
+    /// for the given fallback media range (if any) is used when the response's media type
+    /// doesn't match any of the known decoders (or when it's missing).
+    ///
+    /// Empty responses (`204 No Content` or `Content-Length: 0`) are rejected.
+    pub async fn decode_response<R, T>(resp: R, fallback_range: Option<&'static str>) -> Result<(T, RawResponse<R>), ApiError<R>>
+    where
+        R: Response + 'static,
+        T: serde::de::DeserializeOwned,
+    {
+        let status = resp.status();
+        if status == http::StatusCode::NO_CONTENT
+            || resp.header(http::header::CONTENT_LENGTH.as_str()).map(|v| v.trim() == \"0\").unwrap_or(false)
+        {
+            return Err(ApiError::EmptyResponse(status, Mutex::new(resp)));
+        }
+
+        let decoder = resp.media_type()
+            .and_then(|ty| media_types::decoder_index(&ty))
+            .or_else(|| fallback_range
+                .and_then(|r| r.parse().ok())
+                .and_then(|ty| media_types::decoder_index(&ty)));
+
+        match decoder {
+            Some(0) => {
+                let (resp, bytes) = resp.body_bytes().await?;
+                let value = serde_json::from_reader(decompress(&resp, bytes.as_ref())?.as_ref()).map_err(ApiError::from)?;
+                Ok((value, RawResponse { status, body: RawBody::Bytes(bytes) }))
+            },
+            Some(1) => {
+                let (resp, bytes) = resp.body_bytes().await?;
+                let value = serde_yaml::from_reader(decompress(&resp, bytes.as_ref())?.as_ref()).map_err(ApiError::from)?;
+                Ok((value, RawResponse { status, body: RawBody::Bytes(bytes) }))
+            },
+            _ => {
+                let ty = resp.header(http::header::CONTENT_TYPE.as_str())
+                    .map(|v| String::from_utf8_lossy(v.as_bytes()).into_owned())
+                    .unwrap_or_default();
+                Err(ApiError::UnsupportedMediaType(ty, Mutex::new(resp)))
+            },
+        }
+    }
+
+    /// Makes the request using the given client and fails with `ApiError::Timeout`
+    /// if the response isn't received within the given timeout (if any).
+    async fn make_request_within<Client>(client: &Client, req: Client::Request, timeout: Option<Duration>) -> Result<Client::Response, ApiError<Client::Response>>
+    where
+        Client: ApiClient + Sync + 'static,
+    {
+        let timeout = match timeout {
+            Some(t) => t,
+            None => return client.make_request(req).await,
+        };
+
+        match futures_preview::future::select(client.make_request(req), client.sleep(timeout)).await {
+            futures_preview::future::Either::Left((result, _)) => result,
+            futures_preview::future::Either::Right(_) => Err(ApiError::Timeout(timeout)),
+        }
+    }
+
+    /// Wrapper for overriding the client's [`timeout`](trait.ApiClient.html#method.timeout)
+    /// for a single API call. Builders for operations have a `timeout` method for
+    /// wrapping themselves once all the required fields have been set.
+    #[derive(Debug, Clone)]
+    pub struct Timeout<S> {
+        inner: S,
+        timeout: Duration,
+    }
+
+    impl<S> Timeout<S> {
+        /// Wraps the given sendable object with the given timeout.
+        pub fn new(inner: S, timeout: Duration) -> Self {
+            Timeout { inner, timeout }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<Client, S> Sendable<Client> for Timeout<S>
+    where
+        Client: ApiClient + Sync + 'static,
+        S: Sendable<Client> + Sync,
+    {
+        type Output = S::Output;
+
+        const METHOD: http::Method = S::METHOD;
+
+        fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+            self.inner.rel_path()
+        }
+
+        fn empty_path_param(&self) -> Option<&'static str> {
+            self.inner.empty_path_param()
+        }
+
+        fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, ApiError<Client::Response>> {
+            self.inner.modify(client, req)
+        }
+
+        fn fallback_media_range(&self) -> Option<&'static str> {
+            self.inner.fallback_media_range()
+        }
+
+        fn request_timeout(&self) -> Option<Duration> {
+            Some(self.timeout)
+        }
+
+        async fn accept_response(&self, resp: Client::Response) -> Result<(Self::Output, RawResponse<Client::Response>), ApiError<Client::Response>> {
+            self.inner.accept_response(resp).await
+        }
+",
+        None,
+    );
+}
+
+#[test]
+fn test_conditional_requests() {
+    // `304 Not Modified` responses aren't decoded and they're returned
+    // as `NotModified` along with the prior `ETag`.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+        pub async fn send_if_modified<Client>(&self, client: &Client) -> Result<Conditional<S::Output>, ApiError<Client::Response>>
+        where
+            Client: ApiClient + Sync + 'static,
+            S: Sendable<Client> + Sync,
+        {
+            match self.send_raw(client).await {
+                Ok(resp) => {
+                    let etag = resp.header(http::header::ETAG.as_str()).map(String::from);
+                    let (value, _) = self.accept_response(resp).await?;
+                    Ok(Conditional::Modified { value, etag })
+                },
+                Err(ApiError::Failure(_, status, _)) if status == http::StatusCode::NOT_MODIFIED => {
+                    Ok(Conditional::NotModified { etag: self.etag.clone() })
+                },
+                Err(e) => Err(e),
+            }
+        }
+",
+        None,
+    );
+
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+        fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, ApiError<Client::Response>> {
+            let req = self.inner.modify(client, req)?;
+            Ok(req.header(http::header::IF_NONE_MATCH.as_str(), &self.etag))
+        }
+",
+        None,
+    );
+}
+
+#[test]
+fn test_gzip_compression() {
+    // Encoded bodies are set through the client (which compresses them if needed).
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+        fn set_body(&self, req: Self::Request, body: Vec<u8>) -> Result<Self::Request, ApiError<Self::Response>> {
+            #[cfg(feature = \"gzip\")]
+            {
+                if self.gzip_requests() {
+                    use std::io::Write;
+
+                    let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+                    encoder.write_all(&body).map_err(ApiError::Io)?;
+                    return Ok(req
+                        .header(http::header::CONTENT_ENCODING.as_str(), \"gzip\")
+                        .body_bytes(encoder.finish().map_err(ApiError::Io)?))
+                }
+            }
+
+            Ok(req.body_bytes(body))
+        }
+",
+        None,
+    );
+
+    // Responses are decompressed only if they have the appropriate encoding.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+        match encoding.as_ref().map(String::as_str) {
+            Some(\"gzip\") | Some(\"x-gzip\") => flate2::read::GzDecoder::new(bytes).read_to_end(&mut buf)?,
+            Some(\"deflate\") => flate2::read::ZlibDecoder::new(bytes).read_to_end(&mut buf)?,
+            _ => return Ok(Cow::Borrowed(bytes)),
+        };
+",
+        None,
+    );
+
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+    #[cfg(not(feature = \"gzip\"))]
+    fn decompress<'a, R: Response>(_resp: &R, bytes: &'a [u8]) -> std::io::Result<Cow<'a, [u8]>> {
+        Ok(Cow::Borrowed(bytes))
+    }
+",
+        None,
+    );
+}
+
+#[test]
+fn test_client_describe() {
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+    /// Title of the API spec used for generating this client.
+    pub const SPEC_TITLE: &str = \"Petstore\";
+    /// Version of the API spec used for generating this client.
+    pub const SPEC_VERSION: &str = \"1.0.0\";
+    /// Default base URL for API calls.
+    pub const BASE_URL: &str = \"https://pets.com:8888/api\";
+    /// Number of operations supported by this client.
+    pub const OPERATION_COUNT: usize = 10;
+",
+        None,
+    );
+
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+        /// Returns the metadata describing this client's configuration.
+        /// This is useful for debugging and health checks.
+        fn describe(&self) -> ClientDescription {
+            ClientDescription {
+                title: SPEC_TITLE,
+                version: SPEC_VERSION,
+                base_url: self.base_url().into_owned(),
+                auth_scheme: self.auth_scheme().map(Cow::into_owned),
+                operation_count: OPERATION_COUNT,
+            }
+        }
+",
+        None,
+    );
+
+    // CLI reports the base URL it's been configured with.
+    let _ = &*CLI_CODEGEN;
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/cli/main.rs"),
+        "
+    fn base_url(&self) -> Cow<'_, str> {
+        self.url.as_str().into()
+    }
+",
+        None,
+    );
+}
+
+#[test]
+fn test_keep_field_names() {
+    let mut state = EmitterState::default();
+    state.keep_field_names = true;
+    let contents = generate_fixture("keep-field-names", state).read("user.rs");
+
+    // Valid identifiers are kept as they are, whereas others are converted.
+    assert!(contents.starts_with(
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct User {
+    #[serde(rename = \"account-id\")]
+    pub account_id: Option<String>,
+    #[serde(rename = \"type\")]
+    pub type_: Option<String>,
+    #[allow(non_snake_case)]
+    pub userId: Option<i64>,
+}
+"
+    ));
+
+    assert!(contents.contains(
+        "
+    #[inline]
+    pub fn user_id(mut self, value: impl Into<i64>) -> Self {
+        self.body.userId = Some(value.into());
+        self
+    }
+"
+    ));
+}
+
+#[test]
+fn test_additional_derives() {
+    let mut state = EmitterState::default();
+    state.derives = vec!["PartialEq".into(), "Eq".into(), "Hash".into()];
+    let generated = generate_fixture("additional-derives", state);
+
+    // Objects with floats (directly or through their fields) only get `PartialEq`.
+    assert!(generated.read("point.rs").starts_with(
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct Point {"
+    ));
+    assert!(generated.read("shape.rs").starts_with(
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct Shape {"
+    ));
+    // Others get everything.
+    assert!(generated.read("label.rs").starts_with(
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[serde(default)]
+pub struct Label {"
+    ));
+}
+
+#[test]
+fn test_parameter_styles() {
+    let contents = generate_fixture("parameter-styles", EmitterState::default()).read("item.rs");
+    // `collectionFormat` takes precedence, and exploded values are always multiple instances.
+    assert!(contents.contains(
+        "
+pub struct ItemGetBuilder {
+    param_form: Option<crate::util::Delimited<String, crate::util::Csv>>,
+    param_form_exploded: Option<crate::util::Delimited<String, crate::util::Multi>>,
+    param_spaces: Option<crate::util::Delimited<String, crate::util::Ssv>>,
+    param_spaces_exploded: Option<crate::util::Delimited<String, crate::util::Multi>>,
+    param_pipes: Option<crate::util::Delimited<String, crate::util::Pipes>>,
+    param_tabs: Option<crate::util::Delimited<String, crate::util::Tsv>>,
+}
+"
+    ));
+
+    // Delimited values are encoded separately (so that delimiters are left as they are).
+    assert!(contents.contains(
+        "
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        let mut path = String::from(\"/items\");
+        crate::util::append_query(&mut path, &[
+            (\"form\", self.param_form.as_ref().map(|v| v.encode_with(crate::util::encode_query_value))),
+            (\"spaces\", self.param_spaces.as_ref().map(|v| v.encode_with(crate::util::encode_query_value))),
+            (\"pipes\", self.param_pipes.as_ref().map(|v| v.encode_with(crate::util::encode_query_value))),
+            (\"tabs\", self.param_tabs.as_ref().map(|v| v.encode_with(crate::util::encode_query_value))),
+        ]);
+        path.into()
+    }
+"
+    ));
+
+    assert!(contents.contains(
+        "
+        Ok(req
+        .query({
+            &self.param_form_exploded.as_ref().map(|v| {
+                v.iter().map(|v| (\"formExploded\", v.to_string())).collect::<Vec<_>>()
+            }).unwrap_or_default()
+        })
+        .query({
+            &self.param_spaces_exploded.as_ref().map(|v| {
+                v.iter().map(|v| (\"spacesExploded\", v.to_string())).collect::<Vec<_>>()
+            }).unwrap_or_default()
+        }))
+"
+    ));
+}
+
+#[test]
+fn test_server_variables() {
+    let contents = generate_fixture("server-variables", EmitterState::default()).read("mod.rs");
+    // First server (with default values) is used as the base URL.
+    assert!(contents.contains(
+        "
+    pub const BASE_URL: &str = \"https://us.api.example.com/v1\";
+"
+    ));
+
+    // Relative server URLs are resolved against the host.
+    assert!(contents.contains(
+        "
+    pub const SERVERS: &[ServerTemplate] = &[
+        ServerTemplate {
+            url: \"https://{region}.api.example.com/{basePath}\",
+            description: Some(\"Regional server\"),
+            variables: &[
+                ServerVariable {
+                    name: \"basePath\",
+                    default: \"v1\",
+                    allowed: &[],
+                },
+                ServerVariable {
+                    name: \"region\",
+                    default: \"us\",
+                    allowed: &[\"us\", \"eu\"],
+                },
+            ],
+        },
+        ServerTemplate {
+            url: \"https://example.com/v2\",
+            description: None,
+            variables: &[],
+        },
+    ];
+"
+    ));
+
+    // Values outside the enum are rejected.
+    assert!(contents.contains(
+        "
+        pub fn set<V: Into<String>>(mut self, name: &str, value: V) -> Result<Self, ServerConfigError> {
+            let var = self.template.variables.iter().find(|v| v.name == name)
+                .ok_or_else(|| ServerConfigError::UnknownVariable(name.into()))?;
+            let value = value.into();
+            if !var.allowed.is_empty() && !var.allowed.contains(&value.as_str()) {
+                return Err(ServerConfigError::InvalidValue(name.into(), value, var.allowed));
+            }
+
+            self.values.insert(var.name, value);
+            Ok(self)
+        }
+
+        /// Returns the effective base URL with the values of variables substituted.
+        pub fn url(&self) -> String {
+            self.template.variables.iter().fold(String::from(self.template.url), |url, var| {
+                let value = self.values.get(var.name).map(String::as_str).unwrap_or(var.default);
+                url.replace(&(String::from(\"{\") + var.name + \"}\"), value)
+            })
+        }
+"
+    ));
+
+    // Server client uses the effective URL for requests.
+    assert!(contents.contains(
+        "
+        fn request_builder(&self, method: http::Method, rel_path: &str) -> Self::Request {
+            let mut u = String::from(self.base_url.trim_end_matches('/'));
+            let base_len = u.len();
+            u.push('/');
+            u.push_str(rel_path.trim_start_matches('/'));
+            // Clients which don't support absolute URLs use their own base URL.
+            match self.inner.request_builder_for_url(method.clone(), &u) {
+                Some(req) => req,
+                None => self.inner.request_builder(method, &u[base_len..]),
+            }
+        }
+"
+    ));
+    // ... and the inner client's body handling (compression, for instance).
+    assert!(contents.contains(
+        "
+        async fn sleep(&self, duration: Duration) {
+            self.inner.sleep(duration).await
+        }
+
+        fn set_body(&self, req: Self::Request, body: Vec<u8>) -> Result<Self::Request, ApiError<Self::Response>> {
+            self.inner.set_body(req, body)
+        }
+
+        fn base_url(&self) -> Cow<'_, str> {
+            (*self.base_url).into()
+        }
+"
+    ));
+}
+
+#[test]
+fn test_preserved_order() {
+    let mut state = EmitterState::default();
+    state.preserve_order = true;
+    let contents = generate_fixture("preserved-order", state).read("pet.rs");
+
+    // Properties and operations are in the order in which they've been specified.
+    let name = contents
+        .find("pub name: Option<String>,")
+        .expect("name field");
+    let age = contents.find("pub age: Option<i64>,").expect("age field");
+    assert!(name < age);
+
+    let delete = contents.find("pub fn delete_pet()").expect("delete method");
+    let get = contents.find("pub fn get_pet()").expect("get method");
+    assert!(delete < get);
+}
+
+#[test]
+fn test_custom_serde_attrs() {
+    let generated = generate_fixture("custom-serde-attrs", EmitterState::default());
+
+    let contents = generated.read("event.rs");
+
+    assert!(contents.contains(
+        "
+    #[serde(with = \"my_mod\")]
+    pub created: Option<String>,"
+    ));
+    // Custom attributes override the generated ones.
+    assert!(contents.contains(
+        "
+    #[serde(rename = \"kind\", default)]
+    pub event_type: Option<String>,"
+    ));
+}
+
+#[test]
+fn test_into_iterator_setters() {
+    let contents =
+        generate_fixture("into-iterator-setters", EmitterState::default()).read("pet.rs");
+
+    // Setters for collections accept anything that can be iterated
+    // (so that we can pass arrays like `[1, 2, 3]`).
+    assert!(contents.contains(
+        "
+    #[inline]
+    pub fn ids(mut self, value: impl IntoIterator<Item = impl Into<i32>>) -> Self {
+        self.param_ids = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }"
+    ));
+    assert!(contents.contains(
+        "
+    #[inline]
+    pub fn tags(mut self, value: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.body.tags = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }"
+    ));
+}
+
+#[test]
+fn test_xml_coder() {
+    let mut state = EmitterState::default();
+    let mut meta = CrateMeta::default();
+    meta.authors = Some(vec!["Me <me@example.com>".into()]);
+    meta.mode = EmitMode::Crate;
+    state.set_meta(meta);
+    let generated = generate_fixture("xml-coder", state);
+
+    // Root element is renamed and fields have aliases for decoding XML names
+    // (unless an attribute collides with an element).
+    let contents = generated.read("pet.rs");
+    assert!(contents.contains(
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(rename = \"pet\", default)]
+pub struct Pet {
+    pub id: Option<i64>,
+    pub label: Option<String>,
+    #[serde(rename = \"petName\", alias = \"name\")]
+    pub pet_name: Option<String>,
+}"
+    ));
+    assert!(contents.contains(
+        "
+        let req = client.set_body(req, {
+            let mut vec = vec![];
+            serde_xml_rs::to_writer(&mut vec, body)?;
+            vec
+        })?;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), \"application/xml\"))"
+    ));
+
+    // Responses are decoded based on content type.
+    let contents = generated.read("lib.rs");
+    assert!(contents.contains(
+        "
+        #[fail(display = \"Error en/decoding \\\"application/xml\\\" data: {}\", _0)]
+        ApplicationXml(serde_xml_rs::Error),"
+    ));
+    assert!(contents.contains(
+        "
+            Some(1) => {
+                let (resp, bytes) = resp.body_bytes().await?;
+                let value = serde_xml_rs::from_reader(decompress(&resp, bytes.as_ref())?.as_ref()).map_err(ApiError::from)?;"
+    ));
+
+    // Errors of `serde-xml-rs` (before 0.4) aren't `Sync`, which is required for `ApiError`.
+    assert!(generated
+        .read("Cargo.toml")
+        .contains("serde-xml-rs = \"0.4\""));
+}
+
+#[test]
+fn test_operation_level_coders() {
+    let contents =
+        generate_fixture("operation-level-coders", EmitterState::default()).read("pet.rs");
+
+    // Operation-level `consumes` overrides the global one (first supported coder wins) ...
+    assert!(contents.contains(
+        "
+    fn modify_with_body<B: serde::Serialize + Sync>(&self, client: &Client, req: Client::Request, body: &B) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        let req = client.set_body(req, {
+            let mut vec = vec![];
+            serde_xml_rs::to_writer(&mut vec, body)?;
+            vec
+        })?;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), \"application/xml\"))
+    }
+"
+    ));
+    // ... the rest are recorded for overriding the content type ...
+    assert!(contents.contains(
+        "
+    /// Encodes the body in the given content type (one of `application/xml`, `application/yaml`) for this API call.
+    #[inline]
+    pub fn content_type(self, content_type: impl Into<String>) -> crate::client::ContentType<Self> {"
+    ));
+    // ... and operations without them fall back to the global set.
+    assert!(contents.contains(
+        "
+    fn modify_with_body<B: serde::Serialize + Sync>(&self, _client: &Client, req: Client::Request, body: &B) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .json(body))
+    }
+"
+    ));
+}
+
+#[test]
+fn test_less_common_methods() {
+    let contents = generate_fixture("less-common-methods", EmitterState::default()).read("pet.rs");
+    for &(name, builder) in &[
+        ("options", "PetOptionsBuilder"),
+        ("head", "PetHeadBuilder"),
+        ("patch", "PetPatchBuilder"),
+    ] {
+        assert!(contents.contains(&format!(
+            "
+    pub fn {}() -> {}<crate::generics::MissingId> {{",
+            name, builder
+        )));
+    }
+
+    assert!(contents.contains(
+        "
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for PetPatchBuilder<crate::generics::IdExists> {
+    type Output = crate::pet::Pet;
+
+    const METHOD: http::Method = http::Method::PATCH;
+"
+    ));
+
+    // `HEAD` operations don't decode the response (even if there's a schema).
+    assert!(contents.contains(
+        "
+#[async_trait::async_trait]
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for PetHeadBuilder<crate::generics::IdExists> {
+    type Output = ();
+
+    const METHOD: http::Method = http::Method::HEAD;
+
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        format!(\"/pets/{id}\", id=crate::util::encode_path_param(&self.inner.param_id.as_ref().expect(\"missing parameter id?\").to_string())).into()
+    }
+
+    fn empty_path_param(&self) -> Option<&'static str> {
+        if self.inner.param_id.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {
+            return Some(\"id\");
+        }
+
+        None
+    }
+
+    async fn accept_response(&self, resp: Client::Response) -> Result<(Self::Output, crate::client::RawResponse<Client::Response>), crate::client::ApiError<Client::Response>> {
+        use crate::client::Response;
+
+        let status = resp.status();
+        Ok(((), crate::client::RawResponse { status, body: crate::client::RawBody::Empty }))
+    }
+}
+"
+    ));
+}
+
+#[test]
+fn test_flattened_additional_properties() {
+    let generated = generate_fixture("flattened-additional-properties", EmitterState::default());
+
+    // Unknown keys are collected in a flattened map (which doesn't collide with other fields).
+    // Objects generic over `Any` don't default to the derived `Default` (which
+    // needs `Any: Default`), even if all their fields are optional.
+    let contents = generated.read("extension.rs");
+    assert!(contents.starts_with(
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct Extension<Any> {
+    pub additional_properties: Option<i64>,
+    pub name: Option<String>,
+    #[serde(rename = \"type\")]
+    pub type_: Option<String>,
+    #[serde(flatten)]
+    pub additional_properties_: std::collections::BTreeMap<String, Any>,
+}
+"
+    ));
+
+    // Flattened map is not set through builders.
+    assert!(!contents.contains("self.body.additional_properties_ ="));
+
+    assert!(generated.read("plugin.rs").starts_with(
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct Plugin<Any> {
+    pub extension: Option<crate::extension::Extension<Any>>,
+}
+"
+    ));
+}
+
+#[test]
+fn test_anonymous_object_definition_in_body() {
+    let _ = &*CLI_CODEGEN;
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/post_shipments_body.rs"),
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PostShipmentsBody {
+    pub address: Option<crate::post_shipments_body::PostShipmentsBodyAddress>,
+    #[serde(rename = \"orderId\")]
+    pub order_id: Option<String>,
+}
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PostShipmentsBodyAddress {
+    pub code: Option<String>,
+    pub line1: Option<String>,
+    pub line2: Option<String>,
+    pub name: Option<String>,
+}
+
+impl PostShipmentsBody {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PostShipmentsBodyBuilder {
+        PostShipmentsBodyBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Create shipment for order
+    #[deprecated]
+    #[inline]
+    pub fn post() -> PostShipmentsBodyPostBuilder {
+        PostShipmentsBodyPostBuilder {
+            body: Default::default(),
+        }
+    }
+}
+
+impl Into<PostShipmentsBody> for PostShipmentsBodyBuilder {
+    fn into(self) -> PostShipmentsBody {
+        self.body
+    }
+}
+
+impl Into<PostShipmentsBody> for PostShipmentsBodyPostBuilder {
+    fn into(self) -> PostShipmentsBody {
+        self.body
+    }
+}
+
+/// Builder for [`PostShipmentsBody`](./struct.PostShipmentsBody.html) object.
+#[derive(Debug, Clone)]
+pub struct PostShipmentsBodyBuilder {
+    body: self::PostShipmentsBody,
+}
+
+impl PostShipmentsBodyBuilder {
+    #[inline]
+    pub fn address(mut self, value: crate::post_shipments_body::PostShipmentsBodyAddress) -> Self {
+        self.body.address = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn order_id(mut self, value: impl Into<String>) -> Self {
+        self.body.order_id = Some(value.into());
+        self
+    }
+
+    /// Returns the object built using this builder.
+    #[inline]
+    pub fn build(self) -> PostShipmentsBody {
+        self.body
+    }
+}
+
+/// Builder created by [`PostShipmentsBody::post`](./struct.PostShipmentsBody.html#method.post) method for a `POST` operation associated with `PostShipmentsBody`.
+#[derive(Debug, Clone)]
+pub struct PostShipmentsBodyPostBuilder {
+    body: self::PostShipmentsBody,
+}
+
+impl PostShipmentsBodyPostBuilder {
+    #[inline]
+    pub fn address(mut self, value: crate::post_shipments_body::PostShipmentsBodyAddress) -> Self {
+        self.body.address = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn order_id(mut self, value: impl Into<String>) -> Self {
+        self.body.order_id = Some(value.into());
+        self
+    }
+
+    /// Sends the given body for this API call (instead of the one in this builder),
+    /// without moving or cloning it. The body fields needn't be set in that case.
+    #[inline]
+    pub fn with_body(self, body: &self::PostShipmentsBody) -> crate::client::BorrowedBody<'_, PostShipmentsBodyPostBuilder, self::PostShipmentsBody> {
+        let builder = {
+            crate::util::debug_assert_same_layout::<Self, PostShipmentsBodyPostBuilder>();
+            unsafe { std::mem::transmute(self) }
+        };
+        crate::client::BorrowedBody::new(builder, body)
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for PostShipmentsBodyPostBuilder {
+    type Output = serde_yaml::Value;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        \"/shipments\".into()
+    }
+
+    fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        self.modify_with_body(client, req, &self.body)
+    }
+
+    fn modify_with_body<B: serde::Serialize + Sync>(&self, client: &Client, req: Client::Request, body: &B) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        let req = client.set_body(req, {
+            let mut vec = vec![];
+            serde_yaml::to_writer(&mut vec, body)?;
+            vec
+        })?;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), \"application/yaml\")
+        .header(http::header::ACCEPT.as_str(), \"application/yaml\"))
+    }
+
+    fn fallback_media_range(&self) -> Option<&'static str> {
+        Some(\"application/yaml\")
+    }
+}
+
+impl PostShipmentsBodyPostBuilder {
+    /// Overrides the client's timeout for this API call.
+    #[inline]
+    pub fn timeout(self, timeout: std::time::Duration) -> crate::client::Timeout<Self> {
+        crate::client::Timeout::new(self, timeout)
+    }
+
+    /// Adds a header (which isn't declared in the spec) to this API call.
+    #[inline]
+    pub fn header(self, name: impl AsRef<str>, value: impl AsRef<str>) -> crate::client::ExtraHeaders<Self> {
+        crate::client::ExtraHeaders::new(self).header(name, value)
+    }
+
+    /// Returns the request for this API call without sending it (i.e., without any network I/O).
+    #[inline]
+    pub fn dry_run(&self) -> Result<crate::client::DryRunRequest, crate::client::ApiError<reqwest::r#async::Response>>
+    where
+        Self: crate::client::Sendable<crate::client::DryRunClient>,
+    {
+        crate::client::dry_run(self)
+    }
+}
+
+impl PostShipmentsBodyAddress {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PostShipmentsBodyAddressBuilder {
+        PostShipmentsBodyAddressBuilder {
+            body: Default::default(),
+        }
+    }
+}
+
+impl Into<PostShipmentsBodyAddress> for PostShipmentsBodyAddressBuilder {
+    fn into(self) -> PostShipmentsBodyAddress {
+        self.body
+    }
+}
+
+/// Builder for [`PostShipmentsBodyAddress`](./struct.PostShipmentsBodyAddress.html) object.
+#[derive(Debug, Clone)]
+pub struct PostShipmentsBodyAddressBuilder {
+    body: self::PostShipmentsBodyAddress,
+}
+
+impl PostShipmentsBodyAddressBuilder {
+    #[inline]
+    pub fn code(mut self, value: impl Into<String>) -> Self {
+        self.body.code = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn line1(mut self, value: impl Into<String>) -> Self {
+        self.body.line1 = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn line2(mut self, value: impl Into<String>) -> Self {
+        self.body.line2 = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Returns the object built using this builder.
+    #[inline]
+    pub fn build(self) -> PostShipmentsBodyAddress {
+        self.body
+    }
+}
+",
+        Some(0),
+    );
+}
+
+#[test]
+fn test_anonymous_object_definition_in_response() {
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/get_shipments_id_response.rs"),
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct GetShipmentsIdResponse {
+    pub address: Option<crate::get_shipments_id_response::GetShipmentsIdResponseAddress>,
+    #[serde(rename = \"createdOn\")]
+    pub created_on: Option<String>,
+    #[serde(rename = \"orderId\")]
+    pub order_id: Option<String>,
+    #[serde(rename = \"shippedOn\")]
+    pub shipped_on: Option<String>,
+}
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct GetShipmentsIdResponseAddress {
+    pub code: Option<String>,
+    pub line1: Option<String>,
+    pub line2: Option<String>,
+    pub name: Option<String>,
+}
+
+impl GetShipmentsIdResponse {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> GetShipmentsIdResponseBuilder {
+        GetShipmentsIdResponseBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// Fetch shipment by ID
+    #[inline]
+    pub fn get_shipment() -> GetShipmentsIdResponseGetBuilder<crate::generics::MissingId> {
+        GetShipmentsIdResponseGetBuilder {
+            inner: Default::default(),
+            _param_id: core::marker::PhantomData,
+        }
+    }
+}
+
+impl Into<GetShipmentsIdResponse> for GetShipmentsIdResponseBuilder {
+    fn into(self) -> GetShipmentsIdResponse {
+        self.body
+    }
+}
+
+/// Builder for [`GetShipmentsIdResponse`](./struct.GetShipmentsIdResponse.html) object.
+#[derive(Debug, Clone)]
+pub struct GetShipmentsIdResponseBuilder {
+    body: self::GetShipmentsIdResponse,
+}
+
+impl GetShipmentsIdResponseBuilder {
+    #[inline]
+    pub fn address(mut self, value: crate::get_shipments_id_response::GetShipmentsIdResponseAddress) -> Self {
+        self.body.address = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn created_on(mut self, value: impl Into<String>) -> Self {
+        self.body.created_on = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn order_id(mut self, value: impl Into<String>) -> Self {
+        self.body.order_id = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn shipped_on(mut self, value: impl Into<String>) -> Self {
+        self.body.shipped_on = Some(value.into());
+        self
+    }
+
+    /// Returns the object built using this builder.
+    #[inline]
+    pub fn build(self) -> GetShipmentsIdResponse {
+        self.body
+    }
+}
+
+/// Builder created by [`GetShipmentsIdResponse::get_shipment`](./struct.GetShipmentsIdResponse.html#method.get_shipment) method for a `GET` operation associated with `GetShipmentsIdResponse`.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct GetShipmentsIdResponseGetBuilder<Id> {
+    inner: GetShipmentsIdResponseGetBuilderContainer,
+    _param_id: core::marker::PhantomData<Id>,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone)]
+struct GetShipmentsIdResponseGetBuilderContainer {
+    param_id: Option<String>,
+}
+
+impl<Id> GetShipmentsIdResponseGetBuilder<Id> {
+    #[inline]
+    pub fn id(mut self, value: impl Into<String>) -> GetShipmentsIdResponseGetBuilder<crate::generics::IdExists> {
+        self.inner.param_id = Some(value.into());
+        crate::util::debug_assert_same_layout::<Self, GetShipmentsIdResponseGetBuilder<crate::generics::IdExists>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
+    #[inline]
+    pub fn reset(self) -> GetShipmentsIdResponseGetBuilder<crate::generics::MissingId> {
+        crate::util::debug_assert_same_layout::<Self, GetShipmentsIdResponseGetBuilder<crate::generics::MissingId>>();
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for GetShipmentsIdResponseGetBuilder<crate::generics::IdExists> {
+    type Output = GetShipmentsIdResponse;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        format!(\"/shipments/{id}\", id=crate::util::encode_path_param(&self.inner.param_id.as_ref().expect(\"missing parameter id?\").to_string())).into()
+    }
+
+    fn empty_path_param(&self) -> Option<&'static str> {
+        if self.inner.param_id.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {
+            return Some(\"id\");
+        }
+
+        None
+    }
+}
+
+impl GetShipmentsIdResponseGetBuilder<crate::generics::IdExists> {
+    /// Overrides the client's timeout for this API call.
+    #[inline]
+    pub fn timeout(self, timeout: std::time::Duration) -> crate::client::Timeout<Self> {
+        crate::client::Timeout::new(self, timeout)
+    }
+
+    /// Makes this a conditional API call using the `ETag` from an earlier response.
+    #[inline]
+    pub fn if_none_match(self, etag: impl Into<String>) -> crate::client::IfNoneMatch<Self> {
+        crate::client::IfNoneMatch::new(self, etag)
+    }
+
+    /// Adds a header (which isn't declared in the spec) to this API call.
+    #[inline]
+    pub fn header(self, name: impl AsRef<str>, value: impl AsRef<str>) -> crate::client::ExtraHeaders<Self> {
+        crate::client::ExtraHeaders::new(self).header(name, value)
+    }
+
+    /// Returns the request for this API call without sending it (i.e., without any network I/O).
+    #[inline]
+    pub fn dry_run(&self) -> Result<crate::client::DryRunRequest, crate::client::ApiError<reqwest::r#async::Response>>
+    where
+        Self: crate::client::Sendable<crate::client::DryRunClient>,
+    {
+        crate::client::dry_run(self)
+    }
+}
+
+impl GetShipmentsIdResponseAddress {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> GetShipmentsIdResponseAddressBuilder {
+        GetShipmentsIdResponseAddressBuilder {
+            body: Default::default(),
+        }
+    }
+}
+
+impl Into<GetShipmentsIdResponseAddress> for GetShipmentsIdResponseAddressBuilder {
+    fn into(self) -> GetShipmentsIdResponseAddress {
+        self.body
+    }
+}
+
+/// Builder for [`GetShipmentsIdResponseAddress`](./struct.GetShipmentsIdResponseAddress.html) object.
+#[derive(Debug, Clone)]
+pub struct GetShipmentsIdResponseAddressBuilder {
+    body: self::GetShipmentsIdResponseAddress,
+}
+
+impl GetShipmentsIdResponseAddressBuilder {
+    #[inline]
+    pub fn code(mut self, value: impl Into<String>) -> Self {
+        self.body.code = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn line1(mut self, value: impl Into<String>) -> Self {
+        self.body.line1 = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn line2(mut self, value: impl Into<String>) -> Self {
+        self.body.line2 = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
+        self
+    }
+
+    /// Returns the object built using this builder.
+    #[inline]
+    pub fn build(self) -> GetShipmentsIdResponseAddress {
+        self.body
+    }
+}
+",
+        Some(0),
+    );
+}
+
+#[test]
+fn test_simple_array_parameter_in_path() {
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/status.rs"),
+        "
+/// Builder created by [`Status::delete`](./struct.Status.html#method.delete) method for a `DELETE` operation associated with `Status`.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct StatusDeleteBuilder<PetId> {
+    inner: StatusDeleteBuilderContainer,
+    _param_pet_id: core::marker::PhantomData<PetId>,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone)]
+struct StatusDeleteBuilderContainer {
+    param_pet_id: Option<crate::util::Delimited<i64, crate::util::Csv>>,
+}
+
+impl<PetId> StatusDeleteBuilder<PetId> {
+    #[inline]
+    pub fn pet_id(mut self, value: impl IntoIterator<Item = impl Into<i64>>) -> StatusDeleteBuilder<crate::generics::PetIdExists> {
+        self.inner.param_pet_id = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
+        crate::util::debug_assert_same_layout::<Self, StatusDeleteBuilder<crate::generics::PetIdExists>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
+    #[inline]
+    pub fn reset(self) -> StatusDeleteBuilder<crate::generics::MissingPetId> {
+        crate::util::debug_assert_same_layout::<Self, StatusDeleteBuilder<crate::generics::MissingPetId>>();
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for StatusDeleteBuilder<crate::generics::PetIdExists> {
+    type Output = Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        format!(\"/pets/{petId}\", petId=self.inner.param_pet_id.as_ref().expect(\"missing parameter pet_id?\").encode_with(crate::util::encode_path_param)).into()
+    }
+
+    fn empty_path_param(&self) -> Option<&'static str> {
+        if self.inner.param_pet_id.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {
+            return Some(\"petId\");
+        }
+
+        None
+    }
+}
+",
+        Some(1430),
+    );
+}
+
+#[test]
+fn test_nested_arrays() {
+    // It's in miscellaneous because there's no body parameter or response body.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/miscellaneous.rs"),
+        "
+/// Builder created by [`Miscellaneous::get`](./struct.Miscellaneous.html#method.get) method for a `GET` operation associated with `Miscellaneous`.
+#[derive(Debug, Clone)]
+pub struct MiscellaneousGetBuilder;
+
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for MiscellaneousGetBuilder {
+    type Output = Vec<Vec<crate::test_nested_array_with_object::TestNestedArrayWithObjectItemItem>>;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        \"/test/array\".into()
+    }
+}
+",
+        Some(626),
+    );
+
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/miscellaneous.rs"),
+        "
+/// Builder created by [`Miscellaneous::post_2`](./struct.Miscellaneous.html#method.post_2) method for a `POST` operation associated with `Miscellaneous`.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct MiscellaneousPostBuilder2<Values> {
+    inner: MiscellaneousPostBuilder2Container,
+    _param_values: core::marker::PhantomData<Values>,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone)]
+struct MiscellaneousPostBuilder2Container {
+    param_values: Option<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<String, crate::util::Pipes>, crate::util::Csv>, crate::util::Ssv>, crate::util::Tsv>>,
+    param_x_foobar: Option<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<f64, crate::util::Ssv>, crate::util::Tsv>, crate::util::Csv>, crate::util::Pipes>>,
+    param_booya: Option<crate::util::Delimited<crate::util::Delimited<i64, crate::util::Csv>, crate::util::Multi>>,
+    param_foo: Option<crate::util::Delimited<crate::util::Delimited<String, crate::util::Csv>, crate::util::Multi>>,
+}
+
+impl<Values> MiscellaneousPostBuilder2<Values> {
+    #[inline]
+    pub fn values(mut self, value: impl IntoIterator<Item = impl IntoIterator<Item = impl IntoIterator<Item = impl IntoIterator<Item = impl Into<String>>>>>) -> MiscellaneousPostBuilder2<crate::generics::ValuesExists> {
+        self.inner.param_values = Some(value.into_iter().map(|value| value.into_iter().map(|value| value.into_iter().map(|value| value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
+        crate::util::debug_assert_same_layout::<Self, MiscellaneousPostBuilder2<crate::generics::ValuesExists>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn x_foobar(mut self, value: impl IntoIterator<Item = impl IntoIterator<Item = impl IntoIterator<Item = impl IntoIterator<Item = impl Into<f64>>>>>) -> Self {
+        self.inner.param_x_foobar = Some(value.into_iter().map(|value| value.into_iter().map(|value| value.into_iter().map(|value| value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Clears the value set for `x_foobar` (if any).
+    #[inline]
+    pub fn clear_x_foobar(mut self) -> Self {
+        self.inner.param_x_foobar = None;
+        self
+    }
+
+    #[inline]
+    pub fn booya(mut self, value: impl IntoIterator<Item = impl IntoIterator<Item = impl Into<i64>>>) -> Self {
+        self.inner.param_booya = Some(value.into_iter().map(|value| value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Clears the value set for `booya` (if any).
+    #[inline]
+    pub fn clear_booya(mut self) -> Self {
+        self.inner.param_booya = None;
+        self
+    }
+
+    #[inline]
+    pub fn foo(mut self, value: impl IntoIterator<Item = impl IntoIterator<Item = impl Into<String>>>) -> Self {
+        self.inner.param_foo = Some(value.into_iter().map(|value| value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Clears the value set for `foo` (if any).
+    #[inline]
+    pub fn clear_foo(mut self) -> Self {
+        self.inner.param_foo = None;
+        self
+    }
+
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
+    #[inline]
+    pub fn reset(self) -> MiscellaneousPostBuilder2<crate::generics::MissingValues> {
+        crate::util::debug_assert_same_layout::<Self, MiscellaneousPostBuilder2<crate::generics::MissingValues>>();
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for MiscellaneousPostBuilder2<crate::generics::ValuesExists> {
+    type Output = String;
+
+    const METHOD: http::Method = http::Method::POST;
+
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        format!(\"/test/parameter/{values}\", values=self.inner.param_values.as_ref().expect(\"missing parameter values?\").encode_with(crate::util::encode_path_param)).into()
+    }
+
+    fn empty_path_param(&self) -> Option<&'static str> {
+        if self.inner.param_values.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {
+            return Some(\"values\");
+        }
+
+        None
+    }
+
+    fn modify(&self, _client: &Client, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        let mut req = req;
+        if let Some(v) = &self.inner.param_x_foobar.as_ref().map(std::string::ToString::to_string) {
+            req = req.header(\"X-foobar\", &v);
+        }
+
+        Ok(req
+        .body_bytes({
+            let mut ser = url::form_urlencoded::Serializer::new(String::new());
+            if let Some(stuff) = self.inner.param_booya.as_ref() {
+                for v in stuff.iter() {
+                    ser.append_pair(\"booya\", &v.to_string());
+                }
+            }
+            ser.finish().into_bytes()
+        })
+        .header(http::header::CONTENT_TYPE.as_str(), \"application/x-www-form-urlencoded\")
+        .query({
+            &self.inner.param_foo.as_ref().map(|v| {
+                v.iter().map(|v| (\"foo\", v.to_string())).collect::<Vec<_>>()
+            }).unwrap_or_default()
+        }))
+    }
+}
+",
+        Some(4492),
+    );
+}
+
+#[test]
+fn test_builder_from_args_with_delimited() {
+    let _ = &*CLI_CODEGEN;
+
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/cli/miscellaneous.rs"),
+        "
+#[allow(unused_variables)]
+impl MiscellaneousPostBuilder2<crate::generics::ValuesExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = MiscellaneousPostBuilder2 {
+            inner: MiscellaneousPostBuilder2Container {
+            param_values: matches.and_then(|m| {
+                    m.value_of(\"values\").map(|_| {
+                        value_t!(m, \"values\", crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<String, crate::util::Pipes>, crate::util::Csv>, crate::util::Ssv>, crate::util::Tsv>).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_x_foobar: matches.and_then(|m| {
+                    m.value_of(\"x-foobar\").map(|_| {
+                        value_t!(m, \"x-foobar\", crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<f64, crate::util::Ssv>, crate::util::Tsv>, crate::util::Csv>, crate::util::Pipes>).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_booya: matches.and_then(|m| {
+                    m.value_of(\"booya\").map(|_| {
+                        value_t!(m, \"booya\", crate::util::Delimited<crate::util::Delimited<i64, crate::util::Csv>, crate::util::Multi>).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_foo: matches.and_then(|m| {
+                    m.value_of(\"foo\").map(|_| {
+                        value_t!(m, \"foo\", crate::util::Delimited<crate::util::Delimited<String, crate::util::Csv>, crate::util::Multi>).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_values: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+",
+        Some(11441),
+    );
+}
+
+#[test]
+fn test_file_response() {
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/miscellaneous.rs"),
+        "
+/// Builder created by [`Miscellaneous::get_1`](./struct.Miscellaneous.html#method.get_1) method for a `GET` operation associated with `Miscellaneous`.
+#[derive(Debug, Clone)]
+pub struct MiscellaneousGetBuilder1;
+
+
+#[async_trait::async_trait]
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for MiscellaneousGetBuilder1 {
+    type Output = crate::util::ResponseStream<<<Client as crate::client::ApiClient>::Response as crate::client::Response>::Stream>;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        \"/test/file\".into()
+    }
+
+    async fn accept_response(&self, mut resp: Client::Response) -> Result<(Self::Output, crate::client::RawResponse<Client::Response>), crate::client::ApiError<Client::Response>> {
+        use crate::client::Response;
+
+        let status = resp.status();
+        Ok((resp.stream(), crate::client::RawResponse { status, body: crate::client::RawBody::Streamed }))
+    }
+}
+",
+        Some(2342),
+    );
+}
+
+#[test]
+fn test_multipart_with_file() {
+    let _ = &*CLI_CODEGEN;
+
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/cli/status.rs"),
+        "
+/// Builder created by [`Status::put_1`](./struct.Status.html#method.put_1) method for a `PUT` operation associated with `Status`.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct StatusPutBuilder1<SomeDataFile, Foobar> {
+    inner: StatusPutBuilder1Container,
+    _param_some_data_file: core::marker::PhantomData<SomeDataFile>,
+    _param_foobar: core::marker::PhantomData<Foobar>,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone)]
+struct StatusPutBuilder1Container {
+    param_some_data_file: Option<std::path::PathBuf>,
+    param_some_other_file: Option<std::path::PathBuf>,
+    param_foobar: Option<String>,
+    param_booya: Option<crate::util::Delimited<crate::util::Delimited<i64, crate::util::Csv>, crate::util::Multi>>,
+}
+
+impl<SomeDataFile, Foobar> StatusPutBuilder1<SomeDataFile, Foobar> {
+    #[inline]
+    pub fn some_data_file(mut self, value: impl AsRef<std::path::Path>) -> StatusPutBuilder1<crate::generics::SomeDataFileExists, Foobar> {
+        self.inner.param_some_data_file = Some(value.as_ref().into());
+        crate::util::debug_assert_same_layout::<Self, StatusPutBuilder1<crate::generics::SomeDataFileExists, Foobar>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn some_other_file(mut self, value: impl AsRef<std::path::Path>) -> Self {
+        self.inner.param_some_other_file = Some(value.as_ref().into());
+        self
+    }
+
+    /// Clears the value set for `some_other_file` (if any).
+    #[inline]
+    pub fn clear_some_other_file(mut self) -> Self {
+        self.inner.param_some_other_file = None;
+        self
+    }
+
+    #[inline]
+    pub fn foobar(mut self, value: impl Into<String>) -> StatusPutBuilder1<SomeDataFile, crate::generics::FoobarExists> {
+        self.inner.param_foobar = Some(value.into());
+        crate::util::debug_assert_same_layout::<Self, StatusPutBuilder1<SomeDataFile, crate::generics::FoobarExists>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    #[inline]
+    pub fn booya(mut self, value: impl IntoIterator<Item = impl IntoIterator<Item = impl Into<i64>>>) -> Self {
+        self.inner.param_booya = Some(value.into_iter().map(|value| value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Clears the value set for `booya` (if any).
+    #[inline]
+    pub fn clear_booya(mut self) -> Self {
+        self.inner.param_booya = None;
+        self
+    }
+
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
+    #[inline]
+    pub fn reset(self) -> StatusPutBuilder1<crate::generics::MissingSomeDataFile, crate::generics::MissingFoobar> {
+        crate::util::debug_assert_same_layout::<Self, StatusPutBuilder1<crate::generics::MissingSomeDataFile, crate::generics::MissingFoobar>>();
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for StatusPutBuilder1<crate::generics::SomeDataFileExists, crate::generics::FoobarExists> {
+    type Output = Status;
+
+    const METHOD: http::Method = http::Method::PUT;
+
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        \"/test/file\".into()
+    }
+
+    fn modify(&self, _client: &Client, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .multipart_form_data({
+            use crate::client::Form;
+            let mut form = <Client::Request as Request>::Form::new();
+            if let Some(v) = self.inner.param_some_data_file.as_ref() {
+                form = form.file(\"someDataFile\", v)?;
+            }
+            if let Some(v) = self.inner.param_some_other_file.as_ref() {
+                form = form.file(\"someOtherFile\", v)?;
+            }
+            if let Some(v) = self.inner.param_foobar.as_ref() {
+                form = form.text(\"foobar\", v.to_string());
+            }
+            if let Some(stuff) = self.inner.param_booya.as_ref() {
+                for v in stuff.iter() {
+                    form = form.text(\"booya\", v.to_string());
+                }
+            }
+            form
+        }))
+    }
+}
+
+impl StatusPutBuilder1<crate::generics::SomeDataFileExists, crate::generics::FoobarExists> {
+    /// Overrides the client's timeout for this API call.
+    #[inline]
+    pub fn timeout(self, timeout: std::time::Duration) -> crate::client::Timeout<Self> {
+        crate::client::Timeout::new(self, timeout)
+    }
+
+    /// Adds a header (which isn't declared in the spec) to this API call.
+    #[inline]
+    pub fn header(self, name: impl AsRef<str>, value: impl AsRef<str>) -> crate::client::ExtraHeaders<Self> {
+        crate::client::ExtraHeaders::new(self).header(name, value)
+    }
+
+    /// Returns the request for this API call without sending it (i.e., without any network I/O).
+    #[inline]
+    pub fn dry_run(&self) -> Result<crate::client::DryRunRequest, crate::client::ApiError<reqwest::r#async::Response>>
+    where
+        Self: crate::client::Sendable<crate::client::DryRunClient>,
+    {
+        crate::client::dry_run(self)
+    }
+}
+
+#[allow(unused_variables)]
+impl StatusPutBuilder1<crate::generics::SomeDataFileExists, crate::generics::FoobarExists> {
+    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
+        let thing = StatusPutBuilder1 {
+            inner: StatusPutBuilder1Container {
+            param_some_data_file: matches.and_then(|m| {
+                    m.value_of(\"some-data-file\").map(|_| {
+                        value_t!(m, \"some-data-file\", std::path::PathBuf).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_some_other_file: matches.and_then(|m| {
+                    m.value_of(\"some-other-file\").map(|_| {
+                        value_t!(m, \"some-other-file\", std::path::PathBuf).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_foobar: matches.and_then(|m| {
+                    m.value_of(\"foobar\").map(|_| {
+                        value_t!(m, \"foobar\", String).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            param_booya: matches.and_then(|m| {
+                    m.value_of(\"booya\").map(|_| {
+                        value_t!(m, \"booya\", crate::util::Delimited<crate::util::Delimited<i64, crate::util::Csv>, crate::util::Multi>).unwrap_or_else(|e| e.exit())
+                    })
+                }),
+
+            },
+            _param_some_data_file: core::marker::PhantomData,
+            _param_foobar: core::marker::PhantomData,
+        };
+
+        Ok(thing)
+    }
+}
+",
+        Some(4979),
+    );
+}
+
+#[test]
+fn test_unreachable_operations() {
+    let generated = generate_fixture("unreachable-operations", EmitterState::default());
+
+    // `GET /pets` falls back to `get`, which has been claimed by `POST /pets`.
+    assert_eq!(
+        generated.emitter.state().unreachable_operations(),
+        vec![String::from("GET /pets")]
+    );
+
+    let contents = generated.read("pet.rs");
+
+    // Invalid operation IDs fallback to method-based names.
+    assert!(contents.contains("pub fn delete_1()"));
+    assert_eq!(contents.matches("pub fn get()").count(), 1);
+
+    let mut state = EmitterState::default();
+    state.strict = true;
+    let err = try_generate_fixture("unreachable-operations", state)
+        .err()
+        .expect("strict codegen");
+    assert_eq!(
+        err.to_string(),
+        "Cannot derive names for operation(s): [\"GET /pets\"]"
+    );
+}
+
+#[test]
+fn test_shared_client() {
+    let contents = generate_fixture("shared-client", EmitterState::default()).read("mod.rs");
+
+    // Clients wrapped in `Arc` can be used for API calls.
+    assert!(contents.contains(
+        "
+    #[async_trait::async_trait]
+    impl<C: ApiClient + Send + Sync> ApiClient for Arc<C> {
+        type Request = C::Request;
+        type Response = C::Response;
+
+        fn request_builder(&self, method: http::Method, rel_path: &str) -> Self::Request {
+            (**self).request_builder(method, rel_path)
+        }
+"
+    ));
+
+    // Server clients share the inner client and the configuration among clones.
+    assert!(contents.contains(
+        "
+    #[derive(Debug)]
+    pub struct ServerClient<C> {
+        inner: Arc<C>,
+        base_url: Arc<str>,
+    }
+
+    impl<C> ServerClient<C> {
+        /// Wraps the given client for sending requests to the configured server.
+        pub fn new(client: C, config: &ServerConfig) -> Self {
+            Self::with_shared(Arc::new(client), config)
+        }
+
+        /// Same as [`new`](#method.new), but uses a client shared with other
+        /// API clients (and hence, its connection pool).
+        pub fn with_shared(client: Arc<C>, config: &ServerConfig) -> Self {
+            ServerClient {
+                inner: client,
+                base_url: config.url().into(),
+            }
+        }
+
+        /// Returns the wrapped client.
+        pub fn inner(&self) -> &Arc<C> {
+            &self.inner
+        }
+    }
+
+    impl<C> Clone for ServerClient<C> {
+        fn clone(&self) -> Self {
+            ServerClient {
+                inner: self.inner.clone(),
+                base_url: self.base_url.clone(),
+            }
+        }
+    }
+"
+    ));
+}
+
+#[test]
+fn test_write_only_fields() {
+    let contents = generate_fixture("write-only-fields", EmitterState::default()).read("user.rs");
+
+    // Required write-only fields default when they're missing in responses.
+    assert!(contents.contains(
+        "
+pub struct User {
+    pub name: String,
+    #[serde(default)]
+    pub password: String,
+    pub pin: Option<i64>,
+}
+"
+    ));
+
+    // ... but they're still required for requests.
+    assert!(contents.contains(
+        "
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for UserPostBuilder<crate::generics::NameExists, crate::generics::PasswordExists> {"
+    ));
+}
+
+#[test]
+fn test_operation_registry() {
+    let contents =
+        generate_fixture("operation-registry", EmitterState::default()).read("operations.rs");
+
+    // Operations sharing a path have distinct entries.
+    assert!(contents.contains(
+        "
+    Operation {
+        method: \"GET\",
+        path: \"/pets/{id}\",
+        id: Some(\"getPet\"),
+        params: &[
+            Parameter {
+                name: \"fields\",
+                location: ParameterIn::Query,
+                required: false,
+            },
+            Parameter {
+                name: \"id\",
+                location: ParameterIn::Path,
+                required: true,
+            },
+        ],
+        body: None,
+        response: Some(\"crate::pet::Pet\"),
+    },
+    Operation {
+        method: \"DELETE\",
+        path: \"/pets/{id}\",
+        id: Some(\"deletePet\"),
+        params: &[
+            Parameter {
+                name: \"id\",
+                location: ParameterIn::Path,
+                required: true,
+            },
+        ],
+        body: None,
+        response: Some(\"crate::pet::Pet\"),
+    },
+"
+    ));
+
+    assert!(contents.contains(
+        "
+    Operation {
+        method: \"POST\",
+        path: \"/pets\",
+        id: Some(\"addPet\"),
+        params: &[],
+        body: Some(\"crate::pet::Pet\"),
+        response: Some(\"crate::pet::Pet\"),
+    },
+"
+    ));
+}
+
+#[test]
+fn test_parameters_sharing_name() {
+    let contents =
+        generate_fixture("parameters-sharing-name", EmitterState::default()).read("pet.rs");
+
+    // Parameters are unique by their name and location.
+    assert!(contents.contains(
+        "
+struct PetGetBuilderContainer {
+    param_id_query: Option<i64>,
+    param_id: Option<String>,
+}
+
+impl<Id> PetGetBuilder<Id> {
+    #[inline]
+    pub fn id_query(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_id_query = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `id_query` (if any).
+    #[inline]
+    pub fn clear_id_query(mut self) -> Self {
+        self.inner.param_id_query = None;
+        self
+    }
+
+    #[inline]
+    pub fn id(mut self, value: impl Into<String>) -> PetGetBuilder<crate::generics::IdExists> {
+        self.inner.param_id = Some(value.into());
+        crate::util::debug_assert_same_layout::<Self, PetGetBuilder<crate::generics::IdExists>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
+    #[inline]
+    pub fn reset(self) -> PetGetBuilder<crate::generics::MissingId> {
+        crate::util::debug_assert_same_layout::<Self, PetGetBuilder<crate::generics::MissingId>>();
+        unsafe { std::mem::transmute(self) }
+    }
+}
+"
+    ));
+
+    // ... but they still go by their actual names in API calls.
+    assert!(contents.contains(
+        "
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        format!(\"/pets/{id}\", id=crate::util::encode_path_param(&self.inner.param_id.as_ref().expect(\"missing parameter id?\").to_string())).into()
+    }
+
+    fn empty_path_param(&self) -> Option<&'static str> {
+        if self.inner.param_id.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {
+            return Some(\"id\");
+        }
+
+        None
+    }
+"
+    ));
+
+    assert!(contents.contains(
+        "
+            (\"id\", self.inner.param_id_query.as_ref().map(std::string::ToString::to_string))
+"
+    ));
+}
+
+#[test]
+fn test_clearing_optional_parameters() {
+    let contents =
+        generate_fixture("clearing-optional-parameters", EmitterState::default()).read("pet.rs");
+
+    // Optional parameters can be cleared (and they're skipped in queries when unset).
+    assert!(contents.contains(
+        "
+    #[inline]
+    pub fn fields(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_fields = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `fields` (if any).
+    #[inline]
+    pub fn clear_fields(mut self) -> Self {
+        self.inner.param_fields = None;
         self
     }
+"
+    ));
+    assert!(contents.contains(
+        "
+            (\"fields\", self.inner.param_fields.as_ref().map(std::string::ToString::to_string))
+"
+    ));
+
+    // ... but required parameters can't be cleared.
+    assert!(!contents.contains("pub fn clear_id("));
+}
+
+/// Emitter which groups definitions into modules based on their first tag.
+struct TaggedEmitter {
+    state: EmitterState,
+    flatten: bool,
+}
+
+impl Emitter for TaggedEmitter {
+    type Definition = DefaultSchema;
+
+    fn state(&self) -> &EmitterState {
+        &self.state
+    }
+
+    fn remap_def(
+        &self,
+        _def: &DefaultSchema,
+        mut components: Vec<String>,
+        tags: &[String],
+    ) -> Vec<String> {
+        if self.flatten {
+            components.drain(..components.len() - 1);
+        }
+
+        if let Some(tag) = tags.first() {
+            components.insert(0, tag.clone());
+        }
+
+        components
+    }
+}
+
+#[test]
+fn test_remapping_definitions_by_tag() {
+    let generated = try_generate_fixture_with(
+        "remapping-definitions-by-tag",
+        EmitterState::default(),
+        |state| TaggedEmitter {
+            state,
+            flatten: false,
+        },
+    )
+    .expect("codegen");
+
+    // Definitions are moved into modules named after their tags ...
+    let contents = generated.read("pets/pet.rs");
+    assert!(contents.contains("pub struct Pet {"));
+    // ... and references are updated (untagged definitions stay where they are).
+    assert!(contents.contains("pub owner: Option<crate::owner::Owner>,"));
+    assert!(contents.contains("type Output = crate::pets::pet::Pet;"));
+    assert!(generated.read("owner.rs").contains("pub struct Owner {"));
+    assert!(generated
+        .read("store/v1/order.rs")
+        .contains("pub struct Order {"));
+    assert!(generated
+        .read("store/v2/order.rs")
+        .contains("pub struct Order {"));
+    assert!(generated
+        .read("mod.rs")
+        .contains("pub mod pets {\n    include!(\"./pets/mod.rs\");\n}"));
+
+    // Definitions remapped to the same path are rejected.
+    let err = try_generate_fixture_with(
+        "remapping-definitions-by-tag",
+        EmitterState::default(),
+        |state| TaggedEmitter {
+            state,
+            flatten: true,
+        },
+    )
+    .err()
+    .expect("expected collision");
+    assert!(err.to_string().starts_with(
+        "Definitions \"v1.Order\" and \"v2.Order\" have been mapped to the same path"
+    ));
+}
+
+#[test]
+fn test_event_stream_response() {
+    let generated = generate_fixture("event-stream-response", EmitterState::default());
+
+    let contents = generated.read("message.rs");
+
+    // Events are streamed and their payloads are decoded individually.
+    assert!(contents.contains(
+        "
+    type Output = crate::util::EventStream<<<Client as crate::client::ApiClient>::Response as crate::client::Response>::Stream, Message>;
+"
+    ));
+    assert!(contents.contains(
+        "
+    fn modify(&self, _client: &Client, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .header(http::header::ACCEPT.as_str(), \"text/event-stream\"))
+    }
+"
+    ));
+    assert!(contents.contains(
+        "
+    async fn accept_response(&self, mut resp: Client::Response) -> Result<(Self::Output, crate::client::RawResponse<Client::Response>), crate::client::ApiError<Client::Response>> {
+        use crate::client::Response;
+
+        let status = resp.status();
+        let stream = crate::util::EventStream::new(resp.stream(), |data: &[u8]| serde_yaml::from_reader(data).map_err(Into::into));
+        Ok((stream, crate::client::RawResponse { status, body: crate::client::RawBody::Streamed }))
+    }"
+    ));
+    assert!(!contents.contains("fn fallback_media_range"));
+
+    let contents = generated.read("util.rs");
+    assert!(contents.contains("pub struct EventParser {"));
+    assert!(contents.contains("impl<S, I, E, T> OldStream for EventStream<S, T>"));
+}
+
+#[test]
+fn test_field_docs() {
+    let contents = generate_fixture("field-docs", EmitterState::default()).read("pet.rs");
+
+    assert!(contents.contains(
+        "
+    /// Tag for this pet.
+    ///
+    /// **Deprecated**
+    ///
+    /// Example: `\"good boy\"`
+    pub tag: Option<String>,
+"
+    ));
+    // Docs are emitted even if the field doesn't have a description.
+    assert!(contents.contains(
+        "
+    /// External docs: <https://example.com/owners>
+    pub owner: Option<crate::pet::PetOwner>,
+"
+    ));
+    assert!(contents.contains(
+        "
+    /// External docs: Age of the owner (<https://example.com/owners#age>)
+    ///
+    /// Example: `42`
+    pub age: Option<i64>,
+"
+    ));
+}
+
+#[test]
+fn test_query_structs() {
+    let mut state = EmitterState::default();
+    state.query_structs = true;
+    let generated = generate_fixture("query-structs", state);
+    let contents = generated.read("pet.rs");
+
+    // Query struct reuses the delimiting of parameters and skips unset values.
+    assert!(contents.contains(
+        "
+/// Query parameters for [`PetGetBuilder`](./struct.PetGetBuilder.html).
+#[derive(Debug, Clone, Serialize)]
+pub struct PetGetQuery {
+    pub status: String,
+    /// Maximum number of pets.
+    #[serde(skip_serializing_if = \"Option::is_none\")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = \"Option::is_none\")]
+    pub tags: Option<crate::util::Delimited<String, crate::util::Csv>>,
+    #[serde(rename = \"type\", skip_serializing_if = \"Option::is_none\")]
+    pub type_: Option<String>,
+}
+"
+    ));
+    // Required parameters are enforced by the constructor ...
+    assert!(contents.contains(
+        "
+impl PetGetQuery {
+    /// Creates the query parameters with the given values for required
+    /// parameters (optional parameters are unset).
+    #[inline]
+    pub fn new(status: String) -> Self {
+        PetGetQuery { status, limit: None, tags: None, type_: None }
+    }
+}
+"
+    ));
+    // ... and the builder marks them as set when the struct is passed.
+    assert!(contents.contains(
+        "
+impl<Status, StoreId> PetGetBuilder<Status, StoreId> {
+    /// Sets all query parameters of this operation at once (overriding the ones set before).
+    #[inline]
+    pub fn query(mut self, query: PetGetQuery) -> PetGetBuilder<crate::generics::StatusExists, StoreId> {
+        self.inner.param_status = Some(query.status);
+        self.inner.param_limit = query.limit;
+        self.inner.param_tags = query.tags;
+        self.inner.param_type = query.type_;
+        crate::util::debug_assert_same_layout::<Self, PetGetBuilder<crate::generics::StatusExists, StoreId>>();
+        unsafe { std::mem::transmute(self) }
+    }
+}
+"
+    ));
+    // Values from the builder end up in the query string as usual.
+    assert!(contents.contains(
+        "
+        crate::util::append_query(&mut path, &[
+            (\"tags\", self.inner.param_tags.as_ref().map(|v| v.encode_with(crate::util::encode_query_value))),
+        ]);"
+    ));
+    assert!(contents.contains(
+        "
+        .query(&[
+            (\"status\", self.inner.param_status.as_ref().map(std::string::ToString::to_string)),
+            (\"limit\", self.inner.param_limit.as_ref().map(std::string::ToString::to_string)),
+            (\"type\", self.inner.param_type.as_ref().map(std::string::ToString::to_string))
+        ]))"
+    ));
+
+    let contents = generated.read("util.rs");
+    assert!(contents
+        .contains("impl<T: Display, D: Delimiting + Allowed> Serialize for Delimited<T, D> {"));
+}
 
+#[cfg(feature = "decimal")]
+#[test]
+fn test_decimal_format() {
+    let state = || {
+        let mut state = EmitterState::default();
+        let mut meta = CrateMeta::default();
+        meta.authors = Some(vec!["Me <me@example.com>".into()]);
+        meta.mode = EmitMode::Crate;
+        state.set_meta(meta);
+        state
+    };
+    let generated = generate_fixture("decimal-format", state());
+    // Models are round-tripped through JSON by the tests in `tests/test_decimal`.
+    generate_fixture_in("decimal-format", "test_decimal", state());
+
+    let contents = generated.read("invoice.rs");
+    // Decimal strings are (de)serialized by `rust_decimal` itself, and numbers
+    // stay as floats (since they've already lost precision in JSON).
+    assert!(contents.starts_with(
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct Invoice {
+    pub amount: rust_decimal::Decimal,
+    pub discount: Option<f64>,
+    pub taxes: Option<Vec<rust_decimal::Decimal>>,
+}
+"
+    ));
+    // Decimals are simple types, so setters take anything convertible into them ...
+    assert!(contents.contains(
+        "
     #[inline]
-    pub fn line2(mut self, value: impl Into<String>) -> Self {
-        self.body.line2 = Some(value.into());
-        self
+    pub fn amount(mut self, value: impl Into<rust_decimal::Decimal>) -> InvoiceBuilder<crate::generics::AmountExists> {"
+    ));
+    // ... and they can be delimited in parameters.
+    assert!(contents.contains(
+        "
+pub struct InvoiceGetBuilder {
+    param_amounts: Option<crate::util::Delimited<rust_decimal::Decimal, crate::util::Csv>>,
+    param_min_amount: Option<rust_decimal::Decimal>,
+}
+"
+    ));
+
+    // Generated crate pulls the dependency only when decimals are used.
+    let contents = generated.read("Cargo.toml");
+    assert!(contents.contains(
+        "
+reqwest = \"0.9\"
+rust_decimal = { version = \"1\", features = [\"serde\"] }
+serde = \"1.0\"
+"
+    ));
+}
+
+#[cfg(feature = "time-types")]
+#[test]
+fn test_time_types() {
+    let state = || {
+        let mut state = EmitterState::default();
+        state.time_types = true;
+        let mut meta = CrateMeta::default();
+        meta.authors = Some(vec!["Me <me@example.com>".into()]);
+        meta.mode = EmitMode::Crate;
+        state.set_meta(meta);
+        state
+    };
+    let generated = generate_fixture("time-types", state());
+    // Models are round-tripped through JSON by the tests in `tests/test_time`
+    // (which uses a stable toolchain, since `time` 0.3 needs a newer compiler).
+    generate_fixture_in("time-types", "test_time", state());
+
+    let contents = generated.read("event.rs");
+    // Date-times use RFC 3339 adapters (arrays stay as strings), and required
+    // `time` values default to the epoch.
+    assert!(contents.contains(
+        "#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Event {
+    pub day: time::Date,
+    #[serde(rename = \"endsAt\", default, with = \"time::serde::rfc3339::option\")]
+    pub ends_at: Option<time::OffsetDateTime>,
+    pub reminders: Option<Vec<String>>,
+    #[serde(rename = \"startsAt\", with = \"time::serde::rfc3339\")]
+    pub starts_at: time::OffsetDateTime,
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        Event {
+            day: time::OffsetDateTime::UNIX_EPOCH.date(),
+            ends_at: Default::default(),
+            reminders: Default::default(),
+            starts_at: time::OffsetDateTime::UNIX_EPOCH,
+        }
     }
+}
+"
+    ));
+    assert!(contents.contains(
+        "
+    #[inline]
+    pub fn starts_at(mut self, value: impl Into<time::OffsetDateTime>) -> EventBuilder<Day, crate::generics::StartsAtExists> {"
+    ));
+
+    let contents = generated.read("Cargo.toml");
+    assert!(contents.contains(
+        "
+serde_yaml = \"0.8\"
+time = { version = \"0.3\", features = [\"serde-human-readable\", \"serde-well-known\"] }
+"
+    ));
+}
+
+#[test]
+fn test_additional_properties_setter() {
+    let generated = generate_fixture("additional-properties-setter", EmitterState::default());
 
+    // Free-form values go into the generic map.
+    let contents = generated.read("label.rs");
+    assert!(contents.contains(
+        "
+impl<Name, Any> LabelBuilder<Name, Any> {
     #[inline]
-    pub fn name(mut self, value: impl Into<String>) -> Self {
-        self.body.name = Some(value.into());
+    pub fn name(mut self, value: impl Into<String>) -> LabelBuilder<crate::generics::NameExists, Any> {
+        self.body.name = value.into();
+        crate::util::debug_assert_same_layout::<Self, LabelBuilder<crate::generics::NameExists, Any>>();
+        let this = std::mem::ManuallyDrop::new(self);
+        unsafe { std::mem::transmute_copy(&*this) }
+    }
+
+    /// Inserts an additional property into this object (replacing the previous value for that key, if any).
+    #[inline]
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<Any>) -> Self {
+        self.body.additional_properties.insert(key.into(), value.into());
         self
     }
+
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
+    #[inline]
+    pub fn reset(self) -> LabelBuilder<crate::generics::MissingName, Any> {
+        crate::util::debug_assert_same_layout::<Self, LabelBuilder<crate::generics::MissingName, Any>>();
+        let this = std::mem::ManuallyDrop::new(self);
+        unsafe { std::mem::transmute_copy(&*this) }
+    }
 }
-",
-        Some(0),
+"
+    ));
+
+    // Typed values are flattened too, and the setter (which doesn't collide
+    // with the `extra` field) accepts the value type.
+    let contents = generated.read("quota.rs");
+    assert!(contents.contains(
+        "
+pub struct Quota {
+    pub extra: Option<String>,
+    #[serde(flatten)]
+    pub additional_properties: std::collections::BTreeMap<String, Vec<i64>>,
+}
+"
+    ));
+    assert_eq!(
+        contents
+            .matches(
+                "
+    /// Inserts an additional property into this object (replacing the previous value for that key, if any).
+    #[inline]
+    pub fn extra_(mut self, key: impl Into<String>, value: impl IntoIterator<Item = impl Into<i64>>) -> Self {
+        self.body.additional_properties.insert(key.into(), value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+"
+            )
+            .count(),
+        2
     );
 }
 
 #[test]
-fn test_anonymous_object_definition_in_response() {
-    assert_file_contains_content_at(
-        &(ROOT.clone() + "/tests/test_pet/get_shipments_id_response.rs"),
+fn test_enum_variant_docs() {
+    let generated = generate_fixture("enum-variant-docs", EmitterState::default());
+
+    // Variants without descriptions don't get any docs.
+    assert!(generated.read("order_status.rs").starts_with(
+        "
+/// Status of an order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum OrderStatus {
+    /// Order has been placed.
+    #[serde(rename = \"placed\")]
+    Placed,
+    #[serde(rename = \"approved\")]
+    Approved,
+    /// Order has been delivered.
+    ///
+    /// This is final.
+    #[serde(rename = \"delivered\")]
+    Delivered,
+}
+"
+    ));
+
+    assert!(generated.read("priority.rs").starts_with(
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum Priority {
+    /// Lowest priority.
+    V1 = 1,
+    V2 = 2,
+}
+"
+    ));
+}
+
+#[test]
+fn test_enum_variant_collisions() {
+    let generated = generate_fixture("enum-variant-collisions", EmitterState::default());
+
+    // Colliding variant names are suffixed, keywords are prefixed (like numbers)
+    // and duplicate values are skipped.
+    assert!(generated.read("filter.rs").starts_with(
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Filter {
+    #[serde(rename = \"foo-bar\")]
+    FooBar,
+    #[serde(rename = \"foo_bar\")]
+    FooBar1,
+    A,
+    #[serde(rename = \"a\")]
+    A1,
+    #[serde(rename = \"1.5\")]
+    V15,
+    #[serde(rename = \"15\")]
+    V151,
+    #[serde(rename = \"self\")]
+    VSelf,
+}
+"
+    ));
+
+    assert!(generated.read("level.rs").starts_with(
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum Level {
+    V1 = 1,
+    V2 = 2,
+}
+"
+    ));
+}
+
+#[test]
+fn test_read_only_fields_in_constructor() {
+    let contents = generate_fixture("read-only-fields-in-constructor", EmitterState::default())
+        .read("account.rs");
+    // Read-only fields are still required for deserializing ...
+    assert!(contents.starts_with(
         "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
-pub struct GetShipmentsIdResponse {
-    pub address: Option<crate::get_shipments_id_response::GetShipmentsIdResponseAddress>,
-    #[serde(rename = \"createdOn\")]
-    pub created_on: Option<String>,
-    #[serde(rename = \"orderId\")]
-    pub order_id: Option<String>,
-    #[serde(rename = \"shippedOn\")]
-    pub shipped_on: Option<String>,
+pub struct Account {
+    pub id: String,
+    pub kind: String,
+    pub name: String,
+    #[serde(rename = \"type\")]
+    pub type_: String,
 }
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
-pub struct GetShipmentsIdResponseAddress {
-    pub code: Option<String>,
-    pub line1: Option<String>,
-    pub line2: Option<String>,
-    pub name: Option<String>,
+"
+    ));
+    // ... but not for constructing the object.
+    assert!(contents.contains(
+        "
+    /// Create this object with the fields required for constructing it.
+    #[inline]
+    pub fn new(name: impl Into<String>, r#type: impl Into<String>) -> Self {
+        Self::builder().name(name).r#type(r#type).build()
+    }
+"
+    ));
+    assert!(contents.contains(
+        "
+impl<Name, Type> AccountBuilder<Name, Type> {
+    #[inline]
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.body.id = value.into();
+        self
+    }
+"
+    ));
 }
 
-impl GetShipmentsIdResponse {
-    /// Create a builder for this object.
+#[test]
+fn test_extra_headers() {
+    let generated = generate_fixture("extra-headers", EmitterState::default());
+
+    let contents = generated.read("trace.rs");
+    assert!(contents.contains(
+        "
+    /// Adds a header (which isn't declared in the spec) to this API call.
     #[inline]
-    pub fn builder() -> GetShipmentsIdResponseBuilder {
-        GetShipmentsIdResponseBuilder {
-            body: Default::default(),
+    pub fn header(self, name: impl AsRef<str>, value: impl AsRef<str>) -> crate::client::ExtraHeaders<Self> {
+        crate::client::ExtraHeaders::new(self).header(name, value)
+    }
+"
+    ));
+
+    let contents = generated.read("mod.rs");
+    // Extra headers are set after the header parameters, so they take precedence.
+    assert!(contents.contains(
+        "
+        fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, ApiError<Client::Response>> {
+            if let Some(name) = self.invalid.as_ref() {
+                return Err(ApiError::InvalidHeader(name.clone()));
+            }
+
+            let req = self.inner.modify(client, req)?;
+            Ok(req.headers(self.headers.clone()))
         }
+"
+    ));
+}
+
+#[test]
+fn test_content_type_override() {
+    let contents =
+        generate_fixture("content-type-override", EmitterState::default()).read("note.rs");
+    // Body is encoded with the first supported coder (JSON) by default ...
+    assert!(contents.contains(
+        "
+    fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        self.modify_with_body(client, req, &self.body)
     }
 
-    /// Fetch shipment by ID
+    fn modify_with_body<B: serde::Serialize + Sync>(&self, _client: &Client, req: Client::Request, body: &B) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        Ok(req
+        .json(body))
+    }
+
+    fn modify_with_content_type(&self, client: &Client, req: Client::Request, content_type: &str) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        let req = match content_type {
+            \"application/json\" => client.set_body(req, {
+                let mut vec = vec![];
+                serde_json::to_writer(&mut vec, &self.body)?;
+                vec
+            })?,
+            \"application/yaml\" => client.set_body(req, {
+                let mut vec = vec![];
+                serde_yaml::to_writer(&mut vec, &self.body)?;
+                vec
+            })?,
+            _ => return Err(crate::client::ApiError::UnsupportedContentType(content_type.into())),
+        };
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), content_type))
+    }
+"
+    ));
+    // ... and it can be overridden with any of the acceptable content types.
+    assert!(contents.contains(
+        "
+    /// Encodes the body in the given content type (one of `application/json`, `application/yaml`) for this API call.
     #[inline]
-    pub fn get_shipment() -> GetShipmentsIdResponseGetBuilder<crate::generics::MissingId> {
-        GetShipmentsIdResponseGetBuilder {
-            inner: Default::default(),
-            _param_id: core::marker::PhantomData,
-        }
+    pub fn content_type(self, content_type: impl Into<String>) -> crate::client::ContentType<Self> {
+        crate::client::ContentType::new(self, content_type)
     }
+"
+    ));
 }
 
-impl Into<GetShipmentsIdResponse> for GetShipmentsIdResponseBuilder {
-    fn into(self) -> GetShipmentsIdResponse {
-        self.body
+#[test]
+fn test_boxed_child_with_required_fields() {
+    let generated = generate_fixture("boxed-child-with-required-fields", EmitterState::default());
+
+    let contents = generated.read("node.rs");
+    assert!(contents.starts_with(
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct Node {
+    pub name: String,
+    pub parent: Option<Box<crate::node::Node>>,
+}
+"
+    ));
+    // Optional boxed child can only be set through its (complete) builder.
+    assert!(contents.contains(
+        "
+    #[inline]
+    pub fn parent(mut self, value: crate::node::NodeBuilder<crate::generics::NameExists>) -> Self {
+        self.body.parent = Some(Box::new(value.into()));
+        self
+    }
+"
+    ));
+
+    let contents = generated.read("link.rs");
+    // Same goes for required boxed child.
+    assert!(contents.contains(
+        "
+    #[inline]
+    pub fn next(mut self, value: crate::link::LinkBuilder<crate::generics::NextExists, crate::generics::ValueExists>) -> LinkBuilder<crate::generics::NextExists, Value> {
+        self.body.next = Box::new(value.into());
+        crate::util::debug_assert_same_layout::<Self, LinkBuilder<crate::generics::NextExists, Value>>();
+        unsafe { std::mem::transmute(self) }
     }
+"
+    ));
 }
 
-/// Builder for [`GetShipmentsIdResponse`](./struct.GetShipmentsIdResponse.html) object.
-#[derive(Debug, Clone)]
-pub struct GetShipmentsIdResponseBuilder {
-    body: self::GetShipmentsIdResponse,
+#[test]
+fn test_tag_traits() {
+    let mut state = EmitterState::default();
+    state.tag_traits = true;
+    let generated = generate_fixture("tag-traits", state);
+
+    let contents = generated.read("mod.rs");
+    assert!(contents.contains(
+        "
+pub mod tags {
+    include!(\"./tags.rs\");
 }
+"
+    ));
 
-impl GetShipmentsIdResponseBuilder {
+    let contents = generated.read("tags.rs");
+    // Untagged operations go to the default trait.
+    assert!(contents.starts_with(
+        "
+/// Operations without any tags.
+pub trait DefaultApi {
+    fn delete(&self) -> crate::owner::OwnerDeleteBuilder;
+}
+
+impl<Client: crate::client::ApiClient> DefaultApi for Client {
     #[inline]
-    pub fn address(mut self, value: crate::get_shipments_id_response::GetShipmentsIdResponseAddress) -> Self {
-        self.body.address = Some(value.into());
-        self
+    fn delete(&self) -> crate::owner::OwnerDeleteBuilder {
+        crate::owner::Owner::delete()
+    }
+}
+"
+    ));
+    // Colliding method names are qualified with the object name.
+    assert!(contents.contains(
+        "
+/// Operations tagged with `pets`.
+pub trait PetsApi {
+    fn owner_get(&self) -> crate::owner::OwnerGetBuilder;
+
+    fn pet_get(&self) -> crate::pet::PetGetBuilder;
+
+    /// Adds a pet.
+    fn add_pet(&self) -> crate::pet::PetPostBuilder<crate::generics::MissingName>;
+}
+
+impl<Client: crate::client::ApiClient> PetsApi for Client {
+    #[inline]
+    fn owner_get(&self) -> crate::owner::OwnerGetBuilder {
+        crate::owner::Owner::get()
     }
 
     #[inline]
-    pub fn created_on(mut self, value: impl Into<String>) -> Self {
-        self.body.created_on = Some(value.into());
-        self
+    fn pet_get(&self) -> crate::pet::PetGetBuilder {
+        crate::pet::Pet::get()
     }
 
     #[inline]
-    pub fn order_id(mut self, value: impl Into<String>) -> Self {
-        self.body.order_id = Some(value.into());
+    fn add_pet(&self) -> crate::pet::PetPostBuilder<crate::generics::MissingName> {
+        crate::pet::Pet::add_pet()
+    }
+}
+"
+    ));
+    // Operations with multiple tags appear in all those traits.
+    assert!(contents.ends_with(
+        "
+/// Operations tagged with `store`.
+pub trait StoreApi {
+    /// Adds a pet.
+    fn add_pet(&self) -> crate::pet::PetPostBuilder<crate::generics::MissingName>;
+}
+
+impl<Client: crate::client::ApiClient> StoreApi for Client {
+    #[inline]
+    fn add_pet(&self) -> crate::pet::PetPostBuilder<crate::generics::MissingName> {
+        crate::pet::Pet::add_pet()
+    }
+}
+"
+    ));
+}
+
+#[test]
+fn test_custom_rust_types() {
+    let generated = generate_fixture("custom-rust-types", EmitterState::default());
+
+    // Custom types aren't generated.
+    assert!(!generated.dir.join("user_id.rs").exists());
+
+    let contents = generated.read("user.rs");
+    assert!(contents.starts_with(
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct User {
+    pub friends: Option<Vec<crate::ids::UserId>>,
+    pub id: crate::ids::UserId,
+}
+"
+    ));
+    // ... and they're treated like simple types in builders.
+    assert!(contents.contains(
+        "
+    /// Create this object with the fields required for constructing it.
+    #[inline]
+    pub fn new(id: impl Into<crate::ids::UserId>) -> Self {
+        Self::builder().id(id).build()
+    }
+"
+    ));
+    assert!(contents.contains(
+        "
+    #[inline]
+    pub fn friends(mut self, value: impl IntoIterator<Item = impl Into<crate::ids::UserId>>) -> Self {
+        self.body.friends = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
         self
     }
+"
+    ));
+    // Arrays in queries are still delimited.
+    assert!(contents.contains(
+        "
+#[derive(Debug, Clone)]
+pub struct UserGetBuilder {
+    param_ids: Option<crate::util::Delimited<crate::ids::UserId, crate::util::Csv>>,
+}
 
+impl UserGetBuilder {
     #[inline]
-    pub fn shipped_on(mut self, value: impl Into<String>) -> Self {
-        self.body.shipped_on = Some(value.into());
+    pub fn ids(mut self, value: impl IntoIterator<Item = impl Into<crate::ids::UserId>>) -> Self {
+        self.param_ids = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
         self
     }
+"
+    ));
 }
 
-/// Builder created by [`GetShipmentsIdResponse::get_shipment`](./struct.GetShipmentsIdResponse.html#method.get_shipment) method for a `GET` operation associated with `GetShipmentsIdResponse`.
-#[repr(transparent)]
-#[derive(Debug, Clone)]
-pub struct GetShipmentsIdResponseGetBuilder<Id> {
-    inner: GetShipmentsIdResponseGetBuilderContainer,
-    _param_id: core::marker::PhantomData<Id>,
+#[test]
+fn test_empty_responses() {
+    let generated = generate_fixture("empty-responses", EmitterState::default());
+
+    let contents = generated.read("pet.rs");
+
+    // Successful responses without schema (`200` or `204`) aren't decoded.
+    for builder in &["PetPostBuilder", "PetDeleteBuilder"] {
+        assert!(contents.contains(&format!(
+            "
+#[async_trait::async_trait]
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for {} {{
+    type Output = ();
+",
+            builder
+        )));
+    }
+
+    assert_eq!(
+        contents
+            .matches(
+                "
+    async fn accept_response(&self, resp: Client::Response) -> Result<(Self::Output, crate::client::RawResponse<Client::Response>), crate::client::ApiError<Client::Response>> {
+        use crate::client::Response;
+
+        let status = resp.status();
+        let (_, bytes) = resp.body_bytes().await?;
+        Ok(((), crate::client::RawResponse { status, body: crate::client::RawBody::Bytes(bytes) }))
+    }
+"
+            )
+            .count(),
+        2
+    );
+
+    // Responses with schema are decoded.
+    assert!(contents.contains(
+        "
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for PetPutBuilder {
+    type Output = crate::pet::Pet;
+"
+    ));
+
+    // ... and empty bodies are rejected for those.
+    let contents = generated.read("mod.rs");
+    assert!(contents.contains(
+        "
+        let status = resp.status();
+        if status == http::StatusCode::NO_CONTENT
+            || resp.header(http::header::CONTENT_LENGTH.as_str()).map(|v| v.trim() == \"0\").unwrap_or(false)
+        {
+            return Err(ApiError::EmptyResponse(status, Mutex::new(resp)));
+        }
+"
+    ));
 }
 
-#[derive(Debug, Default, Clone)]
-struct GetShipmentsIdResponseGetBuilderContainer {
-    param_id: Option<String>,
+#[test]
+fn test_builder_layout_assertions() {
+    let generated = generate_fixture("builder-layout-assertions", EmitterState::default());
+
+    let contents = generated.read("pet.rs");
+
+    // Layouts are checked (in debug builds) before transmuting builders
+    // (with the actual `Any` type, since it's part of the layout).
+    assert!(contents.contains(
+        "
+    #[inline]
+    pub fn id(mut self, value: impl Into<String>) -> PetPostBuilder<crate::generics::IdExists, Name, Any> {
+        self.inner.param_id = Some(value.into());
+        crate::util::debug_assert_same_layout::<Self, PetPostBuilder<crate::generics::IdExists, Name, Any>>();
+        let this = std::mem::ManuallyDrop::new(self);
+        unsafe { std::mem::transmute_copy(&*this) }
+    }
+"
+    ));
+    assert!(contents.contains(
+        "
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> PetPostBuilder<Id, crate::generics::NameExists, Any> {
+        self.inner.body.name = value.into();
+        crate::util::debug_assert_same_layout::<Self, PetPostBuilder<Id, crate::generics::NameExists, Any>>();
+        let this = std::mem::ManuallyDrop::new(self);
+        unsafe { std::mem::transmute_copy(&*this) }
+    }
+"
+    ));
+
+    // If the layouts differ, then the assertion fails with a message like:
+    //
+    // Builder `crate::pet::PetPostBuilder<crate::generics::MissingId, crate::generics::MissingName, serde_json::Value>`
+    // (size: 96, align: 8) cannot be transmuted into `crate::pet::PetPostBuilder<crate::generics::IdExists,
+    // crate::generics::MissingName, serde_json::Value>` (size: 104, align: 8).
+    let contents = generated.read("util.rs");
+    assert!(contents.contains(
+        "
+#[inline(always)]
+pub fn debug_assert_same_layout<T, U>() {
+    debug_assert!(
+        std::mem::size_of::<T>() == std::mem::size_of::<U>()
+            && std::mem::align_of::<T>() == std::mem::align_of::<U>(),
+        \"Builder `{}` (size: {}, align: {}) cannot be transmuted into `{}` (size: {}, align: {}).\",
+"
+    ));
+}
+
+#[test]
+fn test_default_any_value() {
+    let mut state = EmitterState::default();
+    state.any_value = Some("serde_json::Value".into());
+    let contents = generate_fixture("default-any-value", state).read("pet.rs");
+    // Responses are decoded using the YAML coder (based on spec format), but
+    // `Any` is substituted with the configured type.
+    assert!(contents.contains(
+        "
+impl<Client: crate::client::ApiClient + Sync + 'static, Any: serde::Serialize + Sync> crate::client::Sendable<Client> for PetPostBuilder<crate::generics::NameExists, Any> {
+    type Output = crate::pet::Pet<serde_json::Value>;
+"
+    ));
+    assert!(contents.contains(
+        "
+            serde_yaml::to_writer(&mut vec, body)?;
+"
+    ));
+}
+
+#[test]
+fn test_builder_reset() {
+    let contents = generate_fixture("builder-reset", EmitterState::default()).read("pet.rs");
+
+    // Builders can be configured once and then cloned and reset, so that
+    // only the required values should be supplied again. For example,
+    //
+    // let base = Pet::builder().tag("cat").name("Tom");
+    // let jerry = base.clone().reset().name("Jerry").build();
+    // let pet = base.clone().reset().build(); // won't compile.
+    assert!(contents.contains(
+        "
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
+    #[inline]
+    pub fn reset(self) -> PetBuilder<crate::generics::MissingName> {
+        crate::util::debug_assert_same_layout::<Self, PetBuilder<crate::generics::MissingName>>();
+        unsafe { std::mem::transmute(self) }
+    }
 }
+"
+    ));
 
-impl<Id> GetShipmentsIdResponseGetBuilder<Id> {
+    // The method is renamed if it collides with a parameter.
+    assert!(contents.contains(
+        "
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
     #[inline]
-    pub fn id(mut self, value: impl Into<String>) -> GetShipmentsIdResponseGetBuilder<crate::generics::IdExists> {
-        self.inner.param_id = Some(value.into());
+    pub fn reset_builder(self) -> PetPostBuilder<crate::generics::MissingId, crate::generics::MissingName> {
+        crate::util::debug_assert_same_layout::<Self, PetPostBuilder<crate::generics::MissingId, crate::generics::MissingName>>();
         unsafe { std::mem::transmute(self) }
     }
+"
+    ));
+    assert!(contents.contains(
+        "
+    #[inline]
+    pub fn reset(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_reset = Some(value.into());
+        self
+    }
+"
+    ));
 }
 
-impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for GetShipmentsIdResponseGetBuilder<crate::generics::IdExists> {
-    type Output = GetShipmentsIdResponse;
+#[test]
+fn test_transparent_newtypes() {
+    let mut state = EmitterState::default();
+    state.newtypes = true;
+    let generated = generate_fixture("transparent-newtypes", state);
 
-    const METHOD: http::Method = http::Method::GET;
+    // Named definitions of simple types are wrapped in newtypes.
+    assert!(generated.read("pet_name.rs").starts_with(
+        "
+/// Name of a pet.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct PetName(pub String);
 
-    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
-        format!(\"/shipments/{id}\", id=self.inner.param_id.as_ref().expect(\"missing parameter id?\")).into()
+impl From<String> for PetName {
+    fn from(value: String) -> Self {
+        PetName(value)
     }
 }
+"
+    ));
 
-impl GetShipmentsIdResponseAddress {
-    /// Create a builder for this object.
-    #[inline]
-    pub fn builder() -> GetShipmentsIdResponseAddressBuilder {
-        GetShipmentsIdResponseAddressBuilder {
-            body: Default::default(),
-        }
-    }
+    let contents = generated.read("pet.rs");
+    assert!(contents.starts_with(
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Pet {
+    pub name: Option<crate::pet_name::PetName>,
+    pub owner: Option<crate::owner::Owner>,
 }
+"
+    ));
 
-impl Into<GetShipmentsIdResponseAddress> for GetShipmentsIdResponseAddressBuilder {
-    fn into(self) -> GetShipmentsIdResponseAddress {
-        self.body
-    }
+    // Objects (even with a single property) are still structs.
+    assert!(generated.read("owner.rs").starts_with(
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Owner {
+    pub name: Option<String>,
+}
+"
+    ));
 }
 
-/// Builder for [`GetShipmentsIdResponseAddress`](./struct.GetShipmentsIdResponseAddress.html) object.
-#[derive(Debug, Clone)]
-pub struct GetShipmentsIdResponseAddressBuilder {
-    body: self::GetShipmentsIdResponseAddress,
+#[test]
+fn test_fixtures_from_examples() {
+    let mut state = EmitterState::default();
+    state.fixtures = true;
+    let generated = generate_fixture("fixtures-from-examples", state);
+
+    assert!(generated.read("mod.rs").contains(
+        "
+pub mod fixtures {
+    include!(\"./fixtures.rs\");
 }
+"
+    ));
 
-impl GetShipmentsIdResponseAddressBuilder {
-    #[inline]
-    pub fn code(mut self, value: impl Into<String>) -> Self {
-        self.body.code = Some(value.into());
-        self
+    // Examples which don't match the schema (`Owner` lacks `name`) are skipped.
+    assert_eq!(
+        generated.read("fixtures.rs"),
+        "
+impl crate::pet::Pet {
+    /// Returns the example for this object (from the spec).
+    pub fn example() -> Self {
+        serde_json::from_str(\"{\\\"name\\\":\\\"Tom\\\",\\\"tags\\\":[\\\"cat\\\"]}\").expect(\"invalid example?\")
     }
+}
+"
+    );
+}
 
-    #[inline]
-    pub fn line1(mut self, value: impl Into<String>) -> Self {
-        self.body.line1 = Some(value.into());
-        self
-    }
+#[test]
+fn test_array_body() {
+    let contents = generate_fixture("array-body", EmitterState::default()).read("status.rs");
 
+    // Array bodies are required parameters (with `Any` replaced by the
+    // coder's type, since the builder isn't generic over it).
+    assert!(contents.contains(
+        "
+struct StatusPostBuilderContainer {
+    param_body: Option<Vec<crate::pet::Pet<serde_yaml::Value>>>,
+}
+"
+    ));
+    assert!(contents.contains(
+        "
     #[inline]
-    pub fn line2(mut self, value: impl Into<String>) -> Self {
-        self.body.line2 = Some(value.into());
-        self
+    pub fn body(mut self, value: impl IntoIterator<Item = crate::pet::Pet<serde_yaml::Value>>) -> StatusPostBuilder<crate::generics::BodyExists> {
+        self.inner.param_body = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
+"
+    ));
+
+    // ... which are encoded as they are (i.e., as bare arrays).
+    assert!(contents.contains(
+        "
+    fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        let req = client.set_body(req, {
+            let mut vec = vec![];
+            serde_yaml::to_writer(&mut vec, self.inner.param_body.as_ref().expect(\"missing parameter body?\"))?;
+            vec
+        })?;
+        Ok(req
+        .header(http::header::CONTENT_TYPE.as_str(), \"application/yaml\"))
     }
+"
+    ));
+}
 
+#[test]
+fn test_borrowed_body() {
+    let contents = generate_fixture("borrowed-body", EmitterState::default()).read("pet.rs");
+
+    // Large (or reused) bodies can be sent by reference, without moving them
+    // into builders. `Any` is inferred from the body. For example,
+    //
+    // let pet = Pet::builder().name("Tom").extra(value).build();
+    // let resp = Pet::put().id("1").with_body(&pet).send(&client).await?;
+    // println!("{}", pet.name); // `pet` is still usable.
+    assert!(contents.contains(
+        "
+    /// Sends the given body for this API call (instead of the one in this builder),
+    /// without moving or cloning it. The body fields needn't be set in that case.
     #[inline]
-    pub fn name(mut self, value: impl Into<String>) -> Self {
-        self.body.name = Some(value.into());
-        self
+    pub fn with_body(self, body: &self::Pet<Any>) -> crate::client::BorrowedBody<'_, PetPutBuilder<Id, crate::generics::NameExists, Any>, self::Pet<Any>> {
+        let builder = {
+            crate::util::debug_assert_same_layout::<Self, PetPutBuilder<Id, crate::generics::NameExists, Any>>();
+            let this = std::mem::ManuallyDrop::new(self);
+            unsafe { std::mem::transmute_copy(&*this) }
+        };
+        crate::client::BorrowedBody::new(builder, body)
     }
 }
-",
-        Some(0),
-    );
+"
+    ));
+    // The borrowed body is encoded in the same way as the builder's body.
+    assert!(contents.contains(
+        "
+    fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        self.modify_with_body(client, req, &self.inner.body)
+    }
+
+    fn modify_with_body<B: serde::Serialize + Sync>(&self, client: &Client, req: Client::Request, body: &B) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        let req = client.set_body(req, {
+            let mut vec = vec![];
+            serde_yaml::to_writer(&mut vec, body)?;
+            vec
+        })?;
+"
+    ));
 }
 
 #[test]
-fn test_simple_array_parameter_in_path() {
-    assert_file_contains_content_at(
-        &(ROOT.clone() + "/tests/test_pet/status.rs"),
+fn test_responses_by_status() {
+    let generate = |split_statuses: bool| {
+        let mut state = EmitterState::default();
+        state.split_statuses = split_statuses;
+        generate_fixture("responses-by-status", state).read("pet.rs")
+    };
+
+    // Successful responses with different schemas are decoded into an enum
+    // based on their status codes. Responses sharing the same schema are
+    // collapsed into one variant by default. The enum is suffixed with a number,
+    // since its name has been taken (by the enum for `response` parameter here).
+    let contents = generate(false);
+    assert!(contents.contains(
         "
-/// Builder created by [`Status::delete`](./struct.Status.html#method.delete) method for a `DELETE` operation associated with `Status`.
-#[repr(transparent)]
+pub enum PetPostResponse {
+    #[serde(rename = \"full\")]
+    Full,
+"
+    ));
+    assert!(contents.contains(
+        "
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for PetPostBuilder {
+    type Output = self::PetPostResponse1;
+"
+    ));
+    // For example, a `201 Created` response with `{"name": "Fido"}` as its body
+    // is decoded as `PetPostResponse1::Ok(Pet { name: Some("Fido".into()) })`.
+    assert!(contents.contains(
+        "
+/// Successful responses of the `POST /pets` operation (based on their status codes).
+/// Other successful responses are decoded as the first variant.
 #[derive(Debug, Clone)]
-pub struct StatusDeleteBuilder<PetId> {
-    inner: StatusDeleteBuilderContainer,
-    _param_pet_id: core::marker::PhantomData<PetId>,
+pub enum PetPostResponse1 {
+    /// `200 OK`, `201 Created`
+    Ok(crate::pet::Pet),
+    /// `202 Accepted`
+    Accepted(crate::job::Job),
+    /// `204 No Content`
+    NoContent,
 }
 
-#[derive(Debug, Default, Clone)]
-struct StatusDeleteBuilderContainer {
-    param_pet_id: Option<crate::util::Delimited<i64, crate::util::Csv>>,
+#[async_trait::async_trait]
+impl crate::client::FromResponse for PetPostResponse1 {
+    async fn from_response<R: crate::client::Response + 'static>(resp: R, fallback_range: Option<&'static str>) -> Result<(Self, crate::client::RawResponse<R>), crate::client::ApiError<R>> {
+        let status = resp.status();
+        match status.as_u16() {
+            202 => crate::client::decode_response(resp, fallback_range).await
+                .map(|(v, raw)| (PetPostResponse1::Accepted(v), raw)),
+            204 => {
+                let (_, bytes) = resp.body_bytes().await?;
+                Ok((PetPostResponse1::NoContent, crate::client::RawResponse { status, body: crate::client::RawBody::Bytes(bytes) }))
+            },
+            _ => crate::client::decode_response(resp, fallback_range).await
+                .map(|(v, raw)| (PetPostResponse1::Ok(v), raw)),
+        }
+    }
 }
+"
+    ));
 
-impl<PetId> StatusDeleteBuilder<PetId> {
+    // ... or they can have separate variants. Then, the same response is
+    // decoded as `PetPostResponse1::Created(Pet { name: Some("Fido".into()) })`.
+    let contents = generate(true);
+    assert!(contents.contains(
+        "
+            201 => crate::client::decode_response(resp, fallback_range).await
+                .map(|(v, raw)| (PetPostResponse1::Created(v), raw)),
+"
+    ));
+    assert!(contents.contains(
+        "
+pub enum PetPostResponse1 {
+    /// `200 OK`
+    Ok(crate::pet::Pet),
+    /// `201 Created`
+    Created(crate::pet::Pet),
+    /// `202 Accepted`
+    Accepted(crate::job::Job),
+    /// `204 No Content`
+    NoContent,
+}
+"
+    ));
+}
+
+#[test]
+fn test_container_default_for_optional_objects() {
+    let generated = generate_fixture(
+        "container-default-for-optional-objects",
+        EmitterState::default(),
+    );
+
+    // Objects with only optional fields (and flattened maps) use the derived
+    // `Default` for missing fields, so that `{}` decodes into all `None`s
+    // (and an empty map).
+    assert!(generated.read("label.rs").starts_with(
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Label {
+    pub color: Option<String>,
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub additional_properties: std::collections::BTreeMap<String, String>,
+}
+"
+    ));
+    // Others don't.
+    assert!(generated.read("pet.rs").starts_with(
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct Pet {"
+    ));
+}
+
+#[test]
+fn test_dry_run() {
+    let generated = generate_fixture("dry-run", EmitterState::default());
+
+    let contents = generated.read("pet.rs");
+
+    // Complete builders can be checked without a client (and without network I/O).
+    // For example,
+    //
+    // let req = Pet::get().id("1").limit(5).x_trace("abc").dry_run()?;
+    // assert_eq!(req.url, "https://pets.example.com/api/pets/1?limit=5");
+    // assert_eq!(req.headers, vec![("X-Trace".into(), "abc".into())]);
+    assert!(contents.contains(
+        "
+    /// Returns the request for this API call without sending it (i.e., without any network I/O).
     #[inline]
-    pub fn pet_id(mut self, value: impl Iterator<Item = impl Into<i64>>) -> StatusDeleteBuilder<crate::generics::PetIdExists> {
-        self.inner.param_pet_id = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
-        unsafe { std::mem::transmute(self) }
+    pub fn dry_run(&self) -> Result<crate::client::DryRunRequest, crate::client::ApiError<reqwest::r#async::Response>>
+    where
+        Self: crate::client::Sendable<crate::client::DryRunClient>,
+    {
+        crate::client::dry_run(self)
     }
 }
+"
+    ));
+    // URL is resolved from the path and query parameters and headers are set
+    // in the same way as live API calls.
+    assert!(contents.contains(
+        "
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        format!(\"/pets/{id}\", id=crate::util::encode_path_param(&self.inner.param_id.as_ref().expect(\"missing parameter id?\").to_string())).into()
+    }
 
-impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for StatusDeleteBuilder<crate::generics::PetIdExists> {
-    type Output = Status;
+    fn empty_path_param(&self) -> Option<&'static str> {
+        if self.inner.param_id.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {
+            return Some(\"id\");
+        }
 
-    const METHOD: http::Method = http::Method::DELETE;
+        None
+    }
 
-    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
-        format!(\"/pets/{petId}\", petId=self.inner.param_pet_id.as_ref().expect(\"missing parameter pet_id?\")).into()
+    fn modify(&self, _client: &Client, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        use crate::client::Request;
+        let mut req = req;
+        if let Some(v) = &self.inner.param_x_trace.as_ref().map(std::string::ToString::to_string) {
+            req = req.header(\"X-Trace\", &v);
+        }
+
+        Ok(req
+        .query(&[
+            (\"limit\", self.inner.param_limit.as_ref().map(std::string::ToString::to_string))
+        ]))
     }
+"
+    ));
+
+    let contents = generated.read("mod.rs");
+
+    assert!(contents.contains(
+        "
+    pub const BASE_URL: &str = \"https://pets.example.com/api\";
+"
+    ));
+    assert!(contents.contains(
+        "
+        fn request_builder(&self, method: http::Method, rel_path: &str) -> Self::Request {
+            let mut u = String::from(BASE_URL.trim_end_matches('/'));
+            u.push('/');
+            u.push_str(rel_path.trim_start_matches('/'));
+            DryRunRequest { method, url: u, headers: vec![], body: None, form: None, json_error: None }
+        }
+
+        fn request_builder_for_url(&self, method: http::Method, url: &str) -> Option<Self::Request> {
+            Some(DryRunRequest { method, url: url.into(), headers: vec![], body: None, form: None, json_error: None })
+        }
+"
+    ));
+    // Empty path parameters and unencodable JSON bodies fail the dry run (like live API calls).
+    assert!(contents.contains(
+        "
+        if let Some(name) = sendable.empty_path_param() {
+            return Err(ApiError::EmptyPathParameter(name))
+        }
+
+        let client = DryRunClient;
+        let req = client.request_builder(S::METHOD, &sendable.rel_path());
+        let mut req = sendable.modify(&client, req)?;
+        match req.json_error.take() {
+            Some(e) => Err(ApiError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e))),
+            None => Ok(req),
+        }
+"
+    ));
+    // Multipart forms describe the files without reading them.
+    assert!(contents.contains(
+        "
+        fn file<K>(mut self, key: K, path: &Path) -> std::io::Result<Self>
+            where K: Into<Cow<'static, str>>
+        {
+            self.parts.push((key.into().into_owned(), DryRunPart::File(path.into())));
+            Ok(self)
+        }
+"
+    ));
 }
+
+#[test]
+fn test_interop_adapters() {
+    // Assembled requests can be converted into the request types of other libraries
+    // (behind features). Method, URL and headers carry over. For example,
+    //
+    // let req = Pet::get().id("1").limit(5).dry_run()?;
+    // let req = http::Request::<Vec<u8>>::try_from(req)?;
+    // assert_eq!(req.uri(), "https://pets.com:8888/api/pets/1?limit=5");
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+        #[cfg(feature = \"interop-http\")]
+        impl std::convert::TryFrom<DryRunRequest> for http::Request<Vec<u8>> {
+            type Error = ApiError<reqwest::r#async::Response>;
+
+            fn try_from(req: DryRunRequest) -> Result<Self, Self::Error> {
+                if req.form.is_some() {
+                    return Err(ApiError::UnsupportedContentType(\"multipart/form-data\".into()))
+                }
+
+                let mut r = http::Request::new(req.body.unwrap_or_default());
+                let url = req.url;
+                *r.method_mut() = req.method;
+                *r.uri_mut() = url.parse().map_err(|_| ApiError::InvalidUrl(url.clone()))?;
+                for (name, value) in req.headers {
+                    match (
+                        http::header::HeaderName::from_bytes(name.as_bytes()),
+                        http::header::HeaderValue::from_str(&value),
+                    ) {
+                        (Ok(n), Ok(v)) => {
+                            r.headers_mut().append(n, v);
+                        },
+                        _ => return Err(ApiError::InvalidHeader(name)),
+                    }
+                }
+
+                Ok(r)
+            }
+        }
 ",
-        Some(1285),
+        None,
     );
-}
 
-#[test]
-fn test_nested_arrays() {
-    // It's in miscellaneous because there's no body parameter or response body.
+    // Multipart forms are converted for `reqwest` (which streams the files).
     assert_file_contains_content_at(
-        &(ROOT.clone() + "/tests/test_pet/miscellaneous.rs"),
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
         "
-/// Builder created by [`Miscellaneous::get`](./struct.Miscellaneous.html#method.get) method for a `GET` operation associated with `Miscellaneous`.
-#[derive(Debug, Clone)]
-pub struct MiscellaneousGetBuilder;
+            #[cfg(feature = \"interop-reqwest\")]
+            pub fn into_reqwest(self, client: &reqwest::r#async::Client) -> Result<reqwest::r#async::RequestBuilder, ApiError<reqwest::r#async::Response>> {
+                let mut builder = client.request(self.method, self.url.as_str());
+                for (name, value) in &self.headers {
+                    builder = builder.header(name.as_str(), value.as_str());
+                }
 
+                if let Some(body) = self.body {
+                    builder = builder.body(body);
+                }
 
-impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for MiscellaneousGetBuilder {
-    type Output = Vec<Vec<crate::test_nested_array_with_object::TestNestedArrayWithObjectItemItem>>;
+                if let Some(form) = self.form {
+                    let mut f = <reqwest::r#async::multipart::Form as Form>::new();
+                    for (key, part) in form.parts {
+                        f = match part {
+                            DryRunPart::Text(v) => Form::text(f, key, v),
+                            DryRunPart::File(path) => Form::file(f, key, &path)?,
+                        };
+                    }
 
-    const METHOD: http::Method = http::Method::GET;
+                    builder = builder.multipart(f);
+                }
 
-    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
-        \"/test/array\".into()
-    }
-}
+                Ok(builder)
+            }
 ",
-        Some(626),
+        None,
     );
+}
 
-    assert_file_contains_content_at(
-        &(ROOT.clone() + "/tests/test_pet/miscellaneous.rs"),
+#[test]
+fn test_custom_delimiters() {
+    let generated = generate_fixture("custom-delimiters", EmitterState::default());
+
+    let contents = generated.read("pet.rs");
+
+    // Values are joined using the custom delimiters. For example, `tags(vec!["a", "b"])`
+    // and `ranges(vec![vec![1, 2], vec![3]])` result in `?tags=a;b&ranges=1::2|3`.
+    assert!(contents.contains(
         "
-/// Builder created by [`Miscellaneous::post_2`](./struct.Miscellaneous.html#method.post_2) method for a `POST` operation associated with `Miscellaneous`.
-#[repr(transparent)]
-#[derive(Debug, Clone)]
-pub struct MiscellaneousPostBuilder2<Values> {
-    inner: MiscellaneousPostBuilder2Container,
-    _param_values: core::marker::PhantomData<Values>,
+    param_tags: Option<crate::util::Delimited<String, crate::util::Custom3B>>,
+    param_ranges: Option<crate::util::Delimited<crate::util::Delimited<i64, crate::util::Custom3A3A>, crate::util::Pipes>>,
+"
+    ));
+
+    let contents = generated.read("util.rs");
+
+    assert!(contents.contains(
+        "
+// NOTE: We use ampersand only for convenience.
+impl_delim!(Multi => \"&\");
+
+// Custom delimiter (`x-delimiter`). Values containing the delimiter aren't escaped.
+impl_delim!(Custom3A3A => \"::\");
+impl Allowed for Custom3A3A {}
+
+// Custom delimiter (`x-delimiter`). Values containing the delimiter aren't escaped.
+impl_delim!(Custom3B => \";\");
+impl Allowed for Custom3B {}
+"
+    ));
 }
 
-#[derive(Debug, Default, Clone)]
-struct MiscellaneousPostBuilder2Container {
-    param_values: Option<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<String, crate::util::Pipes>, crate::util::Csv>, crate::util::Ssv>, crate::util::Tsv>>,
-    param_x_foobar: Option<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<f64, crate::util::Ssv>, crate::util::Tsv>, crate::util::Csv>, crate::util::Pipes>>,
-    param_booya: Option<crate::util::Delimited<crate::util::Delimited<i64, crate::util::Csv>, crate::util::Multi>>,
-    param_foo: Option<crate::util::Delimited<crate::util::Delimited<String, crate::util::Csv>, crate::util::Multi>>,
+#[test]
+fn test_enum_query_parameters_with_defaults() {
+    let generate = |send_defaults: bool| {
+        let mut state = EmitterState::default();
+        state.send_defaults = send_defaults;
+        generate_fixture("enum-query-parameters-with-defaults", state).read("pet.rs")
+    };
+
+    let contents = generate(false);
+    // Enums are generated for parameters (and the default value is the default variant).
+    assert!(contents.contains(
+        "
+/// Values for `status` parameter of `GET` operation in `/pets`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum PetGetStatus {
+    #[serde(rename = \"available\")]
+    Available,
+    #[serde(rename = \"pending\")]
+    Pending,
+    #[serde(rename = \"sold out\")]
+    SoldOut,
 }
 
-impl<Values> MiscellaneousPostBuilder2<Values> {
-    #[inline]
-    pub fn values(mut self, value: impl Iterator<Item = impl Iterator<Item = impl Iterator<Item = impl Iterator<Item = impl Into<String>>>>>) -> MiscellaneousPostBuilder2<crate::generics::ValuesExists> {
-        self.inner.param_values = Some(value.map(|value| value.map(|value| value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
-        unsafe { std::mem::transmute(self) }
+impl Default for PetGetStatus {
+    fn default() -> Self {
+        PetGetStatus::Pending
     }
+}
 
-    #[inline]
-    pub fn x_foobar(mut self, value: impl Iterator<Item = impl Iterator<Item = impl Iterator<Item = impl Iterator<Item = impl Into<f64>>>>>) -> Self {
-        self.inner.param_x_foobar = Some(value.map(|value| value.map(|value| value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
-        self
+impl std::fmt::Display for PetGetStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            PetGetStatus::Available => \"available\",
+            PetGetStatus::Pending => \"pending\",
+            PetGetStatus::SoldOut => \"sold out\",
+        })
     }
+}
 
-    #[inline]
-    pub fn booya(mut self, value: impl Iterator<Item = impl Iterator<Item = impl Into<i64>>>) -> Self {
-        self.inner.param_booya = Some(value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
-        self
-    }
+impl std::str::FromStr for PetGetStatus {
+    type Err = String;
 
-    #[inline]
-    pub fn foo(mut self, value: impl Iterator<Item = impl Iterator<Item = impl Into<String>>>) -> Self {
-        self.inner.param_foo = Some(value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
-        self
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            \"available\" => Ok(PetGetStatus::Available),
+            \"pending\" => Ok(PetGetStatus::Pending),
+            \"sold out\" => Ok(PetGetStatus::SoldOut),
+            _ => Err(format!(\"unknown variant `{}` for PetGetStatus\", s)),
+        }
+    }
+}
+"
+    ));
+    assert!(contents.contains(
+        "
+impl std::fmt::Display for PetGetSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            PetGetSort::V1 => \"1\",
+            PetGetSort::VMinus1 => \"-1\",
+        })
     }
 }
+"
+    ));
+    // Parameters with defaults aren't required by builders.
+    assert!(contents.contains(
+        "
+pub struct PetGetBuilder {
+    param_status: Option<crate::pet::PetGetStatus>,
+    param_sort: Option<crate::pet::PetGetSort>,
+}
+"
+    ));
+    // Unset parameters are omitted (and explicitly set values are always sent, even
+    // if they're the defaults).
+    assert!(contents.contains(
+        "
+        Ok(req
+        .query(&[
+            (\"status\", self.param_status.as_ref().map(std::string::ToString::to_string)),
+            (\"sort\", self.param_sort.as_ref().map(std::string::ToString::to_string))
+        ]))
+"
+    ));
+
+    // Defaults can be sent for unset parameters.
+    let contents = generate(true);
+    assert!(contents.contains(
+        "
+        Ok(req
+        .query(&[
+            (\"status\", Some(self.param_status.unwrap_or_default().to_string())),
+            (\"sort\", self.param_sort.as_ref().map(std::string::ToString::to_string))
+        ]))
+"
+    ));
+}
 
-impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for MiscellaneousPostBuilder2<crate::generics::ValuesExists> {
-    type Output = String;
+#[test]
+fn test_env_client() {
+    let mut state = EmitterState::default();
+    state.env_prefix = Some("PETS".into());
+    let generated = generate_fixture("env-client", state);
 
-    const METHOD: http::Method = http::Method::POST;
+    let contents = generated.read("mod.rs");
 
-    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
-        format!(\"/test/parameter/{values}\", values=self.inner.param_values.as_ref().expect(\"missing parameter values?\")).into()
+    // Variables are qualified with scheme names when there are multiple schemes
+    // of the same kind. Credentials of schemes in all global requirements are
+    // required, and unsupported schemes (basic) are skipped.
+    assert!(contents.contains(
+        "
+    /// Prefix of the environment variables read by [`EnvClient::from_env`](struct.EnvClient.html#method.from_env).
+    pub const ENV_PREFIX: &str = \"PETS\";
+"
+    ));
+    assert!(contents.contains(
+        "
+    const ENV_CREDENTIALS: &[(&str, &str, CredentialLocation, bool)] = &[
+        (\"headerKey\", \"PETS_HEADER_KEY_API_KEY\", CredentialLocation::Header(\"X-API-Key\"), false),
+        (\"oauth\", \"PETS_ACCESS_TOKEN\", CredentialLocation::Bearer, false),
+        (\"queryKey\", \"PETS_QUERY_KEY_API_KEY\", CredentialLocation::Query(\"api_key\"), true),
+    ];
+"
+    ));
+    // For example,
+    //
+    // std::env::set_var("PETS_BASE_URL", "http://localhost:8000/api");
+    // std::env::set_var("PETS_QUERY_KEY_API_KEY", "secret");
+    // let client = EnvClient::from_env()?;
+    // assert_eq!(client.base_url(), "http://localhost:8000/api");
+    // assert_eq!(client.auth_scheme().as_deref(), Some("queryKey"));
+    //
+    // The `reqwest` client doesn't follow redirects by itself, so that (header)
+    // credentials aren't sent to other origins.
+    assert!(contents.contains(
+        "
+    impl EnvClient<reqwest::r#async::Client> {
+        /// Creates a `reqwest` client configured from environment variables.
+        /// See [`from_env_with`](#method.from_env_with) for the variables.
+        ///
+        /// The `reqwest` client doesn't follow redirects by itself (they're followed
+        /// based on the [`RedirectPolicy`](struct.RedirectPolicy.html)), so that
+        /// the credentials are never sent to other origins.
+        pub fn from_env() -> Result<Self, EnvError> {
+            let client = reqwest::r#async::Client::builder()
+                .redirect(reqwest::RedirectPolicy::none())
+                .build()
+                .map_err(EnvError::Reqwest)?;
+            Self::from_env_with(client)
+        }
+    }
+
+    impl<C> EnvClient<C> {
+        /// Wraps the given client with the configuration obtained from these
+        /// environment variables (empty values are treated as unset):
+        ///
+        /// - `PETS_BASE_URL` for the base URL (defaults to [`BASE_URL`](constant.BASE_URL.html)).
+        /// - `PETS_HEADER_KEY_API_KEY` for `headerKey` security scheme.
+        /// - `PETS_ACCESS_TOKEN` for `oauth` security scheme.
+        /// - `PETS_QUERY_KEY_API_KEY` for `queryKey` security scheme (required).
+        ///
+        /// **NOTE:** The given client shouldn't follow redirects by itself. Otherwise,
+        /// the credentials in headers will be sent to the redirected locations as well.
+        pub fn from_env_with(client: C) -> Result<Self, EnvError> {
+            Self::from_env_shared(Arc::new(client))
+        }
+"
+    ));
+    // Clients generated from different specs can share a `reqwest` client (and its
+    // connection pool), while using their own base URL and credentials. For example,
+    //
+    // let transport = Arc::new(reqwest::r#async::Client::new());
+    // let pets = pets::client::EnvClient::from_env_shared(transport.clone())?;
+    // let stores = stores::client::EnvClient::from_env_shared(transport.clone())?;
+    // assert!(Arc::ptr_eq(pets.inner(), stores.inner()));
+    assert!(contents.contains(
+        "
+        /// Same as [`from_env_with`](#method.from_env_with), but uses a client shared
+        /// with other API clients (and hence, its connection pool). The base URL and
+        /// credentials are still specific to this client.
+        pub fn from_env_shared(client: Arc<C>) -> Result<Self, EnvError> {
+            let var = \"PETS_BASE_URL\";
+            let base_url = match env_var(var)? {
+                Some(u) => {
+                    url::Url::parse(&u).map_err(|e| EnvError::InvalidUrl(var, e.to_string()))?;
+                    u
+                },
+                None => BASE_URL.into(),
+            };
+"
+    ));
+    // Missing required variables are errors (instead of panics).
+    //
+    // std::env::remove_var("PETS_QUERY_KEY_API_KEY");
+    // let err = EnvClient::from_env().unwrap_err();
+    // assert_eq!(err.to_string(), "Missing environment variable: PETS_QUERY_KEY_API_KEY");
+    assert!(contents.contains(
+        "
+            let mut credentials = vec![];
+            for &(scheme, var, location, required) in ENV_CREDENTIALS {
+                match env_var(var)? {
+                    Some(value) => credentials.push((scheme, location, value)),
+                    None if required => return Err(EnvError::Missing(var)),
+                    None => (),
+                }
+            }
+
+            Ok(EnvClient {
+                inner: client,
+                base_url: base_url.into(),
+                credentials: credentials.into(),
+            })
+        }
+
+        /// Returns the wrapped client.
+        pub fn inner(&self) -> &Arc<C> {
+            &self.inner
+        }
     }
+"
+    ));
+    // Bodies are still set by the inner client (compression, for instance).
+    assert!(contents.contains(
+        "
+        fn set_body(&self, req: Self::Request, body: Vec<u8>) -> Result<Self::Request, ApiError<Self::Response>> {
+            self.inner.set_body(req, body)
+        }
 
-    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
-        use crate::client::Request;
-        let mut req = req;
-        if let Some(v) = &self.inner.param_x_foobar.as_ref().map(std::string::ToString::to_string) {
-            req = req.header(\"X-foobar\", &v);
+        fn base_url(&self) -> Cow<'_, str> {
+            (*self.base_url).into()
         }
 
-        Ok(req
-        .body_bytes({
-            let mut ser = url::form_urlencoded::Serializer::new(String::new());
-            if let Some(stuff) = self.inner.param_booya.as_ref() {
-                for v in stuff.iter() {
-                    ser.append_pair(\"booya\", &v.to_string());
-                }
+        fn auth_scheme(&self) -> Option<Cow<'_, str>> {
+            match self.credentials.first() {
+"
+    ));
+    // Query credentials are appended to the query parameters already in the relative
+    // path. For example, `tags(vec!["a", "b"])` results in `/pets?tags=a,b&api_key=secret`.
+    assert!(contents.contains(
+        "
+            let query = query.finish();
+            if !query.is_empty() {
+                // Relative paths already have query parameters for some operations.
+                u.push(if u.contains('?') { '&' } else { '?' });
+                u.push_str(&query);
             }
-            ser.finish().into_bytes()
-        })
-        .header(http::header::CONTENT_TYPE.as_str(), \"application/x-www-form-urlencoded\")
-        .query({
-            &self.inner.param_foo.as_ref().map(|v| {
-                v.iter().map(|v| (\"foo\", v.to_string())).collect::<Vec<_>>()
-            }).unwrap_or_default()
-        }))
+"
+    ));
+
+    let contents = generated.read("pet.rs");
+    assert!(contents.contains(
+        "
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        let mut path = String::from(\"/pets\");
+        crate::util::append_query(&mut path, &[
+            (\"tags\", self.param_tags.as_ref().map(|v| v.encode_with(crate::util::encode_query_value))),
+        ]);
+        path.into()
     }
-}
-",
-        Some(2085),
-    );
+"
+    ));
 }
 
 #[test]
-fn test_builder_from_args_with_delimited() {
-    let _ = &*CLI_CODEGEN;
-
-    assert_file_contains_content_at(
-        &(ROOT.clone() + "/tests/test_pet/cli/miscellaneous.rs"),
+fn test_env_client_default_prefix() {
+    let contents =
+        generate_fixture("env-client-default-prefix", EmitterState::default()).read("mod.rs");
+
+    // Prefix is derived from the title and credentials are optional
+    // when there are no global requirements.
+    assert!(contents.contains("pub const ENV_PREFIX: &str = \"PET_STORE\";"));
+    assert!(contents.contains(
         "
-#[allow(unused_variables)]
-impl MiscellaneousPostBuilder2<crate::generics::ValuesExists> {
-    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
-        let thing = MiscellaneousPostBuilder2 {
-            inner: MiscellaneousPostBuilder2Container {
-            param_values: matches.and_then(|m| {
-                    m.value_of(\"values\").map(|_| {
-                        value_t!(m, \"values\", crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<String, crate::util::Pipes>, crate::util::Csv>, crate::util::Ssv>, crate::util::Tsv>).unwrap_or_else(|e| e.exit())
-                    })
-                }),
+    const ENV_CREDENTIALS: &[(&str, &str, CredentialLocation, bool)] = &[
+        (\"apiKey\", \"PET_STORE_API_KEY\", CredentialLocation::Header(\"X-API-Key\"), false),
+    ];
+"
+    ));
+}
 
-            param_x_foobar: matches.and_then(|m| {
-                    m.value_of(\"x-foobar\").map(|_| {
-                        value_t!(m, \"x-foobar\", crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<crate::util::Delimited<f64, crate::util::Ssv>, crate::util::Tsv>, crate::util::Csv>, crate::util::Pipes>).unwrap_or_else(|e| e.exit())
-                    })
-                }),
+#[test]
+fn test_not_validators() {
+    let generated = generate_fixture("not-validators", EmitterState::default());
 
-            param_booya: matches.and_then(|m| {
-                    m.value_of(\"booya\").map(|_| {
-                        value_t!(m, \"booya\", crate::util::Delimited<crate::util::Delimited<i64, crate::util::Csv>, crate::util::Multi>).unwrap_or_else(|e| e.exit())
-                    })
-                }),
+    let contents = generated.read("pet.rs");
 
-            param_foo: matches.and_then(|m| {
-                    m.value_of(\"foo\").map(|_| {
-                        value_t!(m, \"foo\", crate::util::Delimited<crate::util::Delimited<String, crate::util::Csv>, crate::util::Multi>).unwrap_or_else(|e| e.exit())
-                    })
-                }),
+    // Values matching `not` subschemas are rejected, while unsupported
+    // subschemas (like `type`) are skipped.
+    assert!(contents.contains(
+        "
+impl Pet {
+    /// Checks the values of this object against the `not` constraints in its schema.
+    pub fn validate(&self) -> Result<(), crate::util::ValidationError> {
+        crate::util::check_not(\"name\", &self.name, &[\"\\\"forbidden\\\"\"])?;
+        crate::util::check_not(\"status\", &self.status, &[\"\\\"deleted\\\"\", \"\\\"archived\\\"\"])?;
+        Ok(())
+    }
+}
+"
+    ));
+    assert!(!contents.contains("check_not(\"age\""));
+    // Bodies are validated before sending requests.
+    assert!(contents.contains(
+        "
+    fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
+        self.body.validate()?;
+        self.modify_with_body(client, req, &self.body)
+    }
+"
+    ));
 
-            },
-            _param_values: core::marker::PhantomData,
-        };
+    let contents = generated.read("util.rs");
 
-        Ok(thing)
+    // For example, `Pet { name: "forbidden".into(), .. }.validate()` fails with
+    // "Value \"forbidden\" is not allowed for field \"name\"".
+    assert!(contents.contains(
+        "
+    if forbidden.iter().any(|f| serde_json::from_str::<serde_json::Value>(f).ok().as_ref() == Some(&value)) {
+        return Err(ValidationError {
+            field,
+            value: value.to_string(),
+        });
     }
+"
+    ));
 }
-",
-        Some(6627),
-    );
+
+#[test]
+fn test_prelude() {
+    let contents = generate_fixture("prelude", EmitterState::default()).read("mod.rs");
+
+    // Objects with the same name (among themselves or with the client items) are
+    // aliased with their parent modules. `Pet<Any>` keeps its generic parameter.
+    assert!(contents.contains(
+        "
+/// Re-exports of all objects and the API client (for glob imports).
+pub mod prelude {
+    pub use crate::client::{ApiClient, ApiError, EnvClient, Sendable};
+    pub use crate::pet::Pet;
+    pub use crate::pets::status::Status as PetsStatus;
+    pub use crate::sendable::Sendable as SendableSendable;
+    pub use crate::store::status::Status as StoreStatus;
+}
+"
+    ));
 }
 
 #[test]
-fn test_file_response() {
-    assert_file_contains_content_at(
-        &(ROOT.clone() + "/tests/test_pet/miscellaneous.rs"),
+fn test_runtime_fixture() {
+    let mut state = EmitterState::default();
+    state.blocking = true;
+    state.split_statuses = true;
+    let mut meta = CrateMeta::default();
+    meta.authors = Some(vec!["Me <me@example.com>".into()]);
+    meta.mode = EmitMode::Crate;
+    state.set_meta(meta);
+    // The generated client is exercised against a mock server by the tests
+    // in `tests/test_runtime` (retries, redirects, timeouts, event streams, etc.).
+    generate_fixture_in("runtime", "test_runtime", state);
+}
+
+#[test]
+fn test_blocking_client() {
+    let mut state = EmitterState::default();
+    state.blocking = true;
+    let generated = generate_fixture("blocking-client", state);
+
+    let contents = generated.read("mod.rs");
+
+    assert!(contents.contains(
         "
-/// Builder created by [`Miscellaneous::get_1`](./struct.Miscellaneous.html#method.get_1) method for a `GET` operation associated with `Miscellaneous`.
-#[derive(Debug, Clone)]
-pub struct MiscellaneousGetBuilder1;
+    /// Blocking client and [`Sendable`](../trait.Sendable.html) variant for synchronous API calls.
+    pub mod blocking {"
+    ));
+    assert!(contents.contains(
+        "
+        pub trait Sendable<C: ApiClient> {
+            /// The output object from this API request. Streaming (file) responses
+            /// are returned as [`ResponseReader`](struct.ResponseReader.html).
+            type Output;
 
+            /// Sends the request and blocks until the response object is decoded.
+            fn send(&self, client: &Client<C>) -> Result<Self::Output, ApiError<C::Response>>;
+        }
+"
+    ));
+    // Redirects are followed by the generated client (and not by `reqwest`).
+    assert!(contents.contains(
+        "
+            pub fn new() -> io::Result<Self> {
+                let inner = reqwest::r#async::Client::builder()
+                    .redirect(reqwest::RedirectPolicy::none())
+                    .build()
+                    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+                Client::with_client(inner)
+            }
+"
+    ));
 
-#[async_trait::async_trait]
-impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for MiscellaneousGetBuilder1 {
-    type Output = crate::util::ResponseStream<<<Client as crate::client::ApiClient>::Response as crate::client::Response>::Stream>;
+    // For example, `Pet::get_pet().id(1).send(&client)?.name` (with `blocking::Sendable` in scope).
+    let contents = generated.read("pet.rs");
 
-    const METHOD: http::Method = http::Method::GET;
+    assert!(contents.contains(
+        "
+impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::blocking::Sendable<Client> for PetGetBuilder<crate::generics::IdExists> {
+    type Output = <Self as crate::client::Sendable<Client>>::Output;
 
-    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
-        \"/test/file\".into()
+    fn send(&self, client: &crate::client::blocking::Client<Client>) -> Result<Self::Output, crate::client::ApiError<Client::Response>> {
+        client.block_on(crate::client::Sendable::send(self, client.inner()))
     }
+}
+"
+    ));
 
-    async fn send(&self, client: &Client) -> Result<Self::Output, crate::client::ApiError<Client::Response>> {
-        use crate::client::Response;
+    // Streaming responses are read synchronously.
+    let contents = generated.read("miscellaneous.rs");
+
+    assert!(contents.contains(
+        "
+    type Output = crate::client::blocking::ResponseReader<<<Client as crate::client::ApiClient>::Response as crate::client::Response>::Stream>;
 
-        let mut resp = self.send_raw(client).await?;
-        Ok(resp.stream())
+    fn send(&self, client: &crate::client::blocking::Client<Client>) -> Result<Self::Output, crate::client::ApiError<Client::Response>> {
+        let stream = client.block_on(crate::client::Sendable::send(self, client.inner()))?;
+        Ok(client.reader(stream))
     }
-}
-",
-        Some(1206),
-    );
+"
+    ));
 }
 
 #[test]
-fn test_multipart_with_file() {
-    let _ = &*CLI_CODEGEN;
+fn test_schema_titles() {
+    let generated = generate_fixture("schema-titles", EmitterState::default());
+
+    // Titles are preferred over derived names (`PetBrief` and `Owner`), except
+    // when they collide with other types in the same module.
+    let contents = generated.read("pet.rs");
+    assert!(contents.contains(
+        "pub struct Pet {
+    pub brief: Option<crate::pet::PetSummary>,
+    #[serde(rename = \"mainTag\")]
+    pub main_tag: Option<crate::pet::Tag>,
+    #[serde(rename = \"otherTag\")]
+    pub other_tag: Option<crate::pet::PetOtherTag>,
+    pub owner: Option<crate::owner::PetOwner>,
+}
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PetSummary {
+    pub name: Option<String>,
+}
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Tag {
+    pub label: Option<String>,
+}
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PetOtherTag {
+    pub label: Option<String>,
+}
+"
+    ));
 
-    assert_file_contains_content_at(
-        &(ROOT.clone() + "/tests/test_pet/cli/status.rs"),
-        "
-/// Builder created by [`Status::put_1`](./struct.Status.html#method.put_1) method for a `PUT` operation associated with `Status`.
-#[repr(transparent)]
-#[derive(Debug, Clone)]
-pub struct StatusPutBuilder1<SomeDataFile, Foobar> {
-    inner: StatusPutBuilder1Container,
-    _param_some_data_file: core::marker::PhantomData<SomeDataFile>,
-    _param_foobar: core::marker::PhantomData<Foobar>,
+    let contents = generated.read("owner.rs");
+    assert!(contents.contains("pub struct PetOwner {"));
 }
 
-#[derive(Debug, Default, Clone)]
-struct StatusPutBuilder1Container {
-    param_some_data_file: Option<std::path::PathBuf>,
-    param_some_other_file: Option<std::path::PathBuf>,
-    param_foobar: Option<String>,
-    param_booya: Option<crate::util::Delimited<crate::util::Delimited<i64, crate::util::Csv>, crate::util::Multi>>,
+#[test]
+fn test_operation_paths() {
+    let contents = generate_fixture("operation-paths", EmitterState::default()).read("mod.rs");
+
+    // Path templates are listed verbatim.
+    assert!(contents.contains(
+        "
+    pub const OPERATION_PATHS: &[(http::Method, &str)] = &[
+        (http::Method::POST, \"/pets\"),
+        (http::Method::GET, \"/pets/{id}\"),
+    ];
+"
+    ));
 }
 
-impl<SomeDataFile, Foobar> StatusPutBuilder1<SomeDataFile, Foobar> {
-    #[inline]
-    pub fn some_data_file(mut self, value: impl AsRef<std::path::Path>) -> StatusPutBuilder1<crate::generics::SomeDataFileExists, Foobar> {
-        self.inner.param_some_data_file = Some(value.as_ref().into());
-        unsafe { std::mem::transmute(self) }
-    }
+#[test]
+fn test_flattened_mixins() {
+    let generated = generate_fixture("flattened-mixins", EmitterState::default());
 
+    // The mixin is decoded from the top-level `_links` key of the object
+    // (and it's not set through the builder).
+    let contents = generated.read("pet.rs");
+    assert!(contents.contains(
+        "#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Pet {
+    #[serde(flatten)]
+    pub links: crate::links::Links,
+    pub name: Option<String>,
+}
+"
+    ));
+    assert!(contents.contains(
+        "
+impl PetBuilder {
     #[inline]
-    pub fn some_other_file(mut self, value: impl AsRef<std::path::Path>) -> Self {
-        self.inner.param_some_other_file = Some(value.as_ref().into());
+    pub fn name(mut self, value: impl Into<String>) -> Self {
+        self.body.name = Some(value.into());
         self
     }
+"
+    ));
 
-    #[inline]
-    pub fn foobar(mut self, value: impl Into<String>) -> StatusPutBuilder1<SomeDataFile, crate::generics::FoobarExists> {
-        self.inner.param_foobar = Some(value.into());
-        unsafe { std::mem::transmute(self) }
-    }
+    let contents = generated.read("links.rs");
+    assert!(contents.contains(
+        "
+pub struct Links {
+    #[serde(rename = \"_links\")]
+    pub links: Option<std::collections::BTreeMap<String, String>>,
+}
+"
+    ));
 
-    #[inline]
-    pub fn booya(mut self, value: impl Iterator<Item = impl Iterator<Item = impl Into<i64>>>) -> Self {
-        self.inner.param_booya = Some(value.map(|value| value.map(|value| value.into()).collect::<Vec<_>>().into()).collect::<Vec<_>>().into());
-        self
-    }
+    // Explicit `_links` property is preferred over the mixin.
+    let contents = generated.read("store.rs");
+    assert!(contents.contains(
+        "
+pub struct Store {
+    #[serde(rename = \"_links\")]
+    pub links: Option<String>,
+}
+"
+    ));
 }
 
-impl<Client: crate::client::ApiClient + Sync + 'static> crate::client::Sendable<Client> for StatusPutBuilder1<crate::generics::SomeDataFileExists, crate::generics::FoobarExists> {
-    type Output = Status;
+#[test]
+fn test_path_param_encoding() {
+    // Path parameters are percent-encoded (including slashes).
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/util.rs"),
+        "
+/// Percent-encodes the given path parameter value. Everything other than the unreserved
+/// characters (RFC 3986) is encoded - including slashes, so that the value always fills
+/// a single path segment.
+pub fn encode_path_param(value: &str) -> String {
+    percent_encode(value, b\"\")
+}
+",
+        None,
+    );
 
-    const METHOD: http::Method = http::Method::PUT;
+    // Empty values fail the API call before sending the request.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/lib.rs"),
+        "
+        async fn send_raw(&self, client: &Client) -> Result<Client::Response, ApiError<Client::Response>> {
+            if let Some(name) = self.empty_path_param() {
+                return Err(ApiError::EmptyPathParameter(name))
+            }
+",
+        None,
+    );
 
+    // Whitespace-only values are rejected along with empty ones, since they'd end up as
+    // (encoded) blank path segments. For example, `Pet::get().pet_id(" ").send(&client)`
+    // fails with `ApiError::EmptyPathParameter("petId")`.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/pet.rs"),
+        "
     fn rel_path(&self) -> std::borrow::Cow<'static, str> {
-        \"/test/file\".into()
+        format!(\"/pets/{petId}\", petId=crate::util::encode_path_param(&self.inner.param_pet_id.as_ref().expect(\"missing parameter pet_id?\").to_string())).into()
     }
 
-    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::client::ApiError<Client::Response>> {
-        use crate::client::Request;
-        Ok(req
-        .multipart_form_data({
-            use crate::client::Form;
-            let mut form = <Client::Request as Request>::Form::new();
-            if let Some(v) = self.inner.param_some_data_file.as_ref() {
-                form = form.file(\"someDataFile\", v)?;
-            }
-            if let Some(v) = self.inner.param_some_other_file.as_ref() {
-                form = form.file(\"someOtherFile\", v)?;
-            }
-            if let Some(v) = self.inner.param_foobar.as_ref() {
-                form = form.text(\"foobar\", v.to_string());
-            }
-            if let Some(stuff) = self.inner.param_booya.as_ref() {
-                for v in stuff.iter() {
-                    form = form.text(\"booya\", v.to_string());
-                }
-            }
-            form
-        }))
+    fn empty_path_param(&self) -> Option<&'static str> {
+        if self.inner.param_pet_id.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {
+            return Some(\"petId\");
+        }
+
+        None
     }
+",
+        None,
+    );
 }
 
-#[allow(unused_variables)]
-impl StatusPutBuilder1<crate::generics::SomeDataFileExists, crate::generics::FoobarExists> {
-    pub(crate) fn from_args(matches: Option<&clap::ArgMatches<'_>>) -> Result<Self, crate::ClientError> {
-        let thing = StatusPutBuilder1 {
-            inner: StatusPutBuilder1Container {
-            param_some_data_file: matches.and_then(|m| {
-                    m.value_of(\"some-data-file\").map(|_| {
-                        value_t!(m, \"some-data-file\", std::path::PathBuf).unwrap_or_else(|e| e.exit())
-                    })
-                }),
-
-            param_some_other_file: matches.and_then(|m| {
-                    m.value_of(\"some-other-file\").map(|_| {
-                        value_t!(m, \"some-other-file\", std::path::PathBuf).unwrap_or_else(|e| e.exit())
-                    })
-                }),
+#[test]
+fn test_query_param_encoding() {
+    // Query values are percent-encoded with a different set (slashes are allowed).
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/util.rs"),
+        "
+/// Percent-encodes the given query parameter value (or name). Unlike path parameters,
+/// slashes (along with `?`, `:` and `@`) are left as they are, since they're allowed in
+/// queries (RFC 3986). Spaces are encoded as `%20`.
+pub fn encode_query_value(value: &str) -> String {
+    percent_encode(value, b\"/?:@\")
+}
 
-            param_foobar: matches.and_then(|m| {
-                    m.value_of(\"foobar\").map(|_| {
-                        value_t!(m, \"foobar\", String).unwrap_or_else(|e| e.exit())
-                    })
-                }),
+/// Percent-encodes everything other than the unreserved characters and the given bytes.
+fn percent_encode(value: &str, keep: &[u8]) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for b in value.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => encoded.push(b as char),
+            b if keep.contains(&b) => encoded.push(b as char),
+            _ => encoded.push_str(&format!(\"%{:02X}\", b)),
+        }
+    }
 
-            param_booya: matches.and_then(|m| {
-                    m.value_of(\"booya\").map(|_| {
-                        value_t!(m, \"booya\", crate::util::Delimited<crate::util::Delimited<i64, crate::util::Csv>, crate::util::Multi>).unwrap_or_else(|e| e.exit())
-                    })
-                }),
+    encoded
+}
 
-            },
-            _param_some_data_file: core::marker::PhantomData,
-            _param_foobar: core::marker::PhantomData,
-        };
+/// Appends the given query parameters (with encoded values) to the relative path.
+/// Unset parameters are skipped.
+pub fn append_query(path: &mut String, params: &[(&str, Option<String>)]) {
+    for (name, value) in params {
+        if let Some(v) = value {
+            path.push(if path.contains('?') { '&' } else { '?' });
+            path.push_str(&encode_query_value(name));
+            path.push('=');
+            path.push_str(v);
+        }
+    }
+}
+",
+        None,
+    );
 
-        Ok(thing)
+    // Delimited values are encoded separately, so that the delimiters in values
+    // are encoded, but not the ones joining them.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_pet/util.rs"),
+        "
+impl<T: Display, D: Delimiting + Allowed> Delimited<T, D> {
+    /// Percent-encodes the values with the given encoder and joins them with the delimiter.
+    /// The delimiter itself isn't encoded, so that it's not confused with the delimiters
+    /// in values (spaces and tabs are still encoded when the URL is parsed).
+    pub fn encode_with(&self, encode: fn(&str) -> String) -> String {
+        self.0.iter().map(|v| encode(&v.to_string())).collect::<Vec<_>>().join(D::DELIMITER)
     }
 }
 ",
-        Some(3154),
+        None,
     );
 }
@@ -0,0 +1,31 @@
+use rust_decimal::Decimal;
+use test_decimal::invoice::Invoice;
+
+#[test]
+fn test_invoice_round_trip() {
+    let json =
+        r#"{"amount":"12345678901234567890.000000001","discount":0.5,"taxes":["0.1","0.20"]}"#;
+    let invoice: Invoice = serde_json::from_str(json).expect("deserializing invoice");
+    assert_eq!(invoice.amount.to_string(), "12345678901234567890.000000001");
+    assert_eq!(invoice.discount, Some(0.5));
+    assert_eq!(
+        serde_json::to_string(&invoice).expect("serializing invoice"),
+        json
+    );
+}
+
+#[test]
+fn test_invoice_builder() {
+    let invoice = Invoice::builder()
+        .amount(Decimal::new(110, 2))
+        .taxes(vec![Decimal::new(5, 1)])
+        .build();
+    assert_eq!(
+        serde_json::to_value(&invoice).expect("serializing invoice"),
+        serde_json::json!({
+            "amount": "1.10",
+            "discount": null,
+            "taxes": ["0.5"],
+        })
+    );
+}
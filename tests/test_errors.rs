@@ -82,3 +82,38 @@ paths:
         "Path similar to \"/store/{storeId}/pets/{petId}\" already exists.",
     );
 }
+
+#[test]
+fn test_unknown_formats() {
+    let spec = Cursor::new(
+        b"
+swagger: \"2.0\"
+definitions:
+  Pet:
+    type: object
+    properties:
+      id:
+        type: string
+        format: int-or-string
+info:
+  title:  \"Petstore\"
+  version: \"1.0.0\"
+paths: {}
+" as &[_],
+    );
+
+    let raw: ResolvableApi<DefaultSchema> = v2::from_reader(spec).expect("deserializing spec");
+    let resolved = raw.resolve().expect("resolution");
+
+    let mut state = EmitterState::default();
+    state.working_dir = std::env::temp_dir().join("paperclip_unknown_formats");
+    state.strict_formats = true;
+    let emitter = DefaultEmitter::from(state.clone());
+    let err = emitter.generate(&resolved).unwrap_err().to_string();
+    assert_eq!(err, "Unknown format \"int-or-string\" in \"pet/id\"");
+
+    // Whitelisted formats are mapped to their base types.
+    state.known_formats = vec!["int-or-string".into()];
+    let emitter = DefaultEmitter::from(state);
+    emitter.generate(&resolved).expect("codegen");
+}
@@ -235,13 +235,14 @@ fn test_transparency_with_parameters() {
         &(ROOT.clone() + "/tests/test_k8s/io/k8s/apiextensions_apiserver/pkg/apis/apiextensions/v1beta1/custom_resource_definition.rs"),
         "
 /// Builder created by [`CustomResourceDefinition::create_apiextensions_v1beta1_custom_resource_definition`](./struct.CustomResourceDefinition.html#method.create_apiextensions_v1beta1_custom_resource_definition) method for a `POST` operation associated with `CustomResourceDefinition`.
-#[repr(transparent)]
+#[repr(C)]
 #[derive(Debug, Clone)]
 pub struct CustomResourceDefinitionPostBuilder<Spec, Any> {
     inner: CustomResourceDefinitionPostBuilderContainer<Any>,
     _spec: core::marker::PhantomData<Spec>,
 }
 
+#[repr(C)]
 #[derive(Debug, Default, Clone)]
 struct CustomResourceDefinitionPostBuilderContainer<Any> {
     body: self::CustomResourceDefinition<Any>,
@@ -258,6 +259,13 @@ impl<Spec, Any> CustomResourceDefinitionPostBuilder<Spec, Any> {
         self
     }
 
+    /// Clears the value set for `dry_run` (if any).
+    #[inline]
+    pub fn clear_dry_run(mut self) -> Self {
+        self.inner.param_dry_run = None;
+        self
+    }
+
     /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint.
     #[inline]
     pub fn field_manager(mut self, value: impl Into<String>) -> Self {
@@ -265,6 +273,13 @@ impl<Spec, Any> CustomResourceDefinitionPostBuilder<Spec, Any> {
         self
     }
 
+    /// Clears the value set for `field_manager` (if any).
+    #[inline]
+    pub fn clear_field_manager(mut self) -> Self {
+        self.inner.param_field_manager = None;
+        self
+    }
+
     /// If 'true', then the output is pretty printed.
     #[inline]
     pub fn pretty(mut self, value: impl Into<String>) -> Self {
@@ -272,6 +287,13 @@ impl<Spec, Any> CustomResourceDefinitionPostBuilder<Spec, Any> {
         self
     }
 
+    /// Clears the value set for `pretty` (if any).
+    #[inline]
+    pub fn clear_pretty(mut self) -> Self {
+        self.inner.param_pretty = None;
+        self
+    }
+
     /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
     #[inline]
     pub fn api_version(mut self, value: impl Into<String>) -> Self {
@@ -296,7 +318,9 @@ impl<Spec, Any> CustomResourceDefinitionPostBuilder<Spec, Any> {
     #[inline]
     pub fn spec(mut self, value: crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_spec::CustomResourceDefinitionSpecBuilder<crate::codegen::generics::GroupExists, crate::codegen::generics::NamesExists, crate::codegen::generics::ScopeExists, Any>) -> CustomResourceDefinitionPostBuilder<crate::codegen::generics::SpecExists, Any> {
         self.inner.body.spec = value.into();
-        unsafe { std::mem::transmute(self) }
+        crate::codegen::util::debug_assert_same_layout::<Self, CustomResourceDefinitionPostBuilder<crate::codegen::generics::SpecExists, Any>>();
+        let this = std::mem::ManuallyDrop::new(self);
+        unsafe { std::mem::transmute_copy(&*this) }
     }
 
     /// Status indicates the actual state of the CustomResourceDefinition
@@ -305,9 +329,30 @@ impl<Spec, Any> CustomResourceDefinitionPostBuilder<Spec, Any> {
         self.inner.body.status = Some(value.into());
         self
     }
+
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
+    #[inline]
+    pub fn reset(self) -> CustomResourceDefinitionPostBuilder<crate::codegen::generics::MissingSpec, Any> {
+        crate::codegen::util::debug_assert_same_layout::<Self, CustomResourceDefinitionPostBuilder<crate::codegen::generics::MissingSpec, Any>>();
+        let this = std::mem::ManuallyDrop::new(self);
+        unsafe { std::mem::transmute_copy(&*this) }
+    }
+
+    /// Sends the given body for this API call (instead of the one in this builder),
+    /// without moving or cloning it. The body fields needn't be set in that case.
+    #[inline]
+    pub fn with_body(self, body: &self::CustomResourceDefinition<Any>) -> crate::codegen::client::BorrowedBody<'_, CustomResourceDefinitionPostBuilder<crate::codegen::generics::SpecExists, Any>, self::CustomResourceDefinition<Any>> {
+        let builder = {
+            crate::codegen::util::debug_assert_same_layout::<Self, CustomResourceDefinitionPostBuilder<crate::codegen::generics::SpecExists, Any>>();
+            let this = std::mem::ManuallyDrop::new(self);
+            unsafe { std::mem::transmute_copy(&*this) }
+        };
+        crate::codegen::client::BorrowedBody::new(builder, body)
+    }
 }
 
-impl<Client: crate::codegen::client::ApiClient + Sync + 'static, Any: serde::Serialize> crate::codegen::client::Sendable<Client> for CustomResourceDefinitionPostBuilder<crate::codegen::generics::SpecExists, Any> {
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static, Any: serde::Serialize + Sync> crate::codegen::client::Sendable<Client> for CustomResourceDefinitionPostBuilder<crate::codegen::generics::SpecExists, Any> {
     type Output = crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition::CustomResourceDefinition<serde_json::Value>;
 
     const METHOD: http::Method = http::Method::POST;
@@ -316,10 +361,14 @@ impl<Client: crate::codegen::client::ApiClient + Sync + 'static, Any: serde::Ser
         \"/apis/apiextensions.k8s.io/v1beta1/customresourcedefinitions\".into()
     }
 
-    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+    fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        self.modify_with_body(client, req, &self.inner.body)
+    }
+
+    fn modify_with_body<B: serde::Serialize + Sync>(&self, _client: &Client, req: Client::Request, body: &B) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
         use crate::codegen::client::Request;
         Ok(req
-        .json(&self.inner.body)
+        .json(body)
         .header(http::header::ACCEPT.as_str(), \"application/json\")
         .query(&[
             (\"dryRun\", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
@@ -327,22 +376,27 @@ impl<Client: crate::codegen::client::ApiClient + Sync + 'static, Any: serde::Ser
             (\"pretty\", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
         ]))
     }
+
+    fn fallback_media_range(&self) -> Option<&'static str> {
+        Some(\"application/json\")
+    }
 }
 ",
-        Some(7319),
+        Some(8719),
     );
 
     assert_file_contains_content_at(
         &(ROOT.clone() + "/tests/test_k8s/io/k8s/apiextensions_apiserver/pkg/apis/apiextensions/v1beta1/custom_resource_definition.rs"),
         "
 /// Builder created by [`CustomResourceDefinition::read_apiextensions_v1beta1_custom_resource_definition`](./struct.CustomResourceDefinition.html#method.read_apiextensions_v1beta1_custom_resource_definition) method for a `GET` operation associated with `CustomResourceDefinition`.
-#[repr(transparent)]
+#[repr(C)]
 #[derive(Debug, Clone)]
 pub struct CustomResourceDefinitionGetBuilder1<Name> {
     inner: CustomResourceDefinitionGetBuilder1Container,
     _param_name: core::marker::PhantomData<Name>,
 }
 
+#[repr(C)]
 #[derive(Debug, Default, Clone)]
 struct CustomResourceDefinitionGetBuilder1Container {
     param_exact: Option<bool>,
@@ -359,6 +413,13 @@ impl<Name> CustomResourceDefinitionGetBuilder1<Name> {
         self
     }
 
+    /// Clears the value set for `exact` (if any).
+    #[inline]
+    pub fn clear_exact(mut self) -> Self {
+        self.inner.param_exact = None;
+        self
+    }
+
     /// Should this value be exported.  Export strips fields that a user can not specify. Deprecated. Planned for removal in 1.18.
     #[inline]
     pub fn export(mut self, value: impl Into<bool>) -> Self {
@@ -366,10 +427,18 @@ impl<Name> CustomResourceDefinitionGetBuilder1<Name> {
         self
     }
 
+    /// Clears the value set for `export` (if any).
+    #[inline]
+    pub fn clear_export(mut self) -> Self {
+        self.inner.param_export = None;
+        self
+    }
+
     /// name of the CustomResourceDefinition
     #[inline]
     pub fn name(mut self, value: impl Into<String>) -> CustomResourceDefinitionGetBuilder1<crate::codegen::generics::NameExists> {
         self.inner.param_name = Some(value.into());
+        crate::codegen::util::debug_assert_same_layout::<Self, CustomResourceDefinitionGetBuilder1<crate::codegen::generics::NameExists>>();
         unsafe { std::mem::transmute(self) }
     }
 
@@ -379,6 +448,21 @@ impl<Name> CustomResourceDefinitionGetBuilder1<Name> {
         self.inner.param_pretty = Some(value.into());
         self
     }
+
+    /// Clears the value set for `pretty` (if any).
+    #[inline]
+    pub fn clear_pretty(mut self) -> Self {
+        self.inner.param_pretty = None;
+        self
+    }
+
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
+    #[inline]
+    pub fn reset(self) -> CustomResourceDefinitionGetBuilder1<crate::codegen::generics::MissingName> {
+        crate::codegen::util::debug_assert_same_layout::<Self, CustomResourceDefinitionGetBuilder1<crate::codegen::generics::MissingName>>();
+        unsafe { std::mem::transmute(self) }
+    }
 }
 
 impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for CustomResourceDefinitionGetBuilder1<crate::codegen::generics::NameExists> {
@@ -387,10 +471,18 @@ impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen:
     const METHOD: http::Method = http::Method::GET;
 
     fn rel_path(&self) -> std::borrow::Cow<'static, str> {
-        format!(\"/apis/apiextensions.k8s.io/v1beta1/customresourcedefinitions/{name}\", name=self.inner.param_name.as_ref().expect(\"missing parameter name?\")).into()
+        format!(\"/apis/apiextensions.k8s.io/v1beta1/customresourcedefinitions/{name}\", name=crate::codegen::util::encode_path_param(&self.inner.param_name.as_ref().expect(\"missing parameter name?\").to_string())).into()
+    }
+
+    fn empty_path_param(&self) -> Option<&'static str> {
+        if self.inner.param_name.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {
+            return Some(\"name\");
+        }
+
+        None
     }
 ",
-        Some(12543),
+        Some(16974),
     );
 }
 
@@ -535,14 +627,14 @@ impl<Any> JsonSchemaPropsBuilder<Any> {
     }
 
     #[inline]
-    pub fn all_of(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>) -> Self {
-        self.body.all_of = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+    pub fn all_of(mut self, value: impl IntoIterator<Item = crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>) -> Self {
+        self.body.all_of = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
         self
     }
 
     #[inline]
-    pub fn any_of(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>) -> Self {
-        self.body.any_of = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+    pub fn any_of(mut self, value: impl IntoIterator<Item = crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>) -> Self {
+        self.body.any_of = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
         self
     }
 
@@ -553,14 +645,14 @@ impl<Any> JsonSchemaPropsBuilder<Any> {
     }
 
     #[inline]
-    pub fn definitions(mut self, value: impl Iterator<Item = (String, crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>)>) -> Self {
-        self.body.definitions = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+    pub fn definitions(mut self, value: impl IntoIterator<Item = (String, crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>)>) -> Self {
+        self.body.definitions = Some(value.into_iter().map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
         self
     }
 
     #[inline]
-    pub fn dependencies(mut self, value: impl Iterator<Item = (String, impl Into<Any>)>) -> Self {
-        self.body.dependencies = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+    pub fn dependencies(mut self, value: impl IntoIterator<Item = (String, impl Into<Any>)>) -> Self {
+        self.body.dependencies = Some(value.into_iter().map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
         self
     }
 
@@ -571,8 +663,8 @@ impl<Any> JsonSchemaPropsBuilder<Any> {
     }
 
     #[inline]
-    pub fn r#enum(mut self, value: impl Iterator<Item = impl Into<Any>>) -> Self {
-        self.body.enum_ = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+    pub fn r#enum(mut self, value: impl IntoIterator<Item = impl Into<Any>>) -> Self {
+        self.body.enum_ = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
         self
     }
 
@@ -674,7 +766,7 @@ impl<Any> JsonSchemaPropsBuilder<Any> {
 
     #[inline]
     pub fn not(mut self, value: crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>) -> Self {
-        self.body.not = Some(value.into());
+        self.body.not = Some(Box::new(value.into()));
         self
     }
 
@@ -685,8 +777,8 @@ impl<Any> JsonSchemaPropsBuilder<Any> {
     }
 
     #[inline]
-    pub fn one_of(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>) -> Self {
-        self.body.one_of = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+    pub fn one_of(mut self, value: impl IntoIterator<Item = crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>>) -> Self {
+        self.body.one_of = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
         self
     }
 
@@ -697,20 +789,20 @@ impl<Any> JsonSchemaPropsBuilder<Any> {
     }
 
     #[inline]
-    pub fn pattern_properties(mut self, value: impl Iterator<Item = (String, crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>)>) -> Self {
-        self.body.pattern_properties = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+    pub fn pattern_properties(mut self, value: impl IntoIterator<Item = (String, crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>)>) -> Self {
+        self.body.pattern_properties = Some(value.into_iter().map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
         self
     }
 
     #[inline]
-    pub fn properties(mut self, value: impl Iterator<Item = (String, crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>)>) -> Self {
-        self.body.properties = Some(value.map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
+    pub fn properties(mut self, value: impl IntoIterator<Item = (String, crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps<Any>)>) -> Self {
+        self.body.properties = Some(value.into_iter().map(|(key, value)| (key, value.into())).collect::<std::collections::BTreeMap<_, _>>().into());
         self
     }
 
     #[inline]
-    pub fn required(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
-        self.body.required = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+    pub fn required(mut self, value: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.body.required = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
         self
     }
 
@@ -782,7 +874,7 @@ pub mod miscellaneous {
 }
 
 pub mod client {
-    use crate::codegen::util::{AsyncReadStream, ResponseStream};
+    use crate::codegen::util::{AsyncReadStream, ResponseStream, ValidationError};
     use failure::Fail;
     use futures::{Stream, Future};
     use futures_preview::compat::Future01CompatExt;
@@ -791,6 +883,1099 @@ pub mod client {
     use std::borrow::Cow;
     use std::fmt::Debug;
     use std::path::Path;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// Title of the API spec used for generating this client.
+    pub const SPEC_TITLE: &str = \"Kubernetes\";
+    /// Version of the API spec used for generating this client.
+    pub const SPEC_VERSION: &str = \"v1.16.0\";
+    /// Default base URL for API calls.
+    pub const BASE_URL: &str = \"https://example.com/\";
+    /// Number of operations supported by this client.
+    pub const OPERATION_COUNT: usize = 1078;
+    /// HTTP methods and path templates of the operations supported by this client
+    /// (sorted by path and method).
+    pub const OPERATION_PATHS: &[(http::Method, &str)] = &[
+        (http::Method::GET, \"/api/\"),
+        (http::Method::GET, \"/api/v1/\"),
+        (http::Method::GET, \"/api/v1/componentstatuses\"),
+        (http::Method::GET, \"/api/v1/componentstatuses/{name}\"),
+        (http::Method::GET, \"/api/v1/configmaps\"),
+        (http::Method::GET, \"/api/v1/endpoints\"),
+        (http::Method::GET, \"/api/v1/events\"),
+        (http::Method::GET, \"/api/v1/limitranges\"),
+        (http::Method::GET, \"/api/v1/namespaces\"),
+        (http::Method::POST, \"/api/v1/namespaces\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/bindings\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/configmaps\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/configmaps\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/configmaps\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/configmaps/{name}\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/configmaps/{name}\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/configmaps/{name}\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/configmaps/{name}\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/endpoints\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/endpoints\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/endpoints\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/endpoints/{name}\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/endpoints/{name}\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/endpoints/{name}\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/endpoints/{name}\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/events\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/events\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/events\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/events/{name}\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/events/{name}\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/events/{name}\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/events/{name}\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/limitranges\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/limitranges\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/limitranges\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/limitranges/{name}\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/limitranges/{name}\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/limitranges/{name}\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/limitranges/{name}\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/persistentvolumeclaims\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/persistentvolumeclaims\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/persistentvolumeclaims\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/persistentvolumeclaims/{name}\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/persistentvolumeclaims/{name}\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/persistentvolumeclaims/{name}\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/persistentvolumeclaims/{name}\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/persistentvolumeclaims/{name}/status\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/persistentvolumeclaims/{name}/status\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/persistentvolumeclaims/{name}/status\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/pods\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/pods\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/pods\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/pods/{name}\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/pods/{name}\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/pods/{name}\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/pods/{name}\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/pods/{name}/attach\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/pods/{name}/attach\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/pods/{name}/binding\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/pods/{name}/eviction\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/pods/{name}/exec\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/pods/{name}/exec\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/pods/{name}/log\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/pods/{name}/portforward\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/pods/{name}/portforward\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/pods/{name}/proxy\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/pods/{name}/proxy\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/pods/{name}/proxy\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/pods/{name}/proxy\"),
+        (http::Method::OPTIONS, \"/api/v1/namespaces/{namespace}/pods/{name}/proxy\"),
+        (http::Method::HEAD, \"/api/v1/namespaces/{namespace}/pods/{name}/proxy\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/pods/{name}/proxy\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/pods/{name}/proxy/{path}\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/pods/{name}/proxy/{path}\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/pods/{name}/proxy/{path}\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/pods/{name}/proxy/{path}\"),
+        (http::Method::OPTIONS, \"/api/v1/namespaces/{namespace}/pods/{name}/proxy/{path}\"),
+        (http::Method::HEAD, \"/api/v1/namespaces/{namespace}/pods/{name}/proxy/{path}\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/pods/{name}/proxy/{path}\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/pods/{name}/status\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/pods/{name}/status\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/pods/{name}/status\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/podtemplates\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/podtemplates\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/podtemplates\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/podtemplates/{name}\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/podtemplates/{name}\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/podtemplates/{name}\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/podtemplates/{name}\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/replicationcontrollers\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/replicationcontrollers\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/replicationcontrollers\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/replicationcontrollers/{name}\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/replicationcontrollers/{name}\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/replicationcontrollers/{name}\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/replicationcontrollers/{name}\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/replicationcontrollers/{name}/scale\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/replicationcontrollers/{name}/scale\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/replicationcontrollers/{name}/scale\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/replicationcontrollers/{name}/status\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/replicationcontrollers/{name}/status\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/replicationcontrollers/{name}/status\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/resourcequotas\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/resourcequotas\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/resourcequotas\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/resourcequotas/{name}\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/resourcequotas/{name}\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/resourcequotas/{name}\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/resourcequotas/{name}\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/resourcequotas/{name}/status\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/resourcequotas/{name}/status\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/resourcequotas/{name}/status\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/secrets\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/secrets\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/secrets\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/secrets/{name}\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/secrets/{name}\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/secrets/{name}\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/secrets/{name}\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/serviceaccounts\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/serviceaccounts\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/serviceaccounts\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/serviceaccounts/{name}\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/serviceaccounts/{name}\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/serviceaccounts/{name}\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/serviceaccounts/{name}\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/services\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/services\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/services/{name}\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/services/{name}\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/services/{name}\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/services/{name}\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/services/{name}/proxy\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/services/{name}/proxy\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/services/{name}/proxy\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/services/{name}/proxy\"),
+        (http::Method::OPTIONS, \"/api/v1/namespaces/{namespace}/services/{name}/proxy\"),
+        (http::Method::HEAD, \"/api/v1/namespaces/{namespace}/services/{name}/proxy\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/services/{name}/proxy\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/services/{name}/proxy/{path}\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/services/{name}/proxy/{path}\"),
+        (http::Method::POST, \"/api/v1/namespaces/{namespace}/services/{name}/proxy/{path}\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{namespace}/services/{name}/proxy/{path}\"),
+        (http::Method::OPTIONS, \"/api/v1/namespaces/{namespace}/services/{name}/proxy/{path}\"),
+        (http::Method::HEAD, \"/api/v1/namespaces/{namespace}/services/{name}/proxy/{path}\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/services/{name}/proxy/{path}\"),
+        (http::Method::GET, \"/api/v1/namespaces/{namespace}/services/{name}/status\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{namespace}/services/{name}/status\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{namespace}/services/{name}/status\"),
+        (http::Method::GET, \"/api/v1/namespaces/{name}\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{name}\"),
+        (http::Method::DELETE, \"/api/v1/namespaces/{name}\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{name}\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{name}/finalize\"),
+        (http::Method::GET, \"/api/v1/namespaces/{name}/status\"),
+        (http::Method::PUT, \"/api/v1/namespaces/{name}/status\"),
+        (http::Method::PATCH, \"/api/v1/namespaces/{name}/status\"),
+        (http::Method::GET, \"/api/v1/nodes\"),
+        (http::Method::POST, \"/api/v1/nodes\"),
+        (http::Method::DELETE, \"/api/v1/nodes\"),
+        (http::Method::GET, \"/api/v1/nodes/{name}\"),
+        (http::Method::PUT, \"/api/v1/nodes/{name}\"),
+        (http::Method::DELETE, \"/api/v1/nodes/{name}\"),
+        (http::Method::PATCH, \"/api/v1/nodes/{name}\"),
+        (http::Method::GET, \"/api/v1/nodes/{name}/proxy\"),
+        (http::Method::PUT, \"/api/v1/nodes/{name}/proxy\"),
+        (http::Method::POST, \"/api/v1/nodes/{name}/proxy\"),
+        (http::Method::DELETE, \"/api/v1/nodes/{name}/proxy\"),
+        (http::Method::OPTIONS, \"/api/v1/nodes/{name}/proxy\"),
+        (http::Method::HEAD, \"/api/v1/nodes/{name}/proxy\"),
+        (http::Method::PATCH, \"/api/v1/nodes/{name}/proxy\"),
+        (http::Method::GET, \"/api/v1/nodes/{name}/proxy/{path}\"),
+        (http::Method::PUT, \"/api/v1/nodes/{name}/proxy/{path}\"),
+        (http::Method::POST, \"/api/v1/nodes/{name}/proxy/{path}\"),
+        (http::Method::DELETE, \"/api/v1/nodes/{name}/proxy/{path}\"),
+        (http::Method::OPTIONS, \"/api/v1/nodes/{name}/proxy/{path}\"),
+        (http::Method::HEAD, \"/api/v1/nodes/{name}/proxy/{path}\"),
+        (http::Method::PATCH, \"/api/v1/nodes/{name}/proxy/{path}\"),
+        (http::Method::GET, \"/api/v1/nodes/{name}/status\"),
+        (http::Method::PUT, \"/api/v1/nodes/{name}/status\"),
+        (http::Method::PATCH, \"/api/v1/nodes/{name}/status\"),
+        (http::Method::GET, \"/api/v1/persistentvolumeclaims\"),
+        (http::Method::GET, \"/api/v1/persistentvolumes\"),
+        (http::Method::POST, \"/api/v1/persistentvolumes\"),
+        (http::Method::DELETE, \"/api/v1/persistentvolumes\"),
+        (http::Method::GET, \"/api/v1/persistentvolumes/{name}\"),
+        (http::Method::PUT, \"/api/v1/persistentvolumes/{name}\"),
+        (http::Method::DELETE, \"/api/v1/persistentvolumes/{name}\"),
+        (http::Method::PATCH, \"/api/v1/persistentvolumes/{name}\"),
+        (http::Method::GET, \"/api/v1/persistentvolumes/{name}/status\"),
+        (http::Method::PUT, \"/api/v1/persistentvolumes/{name}/status\"),
+        (http::Method::PATCH, \"/api/v1/persistentvolumes/{name}/status\"),
+        (http::Method::GET, \"/api/v1/pods\"),
+        (http::Method::GET, \"/api/v1/podtemplates\"),
+        (http::Method::GET, \"/api/v1/replicationcontrollers\"),
+        (http::Method::GET, \"/api/v1/resourcequotas\"),
+        (http::Method::GET, \"/api/v1/secrets\"),
+        (http::Method::GET, \"/api/v1/serviceaccounts\"),
+        (http::Method::GET, \"/api/v1/services\"),
+        (http::Method::GET, \"/api/v1/watch/configmaps\"),
+        (http::Method::GET, \"/api/v1/watch/endpoints\"),
+        (http::Method::GET, \"/api/v1/watch/events\"),
+        (http::Method::GET, \"/api/v1/watch/limitranges\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/configmaps\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/configmaps/{name}\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/endpoints\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/endpoints/{name}\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/events\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/events/{name}\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/limitranges\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/limitranges/{name}\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/persistentvolumeclaims\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/persistentvolumeclaims/{name}\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/pods\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/pods/{name}\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/podtemplates\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/podtemplates/{name}\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/replicationcontrollers\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/replicationcontrollers/{name}\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/resourcequotas\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/resourcequotas/{name}\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/secrets\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/secrets/{name}\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/serviceaccounts\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/serviceaccounts/{name}\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/services\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{namespace}/services/{name}\"),
+        (http::Method::GET, \"/api/v1/watch/namespaces/{name}\"),
+        (http::Method::GET, \"/api/v1/watch/nodes\"),
+        (http::Method::GET, \"/api/v1/watch/nodes/{name}\"),
+        (http::Method::GET, \"/api/v1/watch/persistentvolumeclaims\"),
+        (http::Method::GET, \"/api/v1/watch/persistentvolumes\"),
+        (http::Method::GET, \"/api/v1/watch/persistentvolumes/{name}\"),
+        (http::Method::GET, \"/api/v1/watch/pods\"),
+        (http::Method::GET, \"/api/v1/watch/podtemplates\"),
+        (http::Method::GET, \"/api/v1/watch/replicationcontrollers\"),
+        (http::Method::GET, \"/api/v1/watch/resourcequotas\"),
+        (http::Method::GET, \"/api/v1/watch/secrets\"),
+        (http::Method::GET, \"/api/v1/watch/serviceaccounts\"),
+        (http::Method::GET, \"/api/v1/watch/services\"),
+        (http::Method::GET, \"/apis/\"),
+        (http::Method::GET, \"/apis/admissionregistration.k8s.io/\"),
+        (http::Method::GET, \"/apis/admissionregistration.k8s.io/v1beta1/\"),
+        (http::Method::GET, \"/apis/admissionregistration.k8s.io/v1beta1/mutatingwebhookconfigurations\"),
+        (http::Method::POST, \"/apis/admissionregistration.k8s.io/v1beta1/mutatingwebhookconfigurations\"),
+        (http::Method::DELETE, \"/apis/admissionregistration.k8s.io/v1beta1/mutatingwebhookconfigurations\"),
+        (http::Method::GET, \"/apis/admissionregistration.k8s.io/v1beta1/mutatingwebhookconfigurations/{name}\"),
+        (http::Method::PUT, \"/apis/admissionregistration.k8s.io/v1beta1/mutatingwebhookconfigurations/{name}\"),
+        (http::Method::DELETE, \"/apis/admissionregistration.k8s.io/v1beta1/mutatingwebhookconfigurations/{name}\"),
+        (http::Method::PATCH, \"/apis/admissionregistration.k8s.io/v1beta1/mutatingwebhookconfigurations/{name}\"),
+        (http::Method::GET, \"/apis/admissionregistration.k8s.io/v1beta1/validatingwebhookconfigurations\"),
+        (http::Method::POST, \"/apis/admissionregistration.k8s.io/v1beta1/validatingwebhookconfigurations\"),
+        (http::Method::DELETE, \"/apis/admissionregistration.k8s.io/v1beta1/validatingwebhookconfigurations\"),
+        (http::Method::GET, \"/apis/admissionregistration.k8s.io/v1beta1/validatingwebhookconfigurations/{name}\"),
+        (http::Method::PUT, \"/apis/admissionregistration.k8s.io/v1beta1/validatingwebhookconfigurations/{name}\"),
+        (http::Method::DELETE, \"/apis/admissionregistration.k8s.io/v1beta1/validatingwebhookconfigurations/{name}\"),
+        (http::Method::PATCH, \"/apis/admissionregistration.k8s.io/v1beta1/validatingwebhookconfigurations/{name}\"),
+        (http::Method::GET, \"/apis/admissionregistration.k8s.io/v1beta1/watch/mutatingwebhookconfigurations\"),
+        (http::Method::GET, \"/apis/admissionregistration.k8s.io/v1beta1/watch/mutatingwebhookconfigurations/{name}\"),
+        (http::Method::GET, \"/apis/admissionregistration.k8s.io/v1beta1/watch/validatingwebhookconfigurations\"),
+        (http::Method::GET, \"/apis/admissionregistration.k8s.io/v1beta1/watch/validatingwebhookconfigurations/{name}\"),
+        (http::Method::GET, \"/apis/apiextensions.k8s.io/\"),
+        (http::Method::GET, \"/apis/apiextensions.k8s.io/v1beta1/\"),
+        (http::Method::GET, \"/apis/apiextensions.k8s.io/v1beta1/customresourcedefinitions\"),
+        (http::Method::POST, \"/apis/apiextensions.k8s.io/v1beta1/customresourcedefinitions\"),
+        (http::Method::DELETE, \"/apis/apiextensions.k8s.io/v1beta1/customresourcedefinitions\"),
+        (http::Method::GET, \"/apis/apiextensions.k8s.io/v1beta1/customresourcedefinitions/{name}\"),
+        (http::Method::PUT, \"/apis/apiextensions.k8s.io/v1beta1/customresourcedefinitions/{name}\"),
+        (http::Method::DELETE, \"/apis/apiextensions.k8s.io/v1beta1/customresourcedefinitions/{name}\"),
+        (http::Method::PATCH, \"/apis/apiextensions.k8s.io/v1beta1/customresourcedefinitions/{name}\"),
+        (http::Method::GET, \"/apis/apiextensions.k8s.io/v1beta1/customresourcedefinitions/{name}/status\"),
+        (http::Method::PUT, \"/apis/apiextensions.k8s.io/v1beta1/customresourcedefinitions/{name}/status\"),
+        (http::Method::PATCH, \"/apis/apiextensions.k8s.io/v1beta1/customresourcedefinitions/{name}/status\"),
+        (http::Method::GET, \"/apis/apiextensions.k8s.io/v1beta1/watch/customresourcedefinitions\"),
+        (http::Method::GET, \"/apis/apiextensions.k8s.io/v1beta1/watch/customresourcedefinitions/{name}\"),
+        (http::Method::GET, \"/apis/apiregistration.k8s.io/\"),
+        (http::Method::GET, \"/apis/apiregistration.k8s.io/v1/\"),
+        (http::Method::GET, \"/apis/apiregistration.k8s.io/v1/apiservices\"),
+        (http::Method::POST, \"/apis/apiregistration.k8s.io/v1/apiservices\"),
+        (http::Method::DELETE, \"/apis/apiregistration.k8s.io/v1/apiservices\"),
+        (http::Method::GET, \"/apis/apiregistration.k8s.io/v1/apiservices/{name}\"),
+        (http::Method::PUT, \"/apis/apiregistration.k8s.io/v1/apiservices/{name}\"),
+        (http::Method::DELETE, \"/apis/apiregistration.k8s.io/v1/apiservices/{name}\"),
+        (http::Method::PATCH, \"/apis/apiregistration.k8s.io/v1/apiservices/{name}\"),
+        (http::Method::GET, \"/apis/apiregistration.k8s.io/v1/apiservices/{name}/status\"),
+        (http::Method::PUT, \"/apis/apiregistration.k8s.io/v1/apiservices/{name}/status\"),
+        (http::Method::PATCH, \"/apis/apiregistration.k8s.io/v1/apiservices/{name}/status\"),
+        (http::Method::GET, \"/apis/apiregistration.k8s.io/v1/watch/apiservices\"),
+        (http::Method::GET, \"/apis/apiregistration.k8s.io/v1/watch/apiservices/{name}\"),
+        (http::Method::GET, \"/apis/apiregistration.k8s.io/v1beta1/\"),
+        (http::Method::GET, \"/apis/apiregistration.k8s.io/v1beta1/apiservices\"),
+        (http::Method::POST, \"/apis/apiregistration.k8s.io/v1beta1/apiservices\"),
+        (http::Method::DELETE, \"/apis/apiregistration.k8s.io/v1beta1/apiservices\"),
+        (http::Method::GET, \"/apis/apiregistration.k8s.io/v1beta1/apiservices/{name}\"),
+        (http::Method::PUT, \"/apis/apiregistration.k8s.io/v1beta1/apiservices/{name}\"),
+        (http::Method::DELETE, \"/apis/apiregistration.k8s.io/v1beta1/apiservices/{name}\"),
+        (http::Method::PATCH, \"/apis/apiregistration.k8s.io/v1beta1/apiservices/{name}\"),
+        (http::Method::GET, \"/apis/apiregistration.k8s.io/v1beta1/apiservices/{name}/status\"),
+        (http::Method::PUT, \"/apis/apiregistration.k8s.io/v1beta1/apiservices/{name}/status\"),
+        (http::Method::PATCH, \"/apis/apiregistration.k8s.io/v1beta1/apiservices/{name}/status\"),
+        (http::Method::GET, \"/apis/apiregistration.k8s.io/v1beta1/watch/apiservices\"),
+        (http::Method::GET, \"/apis/apiregistration.k8s.io/v1beta1/watch/apiservices/{name}\"),
+        (http::Method::GET, \"/apis/apps/\"),
+        (http::Method::GET, \"/apis/apps/v1/\"),
+        (http::Method::GET, \"/apis/apps/v1/controllerrevisions\"),
+        (http::Method::GET, \"/apis/apps/v1/daemonsets\"),
+        (http::Method::GET, \"/apis/apps/v1/deployments\"),
+        (http::Method::GET, \"/apis/apps/v1/namespaces/{namespace}/controllerrevisions\"),
+        (http::Method::POST, \"/apis/apps/v1/namespaces/{namespace}/controllerrevisions\"),
+        (http::Method::DELETE, \"/apis/apps/v1/namespaces/{namespace}/controllerrevisions\"),
+        (http::Method::GET, \"/apis/apps/v1/namespaces/{namespace}/controllerrevisions/{name}\"),
+        (http::Method::PUT, \"/apis/apps/v1/namespaces/{namespace}/controllerrevisions/{name}\"),
+        (http::Method::DELETE, \"/apis/apps/v1/namespaces/{namespace}/controllerrevisions/{name}\"),
+        (http::Method::PATCH, \"/apis/apps/v1/namespaces/{namespace}/controllerrevisions/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1/namespaces/{namespace}/daemonsets\"),
+        (http::Method::POST, \"/apis/apps/v1/namespaces/{namespace}/daemonsets\"),
+        (http::Method::DELETE, \"/apis/apps/v1/namespaces/{namespace}/daemonsets\"),
+        (http::Method::GET, \"/apis/apps/v1/namespaces/{namespace}/daemonsets/{name}\"),
+        (http::Method::PUT, \"/apis/apps/v1/namespaces/{namespace}/daemonsets/{name}\"),
+        (http::Method::DELETE, \"/apis/apps/v1/namespaces/{namespace}/daemonsets/{name}\"),
+        (http::Method::PATCH, \"/apis/apps/v1/namespaces/{namespace}/daemonsets/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1/namespaces/{namespace}/daemonsets/{name}/status\"),
+        (http::Method::PUT, \"/apis/apps/v1/namespaces/{namespace}/daemonsets/{name}/status\"),
+        (http::Method::PATCH, \"/apis/apps/v1/namespaces/{namespace}/daemonsets/{name}/status\"),
+        (http::Method::GET, \"/apis/apps/v1/namespaces/{namespace}/deployments\"),
+        (http::Method::POST, \"/apis/apps/v1/namespaces/{namespace}/deployments\"),
+        (http::Method::DELETE, \"/apis/apps/v1/namespaces/{namespace}/deployments\"),
+        (http::Method::GET, \"/apis/apps/v1/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::PUT, \"/apis/apps/v1/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::DELETE, \"/apis/apps/v1/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::PATCH, \"/apis/apps/v1/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1/namespaces/{namespace}/deployments/{name}/scale\"),
+        (http::Method::PUT, \"/apis/apps/v1/namespaces/{namespace}/deployments/{name}/scale\"),
+        (http::Method::PATCH, \"/apis/apps/v1/namespaces/{namespace}/deployments/{name}/scale\"),
+        (http::Method::GET, \"/apis/apps/v1/namespaces/{namespace}/deployments/{name}/status\"),
+        (http::Method::PUT, \"/apis/apps/v1/namespaces/{namespace}/deployments/{name}/status\"),
+        (http::Method::PATCH, \"/apis/apps/v1/namespaces/{namespace}/deployments/{name}/status\"),
+        (http::Method::GET, \"/apis/apps/v1/namespaces/{namespace}/replicasets\"),
+        (http::Method::POST, \"/apis/apps/v1/namespaces/{namespace}/replicasets\"),
+        (http::Method::DELETE, \"/apis/apps/v1/namespaces/{namespace}/replicasets\"),
+        (http::Method::GET, \"/apis/apps/v1/namespaces/{namespace}/replicasets/{name}\"),
+        (http::Method::PUT, \"/apis/apps/v1/namespaces/{namespace}/replicasets/{name}\"),
+        (http::Method::DELETE, \"/apis/apps/v1/namespaces/{namespace}/replicasets/{name}\"),
+        (http::Method::PATCH, \"/apis/apps/v1/namespaces/{namespace}/replicasets/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1/namespaces/{namespace}/replicasets/{name}/scale\"),
+        (http::Method::PUT, \"/apis/apps/v1/namespaces/{namespace}/replicasets/{name}/scale\"),
+        (http::Method::PATCH, \"/apis/apps/v1/namespaces/{namespace}/replicasets/{name}/scale\"),
+        (http::Method::GET, \"/apis/apps/v1/namespaces/{namespace}/replicasets/{name}/status\"),
+        (http::Method::PUT, \"/apis/apps/v1/namespaces/{namespace}/replicasets/{name}/status\"),
+        (http::Method::PATCH, \"/apis/apps/v1/namespaces/{namespace}/replicasets/{name}/status\"),
+        (http::Method::GET, \"/apis/apps/v1/namespaces/{namespace}/statefulsets\"),
+        (http::Method::POST, \"/apis/apps/v1/namespaces/{namespace}/statefulsets\"),
+        (http::Method::DELETE, \"/apis/apps/v1/namespaces/{namespace}/statefulsets\"),
+        (http::Method::GET, \"/apis/apps/v1/namespaces/{namespace}/statefulsets/{name}\"),
+        (http::Method::PUT, \"/apis/apps/v1/namespaces/{namespace}/statefulsets/{name}\"),
+        (http::Method::DELETE, \"/apis/apps/v1/namespaces/{namespace}/statefulsets/{name}\"),
+        (http::Method::PATCH, \"/apis/apps/v1/namespaces/{namespace}/statefulsets/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1/namespaces/{namespace}/statefulsets/{name}/scale\"),
+        (http::Method::PUT, \"/apis/apps/v1/namespaces/{namespace}/statefulsets/{name}/scale\"),
+        (http::Method::PATCH, \"/apis/apps/v1/namespaces/{namespace}/statefulsets/{name}/scale\"),
+        (http::Method::GET, \"/apis/apps/v1/namespaces/{namespace}/statefulsets/{name}/status\"),
+        (http::Method::PUT, \"/apis/apps/v1/namespaces/{namespace}/statefulsets/{name}/status\"),
+        (http::Method::PATCH, \"/apis/apps/v1/namespaces/{namespace}/statefulsets/{name}/status\"),
+        (http::Method::GET, \"/apis/apps/v1/replicasets\"),
+        (http::Method::GET, \"/apis/apps/v1/statefulsets\"),
+        (http::Method::GET, \"/apis/apps/v1/watch/controllerrevisions\"),
+        (http::Method::GET, \"/apis/apps/v1/watch/daemonsets\"),
+        (http::Method::GET, \"/apis/apps/v1/watch/deployments\"),
+        (http::Method::GET, \"/apis/apps/v1/watch/namespaces/{namespace}/controllerrevisions\"),
+        (http::Method::GET, \"/apis/apps/v1/watch/namespaces/{namespace}/controllerrevisions/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1/watch/namespaces/{namespace}/daemonsets\"),
+        (http::Method::GET, \"/apis/apps/v1/watch/namespaces/{namespace}/daemonsets/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1/watch/namespaces/{namespace}/deployments\"),
+        (http::Method::GET, \"/apis/apps/v1/watch/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1/watch/namespaces/{namespace}/replicasets\"),
+        (http::Method::GET, \"/apis/apps/v1/watch/namespaces/{namespace}/replicasets/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1/watch/namespaces/{namespace}/statefulsets\"),
+        (http::Method::GET, \"/apis/apps/v1/watch/namespaces/{namespace}/statefulsets/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1/watch/replicasets\"),
+        (http::Method::GET, \"/apis/apps/v1/watch/statefulsets\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/controllerrevisions\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/deployments\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/namespaces/{namespace}/controllerrevisions\"),
+        (http::Method::POST, \"/apis/apps/v1beta1/namespaces/{namespace}/controllerrevisions\"),
+        (http::Method::DELETE, \"/apis/apps/v1beta1/namespaces/{namespace}/controllerrevisions\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/namespaces/{namespace}/controllerrevisions/{name}\"),
+        (http::Method::PUT, \"/apis/apps/v1beta1/namespaces/{namespace}/controllerrevisions/{name}\"),
+        (http::Method::DELETE, \"/apis/apps/v1beta1/namespaces/{namespace}/controllerrevisions/{name}\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta1/namespaces/{namespace}/controllerrevisions/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/namespaces/{namespace}/deployments\"),
+        (http::Method::POST, \"/apis/apps/v1beta1/namespaces/{namespace}/deployments\"),
+        (http::Method::DELETE, \"/apis/apps/v1beta1/namespaces/{namespace}/deployments\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::PUT, \"/apis/apps/v1beta1/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::DELETE, \"/apis/apps/v1beta1/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta1/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::POST, \"/apis/apps/v1beta1/namespaces/{namespace}/deployments/{name}/rollback\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/namespaces/{namespace}/deployments/{name}/scale\"),
+        (http::Method::PUT, \"/apis/apps/v1beta1/namespaces/{namespace}/deployments/{name}/scale\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta1/namespaces/{namespace}/deployments/{name}/scale\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/namespaces/{namespace}/deployments/{name}/status\"),
+        (http::Method::PUT, \"/apis/apps/v1beta1/namespaces/{namespace}/deployments/{name}/status\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta1/namespaces/{namespace}/deployments/{name}/status\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/namespaces/{namespace}/statefulsets\"),
+        (http::Method::POST, \"/apis/apps/v1beta1/namespaces/{namespace}/statefulsets\"),
+        (http::Method::DELETE, \"/apis/apps/v1beta1/namespaces/{namespace}/statefulsets\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/namespaces/{namespace}/statefulsets/{name}\"),
+        (http::Method::PUT, \"/apis/apps/v1beta1/namespaces/{namespace}/statefulsets/{name}\"),
+        (http::Method::DELETE, \"/apis/apps/v1beta1/namespaces/{namespace}/statefulsets/{name}\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta1/namespaces/{namespace}/statefulsets/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/namespaces/{namespace}/statefulsets/{name}/scale\"),
+        (http::Method::PUT, \"/apis/apps/v1beta1/namespaces/{namespace}/statefulsets/{name}/scale\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta1/namespaces/{namespace}/statefulsets/{name}/scale\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/namespaces/{namespace}/statefulsets/{name}/status\"),
+        (http::Method::PUT, \"/apis/apps/v1beta1/namespaces/{namespace}/statefulsets/{name}/status\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta1/namespaces/{namespace}/statefulsets/{name}/status\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/statefulsets\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/watch/controllerrevisions\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/watch/deployments\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/watch/namespaces/{namespace}/controllerrevisions\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/watch/namespaces/{namespace}/controllerrevisions/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/watch/namespaces/{namespace}/deployments\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/watch/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/watch/namespaces/{namespace}/statefulsets\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/watch/namespaces/{namespace}/statefulsets/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1beta1/watch/statefulsets\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/controllerrevisions\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/daemonsets\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/deployments\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/namespaces/{namespace}/controllerrevisions\"),
+        (http::Method::POST, \"/apis/apps/v1beta2/namespaces/{namespace}/controllerrevisions\"),
+        (http::Method::DELETE, \"/apis/apps/v1beta2/namespaces/{namespace}/controllerrevisions\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/namespaces/{namespace}/controllerrevisions/{name}\"),
+        (http::Method::PUT, \"/apis/apps/v1beta2/namespaces/{namespace}/controllerrevisions/{name}\"),
+        (http::Method::DELETE, \"/apis/apps/v1beta2/namespaces/{namespace}/controllerrevisions/{name}\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta2/namespaces/{namespace}/controllerrevisions/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/namespaces/{namespace}/daemonsets\"),
+        (http::Method::POST, \"/apis/apps/v1beta2/namespaces/{namespace}/daemonsets\"),
+        (http::Method::DELETE, \"/apis/apps/v1beta2/namespaces/{namespace}/daemonsets\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/namespaces/{namespace}/daemonsets/{name}\"),
+        (http::Method::PUT, \"/apis/apps/v1beta2/namespaces/{namespace}/daemonsets/{name}\"),
+        (http::Method::DELETE, \"/apis/apps/v1beta2/namespaces/{namespace}/daemonsets/{name}\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta2/namespaces/{namespace}/daemonsets/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/namespaces/{namespace}/daemonsets/{name}/status\"),
+        (http::Method::PUT, \"/apis/apps/v1beta2/namespaces/{namespace}/daemonsets/{name}/status\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta2/namespaces/{namespace}/daemonsets/{name}/status\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/namespaces/{namespace}/deployments\"),
+        (http::Method::POST, \"/apis/apps/v1beta2/namespaces/{namespace}/deployments\"),
+        (http::Method::DELETE, \"/apis/apps/v1beta2/namespaces/{namespace}/deployments\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::PUT, \"/apis/apps/v1beta2/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::DELETE, \"/apis/apps/v1beta2/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta2/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/namespaces/{namespace}/deployments/{name}/scale\"),
+        (http::Method::PUT, \"/apis/apps/v1beta2/namespaces/{namespace}/deployments/{name}/scale\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta2/namespaces/{namespace}/deployments/{name}/scale\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/namespaces/{namespace}/deployments/{name}/status\"),
+        (http::Method::PUT, \"/apis/apps/v1beta2/namespaces/{namespace}/deployments/{name}/status\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta2/namespaces/{namespace}/deployments/{name}/status\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/namespaces/{namespace}/replicasets\"),
+        (http::Method::POST, \"/apis/apps/v1beta2/namespaces/{namespace}/replicasets\"),
+        (http::Method::DELETE, \"/apis/apps/v1beta2/namespaces/{namespace}/replicasets\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}\"),
+        (http::Method::PUT, \"/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}\"),
+        (http::Method::DELETE, \"/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}/scale\"),
+        (http::Method::PUT, \"/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}/scale\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}/scale\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}/status\"),
+        (http::Method::PUT, \"/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}/status\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta2/namespaces/{namespace}/replicasets/{name}/status\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/namespaces/{namespace}/statefulsets\"),
+        (http::Method::POST, \"/apis/apps/v1beta2/namespaces/{namespace}/statefulsets\"),
+        (http::Method::DELETE, \"/apis/apps/v1beta2/namespaces/{namespace}/statefulsets\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}\"),
+        (http::Method::PUT, \"/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}\"),
+        (http::Method::DELETE, \"/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}/scale\"),
+        (http::Method::PUT, \"/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}/scale\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}/scale\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}/status\"),
+        (http::Method::PUT, \"/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}/status\"),
+        (http::Method::PATCH, \"/apis/apps/v1beta2/namespaces/{namespace}/statefulsets/{name}/status\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/replicasets\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/statefulsets\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/watch/controllerrevisions\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/watch/daemonsets\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/watch/deployments\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/watch/namespaces/{namespace}/controllerrevisions\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/watch/namespaces/{namespace}/controllerrevisions/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/watch/namespaces/{namespace}/daemonsets\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/watch/namespaces/{namespace}/daemonsets/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/watch/namespaces/{namespace}/deployments\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/watch/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/watch/namespaces/{namespace}/replicasets\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/watch/namespaces/{namespace}/replicasets/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/watch/namespaces/{namespace}/statefulsets\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/watch/namespaces/{namespace}/statefulsets/{name}\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/watch/replicasets\"),
+        (http::Method::GET, \"/apis/apps/v1beta2/watch/statefulsets\"),
+        (http::Method::GET, \"/apis/auditregistration.k8s.io/\"),
+        (http::Method::GET, \"/apis/auditregistration.k8s.io/v1alpha1/\"),
+        (http::Method::GET, \"/apis/auditregistration.k8s.io/v1alpha1/auditsinks\"),
+        (http::Method::POST, \"/apis/auditregistration.k8s.io/v1alpha1/auditsinks\"),
+        (http::Method::DELETE, \"/apis/auditregistration.k8s.io/v1alpha1/auditsinks\"),
+        (http::Method::GET, \"/apis/auditregistration.k8s.io/v1alpha1/auditsinks/{name}\"),
+        (http::Method::PUT, \"/apis/auditregistration.k8s.io/v1alpha1/auditsinks/{name}\"),
+        (http::Method::DELETE, \"/apis/auditregistration.k8s.io/v1alpha1/auditsinks/{name}\"),
+        (http::Method::PATCH, \"/apis/auditregistration.k8s.io/v1alpha1/auditsinks/{name}\"),
+        (http::Method::GET, \"/apis/auditregistration.k8s.io/v1alpha1/watch/auditsinks\"),
+        (http::Method::GET, \"/apis/auditregistration.k8s.io/v1alpha1/watch/auditsinks/{name}\"),
+        (http::Method::GET, \"/apis/authentication.k8s.io/\"),
+        (http::Method::GET, \"/apis/authentication.k8s.io/v1/\"),
+        (http::Method::POST, \"/apis/authentication.k8s.io/v1/tokenreviews\"),
+        (http::Method::GET, \"/apis/authentication.k8s.io/v1beta1/\"),
+        (http::Method::POST, \"/apis/authentication.k8s.io/v1beta1/tokenreviews\"),
+        (http::Method::GET, \"/apis/authorization.k8s.io/\"),
+        (http::Method::GET, \"/apis/authorization.k8s.io/v1/\"),
+        (http::Method::POST, \"/apis/authorization.k8s.io/v1/namespaces/{namespace}/localsubjectaccessreviews\"),
+        (http::Method::POST, \"/apis/authorization.k8s.io/v1/selfsubjectaccessreviews\"),
+        (http::Method::POST, \"/apis/authorization.k8s.io/v1/selfsubjectrulesreviews\"),
+        (http::Method::POST, \"/apis/authorization.k8s.io/v1/subjectaccessreviews\"),
+        (http::Method::GET, \"/apis/authorization.k8s.io/v1beta1/\"),
+        (http::Method::POST, \"/apis/authorization.k8s.io/v1beta1/namespaces/{namespace}/localsubjectaccessreviews\"),
+        (http::Method::POST, \"/apis/authorization.k8s.io/v1beta1/selfsubjectaccessreviews\"),
+        (http::Method::POST, \"/apis/authorization.k8s.io/v1beta1/selfsubjectrulesreviews\"),
+        (http::Method::POST, \"/apis/authorization.k8s.io/v1beta1/subjectaccessreviews\"),
+        (http::Method::GET, \"/apis/autoscaling/\"),
+        (http::Method::GET, \"/apis/autoscaling/v1/\"),
+        (http::Method::GET, \"/apis/autoscaling/v1/horizontalpodautoscalers\"),
+        (http::Method::GET, \"/apis/autoscaling/v1/namespaces/{namespace}/horizontalpodautoscalers\"),
+        (http::Method::POST, \"/apis/autoscaling/v1/namespaces/{namespace}/horizontalpodautoscalers\"),
+        (http::Method::DELETE, \"/apis/autoscaling/v1/namespaces/{namespace}/horizontalpodautoscalers\"),
+        (http::Method::GET, \"/apis/autoscaling/v1/namespaces/{namespace}/horizontalpodautoscalers/{name}\"),
+        (http::Method::PUT, \"/apis/autoscaling/v1/namespaces/{namespace}/horizontalpodautoscalers/{name}\"),
+        (http::Method::DELETE, \"/apis/autoscaling/v1/namespaces/{namespace}/horizontalpodautoscalers/{name}\"),
+        (http::Method::PATCH, \"/apis/autoscaling/v1/namespaces/{namespace}/horizontalpodautoscalers/{name}\"),
+        (http::Method::GET, \"/apis/autoscaling/v1/namespaces/{namespace}/horizontalpodautoscalers/{name}/status\"),
+        (http::Method::PUT, \"/apis/autoscaling/v1/namespaces/{namespace}/horizontalpodautoscalers/{name}/status\"),
+        (http::Method::PATCH, \"/apis/autoscaling/v1/namespaces/{namespace}/horizontalpodautoscalers/{name}/status\"),
+        (http::Method::GET, \"/apis/autoscaling/v1/watch/horizontalpodautoscalers\"),
+        (http::Method::GET, \"/apis/autoscaling/v1/watch/namespaces/{namespace}/horizontalpodautoscalers\"),
+        (http::Method::GET, \"/apis/autoscaling/v1/watch/namespaces/{namespace}/horizontalpodautoscalers/{name}\"),
+        (http::Method::GET, \"/apis/autoscaling/v2beta1/\"),
+        (http::Method::GET, \"/apis/autoscaling/v2beta1/horizontalpodautoscalers\"),
+        (http::Method::GET, \"/apis/autoscaling/v2beta1/namespaces/{namespace}/horizontalpodautoscalers\"),
+        (http::Method::POST, \"/apis/autoscaling/v2beta1/namespaces/{namespace}/horizontalpodautoscalers\"),
+        (http::Method::DELETE, \"/apis/autoscaling/v2beta1/namespaces/{namespace}/horizontalpodautoscalers\"),
+        (http::Method::GET, \"/apis/autoscaling/v2beta1/namespaces/{namespace}/horizontalpodautoscalers/{name}\"),
+        (http::Method::PUT, \"/apis/autoscaling/v2beta1/namespaces/{namespace}/horizontalpodautoscalers/{name}\"),
+        (http::Method::DELETE, \"/apis/autoscaling/v2beta1/namespaces/{namespace}/horizontalpodautoscalers/{name}\"),
+        (http::Method::PATCH, \"/apis/autoscaling/v2beta1/namespaces/{namespace}/horizontalpodautoscalers/{name}\"),
+        (http::Method::GET, \"/apis/autoscaling/v2beta1/namespaces/{namespace}/horizontalpodautoscalers/{name}/status\"),
+        (http::Method::PUT, \"/apis/autoscaling/v2beta1/namespaces/{namespace}/horizontalpodautoscalers/{name}/status\"),
+        (http::Method::PATCH, \"/apis/autoscaling/v2beta1/namespaces/{namespace}/horizontalpodautoscalers/{name}/status\"),
+        (http::Method::GET, \"/apis/autoscaling/v2beta1/watch/horizontalpodautoscalers\"),
+        (http::Method::GET, \"/apis/autoscaling/v2beta1/watch/namespaces/{namespace}/horizontalpodautoscalers\"),
+        (http::Method::GET, \"/apis/autoscaling/v2beta1/watch/namespaces/{namespace}/horizontalpodautoscalers/{name}\"),
+        (http::Method::GET, \"/apis/autoscaling/v2beta2/\"),
+        (http::Method::GET, \"/apis/autoscaling/v2beta2/horizontalpodautoscalers\"),
+        (http::Method::GET, \"/apis/autoscaling/v2beta2/namespaces/{namespace}/horizontalpodautoscalers\"),
+        (http::Method::POST, \"/apis/autoscaling/v2beta2/namespaces/{namespace}/horizontalpodautoscalers\"),
+        (http::Method::DELETE, \"/apis/autoscaling/v2beta2/namespaces/{namespace}/horizontalpodautoscalers\"),
+        (http::Method::GET, \"/apis/autoscaling/v2beta2/namespaces/{namespace}/horizontalpodautoscalers/{name}\"),
+        (http::Method::PUT, \"/apis/autoscaling/v2beta2/namespaces/{namespace}/horizontalpodautoscalers/{name}\"),
+        (http::Method::DELETE, \"/apis/autoscaling/v2beta2/namespaces/{namespace}/horizontalpodautoscalers/{name}\"),
+        (http::Method::PATCH, \"/apis/autoscaling/v2beta2/namespaces/{namespace}/horizontalpodautoscalers/{name}\"),
+        (http::Method::GET, \"/apis/autoscaling/v2beta2/namespaces/{namespace}/horizontalpodautoscalers/{name}/status\"),
+        (http::Method::PUT, \"/apis/autoscaling/v2beta2/namespaces/{namespace}/horizontalpodautoscalers/{name}/status\"),
+        (http::Method::PATCH, \"/apis/autoscaling/v2beta2/namespaces/{namespace}/horizontalpodautoscalers/{name}/status\"),
+        (http::Method::GET, \"/apis/autoscaling/v2beta2/watch/horizontalpodautoscalers\"),
+        (http::Method::GET, \"/apis/autoscaling/v2beta2/watch/namespaces/{namespace}/horizontalpodautoscalers\"),
+        (http::Method::GET, \"/apis/autoscaling/v2beta2/watch/namespaces/{namespace}/horizontalpodautoscalers/{name}\"),
+        (http::Method::GET, \"/apis/batch/\"),
+        (http::Method::GET, \"/apis/batch/v1/\"),
+        (http::Method::GET, \"/apis/batch/v1/jobs\"),
+        (http::Method::GET, \"/apis/batch/v1/namespaces/{namespace}/jobs\"),
+        (http::Method::POST, \"/apis/batch/v1/namespaces/{namespace}/jobs\"),
+        (http::Method::DELETE, \"/apis/batch/v1/namespaces/{namespace}/jobs\"),
+        (http::Method::GET, \"/apis/batch/v1/namespaces/{namespace}/jobs/{name}\"),
+        (http::Method::PUT, \"/apis/batch/v1/namespaces/{namespace}/jobs/{name}\"),
+        (http::Method::DELETE, \"/apis/batch/v1/namespaces/{namespace}/jobs/{name}\"),
+        (http::Method::PATCH, \"/apis/batch/v1/namespaces/{namespace}/jobs/{name}\"),
+        (http::Method::GET, \"/apis/batch/v1/namespaces/{namespace}/jobs/{name}/status\"),
+        (http::Method::PUT, \"/apis/batch/v1/namespaces/{namespace}/jobs/{name}/status\"),
+        (http::Method::PATCH, \"/apis/batch/v1/namespaces/{namespace}/jobs/{name}/status\"),
+        (http::Method::GET, \"/apis/batch/v1/watch/jobs\"),
+        (http::Method::GET, \"/apis/batch/v1/watch/namespaces/{namespace}/jobs\"),
+        (http::Method::GET, \"/apis/batch/v1/watch/namespaces/{namespace}/jobs/{name}\"),
+        (http::Method::GET, \"/apis/batch/v1beta1/\"),
+        (http::Method::GET, \"/apis/batch/v1beta1/cronjobs\"),
+        (http::Method::GET, \"/apis/batch/v1beta1/namespaces/{namespace}/cronjobs\"),
+        (http::Method::POST, \"/apis/batch/v1beta1/namespaces/{namespace}/cronjobs\"),
+        (http::Method::DELETE, \"/apis/batch/v1beta1/namespaces/{namespace}/cronjobs\"),
+        (http::Method::GET, \"/apis/batch/v1beta1/namespaces/{namespace}/cronjobs/{name}\"),
+        (http::Method::PUT, \"/apis/batch/v1beta1/namespaces/{namespace}/cronjobs/{name}\"),
+        (http::Method::DELETE, \"/apis/batch/v1beta1/namespaces/{namespace}/cronjobs/{name}\"),
+        (http::Method::PATCH, \"/apis/batch/v1beta1/namespaces/{namespace}/cronjobs/{name}\"),
+        (http::Method::GET, \"/apis/batch/v1beta1/namespaces/{namespace}/cronjobs/{name}/status\"),
+        (http::Method::PUT, \"/apis/batch/v1beta1/namespaces/{namespace}/cronjobs/{name}/status\"),
+        (http::Method::PATCH, \"/apis/batch/v1beta1/namespaces/{namespace}/cronjobs/{name}/status\"),
+        (http::Method::GET, \"/apis/batch/v1beta1/watch/cronjobs\"),
+        (http::Method::GET, \"/apis/batch/v1beta1/watch/namespaces/{namespace}/cronjobs\"),
+        (http::Method::GET, \"/apis/batch/v1beta1/watch/namespaces/{namespace}/cronjobs/{name}\"),
+        (http::Method::GET, \"/apis/batch/v2alpha1/\"),
+        (http::Method::GET, \"/apis/batch/v2alpha1/cronjobs\"),
+        (http::Method::GET, \"/apis/batch/v2alpha1/namespaces/{namespace}/cronjobs\"),
+        (http::Method::POST, \"/apis/batch/v2alpha1/namespaces/{namespace}/cronjobs\"),
+        (http::Method::DELETE, \"/apis/batch/v2alpha1/namespaces/{namespace}/cronjobs\"),
+        (http::Method::GET, \"/apis/batch/v2alpha1/namespaces/{namespace}/cronjobs/{name}\"),
+        (http::Method::PUT, \"/apis/batch/v2alpha1/namespaces/{namespace}/cronjobs/{name}\"),
+        (http::Method::DELETE, \"/apis/batch/v2alpha1/namespaces/{namespace}/cronjobs/{name}\"),
+        (http::Method::PATCH, \"/apis/batch/v2alpha1/namespaces/{namespace}/cronjobs/{name}\"),
+        (http::Method::GET, \"/apis/batch/v2alpha1/namespaces/{namespace}/cronjobs/{name}/status\"),
+        (http::Method::PUT, \"/apis/batch/v2alpha1/namespaces/{namespace}/cronjobs/{name}/status\"),
+        (http::Method::PATCH, \"/apis/batch/v2alpha1/namespaces/{namespace}/cronjobs/{name}/status\"),
+        (http::Method::GET, \"/apis/batch/v2alpha1/watch/cronjobs\"),
+        (http::Method::GET, \"/apis/batch/v2alpha1/watch/namespaces/{namespace}/cronjobs\"),
+        (http::Method::GET, \"/apis/batch/v2alpha1/watch/namespaces/{namespace}/cronjobs/{name}\"),
+        (http::Method::GET, \"/apis/certificates.k8s.io/\"),
+        (http::Method::GET, \"/apis/certificates.k8s.io/v1beta1/\"),
+        (http::Method::GET, \"/apis/certificates.k8s.io/v1beta1/certificatesigningrequests\"),
+        (http::Method::POST, \"/apis/certificates.k8s.io/v1beta1/certificatesigningrequests\"),
+        (http::Method::DELETE, \"/apis/certificates.k8s.io/v1beta1/certificatesigningrequests\"),
+        (http::Method::GET, \"/apis/certificates.k8s.io/v1beta1/certificatesigningrequests/{name}\"),
+        (http::Method::PUT, \"/apis/certificates.k8s.io/v1beta1/certificatesigningrequests/{name}\"),
+        (http::Method::DELETE, \"/apis/certificates.k8s.io/v1beta1/certificatesigningrequests/{name}\"),
+        (http::Method::PATCH, \"/apis/certificates.k8s.io/v1beta1/certificatesigningrequests/{name}\"),
+        (http::Method::PUT, \"/apis/certificates.k8s.io/v1beta1/certificatesigningrequests/{name}/approval\"),
+        (http::Method::GET, \"/apis/certificates.k8s.io/v1beta1/certificatesigningrequests/{name}/status\"),
+        (http::Method::PUT, \"/apis/certificates.k8s.io/v1beta1/certificatesigningrequests/{name}/status\"),
+        (http::Method::PATCH, \"/apis/certificates.k8s.io/v1beta1/certificatesigningrequests/{name}/status\"),
+        (http::Method::GET, \"/apis/certificates.k8s.io/v1beta1/watch/certificatesigningrequests\"),
+        (http::Method::GET, \"/apis/certificates.k8s.io/v1beta1/watch/certificatesigningrequests/{name}\"),
+        (http::Method::GET, \"/apis/coordination.k8s.io/\"),
+        (http::Method::GET, \"/apis/coordination.k8s.io/v1/\"),
+        (http::Method::GET, \"/apis/coordination.k8s.io/v1/leases\"),
+        (http::Method::GET, \"/apis/coordination.k8s.io/v1/namespaces/{namespace}/leases\"),
+        (http::Method::POST, \"/apis/coordination.k8s.io/v1/namespaces/{namespace}/leases\"),
+        (http::Method::DELETE, \"/apis/coordination.k8s.io/v1/namespaces/{namespace}/leases\"),
+        (http::Method::GET, \"/apis/coordination.k8s.io/v1/namespaces/{namespace}/leases/{name}\"),
+        (http::Method::PUT, \"/apis/coordination.k8s.io/v1/namespaces/{namespace}/leases/{name}\"),
+        (http::Method::DELETE, \"/apis/coordination.k8s.io/v1/namespaces/{namespace}/leases/{name}\"),
+        (http::Method::PATCH, \"/apis/coordination.k8s.io/v1/namespaces/{namespace}/leases/{name}\"),
+        (http::Method::GET, \"/apis/coordination.k8s.io/v1/watch/leases\"),
+        (http::Method::GET, \"/apis/coordination.k8s.io/v1/watch/namespaces/{namespace}/leases\"),
+        (http::Method::GET, \"/apis/coordination.k8s.io/v1/watch/namespaces/{namespace}/leases/{name}\"),
+        (http::Method::GET, \"/apis/coordination.k8s.io/v1beta1/\"),
+        (http::Method::GET, \"/apis/coordination.k8s.io/v1beta1/leases\"),
+        (http::Method::GET, \"/apis/coordination.k8s.io/v1beta1/namespaces/{namespace}/leases\"),
+        (http::Method::POST, \"/apis/coordination.k8s.io/v1beta1/namespaces/{namespace}/leases\"),
+        (http::Method::DELETE, \"/apis/coordination.k8s.io/v1beta1/namespaces/{namespace}/leases\"),
+        (http::Method::GET, \"/apis/coordination.k8s.io/v1beta1/namespaces/{namespace}/leases/{name}\"),
+        (http::Method::PUT, \"/apis/coordination.k8s.io/v1beta1/namespaces/{namespace}/leases/{name}\"),
+        (http::Method::DELETE, \"/apis/coordination.k8s.io/v1beta1/namespaces/{namespace}/leases/{name}\"),
+        (http::Method::PATCH, \"/apis/coordination.k8s.io/v1beta1/namespaces/{namespace}/leases/{name}\"),
+        (http::Method::GET, \"/apis/coordination.k8s.io/v1beta1/watch/leases\"),
+        (http::Method::GET, \"/apis/coordination.k8s.io/v1beta1/watch/namespaces/{namespace}/leases\"),
+        (http::Method::GET, \"/apis/coordination.k8s.io/v1beta1/watch/namespaces/{namespace}/leases/{name}\"),
+        (http::Method::GET, \"/apis/events.k8s.io/\"),
+        (http::Method::GET, \"/apis/events.k8s.io/v1beta1/\"),
+        (http::Method::GET, \"/apis/events.k8s.io/v1beta1/events\"),
+        (http::Method::GET, \"/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events\"),
+        (http::Method::POST, \"/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events\"),
+        (http::Method::DELETE, \"/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events\"),
+        (http::Method::GET, \"/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events/{name}\"),
+        (http::Method::PUT, \"/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events/{name}\"),
+        (http::Method::DELETE, \"/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events/{name}\"),
+        (http::Method::PATCH, \"/apis/events.k8s.io/v1beta1/namespaces/{namespace}/events/{name}\"),
+        (http::Method::GET, \"/apis/events.k8s.io/v1beta1/watch/events\"),
+        (http::Method::GET, \"/apis/events.k8s.io/v1beta1/watch/namespaces/{namespace}/events\"),
+        (http::Method::GET, \"/apis/events.k8s.io/v1beta1/watch/namespaces/{namespace}/events/{name}\"),
+        (http::Method::GET, \"/apis/extensions/\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/daemonsets\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/deployments\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/ingresses\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/namespaces/{namespace}/daemonsets\"),
+        (http::Method::POST, \"/apis/extensions/v1beta1/namespaces/{namespace}/daemonsets\"),
+        (http::Method::DELETE, \"/apis/extensions/v1beta1/namespaces/{namespace}/daemonsets\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/namespaces/{namespace}/daemonsets/{name}\"),
+        (http::Method::PUT, \"/apis/extensions/v1beta1/namespaces/{namespace}/daemonsets/{name}\"),
+        (http::Method::DELETE, \"/apis/extensions/v1beta1/namespaces/{namespace}/daemonsets/{name}\"),
+        (http::Method::PATCH, \"/apis/extensions/v1beta1/namespaces/{namespace}/daemonsets/{name}\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/namespaces/{namespace}/daemonsets/{name}/status\"),
+        (http::Method::PUT, \"/apis/extensions/v1beta1/namespaces/{namespace}/daemonsets/{name}/status\"),
+        (http::Method::PATCH, \"/apis/extensions/v1beta1/namespaces/{namespace}/daemonsets/{name}/status\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/namespaces/{namespace}/deployments\"),
+        (http::Method::POST, \"/apis/extensions/v1beta1/namespaces/{namespace}/deployments\"),
+        (http::Method::DELETE, \"/apis/extensions/v1beta1/namespaces/{namespace}/deployments\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::PUT, \"/apis/extensions/v1beta1/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::DELETE, \"/apis/extensions/v1beta1/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::PATCH, \"/apis/extensions/v1beta1/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::POST, \"/apis/extensions/v1beta1/namespaces/{namespace}/deployments/{name}/rollback\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/namespaces/{namespace}/deployments/{name}/scale\"),
+        (http::Method::PUT, \"/apis/extensions/v1beta1/namespaces/{namespace}/deployments/{name}/scale\"),
+        (http::Method::PATCH, \"/apis/extensions/v1beta1/namespaces/{namespace}/deployments/{name}/scale\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/namespaces/{namespace}/deployments/{name}/status\"),
+        (http::Method::PUT, \"/apis/extensions/v1beta1/namespaces/{namespace}/deployments/{name}/status\"),
+        (http::Method::PATCH, \"/apis/extensions/v1beta1/namespaces/{namespace}/deployments/{name}/status\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/namespaces/{namespace}/ingresses\"),
+        (http::Method::POST, \"/apis/extensions/v1beta1/namespaces/{namespace}/ingresses\"),
+        (http::Method::DELETE, \"/apis/extensions/v1beta1/namespaces/{namespace}/ingresses\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/namespaces/{namespace}/ingresses/{name}\"),
+        (http::Method::PUT, \"/apis/extensions/v1beta1/namespaces/{namespace}/ingresses/{name}\"),
+        (http::Method::DELETE, \"/apis/extensions/v1beta1/namespaces/{namespace}/ingresses/{name}\"),
+        (http::Method::PATCH, \"/apis/extensions/v1beta1/namespaces/{namespace}/ingresses/{name}\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/namespaces/{namespace}/ingresses/{name}/status\"),
+        (http::Method::PUT, \"/apis/extensions/v1beta1/namespaces/{namespace}/ingresses/{name}/status\"),
+        (http::Method::PATCH, \"/apis/extensions/v1beta1/namespaces/{namespace}/ingresses/{name}/status\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/namespaces/{namespace}/networkpolicies\"),
+        (http::Method::POST, \"/apis/extensions/v1beta1/namespaces/{namespace}/networkpolicies\"),
+        (http::Method::DELETE, \"/apis/extensions/v1beta1/namespaces/{namespace}/networkpolicies\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/namespaces/{namespace}/networkpolicies/{name}\"),
+        (http::Method::PUT, \"/apis/extensions/v1beta1/namespaces/{namespace}/networkpolicies/{name}\"),
+        (http::Method::DELETE, \"/apis/extensions/v1beta1/namespaces/{namespace}/networkpolicies/{name}\"),
+        (http::Method::PATCH, \"/apis/extensions/v1beta1/namespaces/{namespace}/networkpolicies/{name}\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/namespaces/{namespace}/replicasets\"),
+        (http::Method::POST, \"/apis/extensions/v1beta1/namespaces/{namespace}/replicasets\"),
+        (http::Method::DELETE, \"/apis/extensions/v1beta1/namespaces/{namespace}/replicasets\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/namespaces/{namespace}/replicasets/{name}\"),
+        (http::Method::PUT, \"/apis/extensions/v1beta1/namespaces/{namespace}/replicasets/{name}\"),
+        (http::Method::DELETE, \"/apis/extensions/v1beta1/namespaces/{namespace}/replicasets/{name}\"),
+        (http::Method::PATCH, \"/apis/extensions/v1beta1/namespaces/{namespace}/replicasets/{name}\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/namespaces/{namespace}/replicasets/{name}/scale\"),
+        (http::Method::PUT, \"/apis/extensions/v1beta1/namespaces/{namespace}/replicasets/{name}/scale\"),
+        (http::Method::PATCH, \"/apis/extensions/v1beta1/namespaces/{namespace}/replicasets/{name}/scale\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/namespaces/{namespace}/replicasets/{name}/status\"),
+        (http::Method::PUT, \"/apis/extensions/v1beta1/namespaces/{namespace}/replicasets/{name}/status\"),
+        (http::Method::PATCH, \"/apis/extensions/v1beta1/namespaces/{namespace}/replicasets/{name}/status\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/namespaces/{namespace}/replicationcontrollers/{name}/scale\"),
+        (http::Method::PUT, \"/apis/extensions/v1beta1/namespaces/{namespace}/replicationcontrollers/{name}/scale\"),
+        (http::Method::PATCH, \"/apis/extensions/v1beta1/namespaces/{namespace}/replicationcontrollers/{name}/scale\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/networkpolicies\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/podsecuritypolicies\"),
+        (http::Method::POST, \"/apis/extensions/v1beta1/podsecuritypolicies\"),
+        (http::Method::DELETE, \"/apis/extensions/v1beta1/podsecuritypolicies\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/podsecuritypolicies/{name}\"),
+        (http::Method::PUT, \"/apis/extensions/v1beta1/podsecuritypolicies/{name}\"),
+        (http::Method::DELETE, \"/apis/extensions/v1beta1/podsecuritypolicies/{name}\"),
+        (http::Method::PATCH, \"/apis/extensions/v1beta1/podsecuritypolicies/{name}\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/replicasets\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/watch/daemonsets\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/watch/deployments\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/watch/ingresses\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/watch/namespaces/{namespace}/daemonsets\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/watch/namespaces/{namespace}/daemonsets/{name}\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/watch/namespaces/{namespace}/deployments\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/watch/namespaces/{namespace}/deployments/{name}\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/watch/namespaces/{namespace}/ingresses\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/watch/namespaces/{namespace}/ingresses/{name}\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/watch/namespaces/{namespace}/networkpolicies\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/watch/namespaces/{namespace}/networkpolicies/{name}\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/watch/namespaces/{namespace}/replicasets\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/watch/namespaces/{namespace}/replicasets/{name}\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/watch/networkpolicies\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/watch/podsecuritypolicies\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/watch/podsecuritypolicies/{name}\"),
+        (http::Method::GET, \"/apis/extensions/v1beta1/watch/replicasets\"),
+        (http::Method::GET, \"/apis/networking.k8s.io/\"),
+        (http::Method::GET, \"/apis/networking.k8s.io/v1/\"),
+        (http::Method::GET, \"/apis/networking.k8s.io/v1/namespaces/{namespace}/networkpolicies\"),
+        (http::Method::POST, \"/apis/networking.k8s.io/v1/namespaces/{namespace}/networkpolicies\"),
+        (http::Method::DELETE, \"/apis/networking.k8s.io/v1/namespaces/{namespace}/networkpolicies\"),
+        (http::Method::GET, \"/apis/networking.k8s.io/v1/namespaces/{namespace}/networkpolicies/{name}\"),
+        (http::Method::PUT, \"/apis/networking.k8s.io/v1/namespaces/{namespace}/networkpolicies/{name}\"),
+        (http::Method::DELETE, \"/apis/networking.k8s.io/v1/namespaces/{namespace}/networkpolicies/{name}\"),
+        (http::Method::PATCH, \"/apis/networking.k8s.io/v1/namespaces/{namespace}/networkpolicies/{name}\"),
+        (http::Method::GET, \"/apis/networking.k8s.io/v1/networkpolicies\"),
+        (http::Method::GET, \"/apis/networking.k8s.io/v1/watch/namespaces/{namespace}/networkpolicies\"),
+        (http::Method::GET, \"/apis/networking.k8s.io/v1/watch/namespaces/{namespace}/networkpolicies/{name}\"),
+        (http::Method::GET, \"/apis/networking.k8s.io/v1/watch/networkpolicies\"),
+        (http::Method::GET, \"/apis/networking.k8s.io/v1beta1/\"),
+        (http::Method::GET, \"/apis/networking.k8s.io/v1beta1/ingresses\"),
+        (http::Method::GET, \"/apis/networking.k8s.io/v1beta1/namespaces/{namespace}/ingresses\"),
+        (http::Method::POST, \"/apis/networking.k8s.io/v1beta1/namespaces/{namespace}/ingresses\"),
+        (http::Method::DELETE, \"/apis/networking.k8s.io/v1beta1/namespaces/{namespace}/ingresses\"),
+        (http::Method::GET, \"/apis/networking.k8s.io/v1beta1/namespaces/{namespace}/ingresses/{name}\"),
+        (http::Method::PUT, \"/apis/networking.k8s.io/v1beta1/namespaces/{namespace}/ingresses/{name}\"),
+        (http::Method::DELETE, \"/apis/networking.k8s.io/v1beta1/namespaces/{namespace}/ingresses/{name}\"),
+        (http::Method::PATCH, \"/apis/networking.k8s.io/v1beta1/namespaces/{namespace}/ingresses/{name}\"),
+        (http::Method::GET, \"/apis/networking.k8s.io/v1beta1/namespaces/{namespace}/ingresses/{name}/status\"),
+        (http::Method::PUT, \"/apis/networking.k8s.io/v1beta1/namespaces/{namespace}/ingresses/{name}/status\"),
+        (http::Method::PATCH, \"/apis/networking.k8s.io/v1beta1/namespaces/{namespace}/ingresses/{name}/status\"),
+        (http::Method::GET, \"/apis/networking.k8s.io/v1beta1/watch/ingresses\"),
+        (http::Method::GET, \"/apis/networking.k8s.io/v1beta1/watch/namespaces/{namespace}/ingresses\"),
+        (http::Method::GET, \"/apis/networking.k8s.io/v1beta1/watch/namespaces/{namespace}/ingresses/{name}\"),
+        (http::Method::GET, \"/apis/node.k8s.io/\"),
+        (http::Method::GET, \"/apis/node.k8s.io/v1alpha1/\"),
+        (http::Method::GET, \"/apis/node.k8s.io/v1alpha1/runtimeclasses\"),
+        (http::Method::POST, \"/apis/node.k8s.io/v1alpha1/runtimeclasses\"),
+        (http::Method::DELETE, \"/apis/node.k8s.io/v1alpha1/runtimeclasses\"),
+        (http::Method::GET, \"/apis/node.k8s.io/v1alpha1/runtimeclasses/{name}\"),
+        (http::Method::PUT, \"/apis/node.k8s.io/v1alpha1/runtimeclasses/{name}\"),
+        (http::Method::DELETE, \"/apis/node.k8s.io/v1alpha1/runtimeclasses/{name}\"),
+        (http::Method::PATCH, \"/apis/node.k8s.io/v1alpha1/runtimeclasses/{name}\"),
+        (http::Method::GET, \"/apis/node.k8s.io/v1alpha1/watch/runtimeclasses\"),
+        (http::Method::GET, \"/apis/node.k8s.io/v1alpha1/watch/runtimeclasses/{name}\"),
+        (http::Method::GET, \"/apis/node.k8s.io/v1beta1/\"),
+        (http::Method::GET, \"/apis/node.k8s.io/v1beta1/runtimeclasses\"),
+        (http::Method::POST, \"/apis/node.k8s.io/v1beta1/runtimeclasses\"),
+        (http::Method::DELETE, \"/apis/node.k8s.io/v1beta1/runtimeclasses\"),
+        (http::Method::GET, \"/apis/node.k8s.io/v1beta1/runtimeclasses/{name}\"),
+        (http::Method::PUT, \"/apis/node.k8s.io/v1beta1/runtimeclasses/{name}\"),
+        (http::Method::DELETE, \"/apis/node.k8s.io/v1beta1/runtimeclasses/{name}\"),
+        (http::Method::PATCH, \"/apis/node.k8s.io/v1beta1/runtimeclasses/{name}\"),
+        (http::Method::GET, \"/apis/node.k8s.io/v1beta1/watch/runtimeclasses\"),
+        (http::Method::GET, \"/apis/node.k8s.io/v1beta1/watch/runtimeclasses/{name}\"),
+        (http::Method::GET, \"/apis/policy/\"),
+        (http::Method::GET, \"/apis/policy/v1beta1/\"),
+        (http::Method::GET, \"/apis/policy/v1beta1/namespaces/{namespace}/poddisruptionbudgets\"),
+        (http::Method::POST, \"/apis/policy/v1beta1/namespaces/{namespace}/poddisruptionbudgets\"),
+        (http::Method::DELETE, \"/apis/policy/v1beta1/namespaces/{namespace}/poddisruptionbudgets\"),
+        (http::Method::GET, \"/apis/policy/v1beta1/namespaces/{namespace}/poddisruptionbudgets/{name}\"),
+        (http::Method::PUT, \"/apis/policy/v1beta1/namespaces/{namespace}/poddisruptionbudgets/{name}\"),
+        (http::Method::DELETE, \"/apis/policy/v1beta1/namespaces/{namespace}/poddisruptionbudgets/{name}\"),
+        (http::Method::PATCH, \"/apis/policy/v1beta1/namespaces/{namespace}/poddisruptionbudgets/{name}\"),
+        (http::Method::GET, \"/apis/policy/v1beta1/namespaces/{namespace}/poddisruptionbudgets/{name}/status\"),
+        (http::Method::PUT, \"/apis/policy/v1beta1/namespaces/{namespace}/poddisruptionbudgets/{name}/status\"),
+        (http::Method::PATCH, \"/apis/policy/v1beta1/namespaces/{namespace}/poddisruptionbudgets/{name}/status\"),
+        (http::Method::GET, \"/apis/policy/v1beta1/poddisruptionbudgets\"),
+        (http::Method::GET, \"/apis/policy/v1beta1/podsecuritypolicies\"),
+        (http::Method::POST, \"/apis/policy/v1beta1/podsecuritypolicies\"),
+        (http::Method::DELETE, \"/apis/policy/v1beta1/podsecuritypolicies\"),
+        (http::Method::GET, \"/apis/policy/v1beta1/podsecuritypolicies/{name}\"),
+        (http::Method::PUT, \"/apis/policy/v1beta1/podsecuritypolicies/{name}\"),
+        (http::Method::DELETE, \"/apis/policy/v1beta1/podsecuritypolicies/{name}\"),
+        (http::Method::PATCH, \"/apis/policy/v1beta1/podsecuritypolicies/{name}\"),
+        (http::Method::GET, \"/apis/policy/v1beta1/watch/namespaces/{namespace}/poddisruptionbudgets\"),
+        (http::Method::GET, \"/apis/policy/v1beta1/watch/namespaces/{namespace}/poddisruptionbudgets/{name}\"),
+        (http::Method::GET, \"/apis/policy/v1beta1/watch/poddisruptionbudgets\"),
+        (http::Method::GET, \"/apis/policy/v1beta1/watch/podsecuritypolicies\"),
+        (http::Method::GET, \"/apis/policy/v1beta1/watch/podsecuritypolicies/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/clusterrolebindings\"),
+        (http::Method::POST, \"/apis/rbac.authorization.k8s.io/v1/clusterrolebindings\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1/clusterrolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/clusterrolebindings/{name}\"),
+        (http::Method::PUT, \"/apis/rbac.authorization.k8s.io/v1/clusterrolebindings/{name}\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1/clusterrolebindings/{name}\"),
+        (http::Method::PATCH, \"/apis/rbac.authorization.k8s.io/v1/clusterrolebindings/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/clusterroles\"),
+        (http::Method::POST, \"/apis/rbac.authorization.k8s.io/v1/clusterroles\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1/clusterroles\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/clusterroles/{name}\"),
+        (http::Method::PUT, \"/apis/rbac.authorization.k8s.io/v1/clusterroles/{name}\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1/clusterroles/{name}\"),
+        (http::Method::PATCH, \"/apis/rbac.authorization.k8s.io/v1/clusterroles/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/namespaces/{namespace}/rolebindings\"),
+        (http::Method::POST, \"/apis/rbac.authorization.k8s.io/v1/namespaces/{namespace}/rolebindings\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1/namespaces/{namespace}/rolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/namespaces/{namespace}/rolebindings/{name}\"),
+        (http::Method::PUT, \"/apis/rbac.authorization.k8s.io/v1/namespaces/{namespace}/rolebindings/{name}\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1/namespaces/{namespace}/rolebindings/{name}\"),
+        (http::Method::PATCH, \"/apis/rbac.authorization.k8s.io/v1/namespaces/{namespace}/rolebindings/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/namespaces/{namespace}/roles\"),
+        (http::Method::POST, \"/apis/rbac.authorization.k8s.io/v1/namespaces/{namespace}/roles\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1/namespaces/{namespace}/roles\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/namespaces/{namespace}/roles/{name}\"),
+        (http::Method::PUT, \"/apis/rbac.authorization.k8s.io/v1/namespaces/{namespace}/roles/{name}\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1/namespaces/{namespace}/roles/{name}\"),
+        (http::Method::PATCH, \"/apis/rbac.authorization.k8s.io/v1/namespaces/{namespace}/roles/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/rolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/roles\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/watch/clusterrolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/watch/clusterrolebindings/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/watch/clusterroles\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/watch/clusterroles/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/watch/namespaces/{namespace}/rolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/watch/namespaces/{namespace}/rolebindings/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/watch/namespaces/{namespace}/roles\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/watch/namespaces/{namespace}/roles/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/watch/rolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1/watch/roles\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/clusterrolebindings\"),
+        (http::Method::POST, \"/apis/rbac.authorization.k8s.io/v1alpha1/clusterrolebindings\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1alpha1/clusterrolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/clusterrolebindings/{name}\"),
+        (http::Method::PUT, \"/apis/rbac.authorization.k8s.io/v1alpha1/clusterrolebindings/{name}\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1alpha1/clusterrolebindings/{name}\"),
+        (http::Method::PATCH, \"/apis/rbac.authorization.k8s.io/v1alpha1/clusterrolebindings/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/clusterroles\"),
+        (http::Method::POST, \"/apis/rbac.authorization.k8s.io/v1alpha1/clusterroles\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1alpha1/clusterroles\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/clusterroles/{name}\"),
+        (http::Method::PUT, \"/apis/rbac.authorization.k8s.io/v1alpha1/clusterroles/{name}\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1alpha1/clusterroles/{name}\"),
+        (http::Method::PATCH, \"/apis/rbac.authorization.k8s.io/v1alpha1/clusterroles/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/rolebindings\"),
+        (http::Method::POST, \"/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/rolebindings\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/rolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/rolebindings/{name}\"),
+        (http::Method::PUT, \"/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/rolebindings/{name}\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/rolebindings/{name}\"),
+        (http::Method::PATCH, \"/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/rolebindings/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/roles\"),
+        (http::Method::POST, \"/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/roles\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/roles\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/roles/{name}\"),
+        (http::Method::PUT, \"/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/roles/{name}\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/roles/{name}\"),
+        (http::Method::PATCH, \"/apis/rbac.authorization.k8s.io/v1alpha1/namespaces/{namespace}/roles/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/rolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/roles\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/watch/clusterrolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/watch/clusterrolebindings/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/watch/clusterroles\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/watch/clusterroles/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/watch/namespaces/{namespace}/rolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/watch/namespaces/{namespace}/rolebindings/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/watch/namespaces/{namespace}/roles\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/watch/namespaces/{namespace}/roles/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/watch/rolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1alpha1/watch/roles\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/clusterrolebindings\"),
+        (http::Method::POST, \"/apis/rbac.authorization.k8s.io/v1beta1/clusterrolebindings\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1beta1/clusterrolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/clusterrolebindings/{name}\"),
+        (http::Method::PUT, \"/apis/rbac.authorization.k8s.io/v1beta1/clusterrolebindings/{name}\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1beta1/clusterrolebindings/{name}\"),
+        (http::Method::PATCH, \"/apis/rbac.authorization.k8s.io/v1beta1/clusterrolebindings/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/clusterroles\"),
+        (http::Method::POST, \"/apis/rbac.authorization.k8s.io/v1beta1/clusterroles\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1beta1/clusterroles\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/clusterroles/{name}\"),
+        (http::Method::PUT, \"/apis/rbac.authorization.k8s.io/v1beta1/clusterroles/{name}\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1beta1/clusterroles/{name}\"),
+        (http::Method::PATCH, \"/apis/rbac.authorization.k8s.io/v1beta1/clusterroles/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/namespaces/{namespace}/rolebindings\"),
+        (http::Method::POST, \"/apis/rbac.authorization.k8s.io/v1beta1/namespaces/{namespace}/rolebindings\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1beta1/namespaces/{namespace}/rolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/namespaces/{namespace}/rolebindings/{name}\"),
+        (http::Method::PUT, \"/apis/rbac.authorization.k8s.io/v1beta1/namespaces/{namespace}/rolebindings/{name}\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1beta1/namespaces/{namespace}/rolebindings/{name}\"),
+        (http::Method::PATCH, \"/apis/rbac.authorization.k8s.io/v1beta1/namespaces/{namespace}/rolebindings/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/namespaces/{namespace}/roles\"),
+        (http::Method::POST, \"/apis/rbac.authorization.k8s.io/v1beta1/namespaces/{namespace}/roles\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1beta1/namespaces/{namespace}/roles\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/namespaces/{namespace}/roles/{name}\"),
+        (http::Method::PUT, \"/apis/rbac.authorization.k8s.io/v1beta1/namespaces/{namespace}/roles/{name}\"),
+        (http::Method::DELETE, \"/apis/rbac.authorization.k8s.io/v1beta1/namespaces/{namespace}/roles/{name}\"),
+        (http::Method::PATCH, \"/apis/rbac.authorization.k8s.io/v1beta1/namespaces/{namespace}/roles/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/rolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/roles\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/watch/clusterrolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/watch/clusterrolebindings/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/watch/clusterroles\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/watch/clusterroles/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/watch/namespaces/{namespace}/rolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/watch/namespaces/{namespace}/rolebindings/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/watch/namespaces/{namespace}/roles\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/watch/namespaces/{namespace}/roles/{name}\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/watch/rolebindings\"),
+        (http::Method::GET, \"/apis/rbac.authorization.k8s.io/v1beta1/watch/roles\"),
+        (http::Method::GET, \"/apis/scheduling.k8s.io/\"),
+        (http::Method::GET, \"/apis/scheduling.k8s.io/v1/\"),
+        (http::Method::GET, \"/apis/scheduling.k8s.io/v1/priorityclasses\"),
+        (http::Method::POST, \"/apis/scheduling.k8s.io/v1/priorityclasses\"),
+        (http::Method::DELETE, \"/apis/scheduling.k8s.io/v1/priorityclasses\"),
+        (http::Method::GET, \"/apis/scheduling.k8s.io/v1/priorityclasses/{name}\"),
+        (http::Method::PUT, \"/apis/scheduling.k8s.io/v1/priorityclasses/{name}\"),
+        (http::Method::DELETE, \"/apis/scheduling.k8s.io/v1/priorityclasses/{name}\"),
+        (http::Method::PATCH, \"/apis/scheduling.k8s.io/v1/priorityclasses/{name}\"),
+        (http::Method::GET, \"/apis/scheduling.k8s.io/v1/watch/priorityclasses\"),
+        (http::Method::GET, \"/apis/scheduling.k8s.io/v1/watch/priorityclasses/{name}\"),
+        (http::Method::GET, \"/apis/scheduling.k8s.io/v1alpha1/\"),
+        (http::Method::GET, \"/apis/scheduling.k8s.io/v1alpha1/priorityclasses\"),
+        (http::Method::POST, \"/apis/scheduling.k8s.io/v1alpha1/priorityclasses\"),
+        (http::Method::DELETE, \"/apis/scheduling.k8s.io/v1alpha1/priorityclasses\"),
+        (http::Method::GET, \"/apis/scheduling.k8s.io/v1alpha1/priorityclasses/{name}\"),
+        (http::Method::PUT, \"/apis/scheduling.k8s.io/v1alpha1/priorityclasses/{name}\"),
+        (http::Method::DELETE, \"/apis/scheduling.k8s.io/v1alpha1/priorityclasses/{name}\"),
+        (http::Method::PATCH, \"/apis/scheduling.k8s.io/v1alpha1/priorityclasses/{name}\"),
+        (http::Method::GET, \"/apis/scheduling.k8s.io/v1alpha1/watch/priorityclasses\"),
+        (http::Method::GET, \"/apis/scheduling.k8s.io/v1alpha1/watch/priorityclasses/{name}\"),
+        (http::Method::GET, \"/apis/scheduling.k8s.io/v1beta1/\"),
+        (http::Method::GET, \"/apis/scheduling.k8s.io/v1beta1/priorityclasses\"),
+        (http::Method::POST, \"/apis/scheduling.k8s.io/v1beta1/priorityclasses\"),
+        (http::Method::DELETE, \"/apis/scheduling.k8s.io/v1beta1/priorityclasses\"),
+        (http::Method::GET, \"/apis/scheduling.k8s.io/v1beta1/priorityclasses/{name}\"),
+        (http::Method::PUT, \"/apis/scheduling.k8s.io/v1beta1/priorityclasses/{name}\"),
+        (http::Method::DELETE, \"/apis/scheduling.k8s.io/v1beta1/priorityclasses/{name}\"),
+        (http::Method::PATCH, \"/apis/scheduling.k8s.io/v1beta1/priorityclasses/{name}\"),
+        (http::Method::GET, \"/apis/scheduling.k8s.io/v1beta1/watch/priorityclasses\"),
+        (http::Method::GET, \"/apis/scheduling.k8s.io/v1beta1/watch/priorityclasses/{name}\"),
+        (http::Method::GET, \"/apis/settings.k8s.io/\"),
+        (http::Method::GET, \"/apis/settings.k8s.io/v1alpha1/\"),
+        (http::Method::GET, \"/apis/settings.k8s.io/v1alpha1/namespaces/{namespace}/podpresets\"),
+        (http::Method::POST, \"/apis/settings.k8s.io/v1alpha1/namespaces/{namespace}/podpresets\"),
+        (http::Method::DELETE, \"/apis/settings.k8s.io/v1alpha1/namespaces/{namespace}/podpresets\"),
+        (http::Method::GET, \"/apis/settings.k8s.io/v1alpha1/namespaces/{namespace}/podpresets/{name}\"),
+        (http::Method::PUT, \"/apis/settings.k8s.io/v1alpha1/namespaces/{namespace}/podpresets/{name}\"),
+        (http::Method::DELETE, \"/apis/settings.k8s.io/v1alpha1/namespaces/{namespace}/podpresets/{name}\"),
+        (http::Method::PATCH, \"/apis/settings.k8s.io/v1alpha1/namespaces/{namespace}/podpresets/{name}\"),
+        (http::Method::GET, \"/apis/settings.k8s.io/v1alpha1/podpresets\"),
+        (http::Method::GET, \"/apis/settings.k8s.io/v1alpha1/watch/namespaces/{namespace}/podpresets\"),
+        (http::Method::GET, \"/apis/settings.k8s.io/v1alpha1/watch/namespaces/{namespace}/podpresets/{name}\"),
+        (http::Method::GET, \"/apis/settings.k8s.io/v1alpha1/watch/podpresets\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1/\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1/storageclasses\"),
+        (http::Method::POST, \"/apis/storage.k8s.io/v1/storageclasses\"),
+        (http::Method::DELETE, \"/apis/storage.k8s.io/v1/storageclasses\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1/storageclasses/{name}\"),
+        (http::Method::PUT, \"/apis/storage.k8s.io/v1/storageclasses/{name}\"),
+        (http::Method::DELETE, \"/apis/storage.k8s.io/v1/storageclasses/{name}\"),
+        (http::Method::PATCH, \"/apis/storage.k8s.io/v1/storageclasses/{name}\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1/volumeattachments\"),
+        (http::Method::POST, \"/apis/storage.k8s.io/v1/volumeattachments\"),
+        (http::Method::DELETE, \"/apis/storage.k8s.io/v1/volumeattachments\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1/volumeattachments/{name}\"),
+        (http::Method::PUT, \"/apis/storage.k8s.io/v1/volumeattachments/{name}\"),
+        (http::Method::DELETE, \"/apis/storage.k8s.io/v1/volumeattachments/{name}\"),
+        (http::Method::PATCH, \"/apis/storage.k8s.io/v1/volumeattachments/{name}\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1/volumeattachments/{name}/status\"),
+        (http::Method::PUT, \"/apis/storage.k8s.io/v1/volumeattachments/{name}/status\"),
+        (http::Method::PATCH, \"/apis/storage.k8s.io/v1/volumeattachments/{name}/status\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1/watch/storageclasses\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1/watch/storageclasses/{name}\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1/watch/volumeattachments\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1/watch/volumeattachments/{name}\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1alpha1/\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1alpha1/volumeattachments\"),
+        (http::Method::POST, \"/apis/storage.k8s.io/v1alpha1/volumeattachments\"),
+        (http::Method::DELETE, \"/apis/storage.k8s.io/v1alpha1/volumeattachments\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1alpha1/volumeattachments/{name}\"),
+        (http::Method::PUT, \"/apis/storage.k8s.io/v1alpha1/volumeattachments/{name}\"),
+        (http::Method::DELETE, \"/apis/storage.k8s.io/v1alpha1/volumeattachments/{name}\"),
+        (http::Method::PATCH, \"/apis/storage.k8s.io/v1alpha1/volumeattachments/{name}\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1alpha1/watch/volumeattachments\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1alpha1/watch/volumeattachments/{name}\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1beta1/\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1beta1/csidrivers\"),
+        (http::Method::POST, \"/apis/storage.k8s.io/v1beta1/csidrivers\"),
+        (http::Method::DELETE, \"/apis/storage.k8s.io/v1beta1/csidrivers\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1beta1/csidrivers/{name}\"),
+        (http::Method::PUT, \"/apis/storage.k8s.io/v1beta1/csidrivers/{name}\"),
+        (http::Method::DELETE, \"/apis/storage.k8s.io/v1beta1/csidrivers/{name}\"),
+        (http::Method::PATCH, \"/apis/storage.k8s.io/v1beta1/csidrivers/{name}\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1beta1/csinodes\"),
+        (http::Method::POST, \"/apis/storage.k8s.io/v1beta1/csinodes\"),
+        (http::Method::DELETE, \"/apis/storage.k8s.io/v1beta1/csinodes\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1beta1/csinodes/{name}\"),
+        (http::Method::PUT, \"/apis/storage.k8s.io/v1beta1/csinodes/{name}\"),
+        (http::Method::DELETE, \"/apis/storage.k8s.io/v1beta1/csinodes/{name}\"),
+        (http::Method::PATCH, \"/apis/storage.k8s.io/v1beta1/csinodes/{name}\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1beta1/storageclasses\"),
+        (http::Method::POST, \"/apis/storage.k8s.io/v1beta1/storageclasses\"),
+        (http::Method::DELETE, \"/apis/storage.k8s.io/v1beta1/storageclasses\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1beta1/storageclasses/{name}\"),
+        (http::Method::PUT, \"/apis/storage.k8s.io/v1beta1/storageclasses/{name}\"),
+        (http::Method::DELETE, \"/apis/storage.k8s.io/v1beta1/storageclasses/{name}\"),
+        (http::Method::PATCH, \"/apis/storage.k8s.io/v1beta1/storageclasses/{name}\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1beta1/volumeattachments\"),
+        (http::Method::POST, \"/apis/storage.k8s.io/v1beta1/volumeattachments\"),
+        (http::Method::DELETE, \"/apis/storage.k8s.io/v1beta1/volumeattachments\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1beta1/volumeattachments/{name}\"),
+        (http::Method::PUT, \"/apis/storage.k8s.io/v1beta1/volumeattachments/{name}\"),
+        (http::Method::DELETE, \"/apis/storage.k8s.io/v1beta1/volumeattachments/{name}\"),
+        (http::Method::PATCH, \"/apis/storage.k8s.io/v1beta1/volumeattachments/{name}\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1beta1/watch/csidrivers\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1beta1/watch/csidrivers/{name}\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1beta1/watch/csinodes\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1beta1/watch/csinodes/{name}\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1beta1/watch/storageclasses\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1beta1/watch/storageclasses/{name}\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1beta1/watch/volumeattachments\"),
+        (http::Method::GET, \"/apis/storage.k8s.io/v1beta1/watch/volumeattachments/{name}\"),
+        (http::Method::GET, \"/version/\"),
+    ];
 
     /// Common API errors.
     #[derive(Debug, Fail)]
@@ -803,12 +1988,101 @@ pub mod client {
         Reqwest(reqwest::Error),
         #[fail(display = \"I/O error: {}\", _0)]
         Io(std::io::Error),
+        #[fail(display = \"Timed out waiting for response after {:?}\", _0)]
+        Timeout(Duration),
+        #[fail(display = \"Invalid header: {}\", _0)]
+        InvalidHeader(String),
+        #[fail(display = \"Unsupported content type for request: {}\", _0)]
+        UnsupportedContentType(String),
+        #[fail(display = \"Invalid URL: {}\", _0)]
+        InvalidUrl(String),
+        #[fail(display = \"Empty value for path parameter: {}\", _0)]
+        EmptyPathParameter(&'static str),
+        #[fail(display = \"Expected a response body, but got an empty response (code: {})\", _0)]
+        EmptyResponse(http::status::StatusCode, Mutex<R>),
+        #[fail(display = \"{}\", _0)]
+        Validation(ValidationError),
         #[fail(display = \"Error en/decoding \\\"application/json\\\" data: {}\", _0)]
         ApplicationJson(serde_json::Error),
         #[fail(display = \"Error en/decoding \\\"application/yaml\\\" data: {}\", _0)]
         ApplicationYaml(serde_yaml::Error),
     }
 
+    /// Errors from API calls made through [`Sendable::try_send`](trait.Sendable.html#method.try_send),
+    /// classified based on where the call has failed.
+    #[derive(Debug)]
+    pub enum SendError<R: Debug + Send + 'static> {
+        /// The request couldn't be sent or the response couldn't be received.
+        Transport(ApiError<R>),
+        /// The server has responded with an unsuccessful status code.
+        Api {
+            status: http::status::StatusCode,
+            body: Vec<u8>,
+        },
+        /// The successful response couldn't be decoded.
+        Decode(ApiError<R>),
+        /// The request couldn't be built from the given values (i.e., encoding failed).
+        Validation(ApiError<R>),
+    }
+
+    impl<R: Response + 'static> SendError<R> {
+        /// Classifies the error obtained while sending a request. Unsuccessful
+        /// responses are buffered, so that their bodies can be inspected.
+        async fn from_send(err: ApiError<R>) -> Self {
+            match err {
+                ApiError::Failure(_, status, resp) => match resp.into_inner().body_bytes().await {
+                    Ok((_, body)) => SendError::Api { status, body: body.as_ref().to_vec() },
+                    Err(e) => SendError::Transport(e),
+                },
+                ApiError::Reqwest(_) | ApiError::Io(_) | ApiError::Timeout(_) => SendError::Transport(err),
+                // Coders are only used for encoding the request body at this point.
+                _ => SendError::Validation(err),
+            }
+        }
+
+        /// Classifies the error obtained while accepting a successful response.
+        fn from_accept(err: ApiError<R>) -> Self {
+            match err {
+                ApiError::Reqwest(_) | ApiError::Timeout(_) => SendError::Transport(err),
+                _ => SendError::Decode(err),
+            }
+        }
+    }
+
+    impl<R: Debug + Send + 'static> std::fmt::Display for SendError<R> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            match self {
+                SendError::Transport(e) => write!(f, \"Error sending request: {}\", e),
+                SendError::Api { status, body } => {
+                    write!(f, \"API request failed (code: {})\", status)?;
+                    // Only the beginning of the body is shown, since it could be large.
+                    let body = String::from_utf8_lossy(body);
+                    let body = body.trim();
+                    if !body.is_empty() {
+                        let summary = body.chars().take(100).collect::<String>();
+                        write!(f, \": {}\", summary)?;
+                        if summary.len() < body.len() {
+                            f.write_str(\"...\")?;
+                        }
+                    }
+
+                    Ok(())
+                },
+                SendError::Decode(e) => write!(f, \"Error decoding response: {}\", e),
+                SendError::Validation(e) => write!(f, \"Invalid request: {}\", e),
+            }
+        }
+    }
+
+    impl<R: Debug + Send + 'static> std::error::Error for SendError<R> {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                SendError::Transport(e) | SendError::Decode(e) | SendError::Validation(e) => e.source_error(),
+                SendError::Api { .. } => None,
+            }
+        }
+    }
+
     /// Form object for building multipart request body.
     pub trait Form: Sized {
         /// Creates a new builder.
@@ -831,6 +2105,9 @@ pub mod client {
         /// Sets the header with the given key and value.
         fn header(self, name: &'static str, value: &str) -> Self;
 
+        /// Sets the given headers, replacing the existing values of those headers (if any).
+        fn headers(self, headers: http::HeaderMap) -> Self;
+
         /// Sets body using the given vector of bytes.
         ///
         /// **NOTE:** Appropriate `Content-Type` header must be set
@@ -878,6 +2155,10 @@ pub mod client {
             reqwest::r#async::RequestBuilder::header(self, name, value)
         }
 
+        fn headers(self, headers: http::HeaderMap) -> Self {
+            reqwest::r#async::RequestBuilder::headers(self, headers)
+        }
+
         fn multipart_form_data(self, form: Self::Form) -> Self {
             self.multipart(form)
         }
@@ -913,6 +2194,12 @@ pub mod client {
         /// Response body as a stream.
         fn stream(&mut self) -> ResponseStream<Self::Stream>;
 
+        /// URL from which this response was obtained (if known). This
+        /// is used for resolving relative redirect locations.
+        fn url(&self) -> Option<&str> {
+            None
+        }
+
         /// Vector of bytes from the response body.
         async fn body_bytes(self) -> Result<(Self, Self::Bytes), ApiError<Self>>;
     }
@@ -935,6 +2222,10 @@ pub mod client {
                 .and_then(|v| v.parse().ok())
         }
 
+        fn url(&self) -> Option<&str> {
+            Some(reqwest::r#async::Response::url(self).as_str())
+        }
+
         fn stream(&mut self) -> ResponseStream<Self::Stream> {
             let body = std::mem::replace(self.body_mut(), reqwest::r#async::Decoder::empty());
             ResponseStream::from(body)
@@ -947,6 +2238,210 @@ pub mod client {
         }
     }
 
+    /// Policy for following HTTP redirects when sending API calls.
+    ///
+    /// **NOTE:** The underlying HTTP client shouldn't follow redirects by
+    /// itself (for example, `reqwest` clients should be built with
+    /// `reqwest::RedirectPolicy::none()`) for this policy to take effect.
+    #[derive(Debug, Clone)]
+    pub struct RedirectPolicy {
+        /// Maximum number of redirects followed for a single API call.
+        pub max_redirects: usize,
+        /// Redirect status codes for which the original method, headers and body
+        /// are re-sent. For other redirects (`301`, `302` and `303` - the latter
+        /// is never preserved), the request is switched to `GET` (unless it's `HEAD`)
+        /// without the original headers and body.
+        ///
+        /// **NOTE:** Headers (which may have credentials) and bodies are never
+        /// re-sent to other origins (scheme, host and port), so method-preserving
+        /// redirects to other origins are not followed (i.e., the redirect response
+        /// is returned).
+        pub preserve_method: Vec<http::StatusCode>,
+    }
+
+    impl RedirectPolicy {
+        /// Policy which doesn't follow any redirects.
+        pub fn none() -> Self {
+            RedirectPolicy {
+                max_redirects: 0,
+                preserve_method: vec![],
+            }
+        }
+
+        /// Returns the absolute URL to which the given response redirects (if it's a
+        /// redirect that can be followed) and whether it has the same origin as the response.
+        pub fn redirect_url<R: Response>(&self, resp: &R) -> Option<(String, bool)> {
+            match resp.status() {
+                http::StatusCode::MOVED_PERMANENTLY |
+                http::StatusCode::FOUND |
+                http::StatusCode::SEE_OTHER |
+                http::StatusCode::TEMPORARY_REDIRECT |
+                http::StatusCode::PERMANENT_REDIRECT => (),
+                _ => return None,
+            }
+
+            let location = resp.header(http::header::LOCATION.as_str())?;
+            let base = resp.url().and_then(|u| url::Url::parse(u).ok());
+            let url = match base.as_ref() {
+                Some(base) => base.join(location).ok()?,
+                None => url::Url::parse(location).ok()?,
+            };
+
+            let same_origin = base.map(|b| b.origin() == url.origin()).unwrap_or(false);
+            Some((url.as_str().to_owned(), same_origin))
+        }
+    }
+
+    impl Default for RedirectPolicy {
+        fn default() -> Self {
+            RedirectPolicy {
+                max_redirects: 10,
+                preserve_method: vec![
+                    http::StatusCode::TEMPORARY_REDIRECT,
+                    http::StatusCode::PERMANENT_REDIRECT,
+                ],
+            }
+        }
+    }
+
+    /// Policy for retrying failed API calls with exponential backoff.
+    ///
+    /// **NOTE:** Only the initial request of an API call is retried (i.e.,
+    /// requests made for following redirects are not retried).
+    #[derive(Debug, Clone)]
+    pub struct RetryPolicy {
+        /// Maximum number of retries for a single API call.
+        pub max_retries: usize,
+        /// Methods for which the requests are retried. By default, these
+        /// are idempotent methods (i.e., `POST` and `PATCH` are not retried).
+        pub methods: Vec<http::Method>,
+        /// Response status codes for which the requests are retried.
+        pub statuses: Vec<http::StatusCode>,
+        /// Whether to retry requests which fail without a response (i.e.,
+        /// `Reqwest` and `Io` errors from [`ApiClient::make_request`](trait.ApiClient.html#tymethod.make_request)
+        /// and `Timeout` errors).
+        pub retry_errors: bool,
+        /// Delay before the first retry. This is doubled for every subsequent retry.
+        pub initial_backoff: Duration,
+        /// Maximum delay between retries (including the ones from `Retry-After` headers).
+        pub max_backoff: Duration,
+    }
+
+    impl RetryPolicy {
+        /// Policy which doesn't retry any requests.
+        pub fn none() -> Self {
+            RetryPolicy {
+                max_retries: 0,
+                ..RetryPolicy::default()
+            }
+        }
+
+        /// Returns the delay before retrying the request (if it should be retried)
+        /// based on the number of retries so far and the result of the last attempt.
+        ///
+        /// If the response has a `Retry-After` header (in seconds), then that's
+        /// preferred over the backoff. Either way, the delay is capped at `max_backoff`.
+        pub fn retry_delay<R: Response>(&self, method: &http::Method, retries: usize, result: &Result<R, ApiError<R>>) -> Option<Duration> {
+            if retries >= self.max_retries || !self.methods.contains(method) {
+                return None
+            }
+
+            let retry_after = match result {
+                Ok(resp) if self.statuses.contains(&resp.status()) => resp
+                    .header(http::header::RETRY_AFTER.as_str())
+                    .and_then(|v| v.trim().parse().ok())
+                    .map(Duration::from_secs),
+                Err(ApiError::Reqwest(_))
+                | Err(ApiError::Io(_))
+                | Err(ApiError::Timeout(_)) if self.retry_errors => None,
+                _ => return None,
+            };
+
+            let delay = retry_after.unwrap_or_else(|| {
+                let factor = 1u32.checked_shl(retries as u32).unwrap_or(u32::max_value());
+                self.initial_backoff.checked_mul(factor).unwrap_or(self.max_backoff)
+            });
+
+            Some(delay.min(self.max_backoff))
+        }
+    }
+
+    impl Default for RetryPolicy {
+        fn default() -> Self {
+            RetryPolicy {
+                max_retries: 3,
+                methods: vec![
+                    http::Method::GET,
+                    http::Method::HEAD,
+                    http::Method::PUT,
+                    http::Method::DELETE,
+                    http::Method::OPTIONS,
+                ],
+                statuses: vec![
+                    http::StatusCode::TOO_MANY_REQUESTS,
+                    http::StatusCode::SERVICE_UNAVAILABLE,
+                ],
+                retry_errors: true,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_secs(10),
+            }
+        }
+    }
+
+    /// Raw HTTP response data obtained along with the decoded value in
+    /// [`Sendable::send_with_raw`](trait.Sendable.html#method.send_with_raw).
+    pub struct RawResponse<R: Response> {
+        /// Status code of the response.
+        pub status: http::status::StatusCode,
+        /// Body of the response.
+        pub body: RawBody<R>,
+    }
+
+    /// Body of a raw HTTP response.
+    pub enum RawBody<R: Response> {
+        /// Undecoded bytes of the response body.
+        Bytes(R::Bytes),
+        /// The response body is streamed (for file downloads and event streams), so it's not
+        /// buffered. The stream itself is the decoded value.
+        Streamed,
+        /// The response doesn't have a body (for `HEAD` requests).
+        Empty,
+    }
+
+    impl<R: Response> RawResponse<R> {
+        /// Returns the undecoded bytes of the response body (if it's been buffered).
+        pub fn bytes(&self) -> Option<&[u8]> {
+            match &self.body {
+                RawBody::Bytes(b) => Some(b.as_ref()),
+                RawBody::Streamed | RawBody::Empty => None,
+            }
+        }
+    }
+
+    impl<R: Response> Debug for RawResponse<R> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(\"RawResponse\")
+                .field(\"status\", &self.status)
+                .field(\"body\", &self.bytes().map(String::from_utf8_lossy))
+                .finish()
+        }
+    }
+
+    /// Metadata describing the configuration of an API client.
+    #[derive(Debug, Clone)]
+    pub struct ClientDescription {
+        /// Title of the API spec.
+        pub title: &'static str,
+        /// Version of the API spec.
+        pub version: &'static str,
+        /// Base URL used for API calls.
+        pub base_url: String,
+        /// Name of the authentication scheme used by the client (if any).
+        pub auth_scheme: Option<String>,
+        /// Number of operations supported by the client.
+        pub operation_count: usize,
+    }
+
     /// Represents an API client.
     #[async_trait::async_trait]
     pub trait ApiClient {
@@ -956,6 +2451,93 @@ pub mod client {
         /// Consumes a method and a relative path and produces a request builder for a single API call.
         fn request_builder(&self, method: http::Method, rel_path: &str) -> Self::Request;
 
+        /// Consumes a method and an absolute URL and produces a request builder.
+        /// This is used for following redirects (and by clients wrapping this client
+        /// for sending requests to other base URLs).
+        ///
+        /// By default, absolute URLs are not supported (i.e., this returns `None`),
+        /// in which case redirects are not followed.
+        fn request_builder_for_url(&self, _method: http::Method, _url: &str) -> Option<Self::Request> {
+            None
+        }
+
+        /// Policy for following redirects in API calls.
+        fn redirect_policy(&self) -> RedirectPolicy {
+            RedirectPolicy::default()
+        }
+
+        /// Policy for retrying failed API calls. By default, requests are not retried.
+        fn retry_policy(&self) -> RetryPolicy {
+            RetryPolicy::none()
+        }
+
+        /// Default timeout for API calls (if any). This can be overridden
+        /// for individual API calls using [`Timeout`](struct.Timeout.html).
+        ///
+        /// See [`Sendable::send_raw`](trait.Sendable.html#method.send_raw) for
+        /// what's covered by the timeout.
+        fn timeout(&self) -> Option<Duration> {
+            None
+        }
+
+        /// Waits for the given duration (for backing off before retrying requests
+        /// and for timing out requests).
+        async fn sleep(&self, duration: Duration) {
+            let _ = futures_timer::Delay::new(duration).await;
+        }
+
+        /// Whether request bodies should be compressed with gzip. By default, they're not.
+        ///
+        /// **NOTE:** The server should accept gzipped requests (`Content-Encoding: gzip`).
+        #[cfg(feature = \"gzip\")]
+        fn gzip_requests(&self) -> bool {
+            false
+        }
+
+        /// Sets the given (encoded) bytes as the body of the given request. If the
+        /// `gzip` feature is enabled and [`gzip_requests`](#method.gzip_requests)
+        /// returns `true`, then the body is compressed (and `Content-Encoding` is set).
+        fn set_body(&self, req: Self::Request, body: Vec<u8>) -> Result<Self::Request, ApiError<Self::Response>> {
+            #[cfg(feature = \"gzip\")]
+            {
+                if self.gzip_requests() {
+                    use std::io::Write;
+
+                    let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+                    encoder.write_all(&body).map_err(ApiError::Io)?;
+                    return Ok(req
+                        .header(http::header::CONTENT_ENCODING.as_str(), \"gzip\")
+                        .body_bytes(encoder.finish().map_err(ApiError::Io)?))
+                }
+            }
+
+            Ok(req.body_bytes(body))
+        }
+
+        /// Base URL used by this client for API calls.
+        fn base_url(&self) -> Cow<'_, str> {
+            BASE_URL.into()
+        }
+
+        /// Name of the authentication scheme used by this client (if any).
+        ///
+        /// **NOTE:** This is only the name of the scheme and never the secrets.
+        fn auth_scheme(&self) -> Option<Cow<'_, str>> {
+            None
+        }
+
+        /// Returns the metadata describing this client's configuration.
+        /// This is useful for debugging and health checks.
+        fn describe(&self) -> ClientDescription {
+            ClientDescription {
+                title: SPEC_TITLE,
+                version: SPEC_VERSION,
+                base_url: self.base_url().into_owned(),
+                auth_scheme: self.auth_scheme().map(Cow::into_owned),
+                operation_count: OPERATION_COUNT,
+            }
+        }
+
         /// Performs the HTTP request using the given `Request` object
         /// and returns a `Response` future.
         async fn make_request(&self, req: Self::Request) -> Result<Self::Response, ApiError<Self::Response>>;
@@ -967,11 +2549,16 @@ pub mod client {
         type Response = reqwest::r#async::Response;
 
         fn request_builder(&self, method: http::Method, rel_path: &str) -> Self::Request {
-            let mut u = String::from(\"https://example.com/\");
+            let mut u = String::from(BASE_URL.trim_end_matches('/'));
+            u.push('/');
             u.push_str(rel_path.trim_start_matches('/'));
             self.request(method, &u)
         }
 
+        fn request_builder_for_url(&self, method: http::Method, url: &str) -> Option<Self::Request> {
+            Some(self.request(method, url))
+        }
+
         async fn make_request(&self, req: Self::Request) -> Result<Self::Response, ApiError<Self::Response>> {
             let req = req.build().map_err(ApiError::Reqwest)?;
             let resp = self.execute(req).map_err(ApiError::Reqwest).compat().await?;
@@ -979,150 +2566,1875 @@ pub mod client {
         }
     }
 
-    /// A trait for indicating that the implementor can send an API call.
+    /// Clients wrapped in `Arc` are cheap to clone and all clones share the
+    /// same configuration and transport (connection pools, for instance).
+    ///
+    /// **NOTE:** Overrides for individual API calls (like [`Timeout`](struct.Timeout.html)
+    /// and [`IfNoneMatch`](struct.IfNoneMatch.html)) live on the builders and
+    /// never affect the shared client.
     #[async_trait::async_trait]
-    pub trait Sendable<Client>
-    where
-        Client: ApiClient + Sync + 'static,
-    {
-        /// The output object from this API request.
-        type Output: serde::de::DeserializeOwned;
+    impl<C: ApiClient + Send + Sync> ApiClient for Arc<C> {
+        type Request = C::Request;
+        type Response = C::Response;
 
-        /// HTTP method used by this call.
-        const METHOD: http::Method;
+        fn request_builder(&self, method: http::Method, rel_path: &str) -> Self::Request {
+            (**self).request_builder(method, rel_path)
+        }
 
-        /// Relative URL for this API call formatted appropriately with parameter values.
-        ///
-        /// **NOTE:** This URL **must** begin with `/`.
-        fn rel_path(&self) -> std::borrow::Cow<'static, str>;
+        fn request_builder_for_url(&self, method: http::Method, url: &str) -> Option<Self::Request> {
+            (**self).request_builder_for_url(method, url)
+        }
 
-        /// Modifier for this object. Builders override this method if they
-        /// wish to add query parameters, set body, etc.
-        fn modify(&self, req: Client::Request) -> Result<Client::Request, ApiError<Client::Response>> {
-            Ok(req)
+        fn redirect_policy(&self) -> RedirectPolicy {
+            (**self).redirect_policy()
         }
 
-        /// Sends the request and returns a future for the response object.
-        async fn send(&self, client: &Client) -> Result<Self::Output, ApiError<Client::Response>> {
-            let resp = self.send_raw(client).await?;
-            let media = resp.media_type();
-            if let Some(ty) = media {
-                if media_types::M_0.matches(&ty) {
-                    let (_, bytes) = resp.body_bytes().await?;
-                    return serde_json::from_reader(bytes.as_ref()).map_err(ApiError::from)
-                }
-                else if media_types::M_1.matches(&ty) {
-                    let (_, bytes) = resp.body_bytes().await?;
-                    return serde_yaml::from_reader(bytes.as_ref()).map_err(ApiError::from)
-                }
-            }
+        fn retry_policy(&self) -> RetryPolicy {
+            (**self).retry_policy()
+        }
 
-            let ty = resp.header(http::header::CONTENT_TYPE.as_str())
-                .map(|v| String::from_utf8_lossy(v.as_bytes()).into_owned())
-                .unwrap_or_default();
-            Err(ApiError::UnsupportedMediaType(ty, Mutex::new(resp)))
+        fn timeout(&self) -> Option<Duration> {
+            (**self).timeout()
         }
 
-        /// Convenience method for returning a raw response after sending a request.
-        async fn send_raw(&self, client: &Client) -> Result<Client::Response, ApiError<Client::Response>> {
-            let rel_path = self.rel_path();
-            let req = self.modify(client.request_builder(Self::METHOD, &rel_path))?;
-            let resp = client.make_request(req).await?;
-            if resp.status().is_success() {
-                Ok(resp)
-            } else {
-                Err(ApiError::Failure(rel_path.into_owned(), resp.status(), Mutex::new(resp)))
-            }
+        #[cfg(feature = \"gzip\")]
+        fn gzip_requests(&self) -> bool {
+            (**self).gzip_requests()
         }
-    }
 
-    pub mod media_types {
-        use lazy_static::lazy_static;
+        async fn sleep(&self, duration: Duration) {
+            (**self).sleep(duration).await
+        }
 
-        lazy_static! {
-            pub static ref M_0: mime::MediaRange =
-                mime::MediaRange::parse(\"application/json\").expect(\"cannot parse \\\"application/json\\\" as media range\");
-            pub static ref M_1: mime::MediaRange =
-                mime::MediaRange::parse(\"application/yaml\").expect(\"cannot parse \\\"application/yaml\\\" as media range\");
+        fn set_body(&self, req: Self::Request, body: Vec<u8>) -> Result<Self::Request, ApiError<Self::Response>> {
+            (**self).set_body(req, body)
         }
-    }
 
-    impl<R: Response + 'static> From<std::io::Error> for ApiError<R> {
-        fn from(e: std::io::Error) -> Self {
-            ApiError::Io(e)
+        fn base_url(&self) -> Cow<'_, str> {
+            (**self).base_url()
         }
-    }
 
-    impl<R: Response + 'static> From<serde_json::Error> for ApiError<R> {
-        fn from(e: serde_json::Error) -> Self {
-            ApiError::ApplicationJson(e)
+        fn auth_scheme(&self) -> Option<Cow<'_, str>> {
+            (**self).auth_scheme()
         }
-    }
 
-    impl<R: Response + 'static> From<serde_yaml::Error> for ApiError<R> {
-        fn from(e: serde_yaml::Error) -> Self {
-            ApiError::ApplicationYaml(e)
+        fn describe(&self) -> ClientDescription {
+            (**self).describe()
+        }
+
+        async fn make_request(&self, req: Self::Request) -> Result<Self::Response, ApiError<Self::Response>> {
+            (**self).make_request(req).await
         }
     }
-}
 
-pub mod generics {
-    include!(\"./generics.rs\");
-}
+    /// Prefix of the environment variables read by [`EnvClient::from_env`](struct.EnvClient.html#method.from_env).
+    pub const ENV_PREFIX: &str = \"KUBERNETES\";
+
+    /// Where a credential goes in requests.
+    #[allow(dead_code)]
+    #[derive(Debug, Clone, Copy)]
+    enum CredentialLocation {
+        /// Header with the given name.
+        Header(&'static str),
+        /// Query parameter with the given name.
+        Query(&'static str),
+        /// `Authorization` header with a bearer token.
+        Bearer,
+    }
 
-pub mod util {
-    include!(\"./util.rs\");
-}
-",
-        Some(0),
-    );
-}
+    /// Security schemes (name, environment variable, location and whether
+    /// it's required) for which credentials are read from the environment.
+    const ENV_CREDENTIALS: &[(&str, &str, CredentialLocation, bool)] = &[
+        (\"BearerToken\", \"KUBERNETES_API_KEY\", CredentialLocation::Header(\"authorization\"), true),
+    ];
 
-#[test]
-fn test_generics_mod() {
-    assert_file_contains_content_at(
-        &(ROOT.clone() + "/tests/test_k8s/generics.rs"),
-        "
-pub struct",
-        Some(0),
-    );
-}
+    /// Errors in reading the client configuration from environment variables.
+    #[derive(Debug, Fail)]
+    pub enum EnvError {
+        #[fail(display = \"Missing environment variable: {}\", _0)]
+        Missing(&'static str),
+        #[fail(display = \"Environment variable {} is not valid unicode\", _0)]
+        NotUnicode(&'static str),
+        #[fail(display = \"Invalid URL in environment variable {}: {}\", _0, _1)]
+        InvalidUrl(&'static str, String),
+        #[fail(display = \"Failed to build the HTTP client: {}\", _0)]
+        Reqwest(reqwest::Error),
+    }
 
-#[test]
-fn test_same_object_creates_multiple_builders() {
-    assert_file_contains_content_at(
-        &(ROOT.clone() + "/tests/test_k8s/io/k8s/api/core/v1/config_map.rs"),
-        "
-impl ConfigMap {
-    /// Create a builder for this object.
-    #[inline]
-    pub fn builder() -> ConfigMapBuilder {
-        ConfigMapBuilder {
-            body: Default::default(),
+    /// Returns the value of the given environment variable (if it's set and non-empty).
+    fn env_var(name: &'static str) -> Result<Option<String>, EnvError> {
+        match std::env::var(name) {
+            Ok(v) => Ok(Some(v).filter(|v| !v.is_empty())),
+            Err(std::env::VarError::NotPresent) => Ok(None),
+            Err(std::env::VarError::NotUnicode(_)) => Err(EnvError::NotUnicode(name)),
         }
     }
 
-    /// create a ConfigMap
-    #[inline]
-    pub fn create_core_v1_namespaced_config_map() -> ConfigMapPostBuilder<crate::codegen::generics::MissingNamespace> {
-        ConfigMapPostBuilder {
-            inner: Default::default(),
-            _param_namespace: core::marker::PhantomData,
-        }
+    /// API client which sends requests to the base URL (and with the credentials)
+    /// obtained from environment variables.
+    ///
+    /// The client and the configuration are shared (through `Arc`), so this
+    /// is cheap to clone.
+    ///
+    /// **NOTE:** The inner client should support absolute URLs (see
+    /// [`ApiClient::request_builder_for_url`](trait.ApiClient.html#method.request_builder_for_url)).
+    /// Otherwise, requests are sent to its own base URL.
+    pub struct EnvClient<C> {
+        inner: Arc<C>,
+        base_url: Arc<str>,
+        credentials: Arc<[(&'static str, CredentialLocation, String)]>,
     }
 
-    /// read the specified ConfigMap
-    #[inline]
-    pub fn read_core_v1_namespaced_config_map() -> ConfigMapGetBuilder1<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
-        ConfigMapGetBuilder1 {
-            inner: Default::default(),
-            _param_name: core::marker::PhantomData,
-            _param_namespace: core::marker::PhantomData,
+    impl EnvClient<reqwest::r#async::Client> {
+        /// Creates a `reqwest` client configured from environment variables.
+        /// See [`from_env_with`](#method.from_env_with) for the variables.
+        ///
+        /// The `reqwest` client doesn't follow redirects by itself (they're followed
+        /// based on the [`RedirectPolicy`](struct.RedirectPolicy.html)), so that
+        /// the credentials are never sent to other origins.
+        pub fn from_env() -> Result<Self, EnvError> {
+            let client = reqwest::r#async::Client::builder()
+                .redirect(reqwest::RedirectPolicy::none())
+                .build()
+                .map_err(EnvError::Reqwest)?;
+            Self::from_env_with(client)
         }
     }
 
-    /// replace the specified ConfigMap
-    #[inline]
+    impl<C> EnvClient<C> {
+        /// Wraps the given client with the configuration obtained from these
+        /// environment variables (empty values are treated as unset):
+        ///
+        /// - `KUBERNETES_BASE_URL` for the base URL (defaults to [`BASE_URL`](constant.BASE_URL.html)).
+        /// - `KUBERNETES_API_KEY` for `BearerToken` security scheme (required).
+        ///
+        /// **NOTE:** The given client shouldn't follow redirects by itself. Otherwise,
+        /// the credentials in headers will be sent to the redirected locations as well.
+        pub fn from_env_with(client: C) -> Result<Self, EnvError> {
+            Self::from_env_shared(Arc::new(client))
+        }
+
+        /// Same as [`from_env_with`](#method.from_env_with), but uses a client shared
+        /// with other API clients (and hence, its connection pool). The base URL and
+        /// credentials are still specific to this client.
+        pub fn from_env_shared(client: Arc<C>) -> Result<Self, EnvError> {
+            let var = \"KUBERNETES_BASE_URL\";
+            let base_url = match env_var(var)? {
+                Some(u) => {
+                    url::Url::parse(&u).map_err(|e| EnvError::InvalidUrl(var, e.to_string()))?;
+                    u
+                },
+                None => BASE_URL.into(),
+            };
+
+            let mut credentials = vec![];
+            for &(scheme, var, location, required) in ENV_CREDENTIALS {
+                match env_var(var)? {
+                    Some(value) => credentials.push((scheme, location, value)),
+                    None if required => return Err(EnvError::Missing(var)),
+                    None => (),
+                }
+            }
+
+            Ok(EnvClient {
+                inner: client,
+                base_url: base_url.into(),
+                credentials: credentials.into(),
+            })
+        }
+
+        /// Returns the wrapped client.
+        pub fn inner(&self) -> &Arc<C> {
+            &self.inner
+        }
+    }
+
+    impl<C> Clone for EnvClient<C> {
+        fn clone(&self) -> Self {
+            EnvClient {
+                inner: self.inner.clone(),
+                base_url: self.base_url.clone(),
+                credentials: self.credentials.clone(),
+            }
+        }
+    }
+
+    // Credentials are never shown.
+    impl<C> Debug for EnvClient<C> {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.debug_struct(\"EnvClient\")
+                .field(\"base_url\", &self.base_url)
+                .field(\"auth_schemes\", &self.credentials.iter().map(|c| c.0).collect::<Vec<_>>())
+                .finish()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<C: ApiClient + Send + Sync> ApiClient for EnvClient<C> {
+        type Request = C::Request;
+        type Response = C::Response;
+
+        fn request_builder(&self, method: http::Method, rel_path: &str) -> Self::Request {
+            let mut u = String::from(self.base_url.trim_end_matches('/'));
+            let base_len = u.len();
+            u.push('/');
+            u.push_str(rel_path.trim_start_matches('/'));
+            let mut query = url::form_urlencoded::Serializer::new(String::new());
+            for (_, location, value) in self.credentials.iter() {
+                if let CredentialLocation::Query(name) = *location {
+                    query.append_pair(name, value);
+                }
+            }
+
+            let query = query.finish();
+            if !query.is_empty() {
+                // Relative paths already have query parameters for some operations.
+                u.push(if u.contains('?') { '&' } else { '?' });
+                u.push_str(&query);
+            }
+
+            // Credentials are added for the configured base URL. Redirects are followed by
+            // `Sendable::send_raw`, which doesn't re-send headers to other origins (as long
+            // as the inner client doesn't follow redirects by itself).
+            // Clients which don't support absolute URLs use their own base URL.
+            let req = match self.inner.request_builder_for_url(method.clone(), &u) {
+                Some(req) => req,
+                None => self.inner.request_builder(method, &u[base_len..]),
+            };
+
+            self.credentials.iter().fold(req, |req, (_, location, value)| {
+                match *location {
+                    CredentialLocation::Header(name) => req.header(name, value),
+                    CredentialLocation::Bearer => req.header(\"Authorization\", &(String::from(\"Bearer \") + value)),
+                    CredentialLocation::Query(_) => req,
+                }
+            })
+        }
+
+        fn request_builder_for_url(&self, method: http::Method, url: &str) -> Option<Self::Request> {
+            self.inner.request_builder_for_url(method, url)
+        }
+
+        fn redirect_policy(&self) -> RedirectPolicy {
+            self.inner.redirect_policy()
+        }
+
+        fn retry_policy(&self) -> RetryPolicy {
+            self.inner.retry_policy()
+        }
+
+        fn timeout(&self) -> Option<Duration> {
+            self.inner.timeout()
+        }
+
+        #[cfg(feature = \"gzip\")]
+        fn gzip_requests(&self) -> bool {
+            self.inner.gzip_requests()
+        }
+
+        async fn sleep(&self, duration: Duration) {
+            self.inner.sleep(duration).await
+        }
+
+        fn set_body(&self, req: Self::Request, body: Vec<u8>) -> Result<Self::Request, ApiError<Self::Response>> {
+            self.inner.set_body(req, body)
+        }
+
+        fn base_url(&self) -> Cow<'_, str> {
+            (*self.base_url).into()
+        }
+
+        fn auth_scheme(&self) -> Option<Cow<'_, str>> {
+            match self.credentials.first() {
+                Some((scheme, _, _)) => Some((*scheme).into()),
+                None => self.inner.auth_scheme(),
+            }
+        }
+
+        async fn make_request(&self, req: Self::Request) -> Result<Self::Response, ApiError<Self::Response>> {
+            self.inner.make_request(req).await
+        }
+    }
+
+    /// A trait for indicating that the implementor can send an API call.
+    #[async_trait::async_trait]
+    pub trait Sendable<Client>
+    where
+        Client: ApiClient + Sync + 'static,
+    {
+        /// The output object from this API request.
+        type Output: FromResponse;
+
+        /// HTTP method used by this call.
+        const METHOD: http::Method;
+
+        /// Relative URL for this API call formatted appropriately with parameter values.
+        ///
+        /// **NOTE:** This URL **must** begin with `/`.
+        fn rel_path(&self) -> std::borrow::Cow<'static, str>;
+
+        /// Name of the path parameter (if any) with an empty (or whitespace-only) value.
+        /// Such calls fail without sending the request, since the URL would be malformed.
+        fn empty_path_param(&self) -> Option<&'static str> {
+            None
+        }
+
+        /// Modifier for this object. Builders override this method if they
+        /// wish to add query parameters, set body, etc.
+        ///
+        /// **NOTE:** Encoded bodies should be set using [`ApiClient::set_body`](trait.ApiClient.html#method.set_body).
+        fn modify(&self, _client: &Client, req: Client::Request) -> Result<Client::Request, ApiError<Client::Response>> {
+            Ok(req)
+        }
+
+        /// Same as [`modify`](#method.modify), but the body is encoded in the given content type
+        /// (instead of the preferred one). Builders override this method for operations which
+        /// accept multiple content types.
+        fn modify_with_content_type(&self, _client: &Client, _req: Client::Request, content_type: &str) -> Result<Client::Request, ApiError<Client::Response>> {
+            Err(ApiError::UnsupportedContentType(content_type.into()))
+        }
+
+        /// Same as [`modify`](#method.modify), but the given body is encoded (instead of
+        /// the one in this object). Builders override this method for operations which
+        /// have bodies. By default, the body is ignored.
+        fn modify_with_body<B: serde::Serialize + Sync>(&self, client: &Client, req: Client::Request, _body: &B) -> Result<Client::Request, ApiError<Client::Response>> {
+            self.modify(client, req)
+        }
+
+        /// Media range of the decoder to be used when the response's media type
+        /// doesn't match any of the known decoders (or when it's missing). If this
+        /// is `None`, then such responses are rejected.
+        fn fallback_media_range(&self) -> Option<&'static str> {
+            None
+        }
+
+        /// Timeout for this API call, which overrides the client's
+        /// [`timeout`](trait.ApiClient.html#method.timeout) (if any).
+        fn request_timeout(&self) -> Option<Duration> {
+            None
+        }
+
+        /// Sends the request and returns a future for the response object.
+        ///
+        /// The decoder is chosen based on the response's `Content-Type`.
+        async fn send(&self, client: &Client) -> Result<Self::Output, ApiError<Client::Response>> {
+            let (value, _) = self.send_with_raw(client).await?;
+            Ok(value)
+        }
+
+        /// Same as [`send`](#method.send), but this also returns the raw response
+        /// (i.e., status code and undecoded body) along with the response object.
+        /// This is useful for debugging (and for operations with incomplete schema).
+        async fn send_with_raw(&self, client: &Client) -> Result<(Self::Output, RawResponse<Client::Response>), ApiError<Client::Response>> {
+            let resp = self.send_raw(client).await?;
+            self.accept_response(resp).await
+        }
+
+        /// Same as [`send`](#method.send), but the errors are classified (as [`SendError`](enum.SendError.html))
+        /// based on where the call has failed, so that they can be matched upon.
+        ///
+        /// **NOTE:** Successful responses which couldn't be decoded are `Decode` errors
+        /// and not `Api` errors.
+        async fn try_send(&self, client: &Client) -> Result<Self::Output, SendError<Client::Response>> {
+            let resp = match self.send_raw(client).await {
+                Ok(r) => r,
+                Err(e) => return Err(SendError::from_send(e).await),
+            };
+
+            let (value, _) = self.accept_response(resp).await.map_err(SendError::from_accept)?;
+            Ok(value)
+        }
+
+        /// Obtains the response object from a successful response. By default, this
+        /// uses the [`FromResponse`](trait.FromResponse.html) impl of the output object,
+        /// but builders override this method for operations whose responses shouldn't
+        /// be decoded (i.e., file downloads, `HEAD` requests and responses without schema).
+        async fn accept_response(&self, resp: Client::Response) -> Result<(Self::Output, RawResponse<Client::Response>), ApiError<Client::Response>> {
+            <Self::Output as FromResponse>::from_response(resp, self.fallback_media_range()).await
+        }
+
+        /// Convenience method for returning a raw response after sending a request.
+        ///
+        /// Failed requests are retried based on the client's [`RetryPolicy`](struct.RetryPolicy.html)
+        /// and redirects are followed based on the client's [`RedirectPolicy`](struct.RedirectPolicy.html).
+        ///
+        /// The timeout (if any) applies to each request (including retries and redirects)
+        /// and covers connecting to the server until the response headers are received.
+        /// Reading the body isn't covered, so that streamed responses (i.e., file downloads)
+        /// aren't cut off by the timeout. Use the timeout of the underlying HTTP client
+        /// for limiting the total transfer time.
+        async fn send_raw(&self, client: &Client) -> Result<Client::Response, ApiError<Client::Response>> {
+            if let Some(name) = self.empty_path_param() {
+                return Err(ApiError::EmptyPathParameter(name))
+            }
+
+            let rel_path = self.rel_path();
+            let timeout = self.request_timeout().or_else(|| client.timeout());
+            let retry_policy = client.retry_policy();
+            let mut retries = 0;
+            let mut resp = loop {
+                let req = self.modify(client, client.request_builder(Self::METHOD, &rel_path))?;
+                let result = make_request_within(client, req, timeout).await;
+                match retry_policy.retry_delay(&Self::METHOD, retries, &result) {
+                    Some(delay) => client.sleep(delay).await,
+                    None => break result?,
+                }
+
+                retries += 1;
+            };
+
+            let policy = client.redirect_policy();
+            let (mut method, mut resend) = (Self::METHOD, true);
+            for _ in 0..policy.max_redirects {
+                let (url, same_origin) = match policy.redirect_url(&resp) {
+                    Some(u) => u,
+                    None => break,
+                };
+
+                let preserve = resp.status() != http::StatusCode::SEE_OTHER
+                    && policy.preserve_method.contains(&resp.status());
+                // Headers and body are only re-sent to the same origin.
+                if resend && preserve && !same_origin {
+                    break
+                }
+
+                resend &= preserve;
+                if !resend && method != http::Method::HEAD {
+                    method = http::Method::GET;
+                }
+
+                let mut req = match client.request_builder_for_url(method.clone(), &url) {
+                    Some(req) => req,
+                    None => break,
+                };
+
+                if resend {
+                    req = self.modify(client, req)?;
+                }
+
+                resp = make_request_within(client, req, timeout).await?;
+            }
+
+            if resp.status().is_success() {
+                Ok(resp)
+            } else {
+                Err(ApiError::Failure(rel_path.into_owned(), resp.status(), Mutex::new(resp)))
+            }
+        }
+    }
+
+    /// Decompresses the response body based on its `Content-Encoding` (if the
+    /// `gzip` feature is enabled). Bodies with other (or missing) encodings are
+    /// returned as they are, since servers may choose not to compress responses.
+    ///
+    /// **NOTE:** HTTP clients (like `reqwest`) may decompress the body by themselves,
+    /// in which case, `Content-Encoding` is usually removed from the response.
+    #[cfg(feature = \"gzip\")]
+    fn decompress<'a, R: Response>(resp: &R, bytes: &'a [u8]) -> std::io::Result<Cow<'a, [u8]>> {
+        use std::io::Read;
+
+        let encoding = resp.header(http::header::CONTENT_ENCODING.as_str())
+            .map(|e| e.trim().to_ascii_lowercase());
+        let mut buf = vec![];
+        match encoding.as_ref().map(String::as_str) {
+            Some(\"gzip\") | Some(\"x-gzip\") => flate2::read::GzDecoder::new(bytes).read_to_end(&mut buf)?,
+            Some(\"deflate\") => flate2::read::ZlibDecoder::new(bytes).read_to_end(&mut buf)?,
+            _ => return Ok(Cow::Borrowed(bytes)),
+        };
+
+        Ok(Cow::Owned(buf))
+    }
+
+    #[cfg(not(feature = \"gzip\"))]
+    fn decompress<'a, R: Response>(_resp: &R, bytes: &'a [u8]) -> std::io::Result<Cow<'a, [u8]>> {
+        Ok(Cow::Borrowed(bytes))
+    }
+
+    /// Objects obtained from successful responses of API calls.
+    #[async_trait::async_trait]
+    pub trait FromResponse: Sized {
+        /// Obtains this object from the given response. The decoder for the given fallback
+        /// media range (if any) is used when the response's media type doesn't match any
+        /// of the known decoders (or when it's missing).
+        async fn from_response<R: Response + 'static>(resp: R, fallback_range: Option<&'static str>) -> Result<(Self, RawResponse<R>), ApiError<R>>;
+    }
+
+    /// Deserializable objects are decoded from the response body (see [`decode_response`](fn.decode_response.html)).
+    #[async_trait::async_trait]
+    impl<T: serde::de::DeserializeOwned> FromResponse for T {
+        async fn from_response<R: Response + 'static>(resp: R, fallback_range: Option<&'static str>) -> Result<(Self, RawResponse<R>), ApiError<R>> {
+            decode_response(resp, fallback_range).await
+        }
+    }
+
+    /// Decodes the body of the given response based on its `Content-Type`. The decoder
+    /// for the given fallback media range (if any) is used when the response's media type
+    /// doesn't match any of the known decoders (or when it's missing).
+    ///
+    /// Empty responses (`204 No Content` or `Content-Length: 0`) are rejected.
+    pub async fn decode_response<R, T>(resp: R, fallback_range: Option<&'static str>) -> Result<(T, RawResponse<R>), ApiError<R>>
+    where
+        R: Response + 'static,
+        T: serde::de::DeserializeOwned,
+    {
+        let status = resp.status();
+        if status == http::StatusCode::NO_CONTENT
+            || resp.header(http::header::CONTENT_LENGTH.as_str()).map(|v| v.trim() == \"0\").unwrap_or(false)
+        {
+            return Err(ApiError::EmptyResponse(status, Mutex::new(resp)));
+        }
+
+        let decoder = resp.media_type()
+            .and_then(|ty| media_types::decoder_index(&ty))
+            .or_else(|| fallback_range
+                .and_then(|r| r.parse().ok())
+                .and_then(|ty| media_types::decoder_index(&ty)));
+
+        match decoder {
+            Some(0) => {
+                let (resp, bytes) = resp.body_bytes().await?;
+                let value = serde_json::from_reader(decompress(&resp, bytes.as_ref())?.as_ref()).map_err(ApiError::from)?;
+                Ok((value, RawResponse { status, body: RawBody::Bytes(bytes) }))
+            },
+            Some(1) => {
+                let (resp, bytes) = resp.body_bytes().await?;
+                let value = serde_yaml::from_reader(decompress(&resp, bytes.as_ref())?.as_ref()).map_err(ApiError::from)?;
+                Ok((value, RawResponse { status, body: RawBody::Bytes(bytes) }))
+            },
+            _ => {
+                let ty = resp.header(http::header::CONTENT_TYPE.as_str())
+                    .map(|v| String::from_utf8_lossy(v.as_bytes()).into_owned())
+                    .unwrap_or_default();
+                Err(ApiError::UnsupportedMediaType(ty, Mutex::new(resp)))
+            },
+        }
+    }
+
+    /// Makes the request using the given client and fails with `ApiError::Timeout`
+    /// if the response isn't received within the given timeout (if any).
+    async fn make_request_within<Client>(client: &Client, req: Client::Request, timeout: Option<Duration>) -> Result<Client::Response, ApiError<Client::Response>>
+    where
+        Client: ApiClient + Sync + 'static,
+    {
+        let timeout = match timeout {
+            Some(t) => t,
+            None => return client.make_request(req).await,
+        };
+
+        match futures_preview::future::select(client.make_request(req), client.sleep(timeout)).await {
+            futures_preview::future::Either::Left((result, _)) => result,
+            futures_preview::future::Either::Right(_) => Err(ApiError::Timeout(timeout)),
+        }
+    }
+
+    /// Wrapper for overriding the client's [`timeout`](trait.ApiClient.html#method.timeout)
+    /// for a single API call. Builders for operations have a `timeout` method for
+    /// wrapping themselves once all the required fields have been set.
+    #[derive(Debug, Clone)]
+    pub struct Timeout<S> {
+        inner: S,
+        timeout: Duration,
+    }
+
+    impl<S> Timeout<S> {
+        /// Wraps the given sendable object with the given timeout.
+        pub fn new(inner: S, timeout: Duration) -> Self {
+            Timeout { inner, timeout }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<Client, S> Sendable<Client> for Timeout<S>
+    where
+        Client: ApiClient + Sync + 'static,
+        S: Sendable<Client> + Sync,
+    {
+        type Output = S::Output;
+
+        const METHOD: http::Method = S::METHOD;
+
+        fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+            self.inner.rel_path()
+        }
+
+        fn empty_path_param(&self) -> Option<&'static str> {
+            self.inner.empty_path_param()
+        }
+
+        fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, ApiError<Client::Response>> {
+            self.inner.modify(client, req)
+        }
+
+        fn fallback_media_range(&self) -> Option<&'static str> {
+            self.inner.fallback_media_range()
+        }
+
+        fn request_timeout(&self) -> Option<Duration> {
+            Some(self.timeout)
+        }
+
+        async fn accept_response(&self, resp: Client::Response) -> Result<(Self::Output, RawResponse<Client::Response>), ApiError<Client::Response>> {
+            self.inner.accept_response(resp).await
+        }
+    }
+
+    /// Wrapper for making a conditional API call with the `ETag` obtained from an
+    /// earlier response. Builders for `GET` and `HEAD` operations have an `if_none_match`
+    /// method for wrapping themselves once all the required fields have been set.
+    #[derive(Debug, Clone)]
+    pub struct IfNoneMatch<S> {
+        inner: S,
+        etag: String,
+    }
+
+    /// Outcome of a conditional API call made through [`IfNoneMatch`](struct.IfNoneMatch.html).
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Conditional<T> {
+        /// The resource has been modified, along with its new `ETag` (if any).
+        Modified {
+            value: T,
+            etag: Option<String>,
+        },
+        /// The resource hasn't been modified since it was obtained with the given `ETag`.
+        NotModified {
+            etag: String,
+        },
+    }
+
+    impl<S> IfNoneMatch<S> {
+        /// Wraps the given sendable object with the given `ETag`.
+        pub fn new<E: Into<String>>(inner: S, etag: E) -> Self {
+            IfNoneMatch { inner, etag: etag.into() }
+        }
+
+        /// Sends the conditional request. `304 Not Modified` responses aren't
+        /// decoded (as they don't have a body) and they're returned as
+        /// `NotModified` along with the prior `ETag`.
+        pub async fn send_if_modified<Client>(&self, client: &Client) -> Result<Conditional<S::Output>, ApiError<Client::Response>>
+        where
+            Client: ApiClient + Sync + 'static,
+            S: Sendable<Client> + Sync,
+        {
+            match self.send_raw(client).await {
+                Ok(resp) => {
+                    let etag = resp.header(http::header::ETAG.as_str()).map(String::from);
+                    let (value, _) = self.accept_response(resp).await?;
+                    Ok(Conditional::Modified { value, etag })
+                },
+                Err(ApiError::Failure(_, status, _)) if status == http::StatusCode::NOT_MODIFIED => {
+                    Ok(Conditional::NotModified { etag: self.etag.clone() })
+                },
+                Err(e) => Err(e),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<Client, S> Sendable<Client> for IfNoneMatch<S>
+    where
+        Client: ApiClient + Sync + 'static,
+        S: Sendable<Client> + Sync,
+    {
+        type Output = S::Output;
+
+        const METHOD: http::Method = S::METHOD;
+
+        fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+            self.inner.rel_path()
+        }
+
+        fn empty_path_param(&self) -> Option<&'static str> {
+            self.inner.empty_path_param()
+        }
+
+        fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, ApiError<Client::Response>> {
+            let req = self.inner.modify(client, req)?;
+            Ok(req.header(http::header::IF_NONE_MATCH.as_str(), &self.etag))
+        }
+
+        fn fallback_media_range(&self) -> Option<&'static str> {
+            self.inner.fallback_media_range()
+        }
+
+        fn request_timeout(&self) -> Option<Duration> {
+            self.inner.request_timeout()
+        }
+
+        async fn accept_response(&self, resp: Client::Response) -> Result<(Self::Output, RawResponse<Client::Response>), ApiError<Client::Response>> {
+            self.inner.accept_response(resp).await
+        }
+    }
+
+    /// Wrapper for adding headers (which aren't declared in the spec) to a single
+    /// API call. Builders for operations have a `header` method for wrapping
+    /// themselves once all the required fields have been set.
+    ///
+    /// **NOTE:** These headers are set after the header parameters of the operation,
+    /// so they override the parameters with the same name.
+    #[derive(Debug, Clone)]
+    pub struct ExtraHeaders<S> {
+        inner: S,
+        headers: http::HeaderMap,
+        invalid: Option<String>,
+    }
+
+    impl<S> ExtraHeaders<S> {
+        /// Wraps the given sendable object (without any headers).
+        pub fn new(inner: S) -> Self {
+            ExtraHeaders { inner, headers: http::HeaderMap::new(), invalid: None }
+        }
+
+        /// Adds the header with the given name and value. Adding the same header
+        /// again adds another value (instead of replacing the previous one).
+        ///
+        /// **NOTE:** Invalid names or values are reported (as `ApiError::InvalidHeader`)
+        /// when the API call is made.
+        pub fn header(mut self, name: impl AsRef<str>, value: impl AsRef<str>) -> Self {
+            let name = name.as_ref();
+            match (
+                http::header::HeaderName::from_bytes(name.as_bytes()),
+                http::header::HeaderValue::from_str(value.as_ref()),
+            ) {
+                (Ok(n), Ok(v)) => {
+                    self.headers.append(n, v);
+                },
+                _ => {
+                    self.invalid.get_or_insert_with(|| name.into());
+                },
+            }
+
+            self
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<Client, S> Sendable<Client> for ExtraHeaders<S>
+    where
+        Client: ApiClient + Sync + 'static,
+        S: Sendable<Client> + Sync,
+    {
+        type Output = S::Output;
+
+        const METHOD: http::Method = S::METHOD;
+
+        fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+            self.inner.rel_path()
+        }
+
+        fn empty_path_param(&self) -> Option<&'static str> {
+            self.inner.empty_path_param()
+        }
+
+        fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, ApiError<Client::Response>> {
+            if let Some(name) = self.invalid.as_ref() {
+                return Err(ApiError::InvalidHeader(name.clone()));
+            }
+
+            let req = self.inner.modify(client, req)?;
+            Ok(req.headers(self.headers.clone()))
+        }
+
+        fn fallback_media_range(&self) -> Option<&'static str> {
+            self.inner.fallback_media_range()
+        }
+
+        fn request_timeout(&self) -> Option<Duration> {
+            self.inner.request_timeout()
+        }
+
+        async fn accept_response(&self, resp: Client::Response) -> Result<(Self::Output, RawResponse<Client::Response>), ApiError<Client::Response>> {
+            self.inner.accept_response(resp).await
+        }
+    }
+
+    /// Wrapper for encoding the body of a single API call in some other content type
+    /// accepted by the operation. Builders for operations accepting multiple content
+    /// types have a `content_type` method for wrapping themselves once all the required
+    /// fields have been set.
+    ///
+    /// **NOTE:** Content types which aren't accepted by the operation are reported
+    /// (as `ApiError::UnsupportedContentType`) when the API call is made.
+    #[derive(Debug, Clone)]
+    pub struct ContentType<S> {
+        inner: S,
+        content_type: String,
+    }
+
+    impl<S> ContentType<S> {
+        /// Wraps the given sendable object with the given content type.
+        pub fn new<T: Into<String>>(inner: S, content_type: T) -> Self {
+            ContentType { inner, content_type: content_type.into() }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<Client, S> Sendable<Client> for ContentType<S>
+    where
+        Client: ApiClient + Sync + 'static,
+        S: Sendable<Client> + Sync,
+    {
+        type Output = S::Output;
+
+        const METHOD: http::Method = S::METHOD;
+
+        fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+            self.inner.rel_path()
+        }
+
+        fn empty_path_param(&self) -> Option<&'static str> {
+            self.inner.empty_path_param()
+        }
+
+        fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, ApiError<Client::Response>> {
+            self.inner.modify_with_content_type(client, req, &self.content_type)
+        }
+
+        fn fallback_media_range(&self) -> Option<&'static str> {
+            self.inner.fallback_media_range()
+        }
+
+        fn request_timeout(&self) -> Option<Duration> {
+            self.inner.request_timeout()
+        }
+
+        async fn accept_response(&self, resp: Client::Response) -> Result<(Self::Output, RawResponse<Client::Response>), ApiError<Client::Response>> {
+            self.inner.accept_response(resp).await
+        }
+    }
+
+    /// Wrapper for sending an API call with a borrowed body (instead of the one in the
+    /// builder), so that large (or reused) objects needn't be moved or cloned. Builders
+    /// for operations with bodies have a `with_body` method for wrapping themselves.
+    ///
+    /// **NOTE:** The body is encoded only when the API call is made.
+    #[derive(Debug, Clone)]
+    pub struct BorrowedBody<'a, S, B> {
+        inner: S,
+        body: &'a B,
+    }
+
+    impl<'a, S, B> BorrowedBody<'a, S, B> {
+        /// Wraps the given sendable object with the given body.
+        pub fn new(inner: S, body: &'a B) -> Self {
+            BorrowedBody { inner, body }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl<'a, Client, S, B> Sendable<Client> for BorrowedBody<'a, S, B>
+    where
+        Client: ApiClient + Sync + 'static,
+        S: Sendable<Client> + Sync,
+        B: serde::Serialize + Sync,
+    {
+        type Output = S::Output;
+
+        const METHOD: http::Method = S::METHOD;
+
+        fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+            self.inner.rel_path()
+        }
+
+        fn empty_path_param(&self) -> Option<&'static str> {
+            self.inner.empty_path_param()
+        }
+
+        fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, ApiError<Client::Response>> {
+            self.inner.modify_with_body(client, req, self.body)
+        }
+
+        fn fallback_media_range(&self) -> Option<&'static str> {
+            self.inner.fallback_media_range()
+        }
+
+        fn request_timeout(&self) -> Option<Duration> {
+            self.inner.request_timeout()
+        }
+
+        async fn accept_response(&self, resp: Client::Response) -> Result<(Self::Output, RawResponse<Client::Response>), ApiError<Client::Response>> {
+            self.inner.accept_response(resp).await
+        }
+    }
+
+    /// Request assembled for an API call without sending it. This is returned by
+    /// [`dry_run`](fn.dry_run.html) and it's useful for testing and debugging.
+    #[derive(Debug, Clone)]
+    pub struct DryRunRequest {
+        /// HTTP method of the request.
+        pub method: http::Method,
+        /// Absolute URL of the request (including the query parameters).
+        pub url: String,
+        /// Headers of the request (in the order they were set).
+        pub headers: Vec<(String, String)>,
+        /// Encoded body of the request (if any).
+        pub body: Option<Vec<u8>>,
+        /// Multipart form of the request (if any).
+        pub form: Option<DryRunForm>,
+        /// Error in encoding the JSON body (if any), which fails the dry run.
+        json_error: Option<String>,
+    }
+
+    /// Multipart form assembled in a dry run.
+    ///
+    /// **NOTE:** Files are described by their paths (they're not read).
+    #[derive(Debug, Clone, Default)]
+    pub struct DryRunForm {
+        /// Parts of this form (in the order they were added).
+        pub parts: Vec<(String, DryRunPart)>,
+    }
+
+    /// Part of a multipart form assembled in a dry run.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum DryRunPart {
+        /// Text value.
+        Text(String),
+        /// Path of the file to be streamed.
+        File(std::path::PathBuf),
+    }
+
+    impl Form for DryRunForm {
+        fn new() -> Self {
+            DryRunForm::default()
+        }
+
+        fn text<T, U>(mut self, key: T, value: U) -> Self
+            where T: Into<Cow<'static, str>>,
+                  U: Into<Cow<'static, str>>
+        {
+            self.parts.push((key.into().into_owned(), DryRunPart::Text(value.into().into_owned())));
+            self
+        }
+
+        fn file<K>(mut self, key: K, path: &Path) -> std::io::Result<Self>
+            where K: Into<Cow<'static, str>>
+        {
+            self.parts.push((key.into().into_owned(), DryRunPart::File(path.into())));
+            Ok(self)
+        }
+    }
+
+    impl Request for DryRunRequest {
+        type Form = DryRunForm;
+
+        fn header(mut self, name: &'static str, value: &str) -> Self {
+            self.headers.push((name.into(), value.into()));
+            self
+        }
+
+        fn headers(mut self, headers: http::HeaderMap) -> Self {
+            self.headers.retain(|(n, _)| !headers.contains_key(n.as_str()));
+            for (name, value) in headers.iter() {
+                self.headers.push((name.as_str().into(), String::from_utf8_lossy(value.as_bytes()).into_owned()));
+            }
+
+            self
+        }
+
+        fn body_bytes(mut self, body: Vec<u8>) -> Self {
+            self.body = Some(body);
+            self
+        }
+
+        fn json<T: serde::Serialize>(mut self, value: &T) -> Self {
+            if !self.headers.iter().any(|(n, _)| n.eq_ignore_ascii_case(http::header::CONTENT_TYPE.as_str())) {
+                self.headers.push((http::header::CONTENT_TYPE.as_str().into(), \"application/json\".into()));
+            }
+
+            match serde_json::to_vec(value) {
+                Ok(body) => self.body = Some(body),
+                Err(e) => self.json_error = Some(e.to_string()),
+            }
+
+            self
+        }
+
+        fn multipart_form_data(mut self, form: Self::Form) -> Self {
+            self.form = Some(form);
+            self
+        }
+
+        /// **NOTE:** Only `(key, value)` pairs of strings (like the ones set by builders)
+        /// are supported. Pairs with other values (like `None`) are skipped.
+        fn query<T: serde::Serialize>(mut self, params: &T) -> Self {
+            let pairs = match serde_json::to_value(params) {
+                Ok(serde_json::Value::Array(p)) => p,
+                _ => return self,
+            };
+
+            let mut ser = url::form_urlencoded::Serializer::new(String::new());
+            for pair in &pairs {
+                if let (Some(serde_json::Value::String(k)), Some(serde_json::Value::String(v))) = (pair.get(0), pair.get(1)) {
+                    ser.append_pair(k, v);
+                }
+            }
+
+            let query = ser.finish();
+            if !query.is_empty() {
+                self.url.push(if self.url.contains('?') { '&' } else { '?' });
+                self.url.push_str(&query);
+            }
+
+            self
+        }
+    }
+
+    /// Client for assembling requests in dry runs. It doesn't make any network I/O,
+    /// so sending API calls through this client fails with `ApiError::Io`.
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct DryRunClient;
+
+    #[async_trait::async_trait]
+    impl ApiClient for DryRunClient {
+        type Request = DryRunRequest;
+        type Response = reqwest::r#async::Response;
+
+        fn request_builder(&self, method: http::Method, rel_path: &str) -> Self::Request {
+            let mut u = String::from(BASE_URL.trim_end_matches('/'));
+            u.push('/');
+            u.push_str(rel_path.trim_start_matches('/'));
+            DryRunRequest { method, url: u, headers: vec![], body: None, form: None, json_error: None }
+        }
+
+        fn request_builder_for_url(&self, method: http::Method, url: &str) -> Option<Self::Request> {
+            Some(DryRunRequest { method, url: url.into(), headers: vec![], body: None, form: None, json_error: None })
+        }
+
+        async fn make_request(&self, _req: Self::Request) -> Result<Self::Response, ApiError<Self::Response>> {
+            Err(ApiError::Io(std::io::Error::new(std::io::ErrorKind::Other, \"requests are not sent in dry runs\")))
+        }
+    }
+
+    /// Assembles the request (method, URL, headers and body) for the given API call
+    /// without sending it (i.e., without any network I/O). Builders for operations
+    /// have a `dry_run` method for this.
+    ///
+    /// **NOTE:** Multipart forms describe the files (instead of reading them).
+    pub fn dry_run<S>(sendable: &S) -> Result<DryRunRequest, ApiError<reqwest::r#async::Response>>
+    where
+        S: Sendable<DryRunClient>,
+    {
+        if let Some(name) = sendable.empty_path_param() {
+            return Err(ApiError::EmptyPathParameter(name))
+        }
+
+        let client = DryRunClient;
+        let req = client.request_builder(S::METHOD, &sendable.rel_path());
+        let mut req = sendable.modify(&client, req)?;
+        match req.json_error.take() {
+            Some(e) => Err(ApiError::Io(std::io::Error::new(std::io::ErrorKind::InvalidData, e))),
+            None => Ok(req),
+        }
+    }
+
+    /// Adapters for converting the requests assembled in dry runs (i.e., [`DryRunRequest`](../struct.DryRunRequest.html))
+    /// into the request types of other HTTP libraries, so that they can be sent through
+    /// existing pipelines. Each adapter is behind a feature (`interop-http` and `interop-reqwest`).
+    pub mod interop {
+        #[allow(unused_imports)]
+        use super::{ApiError, DryRunPart, DryRunRequest, Form};
+
+        /// Converts into a request of the `http` crate (which is used by `hyper`, `tower`, etc.).
+        ///
+        /// **NOTE:** Multipart forms can't be encoded without reading the files, so they're
+        /// rejected (as `ApiError::UnsupportedContentType`).
+        #[cfg(feature = \"interop-http\")]
+        impl std::convert::TryFrom<DryRunRequest> for http::Request<Vec<u8>> {
+            type Error = ApiError<reqwest::r#async::Response>;
+
+            fn try_from(req: DryRunRequest) -> Result<Self, Self::Error> {
+                if req.form.is_some() {
+                    return Err(ApiError::UnsupportedContentType(\"multipart/form-data\".into()))
+                }
+
+                let mut r = http::Request::new(req.body.unwrap_or_default());
+                let url = req.url;
+                *r.method_mut() = req.method;
+                *r.uri_mut() = url.parse().map_err(|_| ApiError::InvalidUrl(url.clone()))?;
+                for (name, value) in req.headers {
+                    match (
+                        http::header::HeaderName::from_bytes(name.as_bytes()),
+                        http::header::HeaderValue::from_str(&value),
+                    ) {
+                        (Ok(n), Ok(v)) => {
+                            r.headers_mut().append(n, v);
+                        },
+                        _ => return Err(ApiError::InvalidHeader(name)),
+                    }
+                }
+
+                Ok(r)
+            }
+        }
+
+        impl DryRunRequest {
+            /// Converts into a `reqwest` request builder for the given client.
+            ///
+            /// **NOTE:** Files in multipart forms are opened (for streaming) at this point,
+            /// so this fails (with `ApiError::Io`) if they can't be opened.
+            #[cfg(feature = \"interop-reqwest\")]
+            pub fn into_reqwest(self, client: &reqwest::r#async::Client) -> Result<reqwest::r#async::RequestBuilder, ApiError<reqwest::r#async::Response>> {
+                let mut builder = client.request(self.method, self.url.as_str());
+                for (name, value) in &self.headers {
+                    builder = builder.header(name.as_str(), value.as_str());
+                }
+
+                if let Some(body) = self.body {
+                    builder = builder.body(body);
+                }
+
+                if let Some(form) = self.form {
+                    let mut f = <reqwest::r#async::multipart::Form as Form>::new();
+                    for (key, part) in form.parts {
+                        f = match part {
+                            DryRunPart::Text(v) => Form::text(f, key, v),
+                            DryRunPart::File(path) => Form::file(f, key, &path)?,
+                        };
+                    }
+
+                    builder = builder.multipart(f);
+                }
+
+                Ok(builder)
+            }
+        }
+    }
+
+    pub mod media_types {
+        use lazy_static::lazy_static;
+
+        lazy_static! {
+            pub static ref M_0: mime::MediaRange =
+                mime::MediaRange::parse(\"application/json\").expect(\"cannot parse \\\"application/json\\\" as media range\");
+            pub static ref M_1: mime::MediaRange =
+                mime::MediaRange::parse(\"application/yaml\").expect(\"cannot parse \\\"application/yaml\\\" as media range\");
+        }
+
+        /// Returns the index of the decoder matching the given media type (if any).
+        /// Parameters in the media type (like `charset`) are ignored by ranges without them.
+        pub fn decoder_index(ty: &mime::MediaType) -> Option<usize> {
+            if M_0.matches(ty) {
+                return Some(0)
+            }
+            if M_1.matches(ty) {
+                return Some(1)
+            }
+
+            None
+        }
+    }
+
+    impl<R: Debug + Send + 'static> ApiError<R> {
+        /// Returns the underlying error (if any) for chaining through `std::error::Error::source`.
+        fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                ApiError::Reqwest(e) => Some(e),
+                ApiError::Io(e) => Some(e),
+                ApiError::Validation(e) => Some(e),
+                ApiError::ApplicationJson(e) => Some(e),
+                ApiError::ApplicationYaml(e) => Some(e),
+                _ => None,
+            }
+        }
+    }
+
+    impl<R: Response + 'static> From<std::io::Error> for ApiError<R> {
+        fn from(e: std::io::Error) -> Self {
+            ApiError::Io(e)
+        }
+    }
+
+    impl<R: Response + 'static> From<ValidationError> for ApiError<R> {
+        fn from(e: ValidationError) -> Self {
+            ApiError::Validation(e)
+        }
+    }
+
+    impl<R: Response + 'static> From<serde_json::Error> for ApiError<R> {
+        fn from(e: serde_json::Error) -> Self {
+            ApiError::ApplicationJson(e)
+        }
+    }
+
+    impl<R: Response + 'static> From<serde_yaml::Error> for ApiError<R> {
+        fn from(e: serde_yaml::Error) -> Self {
+            ApiError::ApplicationYaml(e)
+        }
+    }
+}
+
+/// Re-exports of all objects and the API client (for glob imports).
+pub mod prelude {
+    pub use crate::codegen::client::{ApiClient, ApiError, EnvClient, Sendable};
+    pub use crate::codegen::io::k8s::api::admissionregistration::v1beta1::mutating_webhook_configuration::MutatingWebhookConfiguration;
+    pub use crate::codegen::io::k8s::api::admissionregistration::v1beta1::mutating_webhook_configuration_list::MutatingWebhookConfigurationList;
+    pub use crate::codegen::io::k8s::api::admissionregistration::v1beta1::rule_with_operations::RuleWithOperations;
+    pub use crate::codegen::io::k8s::api::admissionregistration::v1beta1::service_reference::ServiceReference as AdmissionregistrationV1beta1ServiceReference;
+    pub use crate::codegen::io::k8s::api::admissionregistration::v1beta1::validating_webhook_configuration::ValidatingWebhookConfiguration;
+    pub use crate::codegen::io::k8s::api::admissionregistration::v1beta1::validating_webhook_configuration_list::ValidatingWebhookConfigurationList;
+    pub use crate::codegen::io::k8s::api::admissionregistration::v1beta1::webhook::Webhook as V1beta1Webhook;
+    pub use crate::codegen::io::k8s::api::admissionregistration::v1beta1::webhook_client_config::WebhookClientConfig as AdmissionregistrationV1beta1WebhookClientConfig;
+    pub use crate::codegen::io::k8s::api::apps::v1::controller_revision::ControllerRevision as V1ControllerRevision;
+    pub use crate::codegen::io::k8s::api::apps::v1::controller_revision_list::ControllerRevisionList as V1ControllerRevisionList;
+    pub use crate::codegen::io::k8s::api::apps::v1::daemon_set::DaemonSet as V1DaemonSet;
+    pub use crate::codegen::io::k8s::api::apps::v1::daemon_set_condition::DaemonSetCondition as V1DaemonSetCondition;
+    pub use crate::codegen::io::k8s::api::apps::v1::daemon_set_list::DaemonSetList as V1DaemonSetList;
+    pub use crate::codegen::io::k8s::api::apps::v1::daemon_set_spec::DaemonSetSpec as V1DaemonSetSpec;
+    pub use crate::codegen::io::k8s::api::apps::v1::daemon_set_status::DaemonSetStatus as V1DaemonSetStatus;
+    pub use crate::codegen::io::k8s::api::apps::v1::daemon_set_update_strategy::DaemonSetUpdateStrategy as V1DaemonSetUpdateStrategy;
+    pub use crate::codegen::io::k8s::api::apps::v1::deployment::Deployment as AppsV1Deployment;
+    pub use crate::codegen::io::k8s::api::apps::v1::deployment_condition::DeploymentCondition as AppsV1DeploymentCondition;
+    pub use crate::codegen::io::k8s::api::apps::v1::deployment_list::DeploymentList as AppsV1DeploymentList;
+    pub use crate::codegen::io::k8s::api::apps::v1::deployment_spec::DeploymentSpec as AppsV1DeploymentSpec;
+    pub use crate::codegen::io::k8s::api::apps::v1::deployment_status::DeploymentStatus as AppsV1DeploymentStatus;
+    pub use crate::codegen::io::k8s::api::apps::v1::deployment_strategy::DeploymentStrategy as AppsV1DeploymentStrategy;
+    pub use crate::codegen::io::k8s::api::apps::v1::replica_set::ReplicaSet as V1ReplicaSet;
+    pub use crate::codegen::io::k8s::api::apps::v1::replica_set_condition::ReplicaSetCondition as V1ReplicaSetCondition;
+    pub use crate::codegen::io::k8s::api::apps::v1::replica_set_list::ReplicaSetList as V1ReplicaSetList;
+    pub use crate::codegen::io::k8s::api::apps::v1::replica_set_spec::ReplicaSetSpec as V1ReplicaSetSpec;
+    pub use crate::codegen::io::k8s::api::apps::v1::replica_set_status::ReplicaSetStatus as V1ReplicaSetStatus;
+    pub use crate::codegen::io::k8s::api::apps::v1::rolling_update_daemon_set::RollingUpdateDaemonSet as V1RollingUpdateDaemonSet;
+    pub use crate::codegen::io::k8s::api::apps::v1::rolling_update_deployment::RollingUpdateDeployment as AppsV1RollingUpdateDeployment;
+    pub use crate::codegen::io::k8s::api::apps::v1::rolling_update_stateful_set_strategy::RollingUpdateStatefulSetStrategy as V1RollingUpdateStatefulSetStrategy;
+    pub use crate::codegen::io::k8s::api::apps::v1::stateful_set::StatefulSet as V1StatefulSet;
+    pub use crate::codegen::io::k8s::api::apps::v1::stateful_set_condition::StatefulSetCondition as V1StatefulSetCondition;
+    pub use crate::codegen::io::k8s::api::apps::v1::stateful_set_list::StatefulSetList as V1StatefulSetList;
+    pub use crate::codegen::io::k8s::api::apps::v1::stateful_set_spec::StatefulSetSpec as V1StatefulSetSpec;
+    pub use crate::codegen::io::k8s::api::apps::v1::stateful_set_status::StatefulSetStatus as V1StatefulSetStatus;
+    pub use crate::codegen::io::k8s::api::apps::v1::stateful_set_update_strategy::StatefulSetUpdateStrategy as V1StatefulSetUpdateStrategy;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::controller_revision::ControllerRevision as V1beta1ControllerRevision;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::controller_revision_list::ControllerRevisionList as V1beta1ControllerRevisionList;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::deployment::Deployment as AppsV1beta1Deployment;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::deployment_condition::DeploymentCondition as AppsV1beta1DeploymentCondition;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::deployment_list::DeploymentList as AppsV1beta1DeploymentList;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::deployment_rollback::DeploymentRollback as AppsV1beta1DeploymentRollback;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::deployment_spec::DeploymentSpec as AppsV1beta1DeploymentSpec;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::deployment_status::DeploymentStatus as AppsV1beta1DeploymentStatus;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::deployment_strategy::DeploymentStrategy as AppsV1beta1DeploymentStrategy;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::rollback_config::RollbackConfig as AppsV1beta1RollbackConfig;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::rolling_update_deployment::RollingUpdateDeployment as AppsV1beta1RollingUpdateDeployment;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::rolling_update_stateful_set_strategy::RollingUpdateStatefulSetStrategy as V1beta1RollingUpdateStatefulSetStrategy;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::scale::Scale as AppsV1beta1Scale;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::scale_spec::ScaleSpec as AppsV1beta1ScaleSpec;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::scale_status::ScaleStatus as AppsV1beta1ScaleStatus;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::stateful_set::StatefulSet as V1beta1StatefulSet;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::stateful_set_condition::StatefulSetCondition as V1beta1StatefulSetCondition;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::stateful_set_list::StatefulSetList as V1beta1StatefulSetList;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::stateful_set_spec::StatefulSetSpec as V1beta1StatefulSetSpec;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::stateful_set_status::StatefulSetStatus as V1beta1StatefulSetStatus;
+    pub use crate::codegen::io::k8s::api::apps::v1beta1::stateful_set_update_strategy::StatefulSetUpdateStrategy as V1beta1StatefulSetUpdateStrategy;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::controller_revision::ControllerRevision as V1beta2ControllerRevision;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::controller_revision_list::ControllerRevisionList as V1beta2ControllerRevisionList;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::daemon_set::DaemonSet as V1beta2DaemonSet;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::daemon_set_condition::DaemonSetCondition as V1beta2DaemonSetCondition;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::daemon_set_list::DaemonSetList as V1beta2DaemonSetList;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::daemon_set_spec::DaemonSetSpec as V1beta2DaemonSetSpec;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::daemon_set_status::DaemonSetStatus as V1beta2DaemonSetStatus;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::daemon_set_update_strategy::DaemonSetUpdateStrategy as V1beta2DaemonSetUpdateStrategy;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::deployment::Deployment as AppsV1beta2Deployment;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::deployment_condition::DeploymentCondition as AppsV1beta2DeploymentCondition;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::deployment_list::DeploymentList as AppsV1beta2DeploymentList;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::deployment_spec::DeploymentSpec as AppsV1beta2DeploymentSpec;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::deployment_status::DeploymentStatus as AppsV1beta2DeploymentStatus;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::deployment_strategy::DeploymentStrategy as AppsV1beta2DeploymentStrategy;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::replica_set::ReplicaSet as V1beta2ReplicaSet;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::replica_set_condition::ReplicaSetCondition as V1beta2ReplicaSetCondition;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::replica_set_list::ReplicaSetList as V1beta2ReplicaSetList;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::replica_set_spec::ReplicaSetSpec as V1beta2ReplicaSetSpec;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::replica_set_status::ReplicaSetStatus as V1beta2ReplicaSetStatus;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::rolling_update_daemon_set::RollingUpdateDaemonSet as V1beta2RollingUpdateDaemonSet;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::rolling_update_deployment::RollingUpdateDeployment as AppsV1beta2RollingUpdateDeployment;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::rolling_update_stateful_set_strategy::RollingUpdateStatefulSetStrategy as V1beta2RollingUpdateStatefulSetStrategy;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::scale::Scale as AppsV1beta2Scale;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::scale_spec::ScaleSpec as AppsV1beta2ScaleSpec;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::scale_status::ScaleStatus as AppsV1beta2ScaleStatus;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::stateful_set::StatefulSet as V1beta2StatefulSet;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::stateful_set_condition::StatefulSetCondition as V1beta2StatefulSetCondition;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::stateful_set_list::StatefulSetList as V1beta2StatefulSetList;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::stateful_set_spec::StatefulSetSpec as V1beta2StatefulSetSpec;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::stateful_set_status::StatefulSetStatus as V1beta2StatefulSetStatus;
+    pub use crate::codegen::io::k8s::api::apps::v1beta2::stateful_set_update_strategy::StatefulSetUpdateStrategy as V1beta2StatefulSetUpdateStrategy;
+    pub use crate::codegen::io::k8s::api::auditregistration::v1alpha1::audit_sink::AuditSink;
+    pub use crate::codegen::io::k8s::api::auditregistration::v1alpha1::audit_sink_list::AuditSinkList;
+    pub use crate::codegen::io::k8s::api::auditregistration::v1alpha1::audit_sink_spec::AuditSinkSpec;
+    pub use crate::codegen::io::k8s::api::auditregistration::v1alpha1::policy::Policy;
+    pub use crate::codegen::io::k8s::api::auditregistration::v1alpha1::service_reference::ServiceReference as AuditregistrationV1alpha1ServiceReference;
+    pub use crate::codegen::io::k8s::api::auditregistration::v1alpha1::webhook::Webhook as V1alpha1Webhook;
+    pub use crate::codegen::io::k8s::api::auditregistration::v1alpha1::webhook_client_config::WebhookClientConfig as AuditregistrationV1alpha1WebhookClientConfig;
+    pub use crate::codegen::io::k8s::api::auditregistration::v1alpha1::webhook_throttle_config::WebhookThrottleConfig;
+    pub use crate::codegen::io::k8s::api::authentication::v1::token_review::TokenReview as V1TokenReview;
+    pub use crate::codegen::io::k8s::api::authentication::v1::token_review_spec::TokenReviewSpec as V1TokenReviewSpec;
+    pub use crate::codegen::io::k8s::api::authentication::v1::token_review_status::TokenReviewStatus as V1TokenReviewStatus;
+    pub use crate::codegen::io::k8s::api::authentication::v1::user_info::UserInfo as V1UserInfo;
+    pub use crate::codegen::io::k8s::api::authentication::v1beta1::token_review::TokenReview as V1beta1TokenReview;
+    pub use crate::codegen::io::k8s::api::authentication::v1beta1::token_review_spec::TokenReviewSpec as V1beta1TokenReviewSpec;
+    pub use crate::codegen::io::k8s::api::authentication::v1beta1::token_review_status::TokenReviewStatus as V1beta1TokenReviewStatus;
+    pub use crate::codegen::io::k8s::api::authentication::v1beta1::user_info::UserInfo as V1beta1UserInfo;
+    pub use crate::codegen::io::k8s::api::authorization::v1::local_subject_access_review::LocalSubjectAccessReview as V1LocalSubjectAccessReview;
+    pub use crate::codegen::io::k8s::api::authorization::v1::non_resource_attributes::NonResourceAttributes as V1NonResourceAttributes;
+    pub use crate::codegen::io::k8s::api::authorization::v1::non_resource_rule::NonResourceRule as V1NonResourceRule;
+    pub use crate::codegen::io::k8s::api::authorization::v1::resource_attributes::ResourceAttributes as V1ResourceAttributes;
+    pub use crate::codegen::io::k8s::api::authorization::v1::resource_rule::ResourceRule as V1ResourceRule;
+    pub use crate::codegen::io::k8s::api::authorization::v1::self_subject_access_review::SelfSubjectAccessReview as V1SelfSubjectAccessReview;
+    pub use crate::codegen::io::k8s::api::authorization::v1::self_subject_access_review_spec::SelfSubjectAccessReviewSpec as V1SelfSubjectAccessReviewSpec;
+    pub use crate::codegen::io::k8s::api::authorization::v1::self_subject_rules_review::SelfSubjectRulesReview as V1SelfSubjectRulesReview;
+    pub use crate::codegen::io::k8s::api::authorization::v1::self_subject_rules_review_spec::SelfSubjectRulesReviewSpec as V1SelfSubjectRulesReviewSpec;
+    pub use crate::codegen::io::k8s::api::authorization::v1::subject_access_review::SubjectAccessReview as V1SubjectAccessReview;
+    pub use crate::codegen::io::k8s::api::authorization::v1::subject_access_review_spec::SubjectAccessReviewSpec as V1SubjectAccessReviewSpec;
+    pub use crate::codegen::io::k8s::api::authorization::v1::subject_access_review_status::SubjectAccessReviewStatus as V1SubjectAccessReviewStatus;
+    pub use crate::codegen::io::k8s::api::authorization::v1::subject_rules_review_status::SubjectRulesReviewStatus as V1SubjectRulesReviewStatus;
+    pub use crate::codegen::io::k8s::api::authorization::v1beta1::local_subject_access_review::LocalSubjectAccessReview as V1beta1LocalSubjectAccessReview;
+    pub use crate::codegen::io::k8s::api::authorization::v1beta1::non_resource_attributes::NonResourceAttributes as V1beta1NonResourceAttributes;
+    pub use crate::codegen::io::k8s::api::authorization::v1beta1::non_resource_rule::NonResourceRule as V1beta1NonResourceRule;
+    pub use crate::codegen::io::k8s::api::authorization::v1beta1::resource_attributes::ResourceAttributes as V1beta1ResourceAttributes;
+    pub use crate::codegen::io::k8s::api::authorization::v1beta1::resource_rule::ResourceRule as V1beta1ResourceRule;
+    pub use crate::codegen::io::k8s::api::authorization::v1beta1::self_subject_access_review::SelfSubjectAccessReview as V1beta1SelfSubjectAccessReview;
+    pub use crate::codegen::io::k8s::api::authorization::v1beta1::self_subject_access_review_spec::SelfSubjectAccessReviewSpec as V1beta1SelfSubjectAccessReviewSpec;
+    pub use crate::codegen::io::k8s::api::authorization::v1beta1::self_subject_rules_review::SelfSubjectRulesReview as V1beta1SelfSubjectRulesReview;
+    pub use crate::codegen::io::k8s::api::authorization::v1beta1::self_subject_rules_review_spec::SelfSubjectRulesReviewSpec as V1beta1SelfSubjectRulesReviewSpec;
+    pub use crate::codegen::io::k8s::api::authorization::v1beta1::subject_access_review::SubjectAccessReview as V1beta1SubjectAccessReview;
+    pub use crate::codegen::io::k8s::api::authorization::v1beta1::subject_access_review_spec::SubjectAccessReviewSpec as V1beta1SubjectAccessReviewSpec;
+    pub use crate::codegen::io::k8s::api::authorization::v1beta1::subject_access_review_status::SubjectAccessReviewStatus as V1beta1SubjectAccessReviewStatus;
+    pub use crate::codegen::io::k8s::api::authorization::v1beta1::subject_rules_review_status::SubjectRulesReviewStatus as V1beta1SubjectRulesReviewStatus;
+    pub use crate::codegen::io::k8s::api::autoscaling::v1::cross_version_object_reference::CrossVersionObjectReference as V1CrossVersionObjectReference;
+    pub use crate::codegen::io::k8s::api::autoscaling::v1::horizontal_pod_autoscaler::HorizontalPodAutoscaler as V1HorizontalPodAutoscaler;
+    pub use crate::codegen::io::k8s::api::autoscaling::v1::horizontal_pod_autoscaler_list::HorizontalPodAutoscalerList as V1HorizontalPodAutoscalerList;
+    pub use crate::codegen::io::k8s::api::autoscaling::v1::horizontal_pod_autoscaler_spec::HorizontalPodAutoscalerSpec as V1HorizontalPodAutoscalerSpec;
+    pub use crate::codegen::io::k8s::api::autoscaling::v1::horizontal_pod_autoscaler_status::HorizontalPodAutoscalerStatus as V1HorizontalPodAutoscalerStatus;
+    pub use crate::codegen::io::k8s::api::autoscaling::v1::scale::Scale as AutoscalingV1Scale;
+    pub use crate::codegen::io::k8s::api::autoscaling::v1::scale_spec::ScaleSpec as AutoscalingV1ScaleSpec;
+    pub use crate::codegen::io::k8s::api::autoscaling::v1::scale_status::ScaleStatus as AutoscalingV1ScaleStatus;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta1::cross_version_object_reference::CrossVersionObjectReference as V2beta1CrossVersionObjectReference;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta1::external_metric_source::ExternalMetricSource as V2beta1ExternalMetricSource;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta1::external_metric_status::ExternalMetricStatus as V2beta1ExternalMetricStatus;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta1::horizontal_pod_autoscaler::HorizontalPodAutoscaler as V2beta1HorizontalPodAutoscaler;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta1::horizontal_pod_autoscaler_condition::HorizontalPodAutoscalerCondition as V2beta1HorizontalPodAutoscalerCondition;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta1::horizontal_pod_autoscaler_list::HorizontalPodAutoscalerList as V2beta1HorizontalPodAutoscalerList;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta1::horizontal_pod_autoscaler_spec::HorizontalPodAutoscalerSpec as V2beta1HorizontalPodAutoscalerSpec;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta1::horizontal_pod_autoscaler_status::HorizontalPodAutoscalerStatus as V2beta1HorizontalPodAutoscalerStatus;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta1::metric_spec::MetricSpec as V2beta1MetricSpec;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta1::metric_status::MetricStatus as V2beta1MetricStatus;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta1::object_metric_source::ObjectMetricSource as V2beta1ObjectMetricSource;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta1::object_metric_status::ObjectMetricStatus as V2beta1ObjectMetricStatus;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta1::pods_metric_source::PodsMetricSource as V2beta1PodsMetricSource;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta1::pods_metric_status::PodsMetricStatus as V2beta1PodsMetricStatus;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta1::resource_metric_source::ResourceMetricSource as V2beta1ResourceMetricSource;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta1::resource_metric_status::ResourceMetricStatus as V2beta1ResourceMetricStatus;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::cross_version_object_reference::CrossVersionObjectReference as V2beta2CrossVersionObjectReference;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::external_metric_source::ExternalMetricSource as V2beta2ExternalMetricSource;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::external_metric_status::ExternalMetricStatus as V2beta2ExternalMetricStatus;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::horizontal_pod_autoscaler::HorizontalPodAutoscaler as V2beta2HorizontalPodAutoscaler;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::horizontal_pod_autoscaler_condition::HorizontalPodAutoscalerCondition as V2beta2HorizontalPodAutoscalerCondition;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::horizontal_pod_autoscaler_list::HorizontalPodAutoscalerList as V2beta2HorizontalPodAutoscalerList;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::horizontal_pod_autoscaler_spec::HorizontalPodAutoscalerSpec as V2beta2HorizontalPodAutoscalerSpec;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::horizontal_pod_autoscaler_status::HorizontalPodAutoscalerStatus as V2beta2HorizontalPodAutoscalerStatus;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_identifier::MetricIdentifier;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_spec::MetricSpec as V2beta2MetricSpec;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_status::MetricStatus as V2beta2MetricStatus;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_target::MetricTarget;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::metric_value_status::MetricValueStatus;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::object_metric_source::ObjectMetricSource as V2beta2ObjectMetricSource;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::object_metric_status::ObjectMetricStatus as V2beta2ObjectMetricStatus;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::pods_metric_source::PodsMetricSource as V2beta2PodsMetricSource;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::pods_metric_status::PodsMetricStatus as V2beta2PodsMetricStatus;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::resource_metric_source::ResourceMetricSource as V2beta2ResourceMetricSource;
+    pub use crate::codegen::io::k8s::api::autoscaling::v2beta2::resource_metric_status::ResourceMetricStatus as V2beta2ResourceMetricStatus;
+    pub use crate::codegen::io::k8s::api::batch::v1::job::Job;
+    pub use crate::codegen::io::k8s::api::batch::v1::job_condition::JobCondition;
+    pub use crate::codegen::io::k8s::api::batch::v1::job_list::JobList;
+    pub use crate::codegen::io::k8s::api::batch::v1::job_spec::JobSpec;
+    pub use crate::codegen::io::k8s::api::batch::v1::job_status::JobStatus;
+    pub use crate::codegen::io::k8s::api::batch::v1beta1::cron_job::CronJob as V1beta1CronJob;
+    pub use crate::codegen::io::k8s::api::batch::v1beta1::cron_job_list::CronJobList as V1beta1CronJobList;
+    pub use crate::codegen::io::k8s::api::batch::v1beta1::cron_job_spec::CronJobSpec as V1beta1CronJobSpec;
+    pub use crate::codegen::io::k8s::api::batch::v1beta1::cron_job_status::CronJobStatus as V1beta1CronJobStatus;
+    pub use crate::codegen::io::k8s::api::batch::v1beta1::job_template_spec::JobTemplateSpec as V1beta1JobTemplateSpec;
+    pub use crate::codegen::io::k8s::api::batch::v2alpha1::cron_job::CronJob as V2alpha1CronJob;
+    pub use crate::codegen::io::k8s::api::batch::v2alpha1::cron_job_list::CronJobList as V2alpha1CronJobList;
+    pub use crate::codegen::io::k8s::api::batch::v2alpha1::cron_job_spec::CronJobSpec as V2alpha1CronJobSpec;
+    pub use crate::codegen::io::k8s::api::batch::v2alpha1::cron_job_status::CronJobStatus as V2alpha1CronJobStatus;
+    pub use crate::codegen::io::k8s::api::batch::v2alpha1::job_template_spec::JobTemplateSpec as V2alpha1JobTemplateSpec;
+    pub use crate::codegen::io::k8s::api::certificates::v1beta1::certificate_signing_request::CertificateSigningRequest;
+    pub use crate::codegen::io::k8s::api::certificates::v1beta1::certificate_signing_request_condition::CertificateSigningRequestCondition;
+    pub use crate::codegen::io::k8s::api::certificates::v1beta1::certificate_signing_request_list::CertificateSigningRequestList;
+    pub use crate::codegen::io::k8s::api::certificates::v1beta1::certificate_signing_request_spec::CertificateSigningRequestSpec;
+    pub use crate::codegen::io::k8s::api::certificates::v1beta1::certificate_signing_request_status::CertificateSigningRequestStatus;
+    pub use crate::codegen::io::k8s::api::coordination::v1::lease::Lease as V1Lease;
+    pub use crate::codegen::io::k8s::api::coordination::v1::lease_list::LeaseList as V1LeaseList;
+    pub use crate::codegen::io::k8s::api::coordination::v1::lease_spec::LeaseSpec as V1LeaseSpec;
+    pub use crate::codegen::io::k8s::api::coordination::v1beta1::lease::Lease as V1beta1Lease;
+    pub use crate::codegen::io::k8s::api::coordination::v1beta1::lease_list::LeaseList as V1beta1LeaseList;
+    pub use crate::codegen::io::k8s::api::coordination::v1beta1::lease_spec::LeaseSpec as V1beta1LeaseSpec;
+    pub use crate::codegen::io::k8s::api::core::v1::affinity::Affinity;
+    pub use crate::codegen::io::k8s::api::core::v1::attached_volume::AttachedVolume;
+    pub use crate::codegen::io::k8s::api::core::v1::aws_elastic_block_store_volume_source::AwsElasticBlockStoreVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::azure_disk_volume_source::AzureDiskVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::azure_file_persistent_volume_source::AzureFilePersistentVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::azure_file_volume_source::AzureFileVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::binding::Binding;
+    pub use crate::codegen::io::k8s::api::core::v1::capabilities::Capabilities;
+    pub use crate::codegen::io::k8s::api::core::v1::ceph_fs_persistent_volume_source::CephFsPersistentVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::ceph_fs_volume_source::CephFsVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::cinder_persistent_volume_source::CinderPersistentVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::cinder_volume_source::CinderVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::client_ip_config::ClientIpConfig;
+    pub use crate::codegen::io::k8s::api::core::v1::component_condition::ComponentCondition;
+    pub use crate::codegen::io::k8s::api::core::v1::component_status::ComponentStatus;
+    pub use crate::codegen::io::k8s::api::core::v1::component_status_list::ComponentStatusList;
+    pub use crate::codegen::io::k8s::api::core::v1::config_map::ConfigMap;
+    pub use crate::codegen::io::k8s::api::core::v1::config_map_env_source::ConfigMapEnvSource;
+    pub use crate::codegen::io::k8s::api::core::v1::config_map_key_selector::ConfigMapKeySelector;
+    pub use crate::codegen::io::k8s::api::core::v1::config_map_list::ConfigMapList;
+    pub use crate::codegen::io::k8s::api::core::v1::config_map_node_config_source::ConfigMapNodeConfigSource;
+    pub use crate::codegen::io::k8s::api::core::v1::config_map_projection::ConfigMapProjection;
+    pub use crate::codegen::io::k8s::api::core::v1::config_map_volume_source::ConfigMapVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::container::Container;
+    pub use crate::codegen::io::k8s::api::core::v1::container_image::ContainerImage;
+    pub use crate::codegen::io::k8s::api::core::v1::container_port::ContainerPort;
+    pub use crate::codegen::io::k8s::api::core::v1::container_state::ContainerState;
+    pub use crate::codegen::io::k8s::api::core::v1::container_state_running::ContainerStateRunning;
+    pub use crate::codegen::io::k8s::api::core::v1::container_state_terminated::ContainerStateTerminated;
+    pub use crate::codegen::io::k8s::api::core::v1::container_state_waiting::ContainerStateWaiting;
+    pub use crate::codegen::io::k8s::api::core::v1::container_status::ContainerStatus;
+    pub use crate::codegen::io::k8s::api::core::v1::csi_persistent_volume_source::CsiPersistentVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::csi_volume_source::CsiVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::daemon_endpoint::DaemonEndpoint;
+    pub use crate::codegen::io::k8s::api::core::v1::downward_api_projection::DownwardApiProjection;
+    pub use crate::codegen::io::k8s::api::core::v1::downward_api_volume_file::DownwardApiVolumeFile;
+    pub use crate::codegen::io::k8s::api::core::v1::downward_api_volume_source::DownwardApiVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::empty_dir_volume_source::EmptyDirVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::endpoint_address::EndpointAddress;
+    pub use crate::codegen::io::k8s::api::core::v1::endpoint_port::EndpointPort;
+    pub use crate::codegen::io::k8s::api::core::v1::endpoint_subset::EndpointSubset;
+    pub use crate::codegen::io::k8s::api::core::v1::endpoints::Endpoints;
+    pub use crate::codegen::io::k8s::api::core::v1::endpoints_list::EndpointsList;
+    pub use crate::codegen::io::k8s::api::core::v1::env_from_source::EnvFromSource;
+    pub use crate::codegen::io::k8s::api::core::v1::env_var::EnvVar;
+    pub use crate::codegen::io::k8s::api::core::v1::env_var_source::EnvVarSource;
+    pub use crate::codegen::io::k8s::api::core::v1::event::Event as V1Event;
+    pub use crate::codegen::io::k8s::api::core::v1::event_list::EventList as V1EventList;
+    pub use crate::codegen::io::k8s::api::core::v1::event_series::EventSeries as V1EventSeries;
+    pub use crate::codegen::io::k8s::api::core::v1::event_source::EventSource;
+    pub use crate::codegen::io::k8s::api::core::v1::exec_action::ExecAction;
+    pub use crate::codegen::io::k8s::api::core::v1::fc_volume_source::FcVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::flex_persistent_volume_source::FlexPersistentVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::flex_volume_source::FlexVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::flocker_volume_source::FlockerVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::gce_persistent_disk_volume_source::GcePersistentDiskVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::git_repo_volume_source::GitRepoVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::glusterfs_persistent_volume_source::GlusterfsPersistentVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::glusterfs_volume_source::GlusterfsVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::handler::Handler;
+    pub use crate::codegen::io::k8s::api::core::v1::host_alias::HostAlias;
+    pub use crate::codegen::io::k8s::api::core::v1::host_path_volume_source::HostPathVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::http_get_action::HttpGetAction;
+    pub use crate::codegen::io::k8s::api::core::v1::http_header::HttpHeader;
+    pub use crate::codegen::io::k8s::api::core::v1::iscsi_persistent_volume_source::IscsiPersistentVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::iscsi_volume_source::IscsiVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::key_to_path::KeyToPath;
+    pub use crate::codegen::io::k8s::api::core::v1::lifecycle::Lifecycle;
+    pub use crate::codegen::io::k8s::api::core::v1::limit_range::LimitRange;
+    pub use crate::codegen::io::k8s::api::core::v1::limit_range_item::LimitRangeItem;
+    pub use crate::codegen::io::k8s::api::core::v1::limit_range_list::LimitRangeList;
+    pub use crate::codegen::io::k8s::api::core::v1::limit_range_spec::LimitRangeSpec;
+    pub use crate::codegen::io::k8s::api::core::v1::load_balancer_ingress::LoadBalancerIngress;
+    pub use crate::codegen::io::k8s::api::core::v1::load_balancer_status::LoadBalancerStatus;
+    pub use crate::codegen::io::k8s::api::core::v1::local_object_reference::LocalObjectReference;
+    pub use crate::codegen::io::k8s::api::core::v1::local_volume_source::LocalVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::namespace::Namespace;
+    pub use crate::codegen::io::k8s::api::core::v1::namespace_list::NamespaceList;
+    pub use crate::codegen::io::k8s::api::core::v1::namespace_spec::NamespaceSpec;
+    pub use crate::codegen::io::k8s::api::core::v1::namespace_status::NamespaceStatus;
+    pub use crate::codegen::io::k8s::api::core::v1::nfs_volume_source::NfsVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::node::Node;
+    pub use crate::codegen::io::k8s::api::core::v1::node_address::NodeAddress;
+    pub use crate::codegen::io::k8s::api::core::v1::node_affinity::NodeAffinity;
+    pub use crate::codegen::io::k8s::api::core::v1::node_condition::NodeCondition;
+    pub use crate::codegen::io::k8s::api::core::v1::node_config_source::NodeConfigSource;
+    pub use crate::codegen::io::k8s::api::core::v1::node_config_status::NodeConfigStatus;
+    pub use crate::codegen::io::k8s::api::core::v1::node_daemon_endpoints::NodeDaemonEndpoints;
+    pub use crate::codegen::io::k8s::api::core::v1::node_list::NodeList;
+    pub use crate::codegen::io::k8s::api::core::v1::node_selector::NodeSelector;
+    pub use crate::codegen::io::k8s::api::core::v1::node_selector_requirement::NodeSelectorRequirement;
+    pub use crate::codegen::io::k8s::api::core::v1::node_selector_term::NodeSelectorTerm;
+    pub use crate::codegen::io::k8s::api::core::v1::node_spec::NodeSpec;
+    pub use crate::codegen::io::k8s::api::core::v1::node_status::NodeStatus;
+    pub use crate::codegen::io::k8s::api::core::v1::node_system_info::NodeSystemInfo;
+    pub use crate::codegen::io::k8s::api::core::v1::object_field_selector::ObjectFieldSelector;
+    pub use crate::codegen::io::k8s::api::core::v1::object_reference::ObjectReference;
+    pub use crate::codegen::io::k8s::api::core::v1::persistent_volume::PersistentVolume;
+    pub use crate::codegen::io::k8s::api::core::v1::persistent_volume_claim::PersistentVolumeClaim;
+    pub use crate::codegen::io::k8s::api::core::v1::persistent_volume_claim_condition::PersistentVolumeClaimCondition;
+    pub use crate::codegen::io::k8s::api::core::v1::persistent_volume_claim_list::PersistentVolumeClaimList;
+    pub use crate::codegen::io::k8s::api::core::v1::persistent_volume_claim_spec::PersistentVolumeClaimSpec;
+    pub use crate::codegen::io::k8s::api::core::v1::persistent_volume_claim_status::PersistentVolumeClaimStatus;
+    pub use crate::codegen::io::k8s::api::core::v1::persistent_volume_claim_volume_source::PersistentVolumeClaimVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::persistent_volume_list::PersistentVolumeList;
+    pub use crate::codegen::io::k8s::api::core::v1::persistent_volume_spec::PersistentVolumeSpec;
+    pub use crate::codegen::io::k8s::api::core::v1::persistent_volume_status::PersistentVolumeStatus;
+    pub use crate::codegen::io::k8s::api::core::v1::photon_persistent_disk_volume_source::PhotonPersistentDiskVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::pod::Pod;
+    pub use crate::codegen::io::k8s::api::core::v1::pod_affinity::PodAffinity;
+    pub use crate::codegen::io::k8s::api::core::v1::pod_affinity_term::PodAffinityTerm;
+    pub use crate::codegen::io::k8s::api::core::v1::pod_anti_affinity::PodAntiAffinity;
+    pub use crate::codegen::io::k8s::api::core::v1::pod_condition::PodCondition;
+    pub use crate::codegen::io::k8s::api::core::v1::pod_dns_config::PodDnsConfig;
+    pub use crate::codegen::io::k8s::api::core::v1::pod_dns_config_option::PodDnsConfigOption;
+    pub use crate::codegen::io::k8s::api::core::v1::pod_list::PodList;
+    pub use crate::codegen::io::k8s::api::core::v1::pod_readiness_gate::PodReadinessGate;
+    pub use crate::codegen::io::k8s::api::core::v1::pod_security_context::PodSecurityContext;
+    pub use crate::codegen::io::k8s::api::core::v1::pod_spec::PodSpec;
+    pub use crate::codegen::io::k8s::api::core::v1::pod_status::PodStatus;
+    pub use crate::codegen::io::k8s::api::core::v1::pod_template::PodTemplate;
+    pub use crate::codegen::io::k8s::api::core::v1::pod_template_list::PodTemplateList;
+    pub use crate::codegen::io::k8s::api::core::v1::pod_template_spec::PodTemplateSpec;
+    pub use crate::codegen::io::k8s::api::core::v1::portworx_volume_source::PortworxVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::preferred_scheduling_term::PreferredSchedulingTerm;
+    pub use crate::codegen::io::k8s::api::core::v1::probe::Probe;
+    pub use crate::codegen::io::k8s::api::core::v1::projected_volume_source::ProjectedVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::quobyte_volume_source::QuobyteVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::rbd_persistent_volume_source::RbdPersistentVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::rbd_volume_source::RbdVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::replication_controller::ReplicationController;
+    pub use crate::codegen::io::k8s::api::core::v1::replication_controller_condition::ReplicationControllerCondition;
+    pub use crate::codegen::io::k8s::api::core::v1::replication_controller_list::ReplicationControllerList;
+    pub use crate::codegen::io::k8s::api::core::v1::replication_controller_spec::ReplicationControllerSpec;
+    pub use crate::codegen::io::k8s::api::core::v1::replication_controller_status::ReplicationControllerStatus;
+    pub use crate::codegen::io::k8s::api::core::v1::resource_field_selector::ResourceFieldSelector;
+    pub use crate::codegen::io::k8s::api::core::v1::resource_quota::ResourceQuota;
+    pub use crate::codegen::io::k8s::api::core::v1::resource_quota_list::ResourceQuotaList;
+    pub use crate::codegen::io::k8s::api::core::v1::resource_quota_spec::ResourceQuotaSpec;
+    pub use crate::codegen::io::k8s::api::core::v1::resource_quota_status::ResourceQuotaStatus;
+    pub use crate::codegen::io::k8s::api::core::v1::resource_requirements::ResourceRequirements;
+    pub use crate::codegen::io::k8s::api::core::v1::scale_io_persistent_volume_source::ScaleIoPersistentVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::scale_io_volume_source::ScaleIoVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::scope_selector::ScopeSelector;
+    pub use crate::codegen::io::k8s::api::core::v1::scoped_resource_selector_requirement::ScopedResourceSelectorRequirement;
+    pub use crate::codegen::io::k8s::api::core::v1::se_linux_options::SeLinuxOptions;
+    pub use crate::codegen::io::k8s::api::core::v1::secret::Secret;
+    pub use crate::codegen::io::k8s::api::core::v1::secret_env_source::SecretEnvSource;
+    pub use crate::codegen::io::k8s::api::core::v1::secret_key_selector::SecretKeySelector;
+    pub use crate::codegen::io::k8s::api::core::v1::secret_list::SecretList;
+    pub use crate::codegen::io::k8s::api::core::v1::secret_projection::SecretProjection;
+    pub use crate::codegen::io::k8s::api::core::v1::secret_reference::SecretReference;
+    pub use crate::codegen::io::k8s::api::core::v1::secret_volume_source::SecretVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::security_context::SecurityContext;
+    pub use crate::codegen::io::k8s::api::core::v1::service::Service;
+    pub use crate::codegen::io::k8s::api::core::v1::service_account::ServiceAccount;
+    pub use crate::codegen::io::k8s::api::core::v1::service_account_list::ServiceAccountList;
+    pub use crate::codegen::io::k8s::api::core::v1::service_account_token_projection::ServiceAccountTokenProjection;
+    pub use crate::codegen::io::k8s::api::core::v1::service_list::ServiceList;
+    pub use crate::codegen::io::k8s::api::core::v1::service_port::ServicePort;
+    pub use crate::codegen::io::k8s::api::core::v1::service_spec::ServiceSpec;
+    pub use crate::codegen::io::k8s::api::core::v1::service_status::ServiceStatus;
+    pub use crate::codegen::io::k8s::api::core::v1::session_affinity_config::SessionAffinityConfig;
+    pub use crate::codegen::io::k8s::api::core::v1::storage_os_persistent_volume_source::StorageOsPersistentVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::storage_os_volume_source::StorageOsVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::sysctl::Sysctl;
+    pub use crate::codegen::io::k8s::api::core::v1::taint::Taint;
+    pub use crate::codegen::io::k8s::api::core::v1::tcp_socket_action::TcpSocketAction;
+    pub use crate::codegen::io::k8s::api::core::v1::toleration::Toleration;
+    pub use crate::codegen::io::k8s::api::core::v1::topology_selector_label_requirement::TopologySelectorLabelRequirement;
+    pub use crate::codegen::io::k8s::api::core::v1::topology_selector_term::TopologySelectorTerm;
+    pub use crate::codegen::io::k8s::api::core::v1::typed_local_object_reference::TypedLocalObjectReference;
+    pub use crate::codegen::io::k8s::api::core::v1::volume::Volume;
+    pub use crate::codegen::io::k8s::api::core::v1::volume_device::VolumeDevice;
+    pub use crate::codegen::io::k8s::api::core::v1::volume_mount::VolumeMount;
+    pub use crate::codegen::io::k8s::api::core::v1::volume_node_affinity::VolumeNodeAffinity;
+    pub use crate::codegen::io::k8s::api::core::v1::volume_projection::VolumeProjection;
+    pub use crate::codegen::io::k8s::api::core::v1::vsphere_virtual_disk_volume_source::VsphereVirtualDiskVolumeSource;
+    pub use crate::codegen::io::k8s::api::core::v1::weighted_pod_affinity_term::WeightedPodAffinityTerm;
+    pub use crate::codegen::io::k8s::api::core::v1::windows_security_context_options::WindowsSecurityContextOptions;
+    pub use crate::codegen::io::k8s::api::events::v1beta1::event::Event as V1beta1Event;
+    pub use crate::codegen::io::k8s::api::events::v1beta1::event_list::EventList as V1beta1EventList;
+    pub use crate::codegen::io::k8s::api::events::v1beta1::event_series::EventSeries as V1beta1EventSeries;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::allowed_csi_driver::AllowedCsiDriver as ExtensionsV1beta1AllowedCsiDriver;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::allowed_flex_volume::AllowedFlexVolume as ExtensionsV1beta1AllowedFlexVolume;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::allowed_host_path::AllowedHostPath as ExtensionsV1beta1AllowedHostPath;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::daemon_set::DaemonSet as V1beta1DaemonSet;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::daemon_set_condition::DaemonSetCondition as V1beta1DaemonSetCondition;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::daemon_set_list::DaemonSetList as V1beta1DaemonSetList;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::daemon_set_spec::DaemonSetSpec as V1beta1DaemonSetSpec;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::daemon_set_status::DaemonSetStatus as V1beta1DaemonSetStatus;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::daemon_set_update_strategy::DaemonSetUpdateStrategy as V1beta1DaemonSetUpdateStrategy;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::deployment::Deployment as ExtensionsV1beta1Deployment;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::deployment_condition::DeploymentCondition as ExtensionsV1beta1DeploymentCondition;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::deployment_list::DeploymentList as ExtensionsV1beta1DeploymentList;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::deployment_rollback::DeploymentRollback as ExtensionsV1beta1DeploymentRollback;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::deployment_spec::DeploymentSpec as ExtensionsV1beta1DeploymentSpec;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::deployment_status::DeploymentStatus as ExtensionsV1beta1DeploymentStatus;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::deployment_strategy::DeploymentStrategy as ExtensionsV1beta1DeploymentStrategy;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::fs_group_strategy_options::FsGroupStrategyOptions as ExtensionsV1beta1FsGroupStrategyOptions;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::host_port_range::HostPortRange as ExtensionsV1beta1HostPortRange;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::http_ingress_path::HttpIngressPath as ExtensionsV1beta1HttpIngressPath;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::http_ingress_rule_value::HttpIngressRuleValue as ExtensionsV1beta1HttpIngressRuleValue;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::id_range::IdRange as ExtensionsV1beta1IdRange;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::ingress::Ingress as ExtensionsV1beta1Ingress;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::ingress_backend::IngressBackend as ExtensionsV1beta1IngressBackend;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::ingress_list::IngressList as ExtensionsV1beta1IngressList;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::ingress_rule::IngressRule as ExtensionsV1beta1IngressRule;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::ingress_spec::IngressSpec as ExtensionsV1beta1IngressSpec;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::ingress_status::IngressStatus as ExtensionsV1beta1IngressStatus;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::ingress_tls::IngressTls as ExtensionsV1beta1IngressTls;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::ip_block::IpBlock as V1beta1IpBlock;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::network_policy::NetworkPolicy as V1beta1NetworkPolicy;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::network_policy_egress_rule::NetworkPolicyEgressRule as V1beta1NetworkPolicyEgressRule;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::network_policy_ingress_rule::NetworkPolicyIngressRule as V1beta1NetworkPolicyIngressRule;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::network_policy_list::NetworkPolicyList as V1beta1NetworkPolicyList;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::network_policy_peer::NetworkPolicyPeer as V1beta1NetworkPolicyPeer;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::network_policy_port::NetworkPolicyPort as V1beta1NetworkPolicyPort;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::network_policy_spec::NetworkPolicySpec as V1beta1NetworkPolicySpec;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::pod_security_policy::PodSecurityPolicy as ExtensionsV1beta1PodSecurityPolicy;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::pod_security_policy_list::PodSecurityPolicyList as ExtensionsV1beta1PodSecurityPolicyList;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::pod_security_policy_spec::PodSecurityPolicySpec as ExtensionsV1beta1PodSecurityPolicySpec;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::replica_set::ReplicaSet as V1beta1ReplicaSet;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::replica_set_condition::ReplicaSetCondition as V1beta1ReplicaSetCondition;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::replica_set_list::ReplicaSetList as V1beta1ReplicaSetList;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::replica_set_spec::ReplicaSetSpec as V1beta1ReplicaSetSpec;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::replica_set_status::ReplicaSetStatus as V1beta1ReplicaSetStatus;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::rollback_config::RollbackConfig as ExtensionsV1beta1RollbackConfig;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::rolling_update_daemon_set::RollingUpdateDaemonSet as V1beta1RollingUpdateDaemonSet;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::rolling_update_deployment::RollingUpdateDeployment as ExtensionsV1beta1RollingUpdateDeployment;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::run_as_group_strategy_options::RunAsGroupStrategyOptions as ExtensionsV1beta1RunAsGroupStrategyOptions;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::run_as_user_strategy_options::RunAsUserStrategyOptions as ExtensionsV1beta1RunAsUserStrategyOptions;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::runtime_class_strategy_options::RuntimeClassStrategyOptions as ExtensionsV1beta1RuntimeClassStrategyOptions;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::scale::Scale as ExtensionsV1beta1Scale;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::scale_spec::ScaleSpec as ExtensionsV1beta1ScaleSpec;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::scale_status::ScaleStatus as ExtensionsV1beta1ScaleStatus;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::se_linux_strategy_options::SeLinuxStrategyOptions as ExtensionsV1beta1SeLinuxStrategyOptions;
+    pub use crate::codegen::io::k8s::api::extensions::v1beta1::supplemental_groups_strategy_options::SupplementalGroupsStrategyOptions as ExtensionsV1beta1SupplementalGroupsStrategyOptions;
+    pub use crate::codegen::io::k8s::api::networking::v1::ip_block::IpBlock as V1IpBlock;
+    pub use crate::codegen::io::k8s::api::networking::v1::network_policy::NetworkPolicy as V1NetworkPolicy;
+    pub use crate::codegen::io::k8s::api::networking::v1::network_policy_egress_rule::NetworkPolicyEgressRule as V1NetworkPolicyEgressRule;
+    pub use crate::codegen::io::k8s::api::networking::v1::network_policy_ingress_rule::NetworkPolicyIngressRule as V1NetworkPolicyIngressRule;
+    pub use crate::codegen::io::k8s::api::networking::v1::network_policy_list::NetworkPolicyList as V1NetworkPolicyList;
+    pub use crate::codegen::io::k8s::api::networking::v1::network_policy_peer::NetworkPolicyPeer as V1NetworkPolicyPeer;
+    pub use crate::codegen::io::k8s::api::networking::v1::network_policy_port::NetworkPolicyPort as V1NetworkPolicyPort;
+    pub use crate::codegen::io::k8s::api::networking::v1::network_policy_spec::NetworkPolicySpec as V1NetworkPolicySpec;
+    pub use crate::codegen::io::k8s::api::networking::v1beta1::http_ingress_path::HttpIngressPath as NetworkingV1beta1HttpIngressPath;
+    pub use crate::codegen::io::k8s::api::networking::v1beta1::http_ingress_rule_value::HttpIngressRuleValue as NetworkingV1beta1HttpIngressRuleValue;
+    pub use crate::codegen::io::k8s::api::networking::v1beta1::ingress::Ingress as NetworkingV1beta1Ingress;
+    pub use crate::codegen::io::k8s::api::networking::v1beta1::ingress_backend::IngressBackend as NetworkingV1beta1IngressBackend;
+    pub use crate::codegen::io::k8s::api::networking::v1beta1::ingress_list::IngressList as NetworkingV1beta1IngressList;
+    pub use crate::codegen::io::k8s::api::networking::v1beta1::ingress_rule::IngressRule as NetworkingV1beta1IngressRule;
+    pub use crate::codegen::io::k8s::api::networking::v1beta1::ingress_spec::IngressSpec as NetworkingV1beta1IngressSpec;
+    pub use crate::codegen::io::k8s::api::networking::v1beta1::ingress_status::IngressStatus as NetworkingV1beta1IngressStatus;
+    pub use crate::codegen::io::k8s::api::networking::v1beta1::ingress_tls::IngressTls as NetworkingV1beta1IngressTls;
+    pub use crate::codegen::io::k8s::api::node::v1alpha1::runtime_class::RuntimeClass as V1alpha1RuntimeClass;
+    pub use crate::codegen::io::k8s::api::node::v1alpha1::runtime_class_list::RuntimeClassList as V1alpha1RuntimeClassList;
+    pub use crate::codegen::io::k8s::api::node::v1alpha1::runtime_class_spec::RuntimeClassSpec;
+    pub use crate::codegen::io::k8s::api::node::v1beta1::runtime_class::RuntimeClass as V1beta1RuntimeClass;
+    pub use crate::codegen::io::k8s::api::node::v1beta1::runtime_class_list::RuntimeClassList as V1beta1RuntimeClassList;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::allowed_csi_driver::AllowedCsiDriver as PolicyV1beta1AllowedCsiDriver;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::allowed_flex_volume::AllowedFlexVolume as PolicyV1beta1AllowedFlexVolume;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::allowed_host_path::AllowedHostPath as PolicyV1beta1AllowedHostPath;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::eviction::Eviction;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::fs_group_strategy_options::FsGroupStrategyOptions as PolicyV1beta1FsGroupStrategyOptions;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::host_port_range::HostPortRange as PolicyV1beta1HostPortRange;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::id_range::IdRange as PolicyV1beta1IdRange;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::pod_disruption_budget::PodDisruptionBudget;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::pod_disruption_budget_list::PodDisruptionBudgetList;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::pod_disruption_budget_spec::PodDisruptionBudgetSpec;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::pod_disruption_budget_status::PodDisruptionBudgetStatus;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::pod_security_policy::PodSecurityPolicy as PolicyV1beta1PodSecurityPolicy;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::pod_security_policy_list::PodSecurityPolicyList as PolicyV1beta1PodSecurityPolicyList;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::pod_security_policy_spec::PodSecurityPolicySpec as PolicyV1beta1PodSecurityPolicySpec;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::run_as_group_strategy_options::RunAsGroupStrategyOptions as PolicyV1beta1RunAsGroupStrategyOptions;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::run_as_user_strategy_options::RunAsUserStrategyOptions as PolicyV1beta1RunAsUserStrategyOptions;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::runtime_class_strategy_options::RuntimeClassStrategyOptions as PolicyV1beta1RuntimeClassStrategyOptions;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::se_linux_strategy_options::SeLinuxStrategyOptions as PolicyV1beta1SeLinuxStrategyOptions;
+    pub use crate::codegen::io::k8s::api::policy::v1beta1::supplemental_groups_strategy_options::SupplementalGroupsStrategyOptions as PolicyV1beta1SupplementalGroupsStrategyOptions;
+    pub use crate::codegen::io::k8s::api::rbac::v1::aggregation_rule::AggregationRule as V1AggregationRule;
+    pub use crate::codegen::io::k8s::api::rbac::v1::cluster_role::ClusterRole as V1ClusterRole;
+    pub use crate::codegen::io::k8s::api::rbac::v1::cluster_role_binding::ClusterRoleBinding as V1ClusterRoleBinding;
+    pub use crate::codegen::io::k8s::api::rbac::v1::cluster_role_binding_list::ClusterRoleBindingList as V1ClusterRoleBindingList;
+    pub use crate::codegen::io::k8s::api::rbac::v1::cluster_role_list::ClusterRoleList as V1ClusterRoleList;
+    pub use crate::codegen::io::k8s::api::rbac::v1::policy_rule::PolicyRule as V1PolicyRule;
+    pub use crate::codegen::io::k8s::api::rbac::v1::role::Role as V1Role;
+    pub use crate::codegen::io::k8s::api::rbac::v1::role_binding::RoleBinding as V1RoleBinding;
+    pub use crate::codegen::io::k8s::api::rbac::v1::role_binding_list::RoleBindingList as V1RoleBindingList;
+    pub use crate::codegen::io::k8s::api::rbac::v1::role_list::RoleList as V1RoleList;
+    pub use crate::codegen::io::k8s::api::rbac::v1::role_ref::RoleRef as V1RoleRef;
+    pub use crate::codegen::io::k8s::api::rbac::v1::subject::Subject as V1Subject;
+    pub use crate::codegen::io::k8s::api::rbac::v1alpha1::aggregation_rule::AggregationRule as V1alpha1AggregationRule;
+    pub use crate::codegen::io::k8s::api::rbac::v1alpha1::cluster_role::ClusterRole as V1alpha1ClusterRole;
+    pub use crate::codegen::io::k8s::api::rbac::v1alpha1::cluster_role_binding::ClusterRoleBinding as V1alpha1ClusterRoleBinding;
+    pub use crate::codegen::io::k8s::api::rbac::v1alpha1::cluster_role_binding_list::ClusterRoleBindingList as V1alpha1ClusterRoleBindingList;
+    pub use crate::codegen::io::k8s::api::rbac::v1alpha1::cluster_role_list::ClusterRoleList as V1alpha1ClusterRoleList;
+    pub use crate::codegen::io::k8s::api::rbac::v1alpha1::policy_rule::PolicyRule as V1alpha1PolicyRule;
+    pub use crate::codegen::io::k8s::api::rbac::v1alpha1::role::Role as V1alpha1Role;
+    pub use crate::codegen::io::k8s::api::rbac::v1alpha1::role_binding::RoleBinding as V1alpha1RoleBinding;
+    pub use crate::codegen::io::k8s::api::rbac::v1alpha1::role_binding_list::RoleBindingList as V1alpha1RoleBindingList;
+    pub use crate::codegen::io::k8s::api::rbac::v1alpha1::role_list::RoleList as V1alpha1RoleList;
+    pub use crate::codegen::io::k8s::api::rbac::v1alpha1::role_ref::RoleRef as V1alpha1RoleRef;
+    pub use crate::codegen::io::k8s::api::rbac::v1alpha1::subject::Subject as V1alpha1Subject;
+    pub use crate::codegen::io::k8s::api::rbac::v1beta1::aggregation_rule::AggregationRule as V1beta1AggregationRule;
+    pub use crate::codegen::io::k8s::api::rbac::v1beta1::cluster_role::ClusterRole as V1beta1ClusterRole;
+    pub use crate::codegen::io::k8s::api::rbac::v1beta1::cluster_role_binding::ClusterRoleBinding as V1beta1ClusterRoleBinding;
+    pub use crate::codegen::io::k8s::api::rbac::v1beta1::cluster_role_binding_list::ClusterRoleBindingList as V1beta1ClusterRoleBindingList;
+    pub use crate::codegen::io::k8s::api::rbac::v1beta1::cluster_role_list::ClusterRoleList as V1beta1ClusterRoleList;
+    pub use crate::codegen::io::k8s::api::rbac::v1beta1::policy_rule::PolicyRule as V1beta1PolicyRule;
+    pub use crate::codegen::io::k8s::api::rbac::v1beta1::role::Role as V1beta1Role;
+    pub use crate::codegen::io::k8s::api::rbac::v1beta1::role_binding::RoleBinding as V1beta1RoleBinding;
+    pub use crate::codegen::io::k8s::api::rbac::v1beta1::role_binding_list::RoleBindingList as V1beta1RoleBindingList;
+    pub use crate::codegen::io::k8s::api::rbac::v1beta1::role_list::RoleList as V1beta1RoleList;
+    pub use crate::codegen::io::k8s::api::rbac::v1beta1::role_ref::RoleRef as V1beta1RoleRef;
+    pub use crate::codegen::io::k8s::api::rbac::v1beta1::subject::Subject as V1beta1Subject;
+    pub use crate::codegen::io::k8s::api::scheduling::v1::priority_class::PriorityClass as V1PriorityClass;
+    pub use crate::codegen::io::k8s::api::scheduling::v1::priority_class_list::PriorityClassList as V1PriorityClassList;
+    pub use crate::codegen::io::k8s::api::scheduling::v1alpha1::priority_class::PriorityClass as V1alpha1PriorityClass;
+    pub use crate::codegen::io::k8s::api::scheduling::v1alpha1::priority_class_list::PriorityClassList as V1alpha1PriorityClassList;
+    pub use crate::codegen::io::k8s::api::scheduling::v1beta1::priority_class::PriorityClass as V1beta1PriorityClass;
+    pub use crate::codegen::io::k8s::api::scheduling::v1beta1::priority_class_list::PriorityClassList as V1beta1PriorityClassList;
+    pub use crate::codegen::io::k8s::api::settings::v1alpha1::pod_preset::PodPreset;
+    pub use crate::codegen::io::k8s::api::settings::v1alpha1::pod_preset_list::PodPresetList;
+    pub use crate::codegen::io::k8s::api::settings::v1alpha1::pod_preset_spec::PodPresetSpec;
+    pub use crate::codegen::io::k8s::api::storage::v1::storage_class::StorageClass as V1StorageClass;
+    pub use crate::codegen::io::k8s::api::storage::v1::storage_class_list::StorageClassList as V1StorageClassList;
+    pub use crate::codegen::io::k8s::api::storage::v1::volume_attachment::VolumeAttachment as V1VolumeAttachment;
+    pub use crate::codegen::io::k8s::api::storage::v1::volume_attachment_list::VolumeAttachmentList as V1VolumeAttachmentList;
+    pub use crate::codegen::io::k8s::api::storage::v1::volume_attachment_source::VolumeAttachmentSource as V1VolumeAttachmentSource;
+    pub use crate::codegen::io::k8s::api::storage::v1::volume_attachment_spec::VolumeAttachmentSpec as V1VolumeAttachmentSpec;
+    pub use crate::codegen::io::k8s::api::storage::v1::volume_attachment_status::VolumeAttachmentStatus as V1VolumeAttachmentStatus;
+    pub use crate::codegen::io::k8s::api::storage::v1::volume_error::VolumeError as V1VolumeError;
+    pub use crate::codegen::io::k8s::api::storage::v1alpha1::volume_attachment::VolumeAttachment as V1alpha1VolumeAttachment;
+    pub use crate::codegen::io::k8s::api::storage::v1alpha1::volume_attachment_list::VolumeAttachmentList as V1alpha1VolumeAttachmentList;
+    pub use crate::codegen::io::k8s::api::storage::v1alpha1::volume_attachment_source::VolumeAttachmentSource as V1alpha1VolumeAttachmentSource;
+    pub use crate::codegen::io::k8s::api::storage::v1alpha1::volume_attachment_spec::VolumeAttachmentSpec as V1alpha1VolumeAttachmentSpec;
+    pub use crate::codegen::io::k8s::api::storage::v1alpha1::volume_attachment_status::VolumeAttachmentStatus as V1alpha1VolumeAttachmentStatus;
+    pub use crate::codegen::io::k8s::api::storage::v1alpha1::volume_error::VolumeError as V1alpha1VolumeError;
+    pub use crate::codegen::io::k8s::api::storage::v1beta1::csi_driver::CsiDriver;
+    pub use crate::codegen::io::k8s::api::storage::v1beta1::csi_driver_list::CsiDriverList;
+    pub use crate::codegen::io::k8s::api::storage::v1beta1::csi_driver_spec::CsiDriverSpec;
+    pub use crate::codegen::io::k8s::api::storage::v1beta1::csi_node::CsiNode;
+    pub use crate::codegen::io::k8s::api::storage::v1beta1::csi_node_driver::CsiNodeDriver;
+    pub use crate::codegen::io::k8s::api::storage::v1beta1::csi_node_list::CsiNodeList;
+    pub use crate::codegen::io::k8s::api::storage::v1beta1::csi_node_spec::CsiNodeSpec;
+    pub use crate::codegen::io::k8s::api::storage::v1beta1::storage_class::StorageClass as V1beta1StorageClass;
+    pub use crate::codegen::io::k8s::api::storage::v1beta1::storage_class_list::StorageClassList as V1beta1StorageClassList;
+    pub use crate::codegen::io::k8s::api::storage::v1beta1::volume_attachment::VolumeAttachment as V1beta1VolumeAttachment;
+    pub use crate::codegen::io::k8s::api::storage::v1beta1::volume_attachment_list::VolumeAttachmentList as V1beta1VolumeAttachmentList;
+    pub use crate::codegen::io::k8s::api::storage::v1beta1::volume_attachment_source::VolumeAttachmentSource as V1beta1VolumeAttachmentSource;
+    pub use crate::codegen::io::k8s::api::storage::v1beta1::volume_attachment_spec::VolumeAttachmentSpec as V1beta1VolumeAttachmentSpec;
+    pub use crate::codegen::io::k8s::api::storage::v1beta1::volume_attachment_status::VolumeAttachmentStatus as V1beta1VolumeAttachmentStatus;
+    pub use crate::codegen::io::k8s::api::storage::v1beta1::volume_error::VolumeError as V1beta1VolumeError;
+    pub use crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_column_definition::CustomResourceColumnDefinition;
+    pub use crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_conversion::CustomResourceConversion;
+    pub use crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition::CustomResourceDefinition;
+    pub use crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_condition::CustomResourceDefinitionCondition;
+    pub use crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_list::CustomResourceDefinitionList;
+    pub use crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_names::CustomResourceDefinitionNames;
+    pub use crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_spec::CustomResourceDefinitionSpec;
+    pub use crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_status::CustomResourceDefinitionStatus;
+    pub use crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition_version::CustomResourceDefinitionVersion;
+    pub use crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_subresource_scale::CustomResourceSubresourceScale;
+    pub use crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_subresource_status::CustomResourceSubresourceStatus;
+    pub use crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_subresources::CustomResourceSubresources;
+    pub use crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_validation::CustomResourceValidation;
+    pub use crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::external_documentation::ExternalDocumentation;
+    pub use crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::json_schema_props::JsonSchemaProps;
+    pub use crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::service_reference::ServiceReference as ApiextensionsV1beta1ServiceReference;
+    pub use crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::webhook_client_config::WebhookClientConfig as ApiextensionsV1beta1WebhookClientConfig;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroup;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::api_group_list::ApiGroupList;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource::ApiResource;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::api_resource_list::ApiResourceList;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::api_versions::ApiVersions;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::delete_options::DeleteOptions;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::fields::Fields;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::group_version_for_discovery::GroupVersionForDiscovery;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::initializer::Initializer;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::initializers::Initializers;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector::LabelSelector;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::label_selector_requirement::LabelSelectorRequirement;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::list_meta::ListMeta;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::managed_fields_entry::ManagedFieldsEntry;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::owner_reference::OwnerReference;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::patch::Patch;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::server_address_by_client_cidr::ServerAddressByClientCidr;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::status_cause::StatusCause;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::status_details::StatusDetails;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::watch_event::WatchEvent;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::runtime::raw_extension::RawExtension;
+    pub use crate::codegen::io::k8s::apimachinery::pkg::version::info::Info;
+    pub use crate::codegen::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1::api_service::ApiService as V1ApiService;
+    pub use crate::codegen::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1::api_service_condition::ApiServiceCondition as V1ApiServiceCondition;
+    pub use crate::codegen::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1::api_service_list::ApiServiceList as V1ApiServiceList;
+    pub use crate::codegen::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1::api_service_spec::ApiServiceSpec as V1ApiServiceSpec;
+    pub use crate::codegen::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1::api_service_status::ApiServiceStatus as V1ApiServiceStatus;
+    pub use crate::codegen::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1::service_reference::ServiceReference as ApiregistrationV1ServiceReference;
+    pub use crate::codegen::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1beta1::api_service::ApiService as V1beta1ApiService;
+    pub use crate::codegen::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1beta1::api_service_condition::ApiServiceCondition as V1beta1ApiServiceCondition;
+    pub use crate::codegen::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1beta1::api_service_list::ApiServiceList as V1beta1ApiServiceList;
+    pub use crate::codegen::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1beta1::api_service_spec::ApiServiceSpec as V1beta1ApiServiceSpec;
+    pub use crate::codegen::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1beta1::api_service_status::ApiServiceStatus as V1beta1ApiServiceStatus;
+    pub use crate::codegen::io::k8s::kube_aggregator::pkg::apis::apiregistration::v1beta1::service_reference::ServiceReference as ApiregistrationV1beta1ServiceReference;
+    pub use crate::codegen::miscellaneous::Miscellaneous;
+}
+
+pub mod generics {
+    include!(\"./generics.rs\");
+}
+
+pub mod operations {
+    include!(\"./operations.rs\");
+}
+
+pub mod util {
+    include!(\"./util.rs\");
+}
+",
+        Some(0),
+    );
+}
+
+#[test]
+fn test_generics_mod() {
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_k8s/generics.rs"),
+        "
+pub struct",
+        Some(0),
+    );
+}
+
+#[test]
+fn test_same_object_creates_multiple_builders() {
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_k8s/io/k8s/api/core/v1/config_map.rs"),
+        "
+impl ConfigMap {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ConfigMapBuilder {
+        ConfigMapBuilder {
+            body: Default::default(),
+        }
+    }
+
+    /// create a ConfigMap
+    #[inline]
+    pub fn create_core_v1_namespaced_config_map() -> ConfigMapPostBuilder<crate::codegen::generics::MissingNamespace> {
+        ConfigMapPostBuilder {
+            inner: Default::default(),
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// read the specified ConfigMap
+    #[inline]
+    pub fn read_core_v1_namespaced_config_map() -> ConfigMapGetBuilder1<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
+        ConfigMapGetBuilder1 {
+            inner: Default::default(),
+            _param_name: core::marker::PhantomData,
+            _param_namespace: core::marker::PhantomData,
+        }
+    }
+
+    /// replace the specified ConfigMap
+    #[inline]
     pub fn replace_core_v1_namespaced_config_map() -> ConfigMapPutBuilder1<crate::codegen::generics::MissingName, crate::codegen::generics::MissingNamespace> {
         ConfigMapPutBuilder1 {
             inner: Default::default(),
@@ -1132,180 +4444,1355 @@ impl ConfigMap {
     }
 }
 
-impl Into<ConfigMap> for ConfigMapBuilder {
-    fn into(self) -> ConfigMap {
-        self.body
+impl Into<ConfigMap> for ConfigMapBuilder {
+    fn into(self) -> ConfigMap {
+        self.body
+    }
+}
+
+impl Into<ConfigMap> for ConfigMapPostBuilder<crate::codegen::generics::NamespaceExists> {
+    fn into(self) -> ConfigMap {
+        self.inner.body
+    }
+}
+
+impl Into<ConfigMap> for ConfigMapPutBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
+    fn into(self) -> ConfigMap {
+        self.inner.body
+    }
+}
+",
+        Some(1907),
+    );
+}
+
+#[test]
+fn test_same_object_with_multiple_builders_has_basic_builder() {
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_k8s/io/k8s/api/core/v1/pod.rs"),
+        "
+/// Builder for [`Pod`](./struct.Pod.html) object.
+#[derive(Debug, Clone)]
+pub struct PodBuilder {
+    body: self::Pod,
+}
+
+impl PodBuilder {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    #[inline]
+    pub fn metadata(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
+        self.body.metadata = Some(value.into());
+        self
+    }
+
+    /// Specification of the desired behavior of the pod. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#spec-and-status
+    #[inline]
+    pub fn spec(mut self, value: crate::codegen::io::k8s::api::core::v1::pod_spec::PodSpecBuilder<crate::codegen::generics::ContainersExists>) -> Self {
+        self.body.spec = Some(value.into());
+        self
+    }
+
+    /// Most recently observed status of the pod. This data may not be up to date. Populated by the system. Read-only. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#spec-and-status
+    #[inline]
+    pub fn status(mut self, value: crate::codegen::io::k8s::api::core::v1::pod_status::PodStatus) -> Self {
+        self.body.status = Some(value.into());
+        self
+    }
+
+    /// Returns the object built using this builder.
+    #[inline]
+    pub fn build(self) -> Pod {
+        self.body
+    }
+}
+",
+        Some(4255),
+    )
+}
+
+#[test]
+fn test_simple_object_builder_with_required_fields() {
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_k8s/io/k8s/api/rbac/v1/policy_rule.rs"),
+        "
+impl PolicyRule {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> PolicyRuleBuilder<crate::codegen::generics::MissingVerbs> {
+        PolicyRuleBuilder {
+            body: Default::default(),
+            _verbs: core::marker::PhantomData,
+        }
+    }
+
+    /// Create this object with the fields required for constructing it.
+    #[inline]
+    pub fn new(verbs: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self::builder().verbs(verbs).build()
+    }
+}
+
+impl Into<PolicyRule> for PolicyRuleBuilder<crate::codegen::generics::VerbsExists> {
+    fn into(self) -> PolicyRule {
+        self.body
+    }
+}
+
+/// Builder for [`PolicyRule`](./struct.PolicyRule.html) object.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct PolicyRuleBuilder<Verbs> {
+    body: self::PolicyRule,
+    _verbs: core::marker::PhantomData<Verbs>,
+}
+
+impl<Verbs> PolicyRuleBuilder<Verbs> {
+    /// APIGroups is the name of the APIGroup that contains the resources.  If multiple API groups are specified, any action requested against one of the enumerated resources in any API group will be allowed.
+    #[inline]
+    pub fn api_groups(mut self, value: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.body.api_groups = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// NonResourceURLs is a set of partial urls that a user should have access to.  *s are allowed, but only as the full, final step in the path Since non-resource URLs are not namespaced, this field is only applicable for ClusterRoles referenced from a ClusterRoleBinding. Rules can either apply to API resources (such as \"pods\" or \"secrets\") or non-resource URL paths (such as \"/api\"),  but not both.
+    #[inline]
+    pub fn non_resource_ur_ls(mut self, value: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.body.non_resource_ur_ls = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// ResourceNames is an optional white list of names that the rule applies to.  An empty set means that everything is allowed.
+    #[inline]
+    pub fn resource_names(mut self, value: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.body.resource_names = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Resources is a list of resources this rule applies to.  ResourceAll represents all resources.
+    #[inline]
+    pub fn resources(mut self, value: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.body.resources = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Verbs is a list of Verbs that apply to ALL the ResourceKinds and AttributeRestrictions contained in this rule.  VerbAll represents all kinds.
+    #[inline]
+    pub fn verbs(mut self, value: impl IntoIterator<Item = impl Into<String>>) -> PolicyRuleBuilder<crate::codegen::generics::VerbsExists> {
+        self.body.verbs = value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into();
+        crate::codegen::util::debug_assert_same_layout::<Self, PolicyRuleBuilder<crate::codegen::generics::VerbsExists>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
+    #[inline]
+    pub fn reset(self) -> PolicyRuleBuilder<crate::codegen::generics::MissingVerbs> {
+        crate::codegen::util::debug_assert_same_layout::<Self, PolicyRuleBuilder<crate::codegen::generics::MissingVerbs>>();
+        unsafe { std::mem::transmute(self) }
+    }
+}
+",
+        Some(1564),
+    );
+}
+
+#[test]
+fn test_builder_with_field_parameter_collision_and_method_collision() {
+    // grace_period_seconds, orphan_dependents and propagation_policy
+    // exist in both the object and as a query parameter. If one is set,
+    // we should also set the other.
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_k8s/io/k8s/apimachinery/pkg/apis/meta/v1/delete_options.rs"),
+        "
+/// Builder created by [`DeleteOptions::delete_rbac_authorization_v1_cluster_role_binding`](./struct.DeleteOptions.html#method.delete_rbac_authorization_v1_cluster_role_binding) method for a `DELETE` operation associated with `DeleteOptions`.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct DeleteOptionsDeleteBuilder56<Name> {
+    inner: DeleteOptionsDeleteBuilder56Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone)]
+struct DeleteOptionsDeleteBuilder56Container {
+    body: self::DeleteOptions,
+    param_dry_run: Option<String>,
+    param_grace_period_seconds: Option<i64>,
+    param_orphan_dependents: Option<bool>,
+    param_propagation_policy: Option<String>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> DeleteOptionsDeleteBuilder56<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `dry_run` (if any).
+    #[inline]
+    pub fn clear_dry_run(mut self) -> Self {
+        self.inner.param_dry_run = None;
+        self
+    }
+
+    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    #[inline]
+    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
+        self.inner.param_grace_period_seconds = Some({
+            let val = value.into();
+            self.inner.body.grace_period_seconds = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the \"orphan\" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    #[inline]
+    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_orphan_dependents = Some({
+            let val = value.into();
+            self.inner.body.orphan_dependents = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    #[inline]
+    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_propagation_policy = Some({
+            let val = value.into();
+            self.inner.body.propagation_policy = val.clone().into();
+            val
+        });
+        self
+    }
+
+    /// name of the ClusterRoleBinding
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder56<crate::codegen::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        crate::codegen::util::debug_assert_same_layout::<Self, DeleteOptionsDeleteBuilder56<crate::codegen::generics::NameExists>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `pretty` (if any).
+    #[inline]
+    pub fn clear_pretty(mut self) -> Self {
+        self.inner.param_pretty = None;
+        self
+    }
+
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.api_version = Some(value.into());
+        self
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.inner.body.kind = Some(value.into());
+        self
+    }
+
+    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    #[inline]
+    pub fn preconditions(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
+        self.inner.body.preconditions = Some(value.into());
+        self
+    }
+
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
+    #[inline]
+    pub fn reset(self) -> DeleteOptionsDeleteBuilder56<crate::codegen::generics::MissingName> {
+        crate::codegen::util::debug_assert_same_layout::<Self, DeleteOptionsDeleteBuilder56<crate::codegen::generics::MissingName>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Sends the given body for this API call (instead of the one in this builder),
+    /// without moving or cloning it. The body fields needn't be set in that case.
+    #[inline]
+    pub fn with_body(self, body: &self::DeleteOptions) -> crate::codegen::client::BorrowedBody<'_, DeleteOptionsDeleteBuilder56<Name>, self::DeleteOptions> {
+        let builder = {
+            crate::codegen::util::debug_assert_same_layout::<Self, DeleteOptionsDeleteBuilder56<Name>>();
+            unsafe { std::mem::transmute(self) }
+        };
+        crate::codegen::client::BorrowedBody::new(builder, body)
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for DeleteOptionsDeleteBuilder56<crate::codegen::generics::NameExists> {
+    type Output = crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+
+    const METHOD: http::Method = http::Method::DELETE;
+
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        format!(\"/apis/rbac.authorization.k8s.io/v1/clusterrolebindings/{name}\", name=crate::codegen::util::encode_path_param(&self.inner.param_name.as_ref().expect(\"missing parameter name?\").to_string())).into()
+    }
+
+    fn empty_path_param(&self) -> Option<&'static str> {
+        if self.inner.param_name.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {
+            return Some(\"name\");
+        }
+
+        None
+    }
+
+    fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        self.modify_with_body(client, req, &self.inner.body)
+    }
+
+    fn modify_with_body<B: serde::Serialize + Sync>(&self, _client: &Client, req: Client::Request, body: &B) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .json(body)
+        .query(&[
+            (\"dryRun\", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            (\"gracePeriodSeconds\", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
+            (\"orphanDependents\", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
+            (\"propagationPolicy\", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            (\"pretty\", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+}
+",
+        Some(598300),
+    );
+}
+
+#[test]
+fn test_unit_builder_with_no_modifier() {
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_k8s/io/k8s/apimachinery/pkg/apis/meta/v1/api_group_list.rs"),
+        "
+impl ApiGroupList {
+    /// Create a builder for this object.
+    #[inline]
+    pub fn builder() -> ApiGroupListBuilder<crate::codegen::generics::MissingGroups> {
+        ApiGroupListBuilder {
+            body: Default::default(),
+            _groups: core::marker::PhantomData,
+        }
+    }
+
+    /// get available API versions
+    #[inline]
+    pub fn get_api_versions() -> ApiGroupListGetBuilder {
+        ApiGroupListGetBuilder
+    }
+
+    /// Create this object with the fields required for constructing it.
+    #[inline]
+    pub fn new(groups: impl IntoIterator<Item = crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::VersionsExists>>) -> Self {
+        Self::builder().groups(groups).build()
+    }
+}
+
+impl Into<ApiGroupList> for ApiGroupListBuilder<crate::codegen::generics::GroupsExists> {
+    fn into(self) -> ApiGroupList {
+        self.body
+    }
+}
+
+/// Builder for [`ApiGroupList`](./struct.ApiGroupList.html) object.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct ApiGroupListBuilder<Groups> {
+    body: self::ApiGroupList,
+    _groups: core::marker::PhantomData<Groups>,
+}
+
+impl<Groups> ApiGroupListBuilder<Groups> {
+    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    #[inline]
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.body.api_version = Some(value.into());
+        self
+    }
+
+    /// groups is a list of APIGroup.
+    #[inline]
+    pub fn groups(mut self, value: impl IntoIterator<Item = crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::VersionsExists>>) -> ApiGroupListBuilder<crate::codegen::generics::GroupsExists> {
+        self.body.groups = value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into();
+        crate::codegen::util::debug_assert_same_layout::<Self, ApiGroupListBuilder<crate::codegen::generics::GroupsExists>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    #[inline]
+    pub fn kind(mut self, value: impl Into<String>) -> Self {
+        self.body.kind = Some(value.into());
+        self
+    }
+
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
+    #[inline]
+    pub fn reset(self) -> ApiGroupListBuilder<crate::codegen::generics::MissingGroups> {
+        crate::codegen::util::debug_assert_same_layout::<Self, ApiGroupListBuilder<crate::codegen::generics::MissingGroups>>();
+        unsafe { std::mem::transmute(self) }
+    }
+}
+
+impl ApiGroupListBuilder<crate::codegen::generics::GroupsExists> {
+    /// Returns the object built using this builder.
+    #[inline]
+    pub fn build(self) -> ApiGroupList {
+        self.body
+    }
+}
+
+/// Builder created by [`ApiGroupList::get_api_versions`](./struct.ApiGroupList.html#method.get_api_versions) method for a `GET` operation associated with `ApiGroupList`.
+#[derive(Debug, Clone)]
+pub struct ApiGroupListGetBuilder;
+
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for ApiGroupListGetBuilder {
+    type Output = ApiGroupList;
+
+    const METHOD: http::Method = http::Method::GET;
+
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        \"/apis/\".into()
+    }
+}
+",
+        Some(979),
+    );
+}
+
+#[test]
+fn test_builder_field_with_iterators() {
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_k8s/io/k8s/api/certificates/v1beta1/certificate_signing_request_spec.rs"),
+        "
+/// Builder for [`CertificateSigningRequestSpec`](./struct.CertificateSigningRequestSpec.html) object.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct CertificateSigningRequestSpecBuilder<Request> {
+    body: self::CertificateSigningRequestSpec,
+    _request: core::marker::PhantomData<Request>,
+}
+
+impl<Request> CertificateSigningRequestSpecBuilder<Request> {
+    /// Extra information about the requesting user. See user.Info interface for details.
+    #[inline]
+    pub fn extra(mut self, value: impl IntoIterator<Item = (String, impl IntoIterator<Item = impl Into<String>>)>) -> Self {
+        self.body.extra = Some(value.into_iter().map(|(key, value)| (key, value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into())).collect::<std::collections::BTreeMap<_, _>>().into());
+        self
+    }
+
+    /// Group information about the requesting user. See user.Info interface for details.
+    #[inline]
+    pub fn groups(mut self, value: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.body.groups = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Base64-encoded PKCS#10 CSR data
+    #[inline]
+    pub fn request(mut self, value: impl Into<String>) -> CertificateSigningRequestSpecBuilder<crate::codegen::generics::RequestExists> {
+        self.body.request = value.into();
+        crate::codegen::util::debug_assert_same_layout::<Self, CertificateSigningRequestSpecBuilder<crate::codegen::generics::RequestExists>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// UID information about the requesting user. See user.Info interface for details.
+    #[inline]
+    pub fn uid(mut self, value: impl Into<String>) -> Self {
+        self.body.uid = Some(value.into());
+        self
+    }
+
+    /// allowedUsages specifies a set of usage contexts the key will be valid for. See: https://tools.ietf.org/html/rfc5280#section-4.2.1.3
+    ///      https://tools.ietf.org/html/rfc5280#section-4.2.1.12
+    #[inline]
+    pub fn usages(mut self, value: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.body.usages = Some(value.into_iter().map(|value| value.into()).collect::<Vec<_>>().into());
+        self
+    }
+
+    /// Information about the requesting user. See user.Info interface for details.
+    #[inline]
+    pub fn username(mut self, value: impl Into<String>) -> Self {
+        self.body.username = Some(value.into());
+        self
+    }
+
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
+    #[inline]
+    pub fn reset(self) -> CertificateSigningRequestSpecBuilder<crate::codegen::generics::MissingRequest> {
+        crate::codegen::util::debug_assert_same_layout::<Self, CertificateSigningRequestSpecBuilder<crate::codegen::generics::MissingRequest>>();
+        unsafe { std::mem::transmute(self) }
+    }
+}
+",
+        Some(1882),
+    );
+}
+
+#[test]
+fn test_any_in_operation_bound_to_unrelated_struct() {
+    assert_file_contains_content_at(
+        &(ROOT.clone() + "/tests/test_k8s/io/k8s/apimachinery/pkg/apis/meta/v1/patch.rs"),
+        "
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for PatchPatchBuilder19<crate::codegen::generics::NameExists> {
+    type Output = crate::codegen::io::k8s::api::core::v1::namespace::Namespace;
+
+    const METHOD: http::Method = http::Method::PATCH;
+
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        format!(\"/api/v1/namespaces/{name}/status\", name=crate::codegen::util::encode_path_param(&self.inner.param_name.as_ref().expect(\"missing parameter name?\").to_string())).into()
+    }
+
+    fn empty_path_param(&self) -> Option<&'static str> {
+        if self.inner.param_name.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {
+            return Some(\"name\");
+        }
+
+        None
+    }
+
+    fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        self.modify_with_body(client, req, &self.inner.body)
+    }
+
+    fn modify_with_body<B: serde::Serialize + Sync>(&self, _client: &Client, req: Client::Request, body: &B) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .json(body)
+        .query(&[
+            (\"dryRun\", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            (\"fieldManager\", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            (\"force\", self.inner.param_force.as_ref().map(std::string::ToString::to_string)),
+            (\"pretty\", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+}
+
+impl PatchPatchBuilder19<crate::codegen::generics::NameExists> {
+    /// Overrides the client's timeout for this API call.
+    #[inline]
+    pub fn timeout(self, timeout: std::time::Duration) -> crate::codegen::client::Timeout<Self> {
+        crate::codegen::client::Timeout::new(self, timeout)
+    }
+
+    /// Adds a header (which isn't declared in the spec) to this API call.
+    #[inline]
+    pub fn header(self, name: impl AsRef<str>, value: impl AsRef<str>) -> crate::codegen::client::ExtraHeaders<Self> {
+        crate::codegen::client::ExtraHeaders::new(self).header(name, value)
+    }
+}
+
+/// Builder created by [`Patch::patch_core_v1_node`](./struct.Patch.html#method.patch_core_v1_node) method for a `PATCH` operation associated with `Patch`.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct PatchPatchBuilder20<Name> {
+    inner: PatchPatchBuilder20Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone)]
+struct PatchPatchBuilder20Container {
+    body: self::Patch,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_force: Option<bool>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> PatchPatchBuilder20<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `dry_run` (if any).
+    #[inline]
+    pub fn clear_dry_run(mut self) -> Self {
+        self.inner.param_dry_run = None;
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint. This field is required for apply requests (application/apply-patch) but optional for non-apply patch types (JsonPatch, MergePatch, StrategicMergePatch).
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `field_manager` (if any).
+    #[inline]
+    pub fn clear_field_manager(mut self) -> Self {
+        self.inner.param_field_manager = None;
+        self
+    }
+
+    /// Force is going to \"force\" Apply requests. It means user will re-acquire conflicting fields owned by other people. Force flag must be unset for non-apply patch requests.
+    #[inline]
+    pub fn force(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_force = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `force` (if any).
+    #[inline]
+    pub fn clear_force(mut self) -> Self {
+        self.inner.param_force = None;
+        self
+    }
+
+    /// name of the Node
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> PatchPatchBuilder20<crate::codegen::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder20<crate::codegen::generics::NameExists>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `pretty` (if any).
+    #[inline]
+    pub fn clear_pretty(mut self) -> Self {
+        self.inner.param_pretty = None;
+        self
+    }
+
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
+    #[inline]
+    pub fn reset(self) -> PatchPatchBuilder20<crate::codegen::generics::MissingName> {
+        crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder20<crate::codegen::generics::MissingName>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Sends the given body for this API call (instead of the one in this builder),
+    /// without moving or cloning it. The body fields needn't be set in that case.
+    #[inline]
+    pub fn with_body(self, body: &self::Patch) -> crate::codegen::client::BorrowedBody<'_, PatchPatchBuilder20<Name>, self::Patch> {
+        let builder = {
+            crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder20<Name>>();
+            unsafe { std::mem::transmute(self) }
+        };
+        crate::codegen::client::BorrowedBody::new(builder, body)
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for PatchPatchBuilder20<crate::codegen::generics::NameExists> {
+    type Output = crate::codegen::io::k8s::api::core::v1::node::Node;
+
+    const METHOD: http::Method = http::Method::PATCH;
+
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        format!(\"/api/v1/nodes/{name}\", name=crate::codegen::util::encode_path_param(&self.inner.param_name.as_ref().expect(\"missing parameter name?\").to_string())).into()
+    }
+
+    fn empty_path_param(&self) -> Option<&'static str> {
+        if self.inner.param_name.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {
+            return Some(\"name\");
+        }
+
+        None
+    }
+
+    fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        self.modify_with_body(client, req, &self.inner.body)
+    }
+
+    fn modify_with_body<B: serde::Serialize + Sync>(&self, _client: &Client, req: Client::Request, body: &B) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .json(body)
+        .query(&[
+            (\"dryRun\", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            (\"fieldManager\", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            (\"force\", self.inner.param_force.as_ref().map(std::string::ToString::to_string)),
+            (\"pretty\", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+}
+
+impl PatchPatchBuilder20<crate::codegen::generics::NameExists> {
+    /// Overrides the client's timeout for this API call.
+    #[inline]
+    pub fn timeout(self, timeout: std::time::Duration) -> crate::codegen::client::Timeout<Self> {
+        crate::codegen::client::Timeout::new(self, timeout)
+    }
+
+    /// Adds a header (which isn't declared in the spec) to this API call.
+    #[inline]
+    pub fn header(self, name: impl AsRef<str>, value: impl AsRef<str>) -> crate::codegen::client::ExtraHeaders<Self> {
+        crate::codegen::client::ExtraHeaders::new(self).header(name, value)
+    }
+}
+
+/// Builder created by [`Patch::patch_core_v1_node_status`](./struct.Patch.html#method.patch_core_v1_node_status) method for a `PATCH` operation associated with `Patch`.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct PatchPatchBuilder21<Name> {
+    inner: PatchPatchBuilder21Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone)]
+struct PatchPatchBuilder21Container {
+    body: self::Patch,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_force: Option<bool>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> PatchPatchBuilder21<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `dry_run` (if any).
+    #[inline]
+    pub fn clear_dry_run(mut self) -> Self {
+        self.inner.param_dry_run = None;
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint. This field is required for apply requests (application/apply-patch) but optional for non-apply patch types (JsonPatch, MergePatch, StrategicMergePatch).
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `field_manager` (if any).
+    #[inline]
+    pub fn clear_field_manager(mut self) -> Self {
+        self.inner.param_field_manager = None;
+        self
+    }
+
+    /// Force is going to \"force\" Apply requests. It means user will re-acquire conflicting fields owned by other people. Force flag must be unset for non-apply patch requests.
+    #[inline]
+    pub fn force(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_force = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `force` (if any).
+    #[inline]
+    pub fn clear_force(mut self) -> Self {
+        self.inner.param_force = None;
+        self
+    }
+
+    /// name of the Node
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> PatchPatchBuilder21<crate::codegen::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder21<crate::codegen::generics::NameExists>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `pretty` (if any).
+    #[inline]
+    pub fn clear_pretty(mut self) -> Self {
+        self.inner.param_pretty = None;
+        self
+    }
+
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
+    #[inline]
+    pub fn reset(self) -> PatchPatchBuilder21<crate::codegen::generics::MissingName> {
+        crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder21<crate::codegen::generics::MissingName>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Sends the given body for this API call (instead of the one in this builder),
+    /// without moving or cloning it. The body fields needn't be set in that case.
+    #[inline]
+    pub fn with_body(self, body: &self::Patch) -> crate::codegen::client::BorrowedBody<'_, PatchPatchBuilder21<Name>, self::Patch> {
+        let builder = {
+            crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder21<Name>>();
+            unsafe { std::mem::transmute(self) }
+        };
+        crate::codegen::client::BorrowedBody::new(builder, body)
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for PatchPatchBuilder21<crate::codegen::generics::NameExists> {
+    type Output = crate::codegen::io::k8s::api::core::v1::node::Node;
+
+    const METHOD: http::Method = http::Method::PATCH;
+
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        format!(\"/api/v1/nodes/{name}/status\", name=crate::codegen::util::encode_path_param(&self.inner.param_name.as_ref().expect(\"missing parameter name?\").to_string())).into()
+    }
+
+    fn empty_path_param(&self) -> Option<&'static str> {
+        if self.inner.param_name.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {
+            return Some(\"name\");
+        }
+
+        None
+    }
+
+    fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        self.modify_with_body(client, req, &self.inner.body)
+    }
+
+    fn modify_with_body<B: serde::Serialize + Sync>(&self, _client: &Client, req: Client::Request, body: &B) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .json(body)
+        .query(&[
+            (\"dryRun\", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            (\"fieldManager\", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            (\"force\", self.inner.param_force.as_ref().map(std::string::ToString::to_string)),
+            (\"pretty\", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
+}
+
+impl PatchPatchBuilder21<crate::codegen::generics::NameExists> {
+    /// Overrides the client's timeout for this API call.
+    #[inline]
+    pub fn timeout(self, timeout: std::time::Duration) -> crate::codegen::client::Timeout<Self> {
+        crate::codegen::client::Timeout::new(self, timeout)
+    }
+
+    /// Adds a header (which isn't declared in the spec) to this API call.
+    #[inline]
+    pub fn header(self, name: impl AsRef<str>, value: impl AsRef<str>) -> crate::codegen::client::ExtraHeaders<Self> {
+        crate::codegen::client::ExtraHeaders::new(self).header(name, value)
     }
 }
 
-impl Into<ConfigMap> for ConfigMapPostBuilder<crate::codegen::generics::NamespaceExists> {
-    fn into(self) -> ConfigMap {
-        self.inner.body
+/// Builder created by [`Patch::patch_core_v1_persistent_volume`](./struct.Patch.html#method.patch_core_v1_persistent_volume) method for a `PATCH` operation associated with `Patch`.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct PatchPatchBuilder22<Name> {
+    inner: PatchPatchBuilder22Container,
+    _param_name: core::marker::PhantomData<Name>,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone)]
+struct PatchPatchBuilder22Container {
+    body: self::Patch,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_force: Option<bool>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> PatchPatchBuilder22<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `dry_run` (if any).
+    #[inline]
+    pub fn clear_dry_run(mut self) -> Self {
+        self.inner.param_dry_run = None;
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint. This field is required for apply requests (application/apply-patch) but optional for non-apply patch types (JsonPatch, MergePatch, StrategicMergePatch).
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `field_manager` (if any).
+    #[inline]
+    pub fn clear_field_manager(mut self) -> Self {
+        self.inner.param_field_manager = None;
+        self
+    }
+
+    /// Force is going to \"force\" Apply requests. It means user will re-acquire conflicting fields owned by other people. Force flag must be unset for non-apply patch requests.
+    #[inline]
+    pub fn force(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_force = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `force` (if any).
+    #[inline]
+    pub fn clear_force(mut self) -> Self {
+        self.inner.param_force = None;
+        self
+    }
+
+    /// name of the PersistentVolume
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> PatchPatchBuilder22<crate::codegen::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder22<crate::codegen::generics::NameExists>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `pretty` (if any).
+    #[inline]
+    pub fn clear_pretty(mut self) -> Self {
+        self.inner.param_pretty = None;
+        self
+    }
+
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
+    #[inline]
+    pub fn reset(self) -> PatchPatchBuilder22<crate::codegen::generics::MissingName> {
+        crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder22<crate::codegen::generics::MissingName>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Sends the given body for this API call (instead of the one in this builder),
+    /// without moving or cloning it. The body fields needn't be set in that case.
+    #[inline]
+    pub fn with_body(self, body: &self::Patch) -> crate::codegen::client::BorrowedBody<'_, PatchPatchBuilder22<Name>, self::Patch> {
+        let builder = {
+            crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder22<Name>>();
+            unsafe { std::mem::transmute(self) }
+        };
+        crate::codegen::client::BorrowedBody::new(builder, body)
     }
 }
 
-impl Into<ConfigMap> for ConfigMapPutBuilder1<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
-    fn into(self) -> ConfigMap {
-        self.inner.body
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for PatchPatchBuilder22<crate::codegen::generics::NameExists> {
+    type Output = crate::codegen::io::k8s::api::core::v1::persistent_volume::PersistentVolume;
+
+    const METHOD: http::Method = http::Method::PATCH;
+
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        format!(\"/api/v1/persistentvolumes/{name}\", name=crate::codegen::util::encode_path_param(&self.inner.param_name.as_ref().expect(\"missing parameter name?\").to_string())).into()
+    }
+
+    fn empty_path_param(&self) -> Option<&'static str> {
+        if self.inner.param_name.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {
+            return Some(\"name\");
+        }
+
+        None
+    }
+
+    fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        self.modify_with_body(client, req, &self.inner.body)
+    }
+
+    fn modify_with_body<B: serde::Serialize + Sync>(&self, _client: &Client, req: Client::Request, body: &B) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .json(body)
+        .query(&[
+            (\"dryRun\", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            (\"fieldManager\", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            (\"force\", self.inner.param_force.as_ref().map(std::string::ToString::to_string)),
+            (\"pretty\", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
     }
 }
-",
-        Some(1889),
-    );
+
+impl PatchPatchBuilder22<crate::codegen::generics::NameExists> {
+    /// Overrides the client's timeout for this API call.
+    #[inline]
+    pub fn timeout(self, timeout: std::time::Duration) -> crate::codegen::client::Timeout<Self> {
+        crate::codegen::client::Timeout::new(self, timeout)
+    }
+
+    /// Adds a header (which isn't declared in the spec) to this API call.
+    #[inline]
+    pub fn header(self, name: impl AsRef<str>, value: impl AsRef<str>) -> crate::codegen::client::ExtraHeaders<Self> {
+        crate::codegen::client::ExtraHeaders::new(self).header(name, value)
+    }
 }
 
-#[test]
-fn test_same_object_with_multiple_builders_has_basic_builder() {
-    assert_file_contains_content_at(
-        &(ROOT.clone() + "/tests/test_k8s/io/k8s/api/core/v1/pod.rs"),
-        "
-/// Builder for [`Pod`](./struct.Pod.html) object.
+/// Builder created by [`Patch::patch_core_v1_persistent_volume_status`](./struct.Patch.html#method.patch_core_v1_persistent_volume_status) method for a `PATCH` operation associated with `Patch`.
+#[repr(C)]
 #[derive(Debug, Clone)]
-pub struct PodBuilder {
-    body: self::Pod,
+pub struct PatchPatchBuilder23<Name> {
+    inner: PatchPatchBuilder23Container,
+    _param_name: core::marker::PhantomData<Name>,
 }
 
-impl PodBuilder {
-    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+#[repr(C)]
+#[derive(Debug, Default, Clone)]
+struct PatchPatchBuilder23Container {
+    body: self::Patch,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_force: Option<bool>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> PatchPatchBuilder23<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
     #[inline]
-    pub fn api_version(mut self, value: impl Into<String>) -> Self {
-        self.body.api_version = Some(value.into());
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
         self
     }
 
-    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    /// Clears the value set for `dry_run` (if any).
     #[inline]
-    pub fn kind(mut self, value: impl Into<String>) -> Self {
-        self.body.kind = Some(value.into());
+    pub fn clear_dry_run(mut self) -> Self {
+        self.inner.param_dry_run = None;
         self
     }
 
-    /// Standard object's metadata. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#metadata
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint. This field is required for apply requests (application/apply-patch) but optional for non-apply patch types (JsonPatch, MergePatch, StrategicMergePatch).
     #[inline]
-    pub fn metadata(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::object_meta::ObjectMeta) -> Self {
-        self.body.metadata = Some(value.into());
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
         self
     }
 
-    /// Specification of the desired behavior of the pod. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#spec-and-status
+    /// Clears the value set for `field_manager` (if any).
     #[inline]
-    pub fn spec(mut self, value: crate::codegen::io::k8s::api::core::v1::pod_spec::PodSpecBuilder<crate::codegen::generics::ContainersExists>) -> Self {
-        self.body.spec = Some(value.into());
+    pub fn clear_field_manager(mut self) -> Self {
+        self.inner.param_field_manager = None;
         self
     }
 
-    /// Most recently observed status of the pod. This data may not be up to date. Populated by the system. Read-only. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#spec-and-status
+    /// Force is going to \"force\" Apply requests. It means user will re-acquire conflicting fields owned by other people. Force flag must be unset for non-apply patch requests.
     #[inline]
-    pub fn status(mut self, value: crate::codegen::io::k8s::api::core::v1::pod_status::PodStatus) -> Self {
-        self.body.status = Some(value.into());
+    pub fn force(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_force = Some(value.into());
         self
     }
-}
-",
-        Some(4237),
-    )
-}
 
-#[test]
-fn test_simple_object_builder_with_required_fields() {
-    assert_file_contains_content_at(
-        &(ROOT.clone() + "/tests/test_k8s/io/k8s/api/rbac/v1/policy_rule.rs"),
-        "
-impl PolicyRule {
-    /// Create a builder for this object.
+    /// Clears the value set for `force` (if any).
     #[inline]
-    pub fn builder() -> PolicyRuleBuilder<crate::codegen::generics::MissingVerbs> {
-        PolicyRuleBuilder {
-            body: Default::default(),
-            _verbs: core::marker::PhantomData,
+    pub fn clear_force(mut self) -> Self {
+        self.inner.param_force = None;
+        self
+    }
+
+    /// name of the PersistentVolume
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> PatchPatchBuilder23<crate::codegen::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder23<crate::codegen::generics::NameExists>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
+    #[inline]
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `pretty` (if any).
+    #[inline]
+    pub fn clear_pretty(mut self) -> Self {
+        self.inner.param_pretty = None;
+        self
+    }
+
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
+    #[inline]
+    pub fn reset(self) -> PatchPatchBuilder23<crate::codegen::generics::MissingName> {
+        crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder23<crate::codegen::generics::MissingName>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Sends the given body for this API call (instead of the one in this builder),
+    /// without moving or cloning it. The body fields needn't be set in that case.
+    #[inline]
+    pub fn with_body(self, body: &self::Patch) -> crate::codegen::client::BorrowedBody<'_, PatchPatchBuilder23<Name>, self::Patch> {
+        let builder = {
+            crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder23<Name>>();
+            unsafe { std::mem::transmute(self) }
+        };
+        crate::codegen::client::BorrowedBody::new(builder, body)
+    }
+}
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for PatchPatchBuilder23<crate::codegen::generics::NameExists> {
+    type Output = crate::codegen::io::k8s::api::core::v1::persistent_volume::PersistentVolume;
+
+    const METHOD: http::Method = http::Method::PATCH;
+
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        format!(\"/api/v1/persistentvolumes/{name}/status\", name=crate::codegen::util::encode_path_param(&self.inner.param_name.as_ref().expect(\"missing parameter name?\").to_string())).into()
+    }
+
+    fn empty_path_param(&self) -> Option<&'static str> {
+        if self.inner.param_name.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {
+            return Some(\"name\");
         }
+
+        None
+    }
+
+    fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        self.modify_with_body(client, req, &self.inner.body)
+    }
+
+    fn modify_with_body<B: serde::Serialize + Sync>(&self, _client: &Client, req: Client::Request, body: &B) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .json(body)
+        .query(&[
+            (\"dryRun\", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            (\"fieldManager\", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            (\"force\", self.inner.param_force.as_ref().map(std::string::ToString::to_string)),
+            (\"pretty\", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
     }
 }
 
-impl Into<PolicyRule> for PolicyRuleBuilder<crate::codegen::generics::VerbsExists> {
-    fn into(self) -> PolicyRule {
-        self.body
+impl PatchPatchBuilder23<crate::codegen::generics::NameExists> {
+    /// Overrides the client's timeout for this API call.
+    #[inline]
+    pub fn timeout(self, timeout: std::time::Duration) -> crate::codegen::client::Timeout<Self> {
+        crate::codegen::client::Timeout::new(self, timeout)
+    }
+
+    /// Adds a header (which isn't declared in the spec) to this API call.
+    #[inline]
+    pub fn header(self, name: impl AsRef<str>, value: impl AsRef<str>) -> crate::codegen::client::ExtraHeaders<Self> {
+        crate::codegen::client::ExtraHeaders::new(self).header(name, value)
     }
 }
 
-/// Builder for [`PolicyRule`](./struct.PolicyRule.html) object.
+/// Builder created by [`Patch::patch_admissionregistration_v1beta1_mutating_webhook_configuration`](./struct.Patch.html#method.patch_admissionregistration_v1beta1_mutating_webhook_configuration) method for a `PATCH` operation associated with `Patch`.
+#[repr(C)]
 #[derive(Debug, Clone)]
-pub struct PolicyRuleBuilder<Verbs> {
-    body: self::PolicyRule,
-    _verbs: core::marker::PhantomData<Verbs>,
+pub struct PatchPatchBuilder24<Name> {
+    inner: PatchPatchBuilder24Container,
+    _param_name: core::marker::PhantomData<Name>,
 }
 
-impl<Verbs> PolicyRuleBuilder<Verbs> {
-    /// APIGroups is the name of the APIGroup that contains the resources.  If multiple API groups are specified, any action requested against one of the enumerated resources in any API group will be allowed.
+#[repr(C)]
+#[derive(Debug, Default, Clone)]
+struct PatchPatchBuilder24Container {
+    body: self::Patch,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_force: Option<bool>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
+}
+
+impl<Name> PatchPatchBuilder24<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
+    #[inline]
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `dry_run` (if any).
+    #[inline]
+    pub fn clear_dry_run(mut self) -> Self {
+        self.inner.param_dry_run = None;
+        self
+    }
+
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint. This field is required for apply requests (application/apply-patch) but optional for non-apply patch types (JsonPatch, MergePatch, StrategicMergePatch).
+    #[inline]
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `field_manager` (if any).
     #[inline]
-    pub fn api_groups(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
-        self.body.api_groups = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+    pub fn clear_field_manager(mut self) -> Self {
+        self.inner.param_field_manager = None;
         self
     }
 
-    /// NonResourceURLs is a set of partial urls that a user should have access to.  *s are allowed, but only as the full, final step in the path Since non-resource URLs are not namespaced, this field is only applicable for ClusterRoles referenced from a ClusterRoleBinding. Rules can either apply to API resources (such as \"pods\" or \"secrets\") or non-resource URL paths (such as \"/api\"),  but not both.
+    /// Force is going to \"force\" Apply requests. It means user will re-acquire conflicting fields owned by other people. Force flag must be unset for non-apply patch requests.
     #[inline]
-    pub fn non_resource_ur_ls(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
-        self.body.non_resource_ur_ls = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+    pub fn force(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_force = Some(value.into());
         self
     }
 
-    /// ResourceNames is an optional white list of names that the rule applies to.  An empty set means that everything is allowed.
+    /// Clears the value set for `force` (if any).
     #[inline]
-    pub fn resource_names(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
-        self.body.resource_names = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+    pub fn clear_force(mut self) -> Self {
+        self.inner.param_force = None;
         self
     }
 
-    /// Resources is a list of resources this rule applies to.  ResourceAll represents all resources.
+    /// name of the MutatingWebhookConfiguration
+    #[inline]
+    pub fn name(mut self, value: impl Into<String>) -> PatchPatchBuilder24<crate::codegen::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder24<crate::codegen::generics::NameExists>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// If 'true', then the output is pretty printed.
     #[inline]
-    pub fn resources(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
-        self.body.resources = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
         self
     }
 
-    /// Verbs is a list of Verbs that apply to ALL the ResourceKinds and AttributeRestrictions contained in this rule.  VerbAll represents all kinds.
+    /// Clears the value set for `pretty` (if any).
+    #[inline]
+    pub fn clear_pretty(mut self) -> Self {
+        self.inner.param_pretty = None;
+        self
+    }
+
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
     #[inline]
-    pub fn verbs(mut self, value: impl Iterator<Item = impl Into<String>>) -> PolicyRuleBuilder<crate::codegen::generics::VerbsExists> {
-        self.body.verbs = value.map(|value| value.into()).collect::<Vec<_>>().into();
+    pub fn reset(self) -> PatchPatchBuilder24<crate::codegen::generics::MissingName> {
+        crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder24<crate::codegen::generics::MissingName>>();
         unsafe { std::mem::transmute(self) }
     }
+
+    /// Sends the given body for this API call (instead of the one in this builder),
+    /// without moving or cloning it. The body fields needn't be set in that case.
+    #[inline]
+    pub fn with_body(self, body: &self::Patch) -> crate::codegen::client::BorrowedBody<'_, PatchPatchBuilder24<Name>, self::Patch> {
+        let builder = {
+            crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder24<Name>>();
+            unsafe { std::mem::transmute(self) }
+        };
+        crate::codegen::client::BorrowedBody::new(builder, body)
+    }
 }
-",
-        Some(1564),
-    );
+
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for PatchPatchBuilder24<crate::codegen::generics::NameExists> {
+    type Output = crate::codegen::io::k8s::api::admissionregistration::v1beta1::mutating_webhook_configuration::MutatingWebhookConfiguration;
+
+    const METHOD: http::Method = http::Method::PATCH;
+
+    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
+        format!(\"/apis/admissionregistration.k8s.io/v1beta1/mutatingwebhookconfigurations/{name}\", name=crate::codegen::util::encode_path_param(&self.inner.param_name.as_ref().expect(\"missing parameter name?\").to_string())).into()
+    }
+
+    fn empty_path_param(&self) -> Option<&'static str> {
+        if self.inner.param_name.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {
+            return Some(\"name\");
+        }
+
+        None
+    }
+
+    fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        self.modify_with_body(client, req, &self.inner.body)
+    }
+
+    fn modify_with_body<B: serde::Serialize + Sync>(&self, _client: &Client, req: Client::Request, body: &B) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        use crate::codegen::client::Request;
+        Ok(req
+        .json(body)
+        .query(&[
+            (\"dryRun\", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
+            (\"fieldManager\", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            (\"force\", self.inner.param_force.as_ref().map(std::string::ToString::to_string)),
+            (\"pretty\", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
+        ]))
+    }
 }
 
-#[test]
-fn test_builder_with_field_parameter_collision_and_method_collision() {
-    // grace_period_seconds, orphan_dependents and propagation_policy
-    // exist in both the object and as a query parameter. If one is set,
-    // we should also set the other.
-    assert_file_contains_content_at(
-        &(ROOT.clone() + "/tests/test_k8s/io/k8s/apimachinery/pkg/apis/meta/v1/delete_options.rs"),
-        "
-/// Builder created by [`DeleteOptions::delete_rbac_authorization_v1_namespaced_role`](./struct.DeleteOptions.html#method.delete_rbac_authorization_v1_namespaced_role) method for a `DELETE` operation associated with `DeleteOptions`.
-#[repr(transparent)]
+impl PatchPatchBuilder24<crate::codegen::generics::NameExists> {
+    /// Overrides the client's timeout for this API call.
+    #[inline]
+    pub fn timeout(self, timeout: std::time::Duration) -> crate::codegen::client::Timeout<Self> {
+        crate::codegen::client::Timeout::new(self, timeout)
+    }
+
+    /// Adds a header (which isn't declared in the spec) to this API call.
+    #[inline]
+    pub fn header(self, name: impl AsRef<str>, value: impl AsRef<str>) -> crate::codegen::client::ExtraHeaders<Self> {
+        crate::codegen::client::ExtraHeaders::new(self).header(name, value)
+    }
+}
+
+/// Builder created by [`Patch::patch_admissionregistration_v1beta1_validating_webhook_configuration`](./struct.Patch.html#method.patch_admissionregistration_v1beta1_validating_webhook_configuration) method for a `PATCH` operation associated with `Patch`.
+#[repr(C)]
 #[derive(Debug, Clone)]
-pub struct DeleteOptionsDeleteBuilder59<Name, Namespace> {
-    inner: DeleteOptionsDeleteBuilder59Container,
+pub struct PatchPatchBuilder25<Name> {
+    inner: PatchPatchBuilder25Container,
     _param_name: core::marker::PhantomData<Name>,
-    _param_namespace: core::marker::PhantomData<Namespace>,
 }
 
+#[repr(C)]
 #[derive(Debug, Default, Clone)]
-struct DeleteOptionsDeleteBuilder59Container {
-    body: self::DeleteOptions,
+struct PatchPatchBuilder25Container {
+    body: self::Patch,
     param_dry_run: Option<String>,
-    param_grace_period_seconds: Option<i64>,
-    param_orphan_dependents: Option<bool>,
-    param_propagation_policy: Option<String>,
+    param_field_manager: Option<String>,
+    param_force: Option<bool>,
     param_name: Option<String>,
-    param_namespace: Option<String>,
     param_pretty: Option<String>,
 }
 
-impl<Name, Namespace> DeleteOptionsDeleteBuilder59<Name, Namespace> {
+impl<Name> PatchPatchBuilder25<Name> {
     /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
     #[inline]
     pub fn dry_run(mut self, value: impl Into<String>) -> Self {
@@ -1313,50 +5800,46 @@ impl<Name, Namespace> DeleteOptionsDeleteBuilder59<Name, Namespace> {
         self
     }
 
-    /// The duration in seconds before the object should be deleted. Value must be non-negative integer. The value zero indicates delete immediately. If this value is nil, the default grace period for the specified type will be used. Defaults to a per object value if not specified. zero means delete immediately.
+    /// Clears the value set for `dry_run` (if any).
     #[inline]
-    pub fn grace_period_seconds(mut self, value: impl Into<i64>) -> Self {
-        self.inner.param_grace_period_seconds = Some({
-            let val = value.into();
-            self.inner.body.grace_period_seconds = val.clone().into();
-            val
-        });
+    pub fn clear_dry_run(mut self) -> Self {
+        self.inner.param_dry_run = None;
         self
     }
 
-    /// Deprecated: please use the PropagationPolicy, this field will be deprecated in 1.7. Should the dependent objects be orphaned. If true/false, the \"orphan\" finalizer will be added to/removed from the object's finalizers list. Either this field or PropagationPolicy may be set, but not both.
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint. This field is required for apply requests (application/apply-patch) but optional for non-apply patch types (JsonPatch, MergePatch, StrategicMergePatch).
     #[inline]
-    pub fn orphan_dependents(mut self, value: impl Into<bool>) -> Self {
-        self.inner.param_orphan_dependents = Some({
-            let val = value.into();
-            self.inner.body.orphan_dependents = val.clone().into();
-            val
-        });
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
         self
     }
 
-    /// Whether and how garbage collection will be performed. Either this field or OrphanDependents may be set, but not both. The default policy is decided by the existing finalizer set in the metadata.finalizers and the resource-specific default policy. Acceptable values are: 'Orphan' - orphan the dependents; 'Background' - allow the garbage collector to delete the dependents in the background; 'Foreground' - a cascading policy that deletes all dependents in the foreground.
+    /// Clears the value set for `field_manager` (if any).
     #[inline]
-    pub fn propagation_policy(mut self, value: impl Into<String>) -> Self {
-        self.inner.param_propagation_policy = Some({
-            let val = value.into();
-            self.inner.body.propagation_policy = val.clone().into();
-            val
-        });
+    pub fn clear_field_manager(mut self) -> Self {
+        self.inner.param_field_manager = None;
         self
     }
 
-    /// name of the Role
+    /// Force is going to \"force\" Apply requests. It means user will re-acquire conflicting fields owned by other people. Force flag must be unset for non-apply patch requests.
     #[inline]
-    pub fn name(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder59<crate::codegen::generics::NameExists, Namespace> {
-        self.inner.param_name = Some(value.into());
-        unsafe { std::mem::transmute(self) }
+    pub fn force(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_force = Some(value.into());
+        self
+    }
+
+    /// Clears the value set for `force` (if any).
+    #[inline]
+    pub fn clear_force(mut self) -> Self {
+        self.inner.param_force = None;
+        self
     }
 
-    /// object name and auth scope, such as for teams and projects
+    /// name of the ValidatingWebhookConfiguration
     #[inline]
-    pub fn namespace(mut self, value: impl Into<String>) -> DeleteOptionsDeleteBuilder59<Name, crate::codegen::generics::NamespaceExists> {
-        self.inner.param_namespace = Some(value.into());
+    pub fn name(mut self, value: impl Into<String>) -> PatchPatchBuilder25<crate::codegen::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder25<crate::codegen::generics::NameExists>>();
         unsafe { std::mem::transmute(self) }
     }
 
@@ -1367,211 +5850,210 @@ impl<Name, Namespace> DeleteOptionsDeleteBuilder59<Name, Namespace> {
         self
     }
 
-    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+    /// Clears the value set for `pretty` (if any).
     #[inline]
-    pub fn api_version(mut self, value: impl Into<String>) -> Self {
-        self.inner.body.api_version = Some(value.into());
+    pub fn clear_pretty(mut self) -> Self {
+        self.inner.param_pretty = None;
         self
     }
 
-    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
     #[inline]
-    pub fn kind(mut self, value: impl Into<String>) -> Self {
-        self.inner.body.kind = Some(value.into());
-        self
+    pub fn reset(self) -> PatchPatchBuilder25<crate::codegen::generics::MissingName> {
+        crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder25<crate::codegen::generics::MissingName>>();
+        unsafe { std::mem::transmute(self) }
     }
 
-    /// Must be fulfilled before a deletion is carried out. If not possible, a 409 Conflict status will be returned.
+    /// Sends the given body for this API call (instead of the one in this builder),
+    /// without moving or cloning it. The body fields needn't be set in that case.
     #[inline]
-    pub fn preconditions(mut self, value: crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::preconditions::Preconditions) -> Self {
-        self.inner.body.preconditions = Some(value.into());
-        self
+    pub fn with_body(self, body: &self::Patch) -> crate::codegen::client::BorrowedBody<'_, PatchPatchBuilder25<Name>, self::Patch> {
+        let builder = {
+            crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder25<Name>>();
+            unsafe { std::mem::transmute(self) }
+        };
+        crate::codegen::client::BorrowedBody::new(builder, body)
     }
 }
 
-impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for DeleteOptionsDeleteBuilder59<crate::codegen::generics::NameExists, crate::codegen::generics::NamespaceExists> {
-    type Output = crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::status::Status;
+impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for PatchPatchBuilder25<crate::codegen::generics::NameExists> {
+    type Output = crate::codegen::io::k8s::api::admissionregistration::v1beta1::validating_webhook_configuration::ValidatingWebhookConfiguration;
 
-    const METHOD: http::Method = http::Method::DELETE;
+    const METHOD: http::Method = http::Method::PATCH;
 
     fn rel_path(&self) -> std::borrow::Cow<'static, str> {
-        format!(\"/apis/rbac.authorization.k8s.io/v1/namespaces/{namespace}/roles/{name}\", name=self.inner.param_name.as_ref().expect(\"missing parameter name?\"), namespace=self.inner.param_namespace.as_ref().expect(\"missing parameter namespace?\")).into()
+        format!(\"/apis/admissionregistration.k8s.io/v1beta1/validatingwebhookconfigurations/{name}\", name=crate::codegen::util::encode_path_param(&self.inner.param_name.as_ref().expect(\"missing parameter name?\").to_string())).into()
+    }
+
+    fn empty_path_param(&self) -> Option<&'static str> {
+        if self.inner.param_name.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {
+            return Some(\"name\");
+        }
+
+        None
+    }
+
+    fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        self.modify_with_body(client, req, &self.inner.body)
     }
 
-    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+    fn modify_with_body<B: serde::Serialize + Sync>(&self, _client: &Client, req: Client::Request, body: &B) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
         use crate::codegen::client::Request;
         Ok(req
-        .json(&self.inner.body)
+        .json(body)
         .query(&[
             (\"dryRun\", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
-            (\"gracePeriodSeconds\", self.inner.param_grace_period_seconds.as_ref().map(std::string::ToString::to_string)),
-            (\"orphanDependents\", self.inner.param_orphan_dependents.as_ref().map(std::string::ToString::to_string)),
-            (\"propagationPolicy\", self.inner.param_propagation_policy.as_ref().map(std::string::ToString::to_string)),
+            (\"fieldManager\", self.inner.param_field_manager.as_ref().map(std::string::ToString::to_string)),
+            (\"force\", self.inner.param_force.as_ref().map(std::string::ToString::to_string)),
             (\"pretty\", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
         ]))
     }
 }
-",
-        Some(448229),
-    );
-}
 
-#[test]
-fn test_unit_builder_with_no_modifier() {
-    assert_file_contains_content_at(
-        &(ROOT.clone() + "/tests/test_k8s/io/k8s/apimachinery/pkg/apis/meta/v1/api_group_list.rs"),
-        "
-impl ApiGroupList {
-    /// Create a builder for this object.
+impl PatchPatchBuilder25<crate::codegen::generics::NameExists> {
+    /// Overrides the client's timeout for this API call.
     #[inline]
-    pub fn builder() -> ApiGroupListBuilder<crate::codegen::generics::MissingGroups> {
-        ApiGroupListBuilder {
-            body: Default::default(),
-            _groups: core::marker::PhantomData,
-        }
+    pub fn timeout(self, timeout: std::time::Duration) -> crate::codegen::client::Timeout<Self> {
+        crate::codegen::client::Timeout::new(self, timeout)
     }
 
-    /// get available API versions
+    /// Adds a header (which isn't declared in the spec) to this API call.
     #[inline]
-    pub fn get_api_versions() -> ApiGroupListGetBuilder {
-        ApiGroupListGetBuilder
+    pub fn header(self, name: impl AsRef<str>, value: impl AsRef<str>) -> crate::codegen::client::ExtraHeaders<Self> {
+        crate::codegen::client::ExtraHeaders::new(self).header(name, value)
     }
 }
 
-impl Into<ApiGroupList> for ApiGroupListBuilder<crate::codegen::generics::GroupsExists> {
-    fn into(self) -> ApiGroupList {
-        self.body
-    }
+/// Builder created by [`Patch::patch_apiextensions_v1beta1_custom_resource_definition`](./struct.Patch.html#method.patch_apiextensions_v1beta1_custom_resource_definition) method for a `PATCH` operation associated with `Patch`.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct PatchPatchBuilder26<Name> {
+    inner: PatchPatchBuilder26Container,
+    _param_name: core::marker::PhantomData<Name>,
 }
 
-/// Builder for [`ApiGroupList`](./struct.ApiGroupList.html) object.
-#[derive(Debug, Clone)]
-pub struct ApiGroupListBuilder<Groups> {
-    body: self::ApiGroupList,
-    _groups: core::marker::PhantomData<Groups>,
+#[repr(C)]
+#[derive(Debug, Default, Clone)]
+struct PatchPatchBuilder26Container {
+    body: self::Patch,
+    param_dry_run: Option<String>,
+    param_field_manager: Option<String>,
+    param_force: Option<bool>,
+    param_name: Option<String>,
+    param_pretty: Option<String>,
 }
 
-impl<Groups> ApiGroupListBuilder<Groups> {
-    /// APIVersion defines the versioned schema of this representation of an object. Servers should convert recognized schemas to the latest internal value, and may reject unrecognized values. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#resources
+impl<Name> PatchPatchBuilder26<Name> {
+    /// When present, indicates that modifications should not be persisted. An invalid or unrecognized dryRun directive will result in an error response and no further processing of the request. Valid values are: - All: all dry run stages will be processed
     #[inline]
-    pub fn api_version(mut self, value: impl Into<String>) -> Self {
-        self.body.api_version = Some(value.into());
+    pub fn dry_run(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_dry_run = Some(value.into());
         self
     }
 
-    /// groups is a list of APIGroup.
+    /// Clears the value set for `dry_run` (if any).
     #[inline]
-    pub fn groups(mut self, value: impl Iterator<Item = crate::codegen::io::k8s::apimachinery::pkg::apis::meta::v1::api_group::ApiGroupBuilder<crate::codegen::generics::NameExists, crate::codegen::generics::VersionsExists>>) -> ApiGroupListBuilder<crate::codegen::generics::GroupsExists> {
-        self.body.groups = value.map(|value| value.into()).collect::<Vec<_>>().into();
-        unsafe { std::mem::transmute(self) }
+    pub fn clear_dry_run(mut self) -> Self {
+        self.inner.param_dry_run = None;
+        self
     }
 
-    /// Kind is a string value representing the REST resource this object represents. Servers may infer this from the endpoint the client submits requests to. Cannot be updated. In CamelCase. More info: https://git.k8s.io/community/contributors/devel/api-conventions.md#types-kinds
+    /// fieldManager is a name associated with the actor or entity that is making these changes. The value must be less than or 128 characters long, and only contain printable characters, as defined by https://golang.org/pkg/unicode/#IsPrint. This field is required for apply requests (application/apply-patch) but optional for non-apply patch types (JsonPatch, MergePatch, StrategicMergePatch).
     #[inline]
-    pub fn kind(mut self, value: impl Into<String>) -> Self {
-        self.body.kind = Some(value.into());
+    pub fn field_manager(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_field_manager = Some(value.into());
         self
     }
-}
-
-/// Builder created by [`ApiGroupList::get_api_versions`](./struct.ApiGroupList.html#method.get_api_versions) method for a `GET` operation associated with `ApiGroupList`.
-#[derive(Debug, Clone)]
-pub struct ApiGroupListGetBuilder;
-
-
-impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for ApiGroupListGetBuilder {
-    type Output = ApiGroupList;
-
-    const METHOD: http::Method = http::Method::GET;
 
-    fn rel_path(&self) -> std::borrow::Cow<'static, str> {
-        \"/apis/\".into()
+    /// Clears the value set for `field_manager` (if any).
+    #[inline]
+    pub fn clear_field_manager(mut self) -> Self {
+        self.inner.param_field_manager = None;
+        self
     }
-}
-",
-        Some(979),
-    );
-}
-
-#[test]
-fn test_builder_field_with_iterators() {
-    assert_file_contains_content_at(
-        &(ROOT.clone() + "/tests/test_k8s/io/k8s/api/certificates/v1beta1/certificate_signing_request_spec.rs"),
-        "
-/// Builder for [`CertificateSigningRequestSpec`](./struct.CertificateSigningRequestSpec.html) object.
-#[derive(Debug, Clone)]
-pub struct CertificateSigningRequestSpecBuilder<Request> {
-    body: self::CertificateSigningRequestSpec,
-    _request: core::marker::PhantomData<Request>,
-}
 
-impl<Request> CertificateSigningRequestSpecBuilder<Request> {
-    /// Extra information about the requesting user. See user.Info interface for details.
+    /// Force is going to \"force\" Apply requests. It means user will re-acquire conflicting fields owned by other people. Force flag must be unset for non-apply patch requests.
     #[inline]
-    pub fn extra(mut self, value: impl Iterator<Item = (String, impl Iterator<Item = impl Into<String>>)>) -> Self {
-        self.body.extra = Some(value.map(|(key, value)| (key, value.map(|value| value.into()).collect::<Vec<_>>().into())).collect::<std::collections::BTreeMap<_, _>>().into());
+    pub fn force(mut self, value: impl Into<bool>) -> Self {
+        self.inner.param_force = Some(value.into());
         self
     }
 
-    /// Group information about the requesting user. See user.Info interface for details.
+    /// Clears the value set for `force` (if any).
     #[inline]
-    pub fn groups(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
-        self.body.groups = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+    pub fn clear_force(mut self) -> Self {
+        self.inner.param_force = None;
         self
     }
 
-    /// Base64-encoded PKCS#10 CSR data
+    /// name of the CustomResourceDefinition
     #[inline]
-    pub fn request(mut self, value: impl Into<String>) -> CertificateSigningRequestSpecBuilder<crate::codegen::generics::RequestExists> {
-        self.body.request = value.into();
+    pub fn name(mut self, value: impl Into<String>) -> PatchPatchBuilder26<crate::codegen::generics::NameExists> {
+        self.inner.param_name = Some(value.into());
+        crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder26<crate::codegen::generics::NameExists>>();
         unsafe { std::mem::transmute(self) }
     }
 
-    /// UID information about the requesting user. See user.Info interface for details.
+    /// If 'true', then the output is pretty printed.
     #[inline]
-    pub fn uid(mut self, value: impl Into<String>) -> Self {
-        self.body.uid = Some(value.into());
+    pub fn pretty(mut self, value: impl Into<String>) -> Self {
+        self.inner.param_pretty = Some(value.into());
         self
     }
 
-    /// allowedUsages specifies a set of usage contexts the key will be valid for. See: https://tools.ietf.org/html/rfc5280#section-4.2.1.3
-    ///      https://tools.ietf.org/html/rfc5280#section-4.2.1.12
+    /// Clears the value set for `pretty` (if any).
     #[inline]
-    pub fn usages(mut self, value: impl Iterator<Item = impl Into<String>>) -> Self {
-        self.body.usages = Some(value.map(|value| value.into()).collect::<Vec<_>>().into());
+    pub fn clear_pretty(mut self) -> Self {
+        self.inner.param_pretty = None;
         self
     }
 
-    /// Information about the requesting user. See user.Info interface for details.
+    /// Resets this builder, so that the required fields and parameters
+    /// should be set again. Other values are retained.
     #[inline]
-    pub fn username(mut self, value: impl Into<String>) -> Self {
-        self.body.username = Some(value.into());
-        self
+    pub fn reset(self) -> PatchPatchBuilder26<crate::codegen::generics::MissingName> {
+        crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder26<crate::codegen::generics::MissingName>>();
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Sends the given body for this API call (instead of the one in this builder),
+    /// without moving or cloning it. The body fields needn't be set in that case.
+    #[inline]
+    pub fn with_body(self, body: &self::Patch) -> crate::codegen::client::BorrowedBody<'_, PatchPatchBuilder26<Name>, self::Patch> {
+        let builder = {
+            crate::codegen::util::debug_assert_same_layout::<Self, PatchPatchBuilder26<Name>>();
+            unsafe { std::mem::transmute(self) }
+        };
+        crate::codegen::client::BorrowedBody::new(builder, body)
     }
-}
-",
-        Some(1686),
-    );
 }
 
-#[test]
-fn test_any_in_operation_bound_to_unrelated_struct() {
-    assert_file_contains_content_at(
-        &(ROOT.clone() + "/tests/test_k8s/io/k8s/apimachinery/pkg/apis/meta/v1/patch.rs"),
-        "
 impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen::client::Sendable<Client> for PatchPatchBuilder26<crate::codegen::generics::NameExists> {
     type Output = crate::codegen::io::k8s::apiextensions_apiserver::pkg::apis::apiextensions::v1beta1::custom_resource_definition::CustomResourceDefinition<serde_json::Value>;
 
     const METHOD: http::Method = http::Method::PATCH;
 
     fn rel_path(&self) -> std::borrow::Cow<'static, str> {
-        format!(\"/apis/apiextensions.k8s.io/v1beta1/customresourcedefinitions/{name}\", name=self.inner.param_name.as_ref().expect(\"missing parameter name?\")).into()
+        format!(\"/apis/apiextensions.k8s.io/v1beta1/customresourcedefinitions/{name}\", name=crate::codegen::util::encode_path_param(&self.inner.param_name.as_ref().expect(\"missing parameter name?\").to_string())).into()
+    }
+
+    fn empty_path_param(&self) -> Option<&'static str> {
+        if self.inner.param_name.as_ref().map_or(false, |v| v.to_string().trim().is_empty()) {
+            return Some(\"name\");
+        }
+
+        None
+    }
+
+    fn modify(&self, client: &Client, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+        self.modify_with_body(client, req, &self.inner.body)
     }
 
-    fn modify(&self, req: Client::Request) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
+    fn modify_with_body<B: serde::Serialize + Sync>(&self, _client: &Client, req: Client::Request, body: &B) -> Result<Client::Request, crate::codegen::client::ApiError<Client::Response>> {
         use crate::codegen::client::Request;
         Ok(req
-        .json(&self.inner.body)
+        .json(body)
         .header(http::header::ACCEPT.as_str(), \"application/json\")
         .query(&[
             (\"dryRun\", self.inner.param_dry_run.as_ref().map(std::string::ToString::to_string)),
@@ -1580,9 +6062,13 @@ impl<Client: crate::codegen::client::ApiClient + Sync + 'static> crate::codegen:
             (\"pretty\", self.inner.param_pretty.as_ref().map(std::string::ToString::to_string))
         ]))
     }
+
+    fn fallback_media_range(&self) -> Option<&'static str> {
+        Some(\"application/json\")
+    }
 }
 ",
-        Some(180127),
+        Some(220997),
     );
 }
 
@@ -1600,8 +6086,10 @@ path = \"main.rs\"
 [dependencies]
 async-trait = \"0.1\"
 failure = \"0.1\"
+flate2 = { version = \"1.0\", optional = true }
 futures = \"0.1\"
 futures-preview = { version = \"0.3.0-alpha.19\", features = [\"compat\"], package = \"futures-preview\" }
+futures-timer = \"1.0\"
 http = \"0.1\"
 lazy_static = \"1.4\"
 log = \"0.4\"
@@ -1622,6 +6110,11 @@ humantime = \"1.2\"
 openssl = { version = \"0.10\", features = [\"vendored\"] }
 tokio = { version = \"0.2.0-alpha.6\", features = [\"rt-current-thread\"] }
 
+[features]
+gzip = [\"flate2\"]
+interop-http = []
+interop-reqwest = []
+
 [workspace]
 ",
         Some(101),
@@ -1644,6 +6137,7 @@ use openssl::pkcs12::Pkcs12;
 use openssl::pkey::PKey;
 use openssl::x509::X509;
 
+use std::borrow::Cow;
 use std::fs::File;
 use std::io::Read;
 use std::path::Path;
@@ -1708,14 +6202,39 @@ impl ApiClient for WrappedClient {
             path = String::from(\"/\") + &path;
         }
 
+        // Relative paths already have (encoded) query parameters for some operations.
+        let (rel_path, query) = match rel_path.find('?') {
+            Some(i) => (&rel_path[..i], Some(&rel_path[i + 1..])),
+            None => (rel_path, None),
+        };
+
         path.push_str(rel_path);
         u.set_path(&path);
+        if let Some(q) = query {
+            let q = match u.query() {
+                Some(base) if !base.is_empty() => format!(\"{}&{}\", base, q),
+                _ => q.into(),
+            };
+
+            u.set_query(Some(&q));
+        }
+
         self.inner.request(method, u)
     }
+
+    fn request_builder_for_url(&self, method: http::Method, url: &str) -> Option<Self::Request> {
+        Some(self.inner.request(method, url))
+    }
+
+    fn base_url(&self) -> Cow<'_, str> {
+        self.url.as_str().into()
+    }
 }
 
 fn make_client<'a>(matches: &'a ArgMatches<'a>) -> Result<WrappedClient, Error> {
-    let mut client = reqwest::r#async::Client::builder();
+    // Redirects are followed by the generated client.
+    let mut client = reqwest::r#async::Client::builder()
+        .redirect(reqwest::RedirectPolicy::none());
 
     if let Some(p) = matches.value_of(\"ca-cert\") {
         let ca_cert = X509::from_pem(&read_file(p)?)
@@ -1793,7 +6312,7 @@ async fn main() {
     }
 }
 ",
-        Some(11157),
+        Some(232240),
     );
 }
 
@@ -0,0 +1,346 @@
+mod mock;
+
+use self::mock::{MockResponse, MockServer, TestClient};
+use futures_preview::stream::TryStreamExt;
+use test_runtime::client::blocking::Client;
+use test_runtime::client::{ApiError, Conditional, RetryPolicy, SendError, Sendable};
+use test_runtime::message::Message;
+use test_runtime::pet::{Pet, PetPostResponse};
+
+use std::net::TcpListener;
+use std::time::Duration;
+
+const PET: &str = r#"{"id":1,"name":"Doggie"}"#;
+
+fn blocking_client(client: TestClient) -> Client<TestClient> {
+    Client::with_client(client).expect("runtime")
+}
+
+fn retry_policy() -> RetryPolicy {
+    RetryPolicy {
+        max_retries: 3,
+        initial_backoff: Duration::from_millis(10),
+        max_backoff: Duration::from_millis(50),
+        ..RetryPolicy::default()
+    }
+}
+
+#[test]
+fn test_retry_until_success() {
+    let server = MockServer::start(vec![
+        MockResponse::new(503),
+        MockResponse::new(503),
+        MockResponse::json(200, PET),
+    ]);
+    let client = blocking_client(TestClient::new(&server.url()).retry_policy(retry_policy()));
+
+    let pet = client
+        .block_on(Pet::get_pet().pet_id("1").send(client.inner()))
+        .expect("pet");
+    assert_eq!(pet.name, "Doggie");
+    assert_eq!(server.requests().len(), 3);
+    // Backoff is doubled for every retry.
+    assert_eq!(
+        client.inner().sleeps(),
+        vec![Duration::from_millis(10), Duration::from_millis(20)]
+    );
+}
+
+#[test]
+fn test_retry_after_is_capped() {
+    let server = MockServer::start(vec![
+        MockResponse::new(429).header("Retry-After", "3600"),
+        MockResponse::json(200, PET),
+    ]);
+    let client = blocking_client(TestClient::new(&server.url()).retry_policy(retry_policy()));
+
+    client
+        .block_on(Pet::get_pet().pet_id("1").send(client.inner()))
+        .expect("pet");
+    assert_eq!(client.inner().sleeps(), vec![Duration::from_millis(50)]);
+}
+
+#[test]
+fn test_retries_exhausted() {
+    let server = MockServer::start(vec![MockResponse::new(503); 4]);
+    let client = blocking_client(TestClient::new(&server.url()).retry_policy(retry_policy()));
+
+    match client.block_on(Pet::get_pet().pet_id("1").send(client.inner())) {
+        Err(ApiError::Failure(path, status, _)) => {
+            assert_eq!(path, "/pets/1");
+            assert_eq!(status, 503);
+        }
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    assert_eq!(server.requests().len(), 4);
+}
+
+#[test]
+fn test_post_is_not_retried() {
+    let server = MockServer::start(vec![MockResponse::new(503), MockResponse::json(200, PET)]);
+    let client = blocking_client(TestClient::new(&server.url()).retry_policy(retry_policy()));
+
+    match client.block_on(Pet::add_pet().name("Doggie").send(client.inner())) {
+        Err(ApiError::Failure(_, status, _)) => assert_eq!(status, 503),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    assert_eq!(server.requests().len(), 1);
+    assert!(client.inner().sleeps().is_empty());
+}
+
+#[test]
+fn test_client_timeout() {
+    let server = MockServer::start(vec![
+        MockResponse::json(200, PET).delay(Duration::from_millis(500))
+    ]);
+    let client = blocking_client(TestClient::new(&server.url()).timeout(Duration::from_millis(50)));
+
+    match client.block_on(Pet::get_pet().pet_id("1").send(client.inner())) {
+        Err(ApiError::Timeout(d)) => assert_eq!(d, Duration::from_millis(50)),
+        r => panic!("unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn test_request_timeout_overrides_client() {
+    let server = MockServer::start(vec![
+        MockResponse::json(200, PET).delay(Duration::from_millis(500))
+    ]);
+    let client = blocking_client(TestClient::new(&server.url()).timeout(Duration::from_secs(5)));
+
+    let builder = Pet::get_pet()
+        .pet_id("1")
+        .timeout(Duration::from_millis(50));
+    match client.block_on(builder.send(client.inner())) {
+        Err(ApiError::Timeout(d)) => assert_eq!(d, Duration::from_millis(50)),
+        r => panic!("unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn test_temporary_redirect_resends_request() {
+    let server = MockServer::start(vec![
+        MockResponse::new(307).header("Location", "/pets/moved"),
+        MockResponse::json(201, PET),
+    ]);
+    let client = blocking_client(TestClient::new(&server.url()));
+
+    match client.block_on(Pet::add_pet().name("Doggie").send(client.inner())) {
+        Ok(PetPostResponse::Created(pet)) => assert_eq!(pet.id, Some(1)),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].method, "POST");
+    assert_eq!(requests[1].path, "/pets/moved");
+    assert_eq!(requests[1].header("Content-Type"), Some("application/json"));
+    assert!(!requests[0].body.is_empty());
+    assert_eq!(requests[1].body, requests[0].body);
+}
+
+#[test]
+fn test_see_other_switches_to_get() {
+    let server = MockServer::start(vec![
+        MockResponse::new(303).header("Location", "/pets/1"),
+        MockResponse::json(200, PET),
+    ]);
+    let client = blocking_client(TestClient::new(&server.url()));
+
+    match client.block_on(Pet::add_pet().name("Doggie").send(client.inner())) {
+        Ok(PetPostResponse::Ok(pet)) => assert_eq!(pet.name, "Doggie"),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].method, "GET");
+    assert_eq!(requests[1].path, "/pets/1");
+    assert_eq!(requests[1].header("Content-Type"), None);
+    assert!(requests[1].body.is_empty());
+}
+
+#[test]
+fn test_cross_origin_redirect_is_not_resent() {
+    // Servers on different ports have different origins.
+    let other = MockServer::start(vec![MockResponse::json(201, PET)]);
+    let server = MockServer::start(vec![
+        MockResponse::new(307).header("Location", &format!("{}/pets", other.url()))
+    ]);
+    let client = blocking_client(TestClient::new(&server.url()));
+
+    match client.block_on(Pet::add_pet().name("Doggie").send(client.inner())) {
+        Err(ApiError::Failure(_, status, _)) => assert_eq!(status, 307),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    assert_eq!(server.requests().len(), 1);
+    assert!(other.requests().is_empty());
+}
+
+#[test]
+fn test_if_none_match() {
+    let server = MockServer::start(vec![
+        MockResponse::new(304).header("ETag", "\"v1\""),
+        MockResponse::json(200, PET).header("ETag", "\"v2\""),
+    ]);
+    let client = blocking_client(TestClient::new(&server.url()));
+
+    let builder = Pet::get_pet().pet_id("1").if_none_match("\"v1\"");
+    match client.block_on(builder.send_if_modified(client.inner())) {
+        Ok(Conditional::NotModified { etag }) => assert_eq!(etag, "\"v1\""),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    match client.block_on(builder.send_if_modified(client.inner())) {
+        Ok(Conditional::Modified { value, etag }) => {
+            assert_eq!(value.name, "Doggie");
+            assert_eq!(etag.as_ref().map(String::as_str), Some("\"v2\""));
+        }
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    let requests = server.requests();
+    assert_eq!(requests[0].header("If-None-Match"), Some("\"v1\""));
+    assert_eq!(requests[1].header("If-None-Match"), Some("\"v1\""));
+}
+
+#[test]
+fn test_event_stream() {
+    let server = MockServer::start(vec![MockResponse::new(200)
+        .header("Content-Type", "text/event-stream")
+        .chunks(&[
+            ": greeting\r\n",
+            "event: note\r\nid: 1\r\ndata: {\"text\": \"hello\"}\r",
+            "\n\r\n",
+            "data: {\"text\":\r\ndata:  \"multi\"}\r\n\r\n",
+            "data: {\"text\": \"incomplete\"}",
+        ])]);
+    let client = blocking_client(TestClient::new(&server.url()));
+
+    let stream = client
+        .block_on(Message::stream_messages().send(client.inner()))
+        .expect("stream");
+    let events = client
+        .block_on(stream.into_stream().try_collect::<Vec<_>>())
+        .expect("events");
+    let summary = events
+        .iter()
+        .map(|e| {
+            (
+                e.event.as_ref().map(String::as_str),
+                e.id.as_ref().map(String::as_str),
+                e.data.text.as_ref().map(String::as_str),
+            )
+        })
+        .collect::<Vec<_>>();
+    // IDs are retained for the later events and incomplete events are discarded.
+    assert_eq!(
+        summary,
+        vec![
+            (Some("note"), Some("1"), Some("hello")),
+            (None, Some("1"), Some("multi")),
+        ]
+    );
+    assert_eq!(
+        server.requests()[0].header("Accept"),
+        Some("text/event-stream")
+    );
+}
+
+#[test]
+fn test_responses_by_status() {
+    let server = MockServer::start(vec![
+        MockResponse::json(201, PET),
+        MockResponse::new(202),
+        MockResponse::json(200, PET),
+    ]);
+    let client = blocking_client(TestClient::new(&server.url()));
+    let builder = Pet::add_pet().name("Doggie");
+
+    match client.block_on(builder.send(client.inner())) {
+        Ok(PetPostResponse::Created(pet)) => assert_eq!(pet.name, "Doggie"),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    match client.block_on(builder.send(client.inner())) {
+        Ok(PetPostResponse::Accepted) => (),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    match client.block_on(builder.send(client.inner())) {
+        Ok(PetPostResponse::Ok(pet)) => assert_eq!(pet.id, Some(1)),
+        r => panic!("unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn test_send_errors() {
+    let server = MockServer::start(vec![
+        MockResponse::new(404).body("no such pet"),
+        MockResponse::json(200, "{\"name\":"),
+    ]);
+    let client = blocking_client(TestClient::new(&server.url()));
+
+    let builder = Pet::get_pet().pet_id("1");
+    match client.block_on(builder.try_send(client.inner())) {
+        Err(SendError::Api { status, body }) => {
+            assert_eq!(status, 404);
+            assert_eq!(body, b"no such pet");
+        }
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    match client.block_on(builder.try_send(client.inner())) {
+        Err(SendError::Decode(ApiError::ApplicationJson(_))) => (),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    // Whitespace-only path parameters are rejected without sending the request.
+    let builder = Pet::get_pet().pet_id(" ");
+    match client.block_on(builder.try_send(client.inner())) {
+        Err(SendError::Validation(ApiError::EmptyPathParameter("petId"))) => (),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    assert_eq!(server.requests().len(), 2);
+
+    // Nothing's listening on this port once the listener is dropped.
+    let port = TcpListener::bind("127.0.0.1:0")
+        .and_then(|l| l.local_addr())
+        .expect("free port")
+        .port();
+    let client = blocking_client(TestClient::new(&format!("http://127.0.0.1:{}", port)));
+    let builder = Pet::get_pet().pet_id("1");
+    match client.block_on(builder.try_send(client.inner())) {
+        Err(SendError::Transport(ApiError::Reqwest(_))) => (),
+        r => panic!("unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn test_builders_borrow_client() {
+    let server = MockServer::start(vec![
+        MockResponse::json(200, PET),
+        MockResponse::json(201, PET),
+        MockResponse::new(304),
+    ]);
+    let client = blocking_client(TestClient::new(&server.url()));
+
+    client
+        .block_on(Pet::get_pet().pet_id("1").send(client.inner()))
+        .expect("pet");
+    client
+        .block_on(Pet::add_pet().name("Doggie").send(client.inner()))
+        .expect("pet");
+    let builder = Pet::get_pet().pet_id("1").if_none_match("\"v1\"");
+    client
+        .block_on(builder.send_if_modified(client.inner()))
+        .expect("pet");
+
+    // API calls are made through the borrowed client, so it's never cloned.
+    assert_eq!(server.requests().len(), 3);
+    assert_eq!(client.inner().clones(), 0);
+}
@@ -0,0 +1,109 @@
+use test_runtime::util::{Event, EventParser};
+
+fn parse(chunks: &[&str]) -> Vec<Event<String>> {
+    let mut parser = EventParser::default();
+    let mut events = vec![];
+    for chunk in chunks {
+        parser.feed(chunk.as_bytes());
+        while let Some(e) = parser.next_event() {
+            events.push(e);
+        }
+    }
+
+    events
+}
+
+fn event(data: &str) -> Event<String> {
+    Event {
+        event: None,
+        id: None,
+        retry: None,
+        data: data.into(),
+    }
+}
+
+#[test]
+fn test_multi_line_data() {
+    assert_eq!(
+        parse(&["data: first\ndata:second\ndata:  third\ndata\n\n"]),
+        vec![event("first\nsecond\n third\n")]
+    );
+}
+
+#[test]
+fn test_comments_are_ignored() {
+    assert_eq!(
+        parse(&[
+            ": keep-alive\n",
+            ":\ndata: foo\n: in between\n\n",
+            ": bye\n\n"
+        ]),
+        vec![event("foo")]
+    );
+}
+
+#[test]
+fn test_line_endings() {
+    let expected = vec![event("foo"), event("bar"), event("baz")];
+    assert_eq!(
+        parse(&["data: foo\r\n\r\ndata: bar\r\rdata: baz\n\n"]),
+        expected
+    );
+    // CRLF split across chunks is a single line break (and not an empty line).
+    assert_eq!(
+        parse(&[
+            "data: foo\r",
+            "\n\r",
+            "\ndata: bar\r",
+            "\r",
+            "data: baz\n",
+            "\n"
+        ]),
+        expected
+    );
+    assert_eq!(
+        parse(&["data: f", "oo\r\n", "data: bar\r", "\n\r\n"]),
+        vec![event("foo\nbar")]
+    );
+}
+
+#[test]
+fn test_fields() {
+    let events = parse(&[
+        "\u{feff}event: add\nid: 1\nretry: 3000\ndata: foo\n\n",
+        "data: bar\n\n",
+        "id\nretry: soon\nevent: remove\ndata: baz\n\n",
+    ]);
+    assert_eq!(
+        events,
+        vec![
+            Event {
+                event: Some("add".into()),
+                id: Some("1".into()),
+                retry: Some(3000),
+                data: "foo".into(),
+            },
+            // Event types are reset, but IDs and reconnection times are retained.
+            Event {
+                event: None,
+                id: Some("1".into()),
+                retry: Some(3000),
+                data: "bar".into(),
+            },
+            Event {
+                event: Some("remove".into()),
+                id: None,
+                retry: Some(3000),
+                data: "baz".into(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_events_without_data_are_skipped() {
+    assert_eq!(
+        parse(&["event: ping\n\n", "data\n\n", "data: foo"]),
+        vec![event("")]
+    );
+}
@@ -0,0 +1,310 @@
+//! Mock HTTP server (which responds with scripted responses) and an API client
+//! for sending requests to it.
+
+// Not every test uses all the helpers.
+#![allow(dead_code)]
+
+use test_runtime::client::{ApiClient, ApiError, RetryPolicy};
+
+use std::borrow::Cow;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// Request received by the mock server.
+#[derive(Debug, Clone)]
+pub struct Recorded {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Recorded {
+    /// Returns the value of the given header (if any).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Scripted response of the mock server.
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    chunks: Vec<Vec<u8>>,
+    streamed: bool,
+    delay: Duration,
+}
+
+impl MockResponse {
+    /// Creates an empty response with the given status code.
+    pub fn new(status: u16) -> Self {
+        MockResponse {
+            status,
+            headers: vec![],
+            chunks: vec![],
+            streamed: false,
+            delay: Duration::from_millis(0),
+        }
+    }
+
+    /// Creates a response with the given JSON body.
+    pub fn json(status: u16, body: &str) -> Self {
+        MockResponse::new(status)
+            .header("Content-Type", "application/json")
+            .body(body)
+    }
+
+    /// Adds the given header to this response.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the body of this response (along with its `Content-Length`).
+    pub fn body(mut self, body: &str) -> Self {
+        self.chunks = vec![body.as_bytes().to_vec()];
+        self
+    }
+
+    /// Streams the body in the given chunks (without `Content-Length`),
+    /// pausing briefly between them.
+    pub fn chunks(mut self, chunks: &[&str]) -> Self {
+        self.chunks = chunks.iter().map(|c| c.as_bytes().to_vec()).collect();
+        self.streamed = true;
+        self
+    }
+
+    /// Delays this response by the given duration.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    fn write_to(&self, mut stream: TcpStream) -> std::io::Result<()> {
+        let mut head = format!("HTTP/1.1 {} Mock\r\nConnection: close\r\n", self.status);
+        for (name, value) in &self.headers {
+            head.push_str(&format!("{}: {}\r\n", name, value));
+        }
+
+        if !self.streamed {
+            let len = self.chunks.iter().map(Vec::len).sum::<usize>();
+            head.push_str(&format!("Content-Length: {}\r\n", len));
+        }
+
+        head.push_str("\r\n");
+        stream.write_all(head.as_bytes())?;
+        for chunk in &self.chunks {
+            stream.write_all(chunk)?;
+            stream.flush()?;
+            if self.streamed {
+                thread::sleep(Duration::from_millis(20));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// HTTP server listening on a local port, which responds to the incoming
+/// requests (one per connection) with the given responses in order. It stops
+/// listening once it runs out of responses.
+pub struct MockServer {
+    addr: SocketAddr,
+    requests: Arc<Mutex<Vec<Recorded>>>,
+}
+
+impl MockServer {
+    /// Starts a server with the given responses.
+    pub fn start(responses: Vec<MockResponse>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("binding mock server");
+        let addr = listener.local_addr().expect("mock server address");
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = requests.clone();
+        thread::spawn(move || {
+            for resp in responses {
+                let stream = match listener.accept() {
+                    Ok((s, _)) => s,
+                    Err(_) => return,
+                };
+
+                let req = match read_request(&stream) {
+                    Ok(r) => r,
+                    Err(_) => continue,
+                };
+
+                recorded.lock().unwrap().push(req);
+                thread::sleep(resp.delay);
+                // The client may have given up (say, because of timeouts).
+                let _ = resp.write_to(stream);
+            }
+        });
+
+        MockServer { addr, requests }
+    }
+
+    /// Base URL of this server.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Port on which this server is listening.
+    pub fn port(&self) -> u16 {
+        self.addr.port()
+    }
+
+    /// Requests received by this server so far.
+    pub fn requests(&self) -> Vec<Recorded> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn read_request(stream: &TcpStream) -> std::io::Result<Recorded> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_owned();
+    let path = parts.next().unwrap_or_default().to_owned();
+
+    let mut headers = vec![];
+    loop {
+        line.clear();
+        reader.read_line(&mut line)?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+
+        if let Some(i) = line.find(':') {
+            headers.push((line[..i].to_owned(), line[i + 1..].trim().to_owned()));
+        }
+    }
+
+    let mut req = Recorded {
+        method,
+        path,
+        headers,
+        body: vec![],
+    };
+
+    let len = req
+        .header("Content-Length")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0);
+    req.body = vec![0; len];
+    reader.read_exact(&mut req.body)?;
+    Ok(req)
+}
+
+/// API client for sending requests to the mock server, which records the
+/// durations it has waited for and counts the number of times it's cloned.
+#[derive(Debug)]
+pub struct TestClient {
+    inner: reqwest::r#async::Client,
+    base_url: String,
+    retry_policy: RetryPolicy,
+    timeout: Option<Duration>,
+    sleeps: Arc<Mutex<Vec<Duration>>>,
+    clones: Arc<AtomicUsize>,
+}
+
+impl TestClient {
+    /// Creates a client for the given base URL, which doesn't retry requests
+    /// (or time out) by default.
+    pub fn new(base_url: &str) -> Self {
+        TestClient {
+            inner: reqwest::r#async::Client::builder()
+                .redirect(reqwest::RedirectPolicy::none())
+                .build()
+                .expect("building client"),
+            base_url: base_url.into(),
+            retry_policy: RetryPolicy::none(),
+            timeout: None,
+            sleeps: Arc::new(Mutex::new(vec![])),
+            clones: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Sets the retry policy for this client.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Sets the timeout for this client.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Durations this client has waited for (for retries and timeouts).
+    pub fn sleeps(&self) -> Vec<Duration> {
+        self.sleeps.lock().unwrap().clone()
+    }
+
+    /// Number of times this client has been cloned.
+    pub fn clones(&self) -> usize {
+        self.clones.load(Ordering::SeqCst)
+    }
+}
+
+impl Clone for TestClient {
+    fn clone(&self) -> Self {
+        self.clones.fetch_add(1, Ordering::SeqCst);
+        TestClient {
+            inner: self.inner.clone(),
+            base_url: self.base_url.clone(),
+            retry_policy: self.retry_policy.clone(),
+            timeout: self.timeout,
+            sleeps: self.sleeps.clone(),
+            clones: self.clones.clone(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ApiClient for TestClient {
+    type Request = reqwest::r#async::RequestBuilder;
+    type Response = reqwest::r#async::Response;
+
+    fn request_builder(&self, method: http::Method, rel_path: &str) -> Self::Request {
+        self.inner
+            .request(method, &format!("{}{}", self.base_url, rel_path))
+    }
+
+    fn request_builder_for_url(&self, method: http::Method, url: &str) -> Option<Self::Request> {
+        Some(self.inner.request(method, url))
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.retry_policy.clone()
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        self.sleeps.lock().unwrap().push(duration);
+        let _ = futures_timer::Delay::new(duration).await;
+    }
+
+    fn base_url(&self) -> Cow<'_, str> {
+        self.base_url.as_str().into()
+    }
+
+    async fn make_request(
+        &self,
+        req: Self::Request,
+    ) -> Result<Self::Response, ApiError<Self::Response>> {
+        self.inner.make_request(req).await
+    }
+}
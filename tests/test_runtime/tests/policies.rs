@@ -0,0 +1,230 @@
+use http::{Method, StatusCode};
+use test_runtime::client::{ApiError, RedirectPolicy, Response, RetryPolicy};
+use test_runtime::util::ResponseStream;
+
+use std::time::Duration;
+
+/// Response with the given status code and headers (and without a body).
+#[derive(Debug)]
+struct FakeResponse {
+    status: StatusCode,
+    headers: Vec<(&'static str, &'static str)>,
+    url: Option<&'static str>,
+}
+
+impl FakeResponse {
+    fn new(status: u16) -> Self {
+        FakeResponse {
+            status: StatusCode::from_u16(status).expect("status"),
+            headers: vec![],
+            url: Some("http://example.com/api/pets"),
+        }
+    }
+
+    fn header(mut self, name: &'static str, value: &'static str) -> Self {
+        self.headers.push((name, value));
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Response for FakeResponse {
+    type Bytes = Vec<u8>;
+    type Stream = ();
+
+    fn header(&self, name: &'static str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| *v)
+    }
+
+    fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    fn media_type(&self) -> Option<mime::MediaType> {
+        None
+    }
+
+    fn stream(&mut self) -> ResponseStream<Self::Stream> {
+        ().into()
+    }
+
+    fn url(&self) -> Option<&str> {
+        self.url
+    }
+
+    async fn body_bytes(self) -> Result<(Self, Self::Bytes), ApiError<Self>> {
+        Ok((self, vec![]))
+    }
+}
+
+fn retry_policy() -> RetryPolicy {
+    RetryPolicy {
+        max_retries: 3,
+        initial_backoff: Duration::from_millis(100),
+        max_backoff: Duration::from_secs(1),
+        ..RetryPolicy::default()
+    }
+}
+
+type Attempt = Result<FakeResponse, ApiError<FakeResponse>>;
+
+fn ok(resp: FakeResponse) -> Attempt {
+    Ok(resp)
+}
+
+#[test]
+fn test_retry_backoff() {
+    let policy = retry_policy();
+    let delays = (0..4)
+        .map(|i| policy.retry_delay(&Method::GET, i, &ok(FakeResponse::new(503))))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        delays,
+        vec![
+            Some(Duration::from_millis(100)),
+            Some(Duration::from_millis(200)),
+            Some(Duration::from_millis(400)),
+            None,
+        ]
+    );
+
+    // Backoff is capped.
+    let policy = RetryPolicy {
+        max_retries: 10,
+        ..retry_policy()
+    };
+    let result = ok(FakeResponse::new(503));
+    assert_eq!(
+        policy.retry_delay(&Method::GET, 5, &result),
+        Some(Duration::from_secs(1))
+    );
+    assert_eq!(
+        policy.retry_delay(&Method::GET, 9, &result),
+        Some(Duration::from_secs(1))
+    );
+}
+
+#[test]
+fn test_retry_after() {
+    let policy = retry_policy();
+    let result = ok(FakeResponse::new(429).header("Retry-After", " 0 "));
+    assert_eq!(
+        policy.retry_delay(&Method::GET, 2, &result),
+        Some(Duration::from_secs(0))
+    );
+    // Delays from the header are capped too.
+    let result = ok(FakeResponse::new(503).header("Retry-After", "120"));
+    assert_eq!(
+        policy.retry_delay(&Method::GET, 0, &result),
+        Some(Duration::from_secs(1))
+    );
+    // HTTP dates aren't supported, so the backoff is used instead.
+    let result = ok(FakeResponse::new(503).header("Retry-After", "Wed, 21 Oct 2015 07:28:00 GMT"));
+    assert_eq!(
+        policy.retry_delay(&Method::GET, 0, &result),
+        Some(Duration::from_millis(100))
+    );
+}
+
+#[test]
+fn test_no_retries() {
+    let policy = retry_policy();
+    // Other statuses and non-idempotent methods aren't retried.
+    assert_eq!(
+        policy.retry_delay(&Method::GET, 0, &ok(FakeResponse::new(500))),
+        None
+    );
+    assert_eq!(
+        policy.retry_delay(&Method::GET, 0, &ok(FakeResponse::new(200))),
+        None
+    );
+    assert_eq!(
+        policy.retry_delay(&Method::POST, 0, &ok(FakeResponse::new(503))),
+        None
+    );
+    assert_eq!(
+        RetryPolicy::none().retry_delay(&Method::GET, 0, &ok(FakeResponse::new(503))),
+        None
+    );
+}
+
+#[test]
+fn test_retry_errors() {
+    let policy = retry_policy();
+    let timeout: Attempt = Err(ApiError::Timeout(Duration::from_secs(1)));
+    assert_eq!(
+        policy.retry_delay(&Method::GET, 1, &timeout),
+        Some(Duration::from_millis(200))
+    );
+    let io: Attempt = Err(ApiError::Io(std::io::ErrorKind::ConnectionReset.into()));
+    assert_eq!(
+        policy.retry_delay(&Method::PUT, 0, &io),
+        Some(Duration::from_millis(100))
+    );
+    // Other errors are never retried.
+    let empty: Attempt = Err(ApiError::EmptyPathParameter("id"));
+    assert_eq!(policy.retry_delay(&Method::GET, 0, &empty), None);
+
+    let policy = RetryPolicy {
+        retry_errors: false,
+        ..retry_policy()
+    };
+    assert_eq!(policy.retry_delay(&Method::GET, 0, &timeout), None);
+}
+
+#[test]
+fn test_redirect_url() {
+    let policy = RedirectPolicy::default();
+    let url = |resp: FakeResponse| policy.redirect_url(&resp);
+
+    // Relative locations are resolved against the response's URL.
+    assert_eq!(
+        url(FakeResponse::new(302).header("Location", "/other")),
+        Some(("http://example.com/other".into(), true))
+    );
+    assert_eq!(
+        url(FakeResponse::new(307).header("Location", "moved?id=1")),
+        Some(("http://example.com/api/moved?id=1".into(), true))
+    );
+    // Other schemes, hosts and ports are different origins.
+    for location in &[
+        "https://example.com/api/pets",
+        "http://api.example.com/pets",
+        "http://example.com:8080/api/pets",
+    ] {
+        let (u, same_origin) =
+            url(FakeResponse::new(308).header("Location", location)).expect("redirect URL");
+        assert_eq!(u, *location);
+        assert!(!same_origin);
+    }
+
+    // Without the response's URL, only absolute locations can be followed
+    // (and their origins can't be compared).
+    let mut resp = FakeResponse::new(301).header("Location", "http://example.com/api/pets");
+    resp.url = None;
+    assert_eq!(
+        url(resp),
+        Some(("http://example.com/api/pets".into(), false))
+    );
+    let mut resp = FakeResponse::new(301).header("Location", "/pets");
+    resp.url = None;
+    assert_eq!(url(resp), None);
+}
+
+#[test]
+fn test_not_redirects() {
+    let policy = RedirectPolicy::default();
+    // Not modified and multiple choices aren't redirects which can be followed.
+    for status in &[200, 300, 304, 404] {
+        let resp = FakeResponse::new(*status).header("Location", "/other");
+        assert_eq!(policy.redirect_url(&resp), None);
+    }
+
+    // Redirects without (valid) locations can't be followed either.
+    assert_eq!(policy.redirect_url(&FakeResponse::new(302)), None);
+    let resp = FakeResponse::new(302).header("Location", "http://[::1");
+    assert_eq!(policy.redirect_url(&resp), None);
+}
@@ -0,0 +1,57 @@
+use test_time::event::Event;
+use time::{format_description::well_known::Rfc3339, Date, Month, OffsetDateTime};
+
+fn date_time(value: &str) -> OffsetDateTime {
+    OffsetDateTime::parse(value, &Rfc3339).expect("date-time")
+}
+
+fn day() -> Date {
+    Date::from_calendar_date(2019, Month::October, 24).expect("date")
+}
+
+#[test]
+fn test_event_round_trip() {
+    let json = r#"{"day":"2019-10-24","endsAt":"2019-10-24T18:30:00Z","reminders":["2019-10-24T08:00:00+05:30"],"startsAt":"2019-10-24T09:00:00.5+05:30"}"#;
+    let event: Event = serde_json::from_str(json).expect("deserializing event");
+    assert_eq!(event.day, day());
+    assert_eq!(event.ends_at, Some(date_time("2019-10-24T18:30:00Z")));
+    assert_eq!(event.starts_at, date_time("2019-10-24T09:00:00.5+05:30"));
+    // Arrays of date-times stay as strings.
+    assert_eq!(
+        event.reminders,
+        Some(vec!["2019-10-24T08:00:00+05:30".to_owned()])
+    );
+    assert_eq!(
+        serde_json::to_string(&event).expect("serializing event"),
+        json
+    );
+}
+
+#[test]
+fn test_event_missing_optional_date_time() {
+    let json = r#"{"day":"2019-10-24","startsAt":"2019-10-24T09:00:00Z"}"#;
+    let event: Event = serde_json::from_str(json).expect("deserializing event");
+    assert_eq!(event.ends_at, None);
+    assert_eq!(
+        serde_json::to_value(&event).expect("serializing event"),
+        serde_json::json!({
+            "day": "2019-10-24",
+            "endsAt": null,
+            "reminders": null,
+            "startsAt": "2019-10-24T09:00:00Z",
+        })
+    );
+}
+
+#[test]
+fn test_event_invalid_date_time() {
+    let json = r#"{"day":"2019-10-24","startsAt":"2019-10-24 09:00"}"#;
+    assert!(serde_json::from_str::<Event>(json).is_err());
+}
+
+#[test]
+fn test_event_defaults_to_epoch() {
+    let event = Event::default();
+    assert_eq!(event.day, OffsetDateTime::UNIX_EPOCH.date());
+    assert_eq!(event.starts_at, date_time("1970-01-01T00:00:00Z"));
+}